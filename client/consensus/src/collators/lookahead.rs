@@ -51,7 +51,7 @@ use {
     cumulus_relay_chain_interface::RelayChainInterface,
     futures::{channel::oneshot, prelude::*},
     nimbus_primitives::NimbusId,
-    pallet_xcm_core_buyer_runtime_api::{BuyingError, XCMCoreBuyerApi},
+    pallet_xcm_core_buyer_runtime_api::{BuyCoreDryRunError, BuyingError, XCMCoreBuyerApi},
     parity_scale_codec::{Codec, Encode},
     polkadot_node_primitives::SubmitCollationParams,
     polkadot_node_subsystem::messages::{
@@ -63,7 +63,7 @@ use {
     sc_consensus::BlockImport,
     sc_consensus_slots::InherentDataProviderExt,
     sc_transaction_pool_api::TransactionPool,
-    sp_api::{ApiError, ProvideRuntimeApi},
+    sp_api::{ApiError, ApiExt, ProvideRuntimeApi},
     sp_blockchain::{HeaderBackend, HeaderMetadata},
     sp_consensus::SyncOracle,
     sp_consensus_aura::{Slot, SlotDuration},
@@ -90,6 +90,7 @@ pub enum BuyCoreError<BlockNumber: std::fmt::Debug, PoolError: std::fmt::Debug>
     ApiError(ApiError),
     BuyingValidationError(BuyingError<BlockNumber>),
     UnableToCreateProof(BuyCollatorProofCreationError),
+    DryRunError(BuyCoreDryRunError),
     TxSubmissionError(PoolError),
 }
 
@@ -169,6 +170,16 @@ impl<BlockNumber: std::fmt::Debug, PoolError: std::fmt::Debug>
                     "Unable to generate buy core proof due to an error",
                 );
             }
+            BuyCoreError::DryRunError(dry_run_error) => {
+                tracing::error!(
+                    target: crate::LOG_TARGET,
+                    ?relay_parent,
+                    ?para_id,
+                    ?slot,
+                    ?dry_run_error,
+                    "Buy core extrinsic failed when dry running it, skipping submission",
+                );
+            }
             BuyCoreError::TxSubmissionError(pool_error) => {
                 tracing::error!(
                     target: crate::LOG_TARGET,
@@ -199,6 +210,14 @@ impl<BlockNumber: std::fmt::Debug, PoolError: std::fmt::Debug> From<ApiError>
     }
 }
 
+impl<BlockNumber: std::fmt::Debug, PoolError: std::fmt::Debug> From<BuyCoreDryRunError>
+    for BuyCoreError<BlockNumber, PoolError>
+{
+    fn from(dry_run_error: BuyCoreDryRunError) -> Self {
+        BuyCoreError::DryRunError(dry_run_error)
+    }
+}
+
 impl<BlockNumber: std::fmt::Debug, PoolError: std::fmt::Debug> From<BuyCollatorProofCreationError>
     for BuyCoreError<BlockNumber, PoolError>
 {
@@ -296,6 +315,25 @@ where
         BuyCoreCollatorProof::new_with_keystore(nonce, para_id, pubkey, keystore)?
             .ok_or(BuyCoreError::UnableToFindKeyForSigning)?;
 
+    // Dry run the purchase first, so that we don't submit an extrinsic that will fail, or an XCM
+    // message that cannot be sent. Orchestrator runtimes before version 2 of the API cannot dry run
+    // it, in that case the extrinsic is submitted directly.
+    let dry_run_available = orchestrator_runtime_api
+        .api_version::<dyn XCMCoreBuyerApi<
+            OBlock,
+            <<OBlock as BlockT>::Header as HeaderT>::Number,
+            ParaId,
+            NimbusId,
+        >>(orchestrator_best_hash)?
+        .map_or(false, |version| version >= 2);
+    if dry_run_available {
+        orchestrator_runtime_api.dry_run_buy_core(
+            orchestrator_best_hash,
+            para_id,
+            collator_buy_core_proof.clone(),
+        )??;
+    }
+
     let extrinsic = orchestrator_runtime_api.create_buy_core_unsigned_extrinsic(
         orchestrator_best_hash,
         para_id,
@@ -15,12 +15,20 @@
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
 
 use {
+    dp_container_chain_genesis_data::{
+        json::container_chain_genesis_data_from_str, ContainerChainGenesisData,
+    },
     parity_scale_codec::Encode,
     sc_chain_spec::{construct_genesis_block, ChainSpec},
+    sc_cli::{CliConfiguration, SharedParams},
+    sc_client_api::{Backend, StorageProvider},
+    sp_blockchain::HeaderBackend,
+    sp_core::hexdisplay::HexDisplay,
     sp_runtime::{
-        traits::{Block as BlockT, Hash as HashT, Header as HeaderT},
+        traits::{Block as BlockT, Hash as HashT, Header as HeaderT, Zero},
         StateVersion,
     },
+    std::{fs, io::Write, path::PathBuf},
 };
 
 /// Generate the genesis block from a given ChainSpec.
@@ -44,3 +52,125 @@ pub fn generate_genesis_block<Block: BlockT>(
 
     Ok(construct_genesis_block(state_root, genesis_state_version))
 }
+
+/// The `export-genesis-data` command used to export the `ContainerChainGenesisData` needed to
+/// register a container chain in the orchestrator.
+#[derive(Debug, clap::Parser)]
+pub struct ExportGenesisDataCmd {
+    /// Output file name or stdout if unspecified.
+    #[arg()]
+    pub output: Option<PathBuf>,
+
+    /// Read the genesis state from the database of this node instead of building it from the
+    /// chain spec. The genesis state must not have been pruned.
+    #[arg(long)]
+    pub from_db: bool,
+
+    /// Write the genesis data as JSON instead of hex encoded SCALE.
+    #[arg(long, conflicts_with = "raw")]
+    pub json: bool,
+
+    /// Write the SCALE encoded genesis data as binary instead of hex.
+    #[arg(short, long)]
+    pub raw: bool,
+
+    /// Max encoded size of the genesis data, as set in `MaxGenesisDataSize` of the orchestrator.
+    #[arg(long, default_value_t = tp_traits::MAX_ENCODED_GENESIS_DATA_SIZE)]
+    pub max_size: u32,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub shared_params: SharedParams,
+}
+
+impl ExportGenesisDataCmd {
+    /// Export the genesis data of `chain_spec`. If `genesis_storage` is provided, it replaces the
+    /// storage built from the chain spec.
+    pub fn run(
+        &self,
+        chain_spec: &dyn ChainSpec,
+        genesis_storage: Option<Vec<(Vec<u8>, Vec<u8>)>>,
+    ) -> sc_cli::Result<()> {
+        let mut genesis_data = container_chain_genesis_data_from_chain_spec(chain_spec)?;
+        if let Some(storage) = genesis_storage {
+            genesis_data.storage = storage.into_iter().map(Into::into).collect();
+        }
+        check_genesis_data_size(&genesis_data, self.max_size)?;
+
+        let output_buf = if self.json {
+            serde_json::to_vec_pretty(&genesis_data)
+                .map_err(|e| format!("Failed to serialize genesis data: {}", e))?
+        } else if self.raw {
+            genesis_data.encode()
+        } else {
+            format!("0x{:?}", HexDisplay::from(&genesis_data.encode())).into_bytes()
+        };
+
+        if let Some(output) = &self.output {
+            fs::write(output, output_buf)?;
+        } else {
+            std::io::stdout().write_all(&output_buf)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl CliConfiguration for ExportGenesisDataCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+}
+
+/// Build the `ContainerChainGenesisData` of a container chain from its chain spec, which does not
+/// need to be raw.
+pub fn container_chain_genesis_data_from_chain_spec(
+    chain_spec: &dyn ChainSpec,
+) -> Result<ContainerChainGenesisData, String> {
+    let raw_chain_spec = chain_spec.as_json(true)?;
+    let (_para_id, genesis_data, _boot_nodes) =
+        container_chain_genesis_data_from_str(&raw_chain_spec)?;
+
+    Ok(genesis_data)
+}
+
+/// Check that the genesis data is small enough to be registered in the orchestrator.
+pub fn check_genesis_data_size(
+    genesis_data: &ContainerChainGenesisData,
+    max_size: u32,
+) -> Result<(), String> {
+    let size = genesis_data.encoded_size();
+    if size > max_size as usize {
+        return Err(format!(
+            "Genesis data is too large to be registered: {} bytes (limit is {})",
+            size, max_size
+        ));
+    }
+
+    Ok(())
+}
+
+/// Read the genesis state of a running chain from the database of the node.
+pub fn genesis_storage_from_client<Block, B, C>(
+    client: &C,
+) -> Result<Vec<(Vec<u8>, Vec<u8>)>, String>
+where
+    Block: BlockT,
+    B: Backend<Block>,
+    C: StorageProvider<Block, B> + HeaderBackend<Block>,
+{
+    let genesis_hash = client
+        .hash(Zero::zero())
+        .map_err(|e| format!("Failed to read genesis hash: {}", e))?
+        .ok_or_else(|| "Genesis block not found in the database".to_string())?;
+    let pairs = client
+        .storage_pairs(genesis_hash, None, None)
+        .map_err(|e| {
+            format!(
+                "Failed to read genesis state, it may have been pruned (see `--state-pruning`): {}",
+                e
+            )
+        })?;
+
+    Ok(pairs.map(|(key, value)| (key.0, value.0)).collect())
+}
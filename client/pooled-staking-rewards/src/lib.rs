@@ -0,0 +1,248 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Reward history of the Pooled Staking pallet.
+//!
+//! The node can optionally index the rewards accrued by each delegator in every
+//! finalized block, aggregated per session and candidate, and serve them through
+//! an RPC. The index is kept in the auxiliary storage of the client.
+
+mod store;
+
+use {
+    futures::StreamExt,
+    jsonrpsee::{
+        core::{async_trait, RpcResult},
+        proc_macros::rpc,
+    },
+    sc_client_api::{AuxStore, BlockchainEvents},
+    serde::{Deserialize, Serialize},
+    sp_api::{ApiExt, ProvideRuntimeApi},
+    sp_blockchain::{Error as ClientError, HeaderBackend},
+    sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor, One, Saturating},
+    std::sync::Arc,
+};
+pub use {
+    pallet_pooled_staking_runtime_api::PooledStakingApi as PooledStakingRuntimeApi,
+    store::{CandidateRewards, RewardHistory, SessionIndex, SessionRewards},
+};
+
+const LOG_TARGET: &str = "reward-history";
+
+/// Maximum number of sessions read by a single call to the RPC.
+pub const MAX_SESSIONS_PER_PAGE: u32 = 100;
+
+/// Maximum number of blocks indexed before yielding back to the executor, so that
+/// catching up after a downtime doesn't starve other tasks.
+const MAX_BLOCKS_PER_BATCH: u32 = 100;
+
+/// A page of the reward history of a delegator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RewardHistoryPage<AccountId, Balance> {
+    /// Rewards of each session of the page in which the delegator earned or claimed
+    /// rewards.
+    pub sessions: Vec<SessionRewards<AccountId, Balance>>,
+    /// First session of the next page, if the requested range has not been fully read.
+    pub next_session: Option<SessionIndex>,
+}
+
+#[rpc(server)]
+pub trait PooledStakingRewardsApi<AccountId, Balance> {
+    /// Rewards of `delegator` from `from_session` to `to_session` (included). At most
+    /// `MAX_SESSIONS_PER_PAGE` sessions are read, the remaining ones can be queried
+    /// starting from `next_session`.
+    #[method(name = "tanssi_pooledStakingRewardHistory")]
+    async fn reward_history(
+        &self,
+        delegator: AccountId,
+        from_session: SessionIndex,
+        to_session: SessionIndex,
+    ) -> RpcResult<RewardHistoryPage<AccountId, Balance>>;
+}
+
+pub struct PooledStakingRewards<Client, AccountId, Balance> {
+    history: RewardHistory<Client, AccountId, Balance>,
+}
+
+impl<Client, AccountId, Balance> PooledStakingRewards<Client, AccountId, Balance>
+where
+    Client: AuxStore,
+    AccountId: parity_scale_codec::Codec + Clone + Ord,
+    Balance: parity_scale_codec::Codec + Copy + Default + Saturating,
+{
+    pub fn new(client: Arc<Client>) -> Self {
+        Self {
+            history: RewardHistory::new(client),
+        }
+    }
+}
+
+#[async_trait]
+impl<Client, AccountId, Balance> PooledStakingRewardsApiServer<AccountId, Balance>
+    for PooledStakingRewards<Client, AccountId, Balance>
+where
+    Client: AuxStore + Sync + Send + 'static,
+    AccountId: parity_scale_codec::Codec + Clone + Ord + Serialize + Send + Sync + 'static,
+    Balance:
+        parity_scale_codec::Codec + Copy + Default + Saturating + Serialize + Send + Sync + 'static,
+{
+    async fn reward_history(
+        &self,
+        delegator: AccountId,
+        from_session: SessionIndex,
+        to_session: SessionIndex,
+    ) -> RpcResult<RewardHistoryPage<AccountId, Balance>> {
+        let oldest_session = self.history.oldest_session().map_err(internal_err)?;
+        let from_session = from_session.max(oldest_session.unwrap_or(from_session));
+        let last_session = to_session.min(from_session.saturating_add(MAX_SESSIONS_PER_PAGE - 1));
+
+        let mut sessions = vec![];
+        for session in from_session..=last_session {
+            if let Some(rewards) = self
+                .history
+                .session_rewards(&delegator, session)
+                .map_err(internal_err)?
+            {
+                sessions.push(SessionRewards { session, rewards });
+            }
+        }
+
+        Ok(RewardHistoryPage {
+            sessions,
+            next_session: (last_session < to_session).then_some(last_session + 1),
+        })
+    }
+}
+
+/// Index the rewards of each finalized block, keeping the last `retained_sessions`
+/// sessions. Blocks finalized while the node was stopped are indexed on the next
+/// finality notification, in batches of `MAX_BLOCKS_PER_BATCH` blocks.
+pub async fn run_reward_history_indexer<Block, Client, AccountId, Balance>(
+    client: Arc<Client>,
+    retained_sessions: u32,
+) where
+    Block: BlockT,
+    Client: BlockchainEvents<Block>
+        + HeaderBackend<Block>
+        + ProvideRuntimeApi<Block>
+        + AuxStore
+        + Send
+        + Sync
+        + 'static,
+    Client::Api: PooledStakingRuntimeApi<Block, AccountId, Balance>,
+    AccountId: parity_scale_codec::Codec + Clone + Ord,
+    Balance: parity_scale_codec::Codec + Copy + Default + Saturating,
+{
+    let history = RewardHistory::<_, AccountId, Balance>::new(client.clone());
+    let mut finality_notifications = client.finality_notification_stream();
+
+    while let Some(notification) = finality_notifications.next().await {
+        let finalized = *notification.header.number();
+
+        loop {
+            match index_until(&*client, &history, finalized, retained_sessions) {
+                Ok(true) => break,
+                Ok(false) => tokio::task::yield_now().await,
+                Err(e) => {
+                    log::error!(
+                        target: LOG_TARGET,
+                        "Failed to index rewards until block {}: {}",
+                        finalized,
+                        e
+                    );
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Index at most `MAX_BLOCKS_PER_BATCH` blocks up to `finalized`, returning whether
+/// `finalized` has been reached.
+
+fn index_until<Block, Client, AccountId, Balance>(
+    client: &Client,
+    history: &RewardHistory<Client, AccountId, Balance>,
+    finalized: NumberFor<Block>,
+    retained_sessions: u32,
+) -> Result<bool, ClientError>
+where
+    Block: BlockT,
+    Client: HeaderBackend<Block> + ProvideRuntimeApi<Block> + AuxStore,
+    Client::Api: PooledStakingRuntimeApi<Block, AccountId, Balance>,
+    AccountId: parity_scale_codec::Codec + Clone + Ord,
+    Balance: parity_scale_codec::Codec + Copy + Default + Saturating,
+{
+    let mut number = match history.last_indexed_block::<NumberFor<Block>>()? {
+        Some(last) => last + One::one(),
+        None => finalized,
+    };
+
+    for _ in 0..MAX_BLOCKS_PER_BATCH {
+        if number > finalized {
+            return Ok(true);
+        }
+
+        let hash = client
+            .hash(number)?
+            .ok_or_else(|| ClientError::UnknownBlock(format!("{}", number)))?;
+
+        // Blocks whose state has been pruned or whose runtime doesn't expose the rewards
+        // are skipped, as retrying them would never succeed. Other runtime API errors are
+        // returned so the block is retried on the next finality notification.
+        let api = client.runtime_api();
+        let has_api = api
+            .has_api::<dyn PooledStakingRuntimeApi<Block, AccountId, Balance>>(hash)
+            .unwrap_or(false);
+        if !has_api {
+            log::warn!(
+                target: LOG_TARGET,
+                "Skipping rewards of block {}: state or runtime API unavailable",
+                number
+            );
+            history.skip_block(number)?;
+        } else {
+            match api.block_reward_accruals(hash)? {
+                Ok((session, accruals)) => {
+                    history.index_block(number, session, accruals, retained_sessions)?
+                }
+                // Computing the rewards of this block will always fail.
+                Err(e) => {
+                    log::error!(
+                        target: LOG_TARGET,
+                        "Skipping rewards of block {}: failed to compute them: {:?}",
+                        number,
+                        e
+                    );
+                    history.skip_block(number)?
+                }
+            }
+        }
+
+        number += One::one();
+    }
+
+    Ok(number > finalized)
+}
+
+pub fn internal_err<T: ToString>(message: T) -> jsonrpsee::types::ErrorObjectOwned {
+    jsonrpsee::types::error::ErrorObject::borrowed(
+        jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+        &message.to_string(),
+        None,
+    )
+    .into_owned()
+}
@@ -0,0 +1,350 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Reward history stored in the auxiliary storage of the client.
+//!
+//! Rewards are aggregated per delegator, session and candidate. Only the last
+//! `retained_sessions` sessions are kept, older ones are pruned as new sessions
+//! are indexed, which bounds the disk footprint of the history.
+
+use {
+    core::marker::PhantomData,
+    pallet_pooled_staking_runtime_api::RewardAccrual,
+    parity_scale_codec::{Decode, Encode},
+    sc_client_api::AuxStore,
+    serde::{Deserialize, Serialize},
+    sp_blockchain::{Error as ClientError, Result as ClientResult},
+    sp_runtime::traits::Saturating,
+    std::{collections::BTreeMap, sync::Arc},
+};
+
+pub type SessionIndex = u32;
+
+const REWARD_HISTORY_ACCRUALS: &[u8] = b"tanssi_reward_history_accruals";
+const REWARD_HISTORY_DELEGATORS: &[u8] = b"tanssi_reward_history_delegators";
+const REWARD_HISTORY_OLDEST_SESSION: &[u8] = b"tanssi_reward_history_oldest_session";
+const REWARD_HISTORY_LAST_BLOCK: &[u8] = b"tanssi_reward_history_last_block";
+
+/// Rewards of a delegator from a candidate during a session.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub struct CandidateRewards<AccountId, Balance> {
+    pub candidate: AccountId,
+    pub auto_compounding_rewards: Balance,
+    pub manual_claim_rewards: Balance,
+    pub claimed_manual_rewards: Balance,
+}
+
+/// Rewards of a delegator during a session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionRewards<AccountId, Balance> {
+    pub session: SessionIndex,
+    pub rewards: Vec<CandidateRewards<AccountId, Balance>>,
+}
+
+fn accruals_key<AccountId: Encode>(delegator: &AccountId, session: SessionIndex) -> Vec<u8> {
+    (REWARD_HISTORY_ACCRUALS, delegator, session).encode()
+}
+
+fn delegators_key(session: SessionIndex) -> Vec<u8> {
+    (REWARD_HISTORY_DELEGATORS, session).encode()
+}
+
+fn load_decode<Backend: AuxStore, T: Decode>(
+    backend: &Backend,
+    key: &[u8],
+) -> ClientResult<Option<T>> {
+    match backend.get_aux(key)? {
+        None => Ok(None),
+        Some(bytes) => T::decode(&mut &bytes[..])
+            .map(Some)
+            .map_err(|e| ClientError::Backend(format!("Reward history DB is corrupted: {}", e))),
+    }
+}
+
+/// Reward history of delegators, indexed block by block.
+pub struct RewardHistory<Backend, AccountId, Balance> {
+    backend: Arc<Backend>,
+    _phantom: PhantomData<(AccountId, Balance)>,
+}
+
+impl<Backend, AccountId, Balance> RewardHistory<Backend, AccountId, Balance>
+where
+    Backend: AuxStore,
+    AccountId: Encode + Decode + Clone + Ord,
+    Balance: Encode + Decode + Copy + Default + Saturating,
+{
+    pub fn new(backend: Arc<Backend>) -> Self {
+        Self {
+            backend,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Rewards of `delegator` during `session`, if any.
+    pub fn session_rewards(
+        &self,
+        delegator: &AccountId,
+        session: SessionIndex,
+    ) -> ClientResult<Option<Vec<CandidateRewards<AccountId, Balance>>>> {
+        load_decode(&*self.backend, &accruals_key(delegator, session))
+    }
+
+    /// Oldest session still in the history, if any session has been indexed.
+    pub fn oldest_session(&self) -> ClientResult<Option<SessionIndex>> {
+        load_decode(&*self.backend, REWARD_HISTORY_OLDEST_SESSION)
+    }
+
+    /// Number of the last indexed block, if any.
+    pub fn last_indexed_block<Number: Decode>(&self) -> ClientResult<Option<Number>> {
+        load_decode(&*self.backend, REWARD_HISTORY_LAST_BLOCK)
+    }
+
+    /// Mark a block as indexed without adding rewards, for blocks in which the rewards
+    /// can't be retrieved.
+    pub fn skip_block<Number: Encode>(&self, number: Number) -> ClientResult<()> {
+        self.backend
+            .insert_aux(&[(REWARD_HISTORY_LAST_BLOCK, &number.encode()[..])], &[])
+    }
+
+    /// Add the rewards of a block to the history and prune the sessions older than the
+    /// last `retained_sessions` sessions. All changes are written atomically.
+    pub fn index_block<Number: Encode>(
+        &self,
+        number: Number,
+        session: SessionIndex,
+        accruals: Vec<RewardAccrual<AccountId, Balance>>,
+        retained_sessions: u32,
+    ) -> ClientResult<()> {
+        let mut inserts: Vec<(Vec<u8>, Vec<u8>)> = vec![];
+        let mut deletes: Vec<Vec<u8>> = vec![];
+
+        // Aggregate the accruals of the block with the ones already indexed for the session.
+        let mut by_delegator = BTreeMap::<AccountId, Vec<RewardAccrual<AccountId, Balance>>>::new();
+        for accrual in accruals {
+            by_delegator
+                .entry(accrual.delegator.clone())
+                .or_default()
+                .push(accrual);
+        }
+
+        let mut delegators: Vec<AccountId> =
+            load_decode(&*self.backend, &delegators_key(session))?.unwrap_or_default();
+        let delegators_count = delegators.len();
+
+        for (delegator, accruals) in by_delegator {
+            let mut rewards = match self.session_rewards(&delegator, session)? {
+                Some(rewards) => rewards,
+                None => {
+                    delegators.push(delegator.clone());
+                    vec![]
+                }
+            };
+
+            for accrual in accruals {
+                let entry = match rewards
+                    .iter()
+                    .position(|r| r.candidate == accrual.candidate)
+                {
+                    Some(index) => &mut rewards[index],
+                    None => {
+                        rewards.push(CandidateRewards {
+                            candidate: accrual.candidate,
+                            auto_compounding_rewards: Default::default(),
+                            manual_claim_rewards: Default::default(),
+                            claimed_manual_rewards: Default::default(),
+                        });
+                        rewards.last_mut().expect("an entry was just pushed")
+                    }
+                };
+
+                entry
+                    .auto_compounding_rewards
+                    .saturating_accrue(accrual.auto_compounding_rewards);
+                entry
+                    .manual_claim_rewards
+                    .saturating_accrue(accrual.manual_claim_rewards);
+                entry
+                    .claimed_manual_rewards
+                    .saturating_accrue(accrual.claimed_manual_rewards);
+            }
+
+            inserts.push((accruals_key(&delegator, session), rewards.encode()));
+        }
+
+        if delegators.len() != delegators_count {
+            inserts.push((delegators_key(session), delegators.encode()));
+        }
+
+        // Prune sessions which are no longer retained.
+        let first_retained = session.saturating_sub(retained_sessions.saturating_sub(1));
+        let oldest = self.oldest_session()?.unwrap_or(session);
+
+        for pruned in oldest..first_retained {
+            let delegators: Vec<AccountId> =
+                load_decode(&*self.backend, &delegators_key(pruned))?.unwrap_or_default();
+
+            for delegator in delegators {
+                deletes.push(accruals_key(&delegator, pruned));
+            }
+            deletes.push(delegators_key(pruned));
+        }
+
+        inserts.push((
+            REWARD_HISTORY_OLDEST_SESSION.to_vec(),
+            oldest.max(first_retained).encode(),
+        ));
+        inserts.push((REWARD_HISTORY_LAST_BLOCK.to_vec(), number.encode()));
+
+        self.backend.insert_aux(
+            &inserts
+                .iter()
+                .map(|(key, value)| (&key[..], &value[..]))
+                .collect::<Vec<_>>(),
+            &deletes.iter().map(|key| &key[..]).collect::<Vec<_>>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::sync::Mutex};
+
+    #[derive(Default)]
+    struct MemoryAux(Mutex<BTreeMap<Vec<u8>, Vec<u8>>>);
+
+    impl AuxStore for MemoryAux {
+        fn insert_aux<
+            'a,
+            'b: 'a,
+            'c: 'a,
+            I: IntoIterator<Item = &'a (&'c [u8], &'c [u8])>,
+            D: IntoIterator<Item = &'a &'b [u8]>,
+        >(
+            &self,
+            insert: I,
+            delete: D,
+        ) -> ClientResult<()> {
+            let mut map = self.0.lock().unwrap();
+            for (key, value) in insert {
+                map.insert(key.to_vec(), value.to_vec());
+            }
+            for key in delete {
+                map.remove(*key);
+            }
+            Ok(())
+        }
+
+        fn get_aux(&self, key: &[u8]) -> ClientResult<Option<Vec<u8>>> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+    }
+
+    fn accrual(
+        delegator: u64,
+        candidate: u64,
+        auto: u128,
+        manual: u128,
+    ) -> RewardAccrual<u64, u128> {
+        RewardAccrual {
+            delegator,
+            candidate,
+            auto_compounding_rewards: auto,
+            manual_claim_rewards: manual,
+            claimed_manual_rewards: 0,
+        }
+    }
+
+    fn rewards(candidate: u64, auto: u128, manual: u128) -> CandidateRewards<u64, u128> {
+        CandidateRewards {
+            candidate,
+            auto_compounding_rewards: auto,
+            manual_claim_rewards: manual,
+            claimed_manual_rewards: 0,
+        }
+    }
+
+    #[test]
+    fn accruals_are_aggregated_per_session_and_candidate() {
+        let history = RewardHistory::<_, u64, u128>::new(Arc::new(MemoryAux::default()));
+
+        history
+            .index_block(
+                1u32,
+                0,
+                vec![accrual(1, 10, 100, 0), accrual(2, 10, 5, 5)],
+                10,
+            )
+            .unwrap();
+        history
+            .index_block(
+                2u32,
+                0,
+                vec![accrual(1, 10, 100, 0), accrual(1, 11, 0, 7)],
+                10,
+            )
+            .unwrap();
+        history
+            .index_block(3u32, 1, vec![accrual(1, 10, 1, 0)], 10)
+            .unwrap();
+
+        assert_eq!(
+            history.session_rewards(&1, 0).unwrap(),
+            Some(vec![rewards(10, 200, 0), rewards(11, 0, 7)])
+        );
+        assert_eq!(
+            history.session_rewards(&2, 0).unwrap(),
+            Some(vec![rewards(10, 5, 5)])
+        );
+        assert_eq!(
+            history.session_rewards(&1, 1).unwrap(),
+            Some(vec![rewards(10, 1, 0)])
+        );
+        assert_eq!(history.session_rewards(&2, 1).unwrap(), None);
+        assert_eq!(history.last_indexed_block::<u32>().unwrap(), Some(3));
+    }
+
+    #[test]
+    fn old_sessions_are_pruned() {
+        let history = RewardHistory::<_, u64, u128>::new(Arc::new(MemoryAux::default()));
+
+        for session in 0..5 {
+            history
+                .index_block(session, session, vec![accrual(1, 10, 100, 0)], 2)
+                .unwrap();
+        }
+
+        assert_eq!(history.oldest_session().unwrap(), Some(3));
+        for session in 0..3 {
+            assert_eq!(history.session_rewards(&1, session).unwrap(), None);
+        }
+        for session in 3..5 {
+            assert_eq!(
+                history.session_rewards(&1, session).unwrap(),
+                Some(vec![rewards(10, 100, 0)])
+            );
+        }
+    }
+
+    #[test]
+    fn skipped_blocks_are_marked_as_indexed() {
+        let history = RewardHistory::<_, u64, u128>::new(Arc::new(MemoryAux::default()));
+
+        history.skip_block(7u32).unwrap();
+
+        assert_eq!(history.last_indexed_block::<u32>().unwrap(), Some(7));
+        assert_eq!(history.oldest_session().unwrap(), None);
+    }
+}
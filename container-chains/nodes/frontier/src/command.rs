@@ -241,6 +241,20 @@ pub fn run() -> Result<()> {
                 cmd.run(&*spec)
             })
         }
+        Some(Subcommand::ExportGenesisData(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|config| {
+                let genesis_storage = if cmd.from_db {
+                    let partials = NodeConfig::new_builder(&config, None)?;
+                    Some(node_common::command::genesis_storage_from_client(
+                        &*partials.client,
+                    )?)
+                } else {
+                    None
+                };
+                cmd.run(&*config.chain_spec, genesis_storage)
+            })
+        }
         Some(Subcommand::Benchmark(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             // Switch on the concrete benchmark sub-command-
@@ -54,6 +54,9 @@ pub enum Subcommand {
     /// Export the genesis wasm of the parachain.
     ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
 
+    /// Export the genesis data used to register the container chain in the orchestrator.
+    ExportGenesisData(node_common::command::ExportGenesisDataCmd),
+
     /// Sub-commands concerned with benchmarking.
     /// The pallet benchmarking moved to the `pallet` sub-command.
     #[command(subcommand)]
@@ -0,0 +1,93 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to read the collators assigned to this container chain by the orchestrator, as
+//! noted by `pallet_cc_authorities_noting`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use {
+    fp_evm::PrecompileHandle,
+    parity_scale_codec::Encode,
+    precompile_utils::prelude::*,
+    sp_core::H256,
+    sp_std::{marker::PhantomData, vec::Vec},
+};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// A precompile to read the authorities noted by `pallet_cc_authorities_noting`.
+pub struct AuthoritiesNotingPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> AuthoritiesNotingPrecompile<Runtime>
+where
+    Runtime: pallet_cc_authorities_noting::Config + pallet_evm::Config,
+{
+    #[precompile::public("orchestratorParaId()")]
+    #[precompile::view]
+    fn orchestrator_para_id(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+        Ok(pallet_cc_authorities_noting::Pallet::<Runtime>::orchestrator_para_id().into())
+    }
+
+    #[precompile::public("authorities()")]
+    #[precompile::view]
+    fn authorities(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<H256>> {
+        let authorities = Self::read_authorities(handle)?;
+
+        authorities
+            .iter()
+            .map(Self::authority_to_h256)
+            .collect::<EvmResult<Vec<_>>>()
+    }
+
+    #[precompile::public("isAuthority(bytes32)")]
+    #[precompile::view]
+    fn is_authority(handle: &mut impl PrecompileHandle, nimbus_id: H256) -> EvmResult<bool> {
+        let authorities = Self::read_authorities(handle)?;
+
+        for authority in authorities.iter() {
+            if Self::authority_to_h256(authority)? == nimbus_id {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn read_authorities(
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<Vec<Runtime::AuthorityId>> {
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+        Ok(pallet_cc_authorities_noting::Pallet::<Runtime>::authorities())
+    }
+
+    /// Authorities are sr25519 public keys, so their encoding is 32 bytes long.
+    fn authority_to_h256(authority: &Runtime::AuthorityId) -> EvmResult<H256> {
+        let encoded = authority.encode();
+        if encoded.len() != 32 {
+            return Err(revert("Authority id is not 32 bytes long"));
+        }
+
+        Ok(H256::from_slice(&encoded))
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+use {
+    super::*,
+    cumulus_pallet_parachain_system::{RelayChainState, RelaychainStateProvider},
+    cumulus_primitives_core::ParaId,
+    frame_support::{
+        construct_runtime, parameter_types,
+        traits::{ConstU128, ConstU32, ConstU64, Everything},
+        weights::Weight,
+    },
+    nimbus_primitives::NimbusId,
+    pallet_evm::{
+        EnsureAddressNever, EnsureAddressRoot, EnsureCreateOrigin, IdentityAddressMapping,
+    },
+    precompile_utils::precompile_set::*,
+    sp_core::{H160, U256},
+    sp_runtime::{
+        traits::{BlakeTwo256, IdentityLookup},
+        BuildStorage,
+    },
+};
+
+pub type AccountId = H160;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Balances: pallet_balances,
+        Evm: pallet_evm,
+        Timestamp: pallet_timestamp,
+        AuthoritiesNoting: pallet_cc_authorities_noting,
+    }
+);
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type Block = Block;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 4];
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<5>;
+    type WeightInfo = ();
+}
+
+pub type Precompiles<R> =
+    PrecompileSetBuilder<R, (PrecompileAt<AddressU64<1>, AuthoritiesNotingPrecompile<R>>,)>;
+
+pub type PCall = AuthoritiesNotingPrecompileCall<Runtime>;
+
+/// Any address can deploy contracts.
+pub struct AllowAllCreate;
+impl EnsureCreateOrigin<Runtime> for AllowAllCreate {
+    fn check_create_origin(_address: &H160) -> Result<(), pallet_evm::Error<Runtime>> {
+        Ok(())
+    }
+}
+
+parameter_types! {
+    pub BlockGasLimit: U256 = U256::from(u64::MAX);
+    pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+    pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+    pub SuicideQuickClearLimit: u32 = 0;
+}
+
+impl pallet_evm::Config for Runtime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = IdentityAddressMapping;
+    type CreateOrigin = AllowAllCreate;
+    type CreateInnerOrigin = AllowAllCreate;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = Precompiles<Self>;
+    type PrecompilesValue = PrecompilesValue;
+    type ChainId = ();
+    type OnChargeTransaction = ();
+    type BlockGasLimit = BlockGasLimit;
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type FindAuthor = ();
+    type OnCreate = ();
+    type GasLimitPovSizeRatio = ();
+    type SuicideQuickClearLimit = SuicideQuickClearLimit;
+    type Timestamp = Timestamp;
+    type WeightInfo = ();
+}
+
+pub struct MockRelayChainStateProvider;
+
+impl RelaychainStateProvider for MockRelayChainStateProvider {
+    fn current_relay_chain_state() -> RelayChainState {
+        RelayChainState {
+            number: 0,
+            state_root: H256::default(),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_relay_chain_state(_state: RelayChainState) {}
+}
+
+parameter_types! {
+    pub const SelfParaId: ParaId = ParaId::new(SELF_PARA_ID);
+}
+
+pub const SELF_PARA_ID: u32 = 2000;
+
+impl pallet_cc_authorities_noting::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type SelfParaId = SelfParaId;
+    type RelayChainStateProvider = MockRelayChainStateProvider;
+    type AuthorityId = NimbusId;
+    type WeightInfo = ();
+}
+
+pub(crate) struct ExtBuilder {
+    orchestrator_para_id: u32,
+    authorities: Vec<NimbusId>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> ExtBuilder {
+        ExtBuilder {
+            orchestrator_para_id: 1000,
+            authorities: vec![],
+        }
+    }
+}
+
+impl ExtBuilder {
+    pub(crate) fn with_orchestrator_para_id(mut self, para_id: u32) -> Self {
+        self.orchestrator_para_id = para_id;
+        self
+    }
+
+    pub(crate) fn with_authorities(mut self, authorities: Vec<NimbusId>) -> Self {
+        self.authorities = authorities;
+        self
+    }
+
+    pub(crate) fn build(self) -> sp_io::TestExternalities {
+        let t = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            AuthoritiesNoting::set_orchestrator_para_id(
+                RuntimeOrigin::root(),
+                self.orchestrator_para_id.into(),
+            )
+            .expect("Root can set the orchestrator para id");
+            AuthoritiesNoting::set_authorities(RuntimeOrigin::root(), self.authorities)
+                .expect("Root can set the authorities");
+        });
+        ext
+    }
+}
+
+pub(crate) fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+/// Nimbus id made of the given byte repeated.
+pub(crate) fn nimbus_id(byte: u8) -> NimbusId {
+    NimbusId::from(sp_core::sr25519::Public::from_raw([byte; 32]))
+}
@@ -0,0 +1,127 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+use {crate::mock::*, precompile_utils::testing::*, sp_core::H256};
+
+#[test]
+fn selectors() {
+    assert!(PCall::orchestrator_para_id_selectors().contains(&0x6fb7cb1c));
+    assert!(PCall::authorities_selectors().contains(&0x2b0d1816));
+    assert!(PCall::is_authority_selectors().contains(&0x8748a0c6));
+}
+
+#[test]
+fn modifiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+        tester.test_view_modifier(PCall::orchestrator_para_id_selectors());
+        tester.test_view_modifier(PCall::authorities_selectors());
+        tester.test_view_modifier(PCall::is_authority_selectors());
+    });
+}
+
+#[test]
+fn selector_less_than_four_bytes_reverts() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, Precompile1, vec![1u8, 2, 3])
+            .execute_reverts(|output| output == b"Tried to read selector out of bounds");
+    });
+}
+
+#[test]
+fn no_selector_exists_but_length_is_right() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, Precompile1, vec![1u8, 2, 3, 4])
+            .execute_reverts(|output| output == b"Unknown selector");
+    });
+}
+
+#[test]
+fn orchestrator_para_id_works() {
+    ExtBuilder::default()
+        .with_orchestrator_para_id(1001)
+        .build()
+        .execute_with(|| {
+            precompiles()
+                .prepare_test(Alice, Precompile1, PCall::orchestrator_para_id {})
+                .expect_no_logs()
+                .execute_returns(1001u32);
+        });
+}
+
+#[test]
+fn authorities_works() {
+    ExtBuilder::default()
+        .with_authorities(vec![nimbus_id(1), nimbus_id(2)])
+        .build()
+        .execute_with(|| {
+            precompiles()
+                .prepare_test(Alice, Precompile1, PCall::authorities {})
+                .expect_no_logs()
+                .execute_returns(vec![H256::repeat_byte(1), H256::repeat_byte(2)]);
+        });
+}
+
+#[test]
+fn authorities_is_empty_before_noting_any_authority() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::authorities {})
+            .expect_no_logs()
+            .execute_returns(Vec::<H256>::new());
+    });
+}
+
+#[test]
+fn is_authority_works() {
+    ExtBuilder::default()
+        .with_authorities(vec![nimbus_id(1), nimbus_id(2)])
+        .build()
+        .execute_with(|| {
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    Precompile1,
+                    PCall::is_authority {
+                        nimbus_id: H256::repeat_byte(2),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(true);
+
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    Precompile1,
+                    PCall::is_authority {
+                        nimbus_id: H256::repeat_byte(3),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(false);
+        });
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(
+        &["../../../test/contracts/solidity/AuthoritiesNoting.sol"],
+        PCall::supports_selector,
+    )
+}
@@ -0,0 +1,255 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to send XCM messages that interact with the orchestrator chain on behalf of the
+//! caller.
+//!
+//! Messages are sent through `pallet_xcm::send`, so they start with a `DescendOrigin` into the
+//! account of the caller, and the orchestrator executes them with the account derived from that
+//! location. That account pays for the XCM execution and for the transacted call, and receives
+//! the unused fees back.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use {
+    cumulus_primitives_core::ParaId,
+    fp_evm::PrecompileHandle,
+    frame_support::{
+        dispatch::{GetDispatchInfo, PostDispatchInfo},
+        traits::{ConstU32, Get},
+        weights::Weight,
+    },
+    pallet_cc_credits_top_up::GetPurchaseCreditsCall,
+    pallet_evm::AddressMapping,
+    parity_scale_codec::{Decode, Encode},
+    precompile_utils::prelude::*,
+    sp_runtime::traits::Dispatchable,
+    sp_std::{boxed::Box, collections::btree_set::BTreeSet, marker::PhantomData, vec::Vec},
+    staging_xcm::{
+        latest::{Asset, AssetFilter, Location, OriginKind, WildAsset, Xcm},
+        prelude::*,
+    },
+};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Max length of a data preserver url, as set in the orchestrator runtime.
+pub const MAX_URL_LEN: u32 = 200;
+/// Max number of para ids in the filter of a data preserver profile, as set in the orchestrator
+/// runtime.
+pub const MAX_PARA_IDS: u32 = 100;
+
+/// Which para ids a data preserver profile can be assigned to. Encoded like
+/// `pallet_data_preservers::ParaIdsFilter`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum ParaIdsFilter {
+    AnyParaId,
+    Whitelist(BTreeSet<ParaId>),
+    Blacklist(BTreeSet<ParaId>),
+}
+
+/// Encoded like `pallet_data_preservers::ProfileMode`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum ProfileMode {
+    Bootnode,
+    Rpc { supports_ethereum_rpcs: bool },
+}
+
+/// Payment accepted by a data preserver profile. Encoded like the `ProviderRequest` of the
+/// orchestrator runtime, which only supports free assignments.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum AssignmentRequest {
+    Free,
+}
+
+/// Data preserver profile. Encoded like `pallet_data_preservers::Profile`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct DataPreserverProfile {
+    pub url: Vec<u8>,
+    pub para_ids: ParaIdsFilter,
+    pub mode: ProfileMode,
+    pub assignment_request: AssignmentRequest,
+}
+
+/// Encodes the `create_profile` call of the orchestrator chain.
+pub trait GetCreateProfileCall {
+    /// Get the encoded call to register `profile`.
+    fn get_encoded(profile: DataPreserverProfile) -> Vec<u8>;
+}
+
+/// Encodes `pallet_data_preservers::Call::create_profile`, given the index of that pallet in the
+/// orchestrator runtime.
+pub struct EncodeCreateProfileCall<DataPreserversIndex>(PhantomData<DataPreserversIndex>);
+
+impl<DataPreserversIndex: Get<u8>> GetCreateProfileCall
+    for EncodeCreateProfileCall<DataPreserversIndex>
+{
+    fn get_encoded(profile: DataPreserverProfile) -> Vec<u8> {
+        // `create_profile` has call index 1
+        (DataPreserversIndex::get(), 1u8, profile).encode()
+    }
+}
+
+/// A precompile to buy credits and register data preserver profiles in the orchestrator chain.
+pub struct OrchestratorXcmPrecompile<Runtime, PurchaseCreditsCall, CreateProfileCall>(
+    PhantomData<(Runtime, PurchaseCreditsCall, CreateProfileCall)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, PurchaseCreditsCall, CreateProfileCall>
+    OrchestratorXcmPrecompile<Runtime, PurchaseCreditsCall, CreateProfileCall>
+where
+    Runtime: pallet_cc_authorities_noting::Config + pallet_evm::Config + pallet_xcm::Config,
+    <Runtime as frame_system::Config>::RuntimeCall:
+        Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        From<Option<Runtime::AccountId>>,
+    <Runtime as frame_system::Config>::RuntimeCall: From<pallet_xcm::Call<Runtime>>,
+    PurchaseCreditsCall: GetPurchaseCreditsCall,
+    CreateProfileCall: GetCreateProfileCall,
+{
+    #[precompile::public("purchaseCredits(uint128,uint128,uint64,uint64)")]
+    fn purchase_credits(
+        handle: &mut impl PrecompileHandle,
+        credit: u128,
+        max_fee: u128,
+        transact_ref_time: u64,
+        transact_proof_size: u64,
+    ) -> EvmResult {
+        let self_para_id = <Runtime as pallet_cc_authorities_noting::Config>::SelfParaId::get();
+        let call = PurchaseCreditsCall::get_encoded(self_para_id, credit);
+
+        Self::send_transact(
+            handle,
+            call,
+            max_fee,
+            Weight::from_parts(transact_ref_time, transact_proof_size),
+        )
+    }
+
+    #[precompile::public("createProfile(string,uint8,uint32[],uint8,uint128,uint64,uint64)")]
+    fn create_profile(
+        handle: &mut impl PrecompileHandle,
+        url: BoundedString<ConstU32<MAX_URL_LEN>>,
+        para_ids_filter: u8,
+        para_ids: BoundedVec<u32, ConstU32<MAX_PARA_IDS>>,
+        mode: u8,
+        max_fee: u128,
+        transact_ref_time: u64,
+        transact_proof_size: u64,
+    ) -> EvmResult {
+        let para_ids: Vec<u32> = para_ids.into();
+        let para_ids: BTreeSet<ParaId> = para_ids.into_iter().map(ParaId::from).collect();
+        let para_ids = match para_ids_filter {
+            0 if para_ids.is_empty() => ParaIdsFilter::AnyParaId,
+            0 => {
+                return Err(RevertReason::custom("Para ids must be empty")
+                    .in_field("paraIds")
+                    .into())
+            }
+            1 => ParaIdsFilter::Whitelist(para_ids),
+            2 => ParaIdsFilter::Blacklist(para_ids),
+            _ => {
+                return Err(RevertReason::custom("Unknown para ids filter")
+                    .in_field("paraIdsFilter")
+                    .into())
+            }
+        };
+        let mode = match mode {
+            0 => ProfileMode::Bootnode,
+            1 => ProfileMode::Rpc {
+                supports_ethereum_rpcs: false,
+            },
+            2 => ProfileMode::Rpc {
+                supports_ethereum_rpcs: true,
+            },
+            _ => return Err(RevertReason::custom("Unknown mode").in_field("mode").into()),
+        };
+
+        let profile = DataPreserverProfile {
+            url: url.as_bytes().to_vec(),
+            para_ids,
+            mode,
+            assignment_request: AssignmentRequest::Free,
+        };
+        let call = CreateProfileCall::get_encoded(profile);
+
+        Self::send_transact(
+            handle,
+            call,
+            max_fee,
+            Weight::from_parts(transact_ref_time, transact_proof_size),
+        )
+    }
+
+    /// Send an XCM message to the orchestrator that withdraws `max_fee` from the account of the
+    /// caller there, and uses it to pay for transacting `call`.
+    fn send_transact(
+        handle: &mut impl PrecompileHandle,
+        call: Vec<u8>,
+        max_fee: u128,
+        transact_weight: Weight,
+    ) -> EvmResult {
+        // OrchestratorParaId
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        let orchestrator_para_id =
+            pallet_cc_authorities_noting::Pallet::<Runtime>::orchestrator_para_id();
+        let self_para_id = <Runtime as pallet_cc_authorities_noting::Config>::SelfParaId::get();
+
+        let caller = handle.context().caller;
+        let orchestrator = Location::new(1, Parachain(orchestrator_para_id.into()));
+        // The orchestrator derives the account of the caller from this location. The network is
+        // not taken into account when deriving it, so it does not need to match the origin added
+        // by `pallet_xcm`.
+        let caller_location = Location::new(
+            1,
+            [
+                Parachain(self_para_id.into()),
+                AccountKey20 {
+                    network: None,
+                    key: caller.0,
+                },
+            ],
+        );
+
+        let fee_asset: Asset = (Here, max_fee).into();
+        let refund_asset_filter: AssetFilter = AssetFilter::Wild(WildAsset::AllCounted(1));
+        let message: Xcm<()> = Xcm::builder()
+            .withdraw_asset(fee_asset.clone())
+            .buy_execution(fee_asset, Unlimited)
+            // Both in case of error and in case of success, we want to refund the unused fees
+            .set_appendix(
+                Xcm::builder_unsafe()
+                    .refund_surplus()
+                    .deposit_asset(refund_asset_filter, caller_location)
+                    .build(),
+            )
+            .transact(OriginKind::SovereignAccount, transact_weight, call)
+            .build();
+
+        let call = pallet_xcm::Call::<Runtime>::send {
+            dest: Box::new(VersionedLocation::V4(orchestrator)),
+            message: Box::new(VersionedXcm::V4(message)),
+        };
+        let origin = Runtime::AddressMapping::into_account_id(caller);
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+        Ok(())
+    }
+}
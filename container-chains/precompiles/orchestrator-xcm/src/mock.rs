@@ -0,0 +1,335 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+use {
+    super::*,
+    cumulus_pallet_parachain_system::{RelayChainState, RelaychainStateProvider},
+    frame_support::{
+        construct_runtime, parameter_types,
+        traits::{ConstU128, ConstU64, Everything, Nothing},
+    },
+    frame_system::EnsureRoot,
+    nimbus_primitives::NimbusId,
+    pallet_cc_credits_top_up::EncodePurchaseCreditsCall,
+    pallet_evm::{
+        EnsureAddressNever, EnsureAddressRoot, EnsureCreateOrigin, IdentityAddressMapping,
+    },
+    precompile_utils::precompile_set::*,
+    sp_core::{H160, H256, U256},
+    sp_runtime::{
+        traits::{BlakeTwo256, IdentityLookup},
+        BuildStorage,
+    },
+    staging_xcm::latest::{
+        Assets, InteriorLocation, NetworkId, SendError, SendResult, SendXcm, XcmHash,
+    },
+    staging_xcm_builder::{
+        AccountKey20Aliases, EnsureXcmOrigin, FixedWeightBounds, FrameTransactionalProcessor,
+        SignedToAccountKey20,
+    },
+    staging_xcm_executor::XcmExecutor,
+    std::cell::RefCell,
+};
+
+pub type AccountId = H160;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Balances: pallet_balances,
+        Evm: pallet_evm,
+        Timestamp: pallet_timestamp,
+        AuthoritiesNoting: pallet_cc_authorities_noting,
+        PolkadotXcm: pallet_xcm,
+    }
+);
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type Block = Block;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 4];
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<5>;
+    type WeightInfo = ();
+}
+
+pub const ORCHESTRATOR_PARA_ID: u32 = 1000;
+pub const SELF_PARA_ID: u32 = 2000;
+pub const SERVICES_PAYMENT_INDEX: u8 = 26;
+pub const DATA_PRESERVERS_INDEX: u8 = 27;
+
+parameter_types! {
+    pub const ServicesPaymentIndex: u8 = SERVICES_PAYMENT_INDEX;
+    pub const DataPreserversIndex: u8 = DATA_PRESERVERS_INDEX;
+}
+
+pub type Precompiles<R> = PrecompileSetBuilder<
+    R,
+    (
+        PrecompileAt<
+            AddressU64<1>,
+            OrchestratorXcmPrecompile<
+                R,
+                EncodePurchaseCreditsCall<ServicesPaymentIndex>,
+                EncodeCreateProfileCall<DataPreserversIndex>,
+            >,
+        >,
+    ),
+>;
+
+pub type PCall = OrchestratorXcmPrecompileCall<
+    Runtime,
+    EncodePurchaseCreditsCall<ServicesPaymentIndex>,
+    EncodeCreateProfileCall<DataPreserversIndex>,
+>;
+
+/// Any address can deploy contracts.
+pub struct AllowAllCreate;
+impl EnsureCreateOrigin<Runtime> for AllowAllCreate {
+    fn check_create_origin(_address: &H160) -> Result<(), pallet_evm::Error<Runtime>> {
+        Ok(())
+    }
+}
+
+parameter_types! {
+    pub BlockGasLimit: U256 = U256::from(u64::MAX);
+    pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+    pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+    pub SuicideQuickClearLimit: u32 = 0;
+}
+
+impl pallet_evm::Config for Runtime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = IdentityAddressMapping;
+    type CreateOrigin = AllowAllCreate;
+    type CreateInnerOrigin = AllowAllCreate;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = Precompiles<Self>;
+    type PrecompilesValue = PrecompilesValue;
+    type ChainId = ();
+    type OnChargeTransaction = ();
+    type BlockGasLimit = BlockGasLimit;
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type FindAuthor = ();
+    type OnCreate = ();
+    type GasLimitPovSizeRatio = ();
+    type SuicideQuickClearLimit = SuicideQuickClearLimit;
+    type Timestamp = Timestamp;
+    type WeightInfo = ();
+}
+
+pub struct MockRelayChainStateProvider;
+
+impl RelaychainStateProvider for MockRelayChainStateProvider {
+    fn current_relay_chain_state() -> RelayChainState {
+        RelayChainState {
+            number: 0,
+            state_root: H256::default(),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_relay_chain_state(_state: RelayChainState) {}
+}
+
+parameter_types! {
+    pub const SelfParaId: ParaId = ParaId::new(SELF_PARA_ID);
+}
+
+impl pallet_cc_authorities_noting::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type SelfParaId = SelfParaId;
+    type RelayChainStateProvider = MockRelayChainStateProvider;
+    type AuthorityId = NimbusId;
+    type WeightInfo = ();
+}
+
+thread_local! {
+    pub static SENT_XCM: RefCell<Vec<(Location, Xcm<()>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Stores the sent messages so that tests can inspect them.
+pub struct MockXcmSender;
+
+impl SendXcm for MockXcmSender {
+    type Ticket = (Location, Xcm<()>);
+    fn validate(
+        destination: &mut Option<Location>,
+        message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        let destination = destination.take().ok_or(SendError::MissingArgument)?;
+        let message = message.take().ok_or(SendError::MissingArgument)?;
+        Ok(((destination, message), Assets::new()))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        SENT_XCM.with(|sent| sent.borrow_mut().push(ticket));
+        Ok([0; 32])
+    }
+}
+
+pub fn sent_xcm() -> Vec<(Location, Xcm<()>)> {
+    SENT_XCM.with(|sent| sent.borrow().clone())
+}
+
+parameter_types! {
+    pub const RelayNetwork: Option<NetworkId> = None;
+    pub UniversalLocation: InteriorLocation = Parachain(SELF_PARA_ID).into();
+    pub const BaseXcmWeight: Weight = Weight::from_parts(1_000, 0);
+    pub const MaxInstructions: u32 = 100;
+}
+
+pub type LocalOriginToLocation = SignedToAccountKey20<RuntimeOrigin, AccountId, RelayNetwork>;
+
+pub struct XcmConfig;
+impl staging_xcm_executor::Config for XcmConfig {
+    type RuntimeCall = RuntimeCall;
+    type XcmSender = MockXcmSender;
+    type AssetTransactor = ();
+    type OriginConverter = ();
+    type IsReserve = ();
+    type IsTeleporter = ();
+    type UniversalLocation = UniversalLocation;
+    type Barrier = ();
+    type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+    type Trader = ();
+    type ResponseHandler = PolkadotXcm;
+    type AssetTrap = PolkadotXcm;
+    type AssetClaims = PolkadotXcm;
+    type SubscriptionService = PolkadotXcm;
+    type PalletInstancesInfo = AllPalletsWithSystem;
+    type MaxAssetsIntoHolding = ConstU32<64>;
+    type AssetLocker = ();
+    type AssetExchanger = ();
+    type FeeManager = ();
+    type MessageExporter = ();
+    type UniversalAliases = Nothing;
+    type CallDispatcher = RuntimeCall;
+    type SafeCallFilter = Everything;
+    type Aliasers = Nothing;
+    type TransactionalProcessor = FrameTransactionalProcessor;
+    type HrmpNewChannelOpenRequestHandler = ();
+    type HrmpChannelAcceptedHandler = ();
+    type HrmpChannelClosingHandler = ();
+    type XcmRecorder = ();
+}
+
+impl pallet_xcm::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+    type XcmRouter = MockXcmSender;
+    type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+    type XcmExecuteFilter = Nothing;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+    type XcmTeleportFilter = Nothing;
+    type XcmReserveTransferFilter = Nothing;
+    type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+    type UniversalLocation = UniversalLocation;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+    type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+    type Currency = Balances;
+    type CurrencyMatcher = ();
+    type TrustedLockers = ();
+    type SovereignAccountOf = AccountKey20Aliases<RelayNetwork, AccountId>;
+    type MaxLockers = ConstU32<8>;
+    type MaxRemoteLockConsumers = ConstU32<0>;
+    type RemoteLockConsumerIdentifier = ();
+    type WeightInfo = pallet_xcm::TestWeightInfo;
+    type AdminOrigin = EnsureRoot<AccountId>;
+}
+
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+    pub(crate) fn build(self) -> sp_io::TestExternalities {
+        let t = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            AuthoritiesNoting::set_orchestrator_para_id(
+                RuntimeOrigin::root(),
+                ORCHESTRATOR_PARA_ID.into(),
+            )
+            .expect("Root can set the orchestrator para id");
+        });
+        ext
+    }
+}
+
+pub(crate) fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
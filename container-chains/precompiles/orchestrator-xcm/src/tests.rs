@@ -0,0 +1,277 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate::{mock::*, AssignmentRequest, DataPreserverProfile, ParaIdsFilter, ProfileMode},
+    cumulus_primitives_core::ParaId,
+    frame_support::weights::Weight,
+    parity_scale_codec::Encode,
+    precompile_utils::testing::*,
+    sp_core::H160,
+    staging_xcm::latest::prelude::*,
+};
+
+/// Message expected to be sent to the orchestrator by Alice to transact `call`.
+fn expected_message(call: Vec<u8>, max_fee: u128, transact_weight: Weight) -> Xcm<()> {
+    let alice: H160 = Alice.into();
+    let fee_asset: Asset = (Here, max_fee).into();
+
+    Xcm(vec![
+        DescendOrigin(
+            AccountKey20 {
+                network: None,
+                key: alice.0,
+            }
+            .into(),
+        ),
+        WithdrawAsset(fee_asset.clone().into()),
+        BuyExecution {
+            fees: fee_asset,
+            weight_limit: Unlimited,
+        },
+        SetAppendix(Xcm(vec![
+            RefundSurplus,
+            DepositAsset {
+                assets: Wild(AllCounted(1)),
+                beneficiary: Location::new(
+                    1,
+                    [
+                        Parachain(SELF_PARA_ID),
+                        AccountKey20 {
+                            network: None,
+                            key: alice.0,
+                        },
+                    ],
+                ),
+            },
+        ])),
+        Transact {
+            origin_kind: OriginKind::SovereignAccount,
+            require_weight_at_most: transact_weight,
+            call: call.into(),
+        },
+    ])
+}
+
+fn orchestrator() -> Location {
+    Location::new(1, Parachain(ORCHESTRATOR_PARA_ID))
+}
+
+#[test]
+fn selectors() {
+    assert!(PCall::purchase_credits_selectors().contains(&0x68f0ceb8));
+    assert!(PCall::create_profile_selectors().contains(&0x23f8b7f8));
+}
+
+#[test]
+fn modifiers() {
+    ExtBuilder.build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+        tester.test_default_modifier(PCall::purchase_credits_selectors());
+        tester.test_default_modifier(PCall::create_profile_selectors());
+    });
+}
+
+#[test]
+fn purchase_credits_sends_transact_to_orchestrator() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::purchase_credits {
+                    credit: 100,
+                    max_fee: 10,
+                    transact_ref_time: 1_000,
+                    transact_proof_size: 2_000,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(());
+
+        let call = (
+            SERVICES_PAYMENT_INDEX,
+            0u8,
+            ParaId::from(SELF_PARA_ID),
+            100u128,
+        )
+            .encode();
+        assert_eq!(
+            sent_xcm(),
+            vec![(
+                orchestrator(),
+                expected_message(call, 10, Weight::from_parts(1_000, 2_000))
+            )]
+        );
+    });
+}
+
+#[test]
+fn create_profile_sends_transact_to_orchestrator() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::create_profile {
+                    url: "/ip4/127.0.0.1/tcp/33049/ws".into(),
+                    para_ids_filter: 1,
+                    para_ids: vec![2001, 2000, 2001].into(),
+                    mode: 2,
+                    max_fee: 10,
+                    transact_ref_time: 1_000,
+                    transact_proof_size: 2_000,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(());
+
+        // Same encoding as `pallet_data_preservers::Call::create_profile` in the orchestrator
+        let url = b"/ip4/127.0.0.1/tcp/33049/ws".to_vec();
+        let mut call = vec![DATA_PRESERVERS_INDEX, 1u8];
+        call.extend(url.encode());
+        // Whitelist of sorted and deduplicated para ids
+        call.push(1);
+        call.extend(vec![2000u32, 2001u32].encode());
+        // Rpc { supports_ethereum_rpcs: true }
+        call.extend([1, 1]);
+        // Free assignment request
+        call.push(0);
+
+        assert_eq!(
+            sent_xcm(),
+            vec![(
+                orchestrator(),
+                expected_message(call, 10, Weight::from_parts(1_000, 2_000))
+            )]
+        );
+    });
+}
+
+#[test]
+fn create_profile_for_any_para_id_and_bootnode() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::create_profile {
+                    url: "/ip4/127.0.0.1/tcp/33049/ws".into(),
+                    para_ids_filter: 0,
+                    para_ids: vec![].into(),
+                    mode: 0,
+                    max_fee: 10,
+                    transact_ref_time: 1_000,
+                    transact_proof_size: 2_000,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(());
+
+        let profile = DataPreserverProfile {
+            url: b"/ip4/127.0.0.1/tcp/33049/ws".to_vec(),
+            para_ids: ParaIdsFilter::AnyParaId,
+            mode: ProfileMode::Bootnode,
+            assignment_request: AssignmentRequest::Free,
+        };
+        let call = (DATA_PRESERVERS_INDEX, 1u8, profile).encode();
+        assert_eq!(
+            sent_xcm(),
+            vec![(
+                orchestrator(),
+                expected_message(call, 10, Weight::from_parts(1_000, 2_000))
+            )]
+        );
+    });
+}
+
+#[test]
+fn create_profile_rejects_para_ids_for_any_para_id() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::create_profile {
+                    url: "/ip4/127.0.0.1/tcp/33049/ws".into(),
+                    para_ids_filter: 0,
+                    para_ids: vec![2000].into(),
+                    mode: 0,
+                    max_fee: 10,
+                    transact_ref_time: 1_000,
+                    transact_proof_size: 2_000,
+                },
+            )
+            .execute_reverts(|output| output == b"paraIds: Para ids must be empty");
+
+        assert!(sent_xcm().is_empty());
+    });
+}
+
+#[test]
+fn create_profile_rejects_unknown_para_ids_filter() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::create_profile {
+                    url: "/ip4/127.0.0.1/tcp/33049/ws".into(),
+                    para_ids_filter: 3,
+                    para_ids: vec![].into(),
+                    mode: 0,
+                    max_fee: 10,
+                    transact_ref_time: 1_000,
+                    transact_proof_size: 2_000,
+                },
+            )
+            .execute_reverts(|output| output == b"paraIdsFilter: Unknown para ids filter");
+
+        assert!(sent_xcm().is_empty());
+    });
+}
+
+#[test]
+fn create_profile_rejects_unknown_mode() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::create_profile {
+                    url: "/ip4/127.0.0.1/tcp/33049/ws".into(),
+                    para_ids_filter: 0,
+                    para_ids: vec![].into(),
+                    mode: 3,
+                    max_fee: 10,
+                    transact_ref_time: 1_000,
+                    transact_proof_size: 2_000,
+                },
+            )
+            .execute_reverts(|output| output == b"mode: Unknown mode");
+
+        assert!(sent_xcm().is_empty());
+    });
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(
+        &["../../../test/contracts/solidity/OrchestratorXcm.sol"],
+        PCall::supports_selector,
+    )
+}
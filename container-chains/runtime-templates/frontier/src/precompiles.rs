@@ -17,13 +17,17 @@
 use {
     crate::{
         xcm_config::{AssetId, ForeignAssetsInstance, XcmConfig},
-        AccountId, Balances, ForeignAssetsCreator, Runtime,
+        AccountId, Balances, ForeignAssetsCreator, OrchestratorDataPreserversIndex,
+        OrchestratorServicesPaymentIndex, Runtime,
     },
     frame_support::parameter_types,
+    pallet_cc_credits_top_up::EncodePurchaseCreditsCall,
     pallet_evm_precompile_balances_erc20::{Erc20BalancesPrecompile, Erc20Metadata},
     pallet_evm_precompile_batch::BatchPrecompile,
     pallet_evm_precompile_call_permit::CallPermitPrecompile,
+    pallet_evm_precompile_cc_authorities_noting::AuthoritiesNotingPrecompile,
     pallet_evm_precompile_modexp::Modexp,
+    pallet_evm_precompile_orchestrator_xcm::{EncodeCreateProfileCall, OrchestratorXcmPrecompile},
     pallet_evm_precompile_proxy::{OnlyIsProxyAndProxy, ProxyPrecompile},
     pallet_evm_precompile_sha3fips::Sha3FIPS256,
     pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256},
@@ -84,6 +88,10 @@ type BalancesPrecompileMatch = SingleAddressMatcher<AccountId, ERC20_BALANCES_PR
 // Type that matches an AccountId with a foreign asset address (if any)
 type ForeignAssetMatch = ForeignAssetMatcher<AccountId, AssetId, Runtime, ForeignAssetsCreator>;
 
+// Orchestrator calls transacted by the orchestrator xcm precompile
+type PurchaseCreditsCall = EncodePurchaseCreditsCall<OrchestratorServicesPaymentIndex>;
+type CreateProfileCall = EncodeCreateProfileCall<OrchestratorDataPreserversIndex>;
+
 #[precompile_utils::precompile_name_from_address]
 type TemplatePrecompilesAt<R> = (
     // Ethereum precompiles:
@@ -128,6 +136,16 @@ type TemplatePrecompilesAt<R> = (
             CallableByPrecompile<OnlyFrom<AddressU64<2049>>>,
         ),
     >,
+    PrecompileAt<
+        AddressU64<2054>,
+        AuthoritiesNotingPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2055>,
+        OrchestratorXcmPrecompile<R, PurchaseCreditsCall, CreateProfileCall>,
+        (CallableByContract, CallableByPrecompile),
+    >,
 );
 
 pub type TemplatePrecompiles<R> = PrecompileSetBuilder<
@@ -0,0 +1,87 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use {
+    common::*,
+    container_chain_template_frontier_runtime::{
+        currency::UNIT, AccountId, Balance, Balances, CreditsTopUp, DealWithFees, TankTopUpAccount,
+        TreasuryAccount, XcmTankTopUp,
+    },
+    frame_support::{
+        traits::{fungible::Balanced, fungible::Inspect, Hooks, OnUnbalanced},
+        weights::Weight,
+    },
+    nimbus_primitives::NimbusId,
+    pallet_cc_credits_top_up::SendTankTopUp,
+};
+
+mod common;
+
+// 20% and 80% of this are rounded down, so 1 is burned
+const FEE: Balance = 1_000 * UNIT + 1;
+const AUTHOR: [u8; 32] = [7u8; 32];
+// The author is paid to the first 20 bytes of its nimbus key
+const AUTHOR_ACCOUNT: [u8; 20] = [7u8; 20];
+
+fn nimbus_id(bytes: [u8; 32]) -> NimbusId {
+    sp_core::sr25519::Public::from_raw(bytes).into()
+}
+
+#[test]
+fn fees_are_split_between_treasury_and_author() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_author(nimbus_id(AUTHOR), vec![nimbus_id(AUTHOR)]);
+        let issuance_before = Balances::total_issuance();
+
+        DealWithFees::on_unbalanced(Balances::issue(FEE));
+
+        assert_eq!(Balances::balance(&TreasuryAccount::get()), 200 * UNIT);
+        assert_eq!(
+            Balances::balance(&AccountId::from(AUTHOR_ACCOUNT)),
+            800 * UNIT
+        );
+        // The tank share is disabled by default
+        assert_eq!(Balances::balance(&TankTopUpAccount::get()), 0);
+        assert_eq!(Balances::total_issuance(), issuance_before + FEE - 1);
+    });
+}
+
+#[test]
+fn author_share_goes_to_treasury_if_author_is_not_assigned() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_author(nimbus_id(AUTHOR), vec![nimbus_id([8u8; 32])]);
+        let issuance_before = Balances::total_issuance();
+
+        DealWithFees::on_unbalanced(Balances::issue(FEE));
+
+        assert_eq!(Balances::balance(&TreasuryAccount::get()), 1_000 * UNIT);
+        assert_eq!(Balances::balance(&AccountId::from(AUTHOR_ACCOUNT)), 0);
+        assert_eq!(Balances::total_issuance(), issuance_before + FEE - 1);
+    });
+}
+
+#[test]
+fn tank_top_up_is_not_sent_below_threshold() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Only the balance check is charged
+        let weight = CreditsTopUp::on_idle(1, Weight::MAX);
+
+        assert_eq!(weight, XcmTankTopUp::check_weight());
+        assert_eq!(Balances::balance(&TankTopUpAccount::get()), 0);
+    });
+}
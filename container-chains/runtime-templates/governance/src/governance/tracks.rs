@@ -0,0 +1,176 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Track configurations for governance.
+//!
+//! Every track decides within a week, which is short enough for a container chain to react to
+//! operational issues while still giving token holders time to vote.
+
+use super::*;
+
+const fn percent(x: i32) -> sp_arithmetic::FixedI64 {
+    sp_arithmetic::FixedI64::from_rational(x as u128, 100)
+}
+use pallet_referenda::Curve;
+const APP_ROOT: Curve = Curve::make_reciprocal(1, 7, percent(80), percent(50), percent(100));
+const SUP_ROOT: Curve = Curve::make_linear(7, 7, percent(0), percent(50));
+const APP_GENERAL_ADMIN: Curve =
+    Curve::make_reciprocal(1, 7, percent(80), percent(50), percent(100));
+const SUP_GENERAL_ADMIN: Curve = Curve::make_reciprocal(2, 7, percent(10), percent(0), percent(50));
+const APP_REFERENDUM_CANCELLER: Curve = Curve::make_linear(4, 7, percent(50), percent(100));
+const SUP_REFERENDUM_CANCELLER: Curve =
+    Curve::make_reciprocal(3, 7, percent(1), percent(0), percent(50));
+const APP_REFERENDUM_KILLER: Curve = Curve::make_linear(4, 7, percent(50), percent(100));
+const SUP_REFERENDUM_KILLER: Curve =
+    Curve::make_reciprocal(3, 7, percent(1), percent(0), percent(50));
+const APP_TREASURER: Curve = Curve::make_reciprocal(1, 7, percent(80), percent(50), percent(100));
+const SUP_TREASURER: Curve = Curve::make_linear(7, 7, percent(0), percent(50));
+const APP_SMALL_SPENDER: Curve = Curve::make_linear(4, 7, percent(50), percent(100));
+const SUP_SMALL_SPENDER: Curve = Curve::make_reciprocal(3, 7, percent(1), percent(0), percent(50));
+const APP_BIG_SPENDER: Curve = Curve::make_linear(7, 7, percent(50), percent(100));
+const SUP_BIG_SPENDER: Curve = Curve::make_reciprocal(5, 7, percent(1), percent(0), percent(50));
+
+const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 7] = [
+    (
+        0,
+        pallet_referenda::TrackInfo {
+            name: "root",
+            max_deciding: 1,
+            decision_deposit: 10_000 * UNIT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_ROOT,
+            min_support: SUP_ROOT,
+        },
+    ),
+    (
+        1,
+        pallet_referenda::TrackInfo {
+            name: "general_admin",
+            max_deciding: 10,
+            decision_deposit: 1_000 * UNIT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_GENERAL_ADMIN,
+            min_support: SUP_GENERAL_ADMIN,
+        },
+    ),
+    (
+        2,
+        pallet_referenda::TrackInfo {
+            name: "referendum_canceller",
+            max_deciding: 20,
+            decision_deposit: 1_000 * UNIT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_REFERENDUM_CANCELLER,
+            min_support: SUP_REFERENDUM_CANCELLER,
+        },
+    ),
+    (
+        3,
+        pallet_referenda::TrackInfo {
+            name: "referendum_killer",
+            max_deciding: 20,
+            decision_deposit: 5_000 * UNIT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_REFERENDUM_KILLER,
+            min_support: SUP_REFERENDUM_KILLER,
+        },
+    ),
+    (
+        10,
+        pallet_referenda::TrackInfo {
+            name: "treasurer",
+            max_deciding: 10,
+            decision_deposit: 1_000 * UNIT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_TREASURER,
+            min_support: SUP_TREASURER,
+        },
+    ),
+    (
+        11,
+        pallet_referenda::TrackInfo {
+            name: "small_spender",
+            max_deciding: 50,
+            decision_deposit: 100 * UNIT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 6 * HOURS,
+            min_enactment_period: DAYS,
+            min_approval: APP_SMALL_SPENDER,
+            min_support: SUP_SMALL_SPENDER,
+        },
+    ),
+    (
+        12,
+        pallet_referenda::TrackInfo {
+            name: "big_spender",
+            max_deciding: 20,
+            decision_deposit: 500 * UNIT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_BIG_SPENDER,
+            min_support: SUP_BIG_SPENDER,
+        },
+    ),
+];
+
+pub struct TracksInfo;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+    type Id = u16;
+    type RuntimeOrigin = <RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin;
+    fn tracks() -> &'static [(Self::Id, pallet_referenda::TrackInfo<Balance, BlockNumber>)] {
+        &TRACKS_DATA[..]
+    }
+    fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+        if let Ok(system_origin) = frame_system::RawOrigin::try_from(id.clone()) {
+            match system_origin {
+                frame_system::RawOrigin::Root => Ok(0),
+                _ => Err(()),
+            }
+        } else if let Ok(custom_origin) = origins::Origin::try_from(id.clone()) {
+            match custom_origin {
+                origins::Origin::GeneralAdmin => Ok(1),
+                // Referendum admins
+                origins::Origin::ReferendumCanceller => Ok(2),
+                origins::Origin::ReferendumKiller => Ok(3),
+                // Treasury spenders
+                origins::Origin::Treasurer => Ok(10),
+                origins::Origin::SmallSpender => Ok(11),
+                origins::Origin::BigSpender => Ok(12),
+            }
+        } else {
+            Err(())
+        }
+    }
+}
+pallet_referenda::impl_tracksinfo_get!(TracksInfo, Balance, BlockNumber);
@@ -0,0 +1,92 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    container_chain_template_governance_runtime::{
+        currency::UNIT, AccountId, Balance, BalancesConfig, ParachainInfoConfig,
+        RuntimeGenesisConfig, RuntimeOrigin, Scheduler, System,
+    },
+    cumulus_primitives_core::ParaId,
+    frame_support::traits::OnInitialize,
+    sp_runtime::BuildStorage,
+};
+
+pub const ALICE: [u8; 32] = [4u8; 32];
+pub const BOB: [u8; 32] = [5u8; 32];
+pub const CHARLIE: [u8; 32] = [6u8; 32];
+
+/// Advances the chain to block `n`, servicing the scheduler agenda of every block in between.
+///
+/// Only the scheduler hooks are executed: referenda are driven by scheduler alarms, and running
+/// the parachain system hooks would require mocking the validation data of every block.
+pub fn run_to_block(n: u32) {
+    while System::block_number() < n {
+        let block_number = System::block_number() + 1;
+        System::set_block_number(block_number);
+        Scheduler::on_initialize(block_number);
+    }
+}
+
+pub fn origin_of(account_id: AccountId) -> RuntimeOrigin {
+    RuntimeOrigin::signed(account_id)
+}
+
+pub struct ExtBuilder {
+    // endowed accounts with balances
+    balances: Vec<(AccountId, Balance)>,
+    own_para_id: ParaId,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self {
+            balances: vec![
+                // Alice holds most of the issuance, so her votes decide referenda on their own
+                (AccountId::from(ALICE), 1_000_000 * UNIT),
+                (AccountId::from(BOB), 100_000 * UNIT),
+            ],
+            own_para_id: 2000.into(),
+        }
+    }
+}
+
+impl ExtBuilder {
+    pub fn build_storage(self) -> sp_core::storage::Storage {
+        RuntimeGenesisConfig {
+            balances: BalancesConfig {
+                balances: self.balances,
+            },
+            parachain_info: ParachainInfoConfig {
+                parachain_id: self.own_para_id,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+        .build_storage()
+        .unwrap()
+    }
+
+    pub fn build(self) -> sp_io::TestExternalities {
+        let t = self.build_storage();
+        let mut ext = sp_io::TestExternalities::new(t);
+
+        ext.execute_with(|| {
+            // Events are not stored in block 0
+            System::set_block_number(1);
+        });
+        ext
+    }
+}
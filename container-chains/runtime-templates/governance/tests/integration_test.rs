@@ -0,0 +1,222 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use {
+    common::*,
+    container_chain_template_governance_runtime::{
+        currency::UNIT,
+        governance::{pallet_custom_origins, TracksInfo},
+        migrations::RemoveSudo,
+        AccountId, Balances, ConvictionVoting, CreditsTopUp, MaintenanceMode, OriginCaller,
+        Preimage, Referenda, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, System, Treasury,
+    },
+    frame_support::{
+        assert_noop, assert_ok,
+        traits::{schedule::DispatchTime, StorePreimage},
+        weights::Weight,
+    },
+    pallet_conviction_voting::{AccountVote, Conviction, Vote},
+    pallet_migrations::Migration,
+    pallet_referenda::TracksInfo as _,
+    sp_runtime::DispatchError,
+};
+
+mod common;
+
+fn submit_and_approve(proposal_origin: OriginCaller, call: RuntimeCall) -> u32 {
+    let index = pallet_referenda::ReferendumCount::<Runtime>::get();
+    assert_ok!(Referenda::submit(
+        origin_of(ALICE.into()),
+        Box::new(proposal_origin),
+        Preimage::bound(call).unwrap(),
+        DispatchTime::After(0),
+    ));
+    assert_ok!(Referenda::place_decision_deposit(
+        origin_of(ALICE.into()),
+        index
+    ));
+    assert_ok!(ConvictionVoting::vote(
+        origin_of(ALICE.into()),
+        index,
+        AccountVote::Standard {
+            vote: Vote {
+                aye: true,
+                conviction: Conviction::Locked1x,
+            },
+            balance: 900_000 * UNIT,
+        }
+    ));
+
+    index
+}
+
+#[test]
+fn root_referendum_is_enacted() {
+    ExtBuilder::default().build().execute_with(|| {
+        let call = RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+            who: AccountId::from(CHARLIE).into(),
+            new_free: 1_000 * UNIT,
+        });
+        let index = submit_and_approve(frame_system::RawOrigin::Root.into(), call);
+
+        let track = TracksInfo::info(0).unwrap();
+        let enactment_block = System::block_number()
+            + track.prepare_period
+            + track.confirm_period
+            + track.min_enactment_period;
+
+        // The call is not dispatched before the minimum enactment period
+        run_to_block(enactment_block - 2);
+        assert!(matches!(
+            pallet_referenda::ReferendumInfoFor::<Runtime>::get(index),
+            Some(pallet_referenda::ReferendumInfo::Approved(..))
+        ));
+        assert_eq!(Balances::free_balance(AccountId::from(CHARLIE)), 0);
+
+        run_to_block(enactment_block + 2);
+        assert_eq!(
+            Balances::free_balance(AccountId::from(CHARLIE)),
+            1_000 * UNIT
+        );
+    });
+}
+
+#[test]
+fn proposals_are_assigned_to_the_track_of_their_origin() {
+    ExtBuilder::default().build().execute_with(|| {
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let origins: [(OriginCaller, u16); 7] = [
+            (frame_system::RawOrigin::Root.into(), 0),
+            (pallet_custom_origins::Origin::GeneralAdmin.into(), 1),
+            (pallet_custom_origins::Origin::ReferendumCanceller.into(), 2),
+            (pallet_custom_origins::Origin::ReferendumKiller.into(), 3),
+            (pallet_custom_origins::Origin::Treasurer.into(), 10),
+            (pallet_custom_origins::Origin::SmallSpender.into(), 11),
+            (pallet_custom_origins::Origin::BigSpender.into(), 12),
+        ];
+
+        for (index, (proposal_origin, track)) in origins.into_iter().enumerate() {
+            assert_ok!(Referenda::submit(
+                origin_of(BOB.into()),
+                Box::new(proposal_origin),
+                Preimage::bound(call.clone()).unwrap(),
+                DispatchTime::After(0),
+            ));
+            match pallet_referenda::ReferendumInfoFor::<Runtime>::get(index as u32) {
+                Some(pallet_referenda::ReferendumInfo::Ongoing(status)) => {
+                    assert_eq!(status.track, track)
+                }
+                _ => panic!("referendum {} should be ongoing", index),
+            }
+        }
+
+        // Signed origins cannot be used as proposal origins
+        assert_noop!(
+            Referenda::submit(
+                origin_of(BOB.into()),
+                Box::new(frame_system::RawOrigin::Signed(AccountId::from(BOB)).into()),
+                Preimage::bound(call).unwrap(),
+                DispatchTime::After(0),
+            ),
+            pallet_referenda::Error::<Runtime>::NoTrack
+        );
+    });
+}
+
+#[test]
+fn spenders_are_limited_by_their_origin() {
+    ExtBuilder::default().build().execute_with(|| {
+        let small_spender: RuntimeOrigin = pallet_custom_origins::Origin::SmallSpender.into();
+
+        assert_ok!(Treasury::spend_local(
+            small_spender.clone(),
+            1_000 * UNIT,
+            AccountId::from(BOB).into()
+        ));
+        System::assert_last_event(RuntimeEvent::Treasury(
+            pallet_treasury::Event::SpendApproved {
+                proposal_index: 0,
+                amount: 1_000 * UNIT,
+                beneficiary: AccountId::from(BOB),
+            },
+        ));
+
+        assert_noop!(
+            Treasury::spend_local(small_spender, 1_001 * UNIT, AccountId::from(BOB).into()),
+            pallet_treasury::Error::<Runtime>::InsufficientPermission
+        );
+        assert_ok!(Treasury::spend_local(
+            pallet_custom_origins::Origin::BigSpender.into(),
+            10_000 * UNIT,
+            AccountId::from(BOB).into()
+        ));
+        assert_noop!(
+            Treasury::spend_local(
+                origin_of(ALICE.into()),
+                1_000 * UNIT,
+                AccountId::from(BOB).into()
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn general_admin_can_manage_the_chain() {
+    ExtBuilder::default().build().execute_with(|| {
+        let general_admin: RuntimeOrigin = pallet_custom_origins::Origin::GeneralAdmin.into();
+
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(
+            general_admin.clone()
+        ));
+        assert_ok!(MaintenanceMode::resume_normal_operation(
+            general_admin.clone()
+        ));
+        assert_ok!(CreditsTopUp::set_top_up_config(general_admin, None));
+
+        assert_noop!(
+            MaintenanceMode::enter_maintenance_mode(origin_of(ALICE.into())),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            MaintenanceMode::enter_maintenance_mode(
+                pallet_custom_origins::Origin::Treasurer.into()
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn remove_sudo_migration_clears_sudo_storage() {
+    ExtBuilder::default().build().execute_with(|| {
+        let sudo_key = frame_support::storage::storage_prefix(b"Sudo", b"Key");
+        frame_support::storage::unhashed::put(&sudo_key, &AccountId::from(ALICE));
+        // Storage of other pallets is left untouched
+        let alice_balance = Balances::free_balance(AccountId::from(ALICE));
+
+        let migration = RemoveSudo::<Runtime>(Default::default());
+        migration.migrate(Weight::MAX);
+
+        assert!(!frame_support::storage::unhashed::exists(&sudo_key));
+        assert_eq!(
+            Balances::free_balance(AccountId::from(ALICE)),
+            alice_balance
+        );
+    });
+}
@@ -43,8 +43,10 @@ use {
         pallet_prelude::DispatchResult,
         parameter_types,
         traits::{
-            tokens::ConversionToAssetBalance, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8,
-            Contains, InsideBoth, InstanceFilter,
+            fungible::{Balanced, Credit, Inspect},
+            tokens::{imbalance::ResolveTo, ConversionToAssetBalance, Fortitude, Preservation},
+            ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Contains, Get, Imbalance,
+            InsideBoth, InstanceFilter, OnUnbalanced,
         },
         weights::{
             constants::{
@@ -54,6 +56,7 @@ use {
             ConstantMultiplier, Weight, WeightToFee as _, WeightToFeeCoefficient,
             WeightToFeeCoefficients, WeightToFeePolynomial,
         },
+        PalletId,
     },
     frame_system::{
         limits::{BlockLength, BlockWeights},
@@ -68,16 +71,20 @@ use {
     smallvec::smallvec,
     sp_api::impl_runtime_apis,
     sp_consensus_slots::{Slot, SlotDuration},
-    sp_core::{MaxEncodedLen, OpaqueMetadata},
+    sp_core::{blake2_256, MaxEncodedLen, OpaqueMetadata},
     sp_runtime::{
         create_runtime_str, generic, impl_opaque_keys,
-        traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, Verify},
+        traits::{
+            AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+            Verify,
+        },
         transaction_validity::{TransactionSource, TransactionValidity},
-        ApplyExtrinsicResult, MultiSignature,
+        ApplyExtrinsicResult, MultiSignature, Percent,
     },
     sp_std::prelude::*,
     sp_version::RuntimeVersion,
     staging_xcm::{
+        v4::{Asset, Junction, Location, WeightLimit},
         IntoVersion, VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm,
     },
     xcm_runtime_apis::{
@@ -395,14 +402,121 @@ impl pallet_balances::Config for Runtime {
     type WeightInfo = weights::pallet_balances::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub TreasuryAccount: AccountId = PalletId(*b"py/trsry").into_account_truncating();
+    pub TankTopUpAccount: AccountId = PalletId(*b"tns/tank").into_account_truncating();
+    // Shares of fees and tips, anything not assigned is burned
+    pub const TreasuryFeeShare: Percent = Percent::from_percent(20);
+    pub const AuthorFeeShare: Percent = Percent::from_percent(80);
+    // Disabled by default, the orchestrator must accept the native token of this chain
+    pub const TankTopUpFeeShare: Percent = Percent::from_percent(0);
+    pub const TankTopUpThreshold: Balance = 100 * currency::UNIT;
+}
+
+/// Account of the current block author, as long as it is one of the collators assigned to this
+/// chain by the orchestrator.
+pub struct BlockAuthor;
+impl Get<Option<AccountId>> for BlockAuthor {
+    fn get() -> Option<AccountId> {
+        let author = pallet_author_inherent::Author::<Runtime>::get()?;
+        if !AuthoritiesNoting::authorities().contains(&author) {
+            return None;
+        }
+        // Collators are paid to the account matching their nimbus key
+        AccountId::decode(&mut author.encode().as_slice()).ok()
+    }
+}
+
+/// Tops up the tank of this chain on the orchestrator with its share of the fees.
+///
+/// Fees are accumulated in `TankTopUpAccount` and sent through XCM from `on_idle` once they reach
+/// `TankTopUpThreshold`, so delivery fees are not paid for every transaction and the weight of
+/// the transfer is accounted for.
+pub struct XcmTankTopUp;
+impl OnUnbalanced<Credit<AccountId, Balances>> for XcmTankTopUp {
+    fn on_nonzero_unbalanced(amount: Credit<AccountId, Balances>) {
+        ResolveTo::<TankTopUpAccount, Balances>::on_unbalanced(amount);
+    }
+}
+
+impl pallet_cc_credits_top_up::SendTankTopUp for XcmTankTopUp {
+    fn check_weight() -> Weight {
+        RocksDbWeight::get().reads(1)
+    }
+
+    fn send_weight() -> Weight {
+        <weights::pallet_xcm::SubstrateWeight<Runtime> as pallet_xcm::WeightInfo>::transfer_assets()
+    }
+
+    fn send_if_ready() -> bool {
+        let pot = TankTopUpAccount::get();
+        let top_up = TankTopUpThreshold::get();
+        if Balances::reducible_balance(&pot, Preservation::Preserve, Fortitude::Polite) < top_up {
+            return false;
+        }
+
+        // Same derivation as `pallet_services_payment::Pallet::parachain_tank`
+        let tank = (b"modlpy/serpayment", ParachainInfo::parachain_id()).using_encoded(blake2_256);
+        let orchestrator = Location::new(
+            1,
+            Junction::Parachain(AuthoritiesNoting::orchestrator_para_id().into()),
+        );
+        let beneficiary = Location::new(
+            0,
+            Junction::AccountId32 {
+                network: None,
+                id: tank,
+            },
+        );
+
+        if let Err(e) = PolkadotXcm::transfer_assets(
+            RuntimeOrigin::signed(pot),
+            Box::new(VersionedLocation::V4(orchestrator)),
+            Box::new(VersionedLocation::V4(beneficiary)),
+            Box::new(VersionedAssets::V4(
+                Asset::from((xcm_config::SelfReserve::get(), top_up)).into(),
+            )),
+            0,
+            WeightLimit::Unlimited,
+        ) {
+            // The funds stay in the pot and are sent with the next top up
+            log::warn!("Failed to top up the tank on the orchestrator: {:?}", e);
+        }
+        true
+    }
+}
+
+/// Splits fees and tips between the treasury, the block author and the orchestrator tank.
+pub struct DealWithFees;
+impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
+    fn on_nonzero_unbalanced(amount: Credit<AccountId, Balances>) {
+        let total = amount.peek();
+        let (to_treasury, rest) = amount.split(TreasuryFeeShare::get().mul_floor(total));
+        let (to_author, rest) = rest.split(AuthorFeeShare::get().mul_floor(total));
+        let (to_tank, _to_burn) = rest.split(TankTopUpFeeShare::get().mul_floor(total));
+
+        // If the author is unknown its share goes to the treasury
+        let to_treasury = match BlockAuthor::get() {
+            Some(author) => match Balances::resolve(&author, to_author) {
+                Ok(()) => to_treasury,
+                Err(to_author) => to_treasury.merge(to_author),
+            },
+            None => to_treasury.merge(to_author),
+        };
+        ResolveTo::<TreasuryAccount, Balances>::on_unbalanced(to_treasury);
+        XcmTankTopUp::on_unbalanced(to_tank);
+        // Balances pallet automatically burns dropped credits by decreasing total_supply accordingly
+    }
+}
+
 parameter_types! {
     pub const TransactionByteFee: Balance = 1;
 }
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    // This will burn the fees
-    type OnChargeTransaction = FungibleAdapter<Balances, ()>;
+    // Fees and tips are split between the treasury, the block author and the orchestrator tank
+    type OnChargeTransaction = FungibleAdapter<Balances, DealWithFees>;
     type OperationalFeeMultiplier = ConstU8<5>;
     type WeightToFee = WeightToFee;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
@@ -661,6 +775,36 @@ impl pallet_multisig::Config for Runtime {
 
 impl_tanssi_pallets_config!(Runtime);
 
+/// `ParaId` of the orchestrator chain, as noted by `AuthoritiesNoting`.
+pub struct OrchestratorParaId;
+impl Get<cumulus_primitives_core::ParaId> for OrchestratorParaId {
+    fn get() -> cumulus_primitives_core::ParaId {
+        AuthoritiesNoting::orchestrator_para_id()
+    }
+}
+
+parameter_types! {
+    /// Index of `pallet_services_payment` in the orchestrator runtime.
+    pub const OrchestratorServicesPaymentIndex: u8 = 26;
+    /// Blocks to wait for a top up to be reflected in the orchestrator before buying credits again.
+    pub const MinCreditsTopUpInterval: BlockNumber = 10;
+}
+
+impl pallet_cc_credits_top_up::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type SelfParaId = parachain_info::Pallet<Runtime>;
+    type OrchestratorParaId = OrchestratorParaId;
+    type RelayChainStateProvider = cumulus_pallet_parachain_system::RelaychainDataProvider<Self>;
+    type XcmSender = xcm_config::XcmRouter;
+    type GetPurchaseCreditsCall =
+        pallet_cc_credits_top_up::EncodePurchaseCreditsCall<OrchestratorServicesPaymentIndex>;
+    // Top ups are disabled until governance sets the threshold and spending limits
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type MinTopUpInterval = MinCreditsTopUpInterval;
+    type SendTankTopUp = XcmTankTopUp;
+    type WeightInfo = weights::pallet_cc_credits_top_up::SubstrateWeight<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
     pub enum Runtime
@@ -688,6 +832,9 @@ construct_runtime!(
         AuthoritiesNoting: pallet_cc_authorities_noting = 50,
         AuthorInherent: pallet_author_inherent = 51,
 
+        // Orchestrator services
+        CreditsTopUp: pallet_cc_credits_top_up = 52,
+
         // XCM
         XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Storage, Event<T>} = 70,
         CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin} = 71,
@@ -719,6 +866,7 @@ mod benches {
         [pallet_multisig, Multisig]
         [pallet_cc_authorities_noting, AuthoritiesNoting]
         [pallet_author_inherent, AuthorInherent]
+        [pallet_cc_credits_top_up, CreditsTopUp]
         [cumulus_pallet_xcmp_queue, XcmpQueue]
         [cumulus_pallet_dmp_queue, DmpQueue]
         [pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
@@ -0,0 +1,79 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    container_chain_template_simple_runtime::{
+        AccountId, Balance, BalancesConfig, ParachainInfoConfig, Runtime, RuntimeGenesisConfig,
+        System,
+    },
+    cumulus_primitives_core::ParaId,
+    nimbus_primitives::NimbusId,
+    sp_runtime::BuildStorage,
+};
+
+/// Sets the author of the current block, and the collators assigned to this chain by the
+/// orchestrator.
+pub fn set_author(author: NimbusId, authorities: Vec<NimbusId>) {
+    pallet_author_inherent::Author::<Runtime>::put(author);
+    // Authorities are only written by the authorities noting inherent
+    frame_support::storage::unhashed::put(
+        &frame_support::storage::storage_prefix(b"AuthoritiesNoting", b"Authorities"),
+        &authorities,
+    );
+}
+
+pub struct ExtBuilder {
+    // endowed accounts with balances
+    balances: Vec<(AccountId, Balance)>,
+    own_para_id: ParaId,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self {
+            balances: vec![],
+            own_para_id: 2000.into(),
+        }
+    }
+}
+
+impl ExtBuilder {
+    pub fn build_storage(self) -> sp_core::storage::Storage {
+        RuntimeGenesisConfig {
+            balances: BalancesConfig {
+                balances: self.balances,
+            },
+            parachain_info: ParachainInfoConfig {
+                parachain_id: self.own_para_id,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+        .build_storage()
+        .unwrap()
+    }
+
+    pub fn build(self) -> sp_io::TestExternalities {
+        let t = self.build_storage();
+        let mut ext = sp_io::TestExternalities::new(t);
+
+        ext.execute_with(|| {
+            // Events are not stored in block 0
+            System::set_block_number(1);
+        });
+        ext
+    }
+}
@@ -69,6 +69,10 @@ pub enum Subcommand {
 
     /// Precompile the WASM runtime into native code
     PrecompileWasm(sc_cli::PrecompileWasmCmd),
+
+    /// Create new container chains from the templates
+    #[command(subcommand)]
+    ContainerChain(ContainerChainCmd),
 }
 
 /// The `build-spec` command used to build a specification.
@@ -119,6 +123,111 @@ pub struct ExportGenesisWasmCommand {
     pub chain: Option<String>,
 }
 
+#[derive(Debug, clap::Subcommand)]
+pub enum ContainerChainCmd {
+    /// Generate the runtime and node crates of a new container chain from the templates.
+    New(NewContainerChainCmd),
+
+    /// Encode the genesis data of a container chain from its raw chain spec, ready to be
+    /// registered.
+    GenesisData(ContainerChainGenesisDataCmd),
+
+    /// Export the genesis data registered on-chain for a container chain as a raw chain spec.
+    ExportChainSpec(ExportContainerChainSpecCmd),
+}
+
+/// Command for generating a new container chain.
+#[derive(Debug, clap::Parser)]
+pub struct NewContainerChainCmd {
+    /// Name of the chain, the crates are named `<name>-runtime` and `<name>-node`.
+    pub name: String,
+
+    /// Id of the parachain the chain will be registered with.
+    #[arg(long)]
+    pub para_id: u32,
+
+    /// Path to the `container-chains` directory of the Tanssi repository.
+    #[arg(long, default_value = "container-chains")]
+    pub templates_path: PathBuf,
+
+    /// Directory where `runtime-templates/<name>` and `nodes/<name>` are created. Defaults to the
+    /// templates path, so the new crates are members of the Tanssi workspace.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+
+    /// Generate an EVM compatible chain, based on the frontier template.
+    #[arg(long, conflicts_with = "governance")]
+    pub evm: bool,
+
+    /// Use on-chain governance (referenda, conviction voting and treasury) instead of sudo.
+    #[arg(long)]
+    pub governance: bool,
+
+    /// Symbol of the native token. Its decimals are the ones of the template: 18 for EVM chains
+    /// and 12 otherwise.
+    #[arg(long, default_value = "UNIT")]
+    pub token_symbol: String,
+
+    /// SS58 prefix of the chain addresses.
+    #[arg(long, default_value_t = 42)]
+    pub ss58_prefix: u16,
+
+    /// Percentage of the transaction fees sent to the treasury.
+    #[arg(long, default_value_t = 20)]
+    pub treasury_fee_share: u8,
+
+    /// Percentage of the transaction fees paid to the block author.
+    #[arg(long, default_value_t = 80)]
+    pub author_fee_share: u8,
+
+    /// Percentage of the transaction fees used to buy credits in the orchestrator. Fees not
+    /// assigned to the treasury, the author or the orchestrator are burned.
+    #[arg(long, default_value_t = 0)]
+    pub tank_top_up_fee_share: u8,
+
+    /// Do not allow registering foreign assets.
+    #[arg(long)]
+    pub no_foreign_assets: bool,
+}
+
+/// Command for exporting the genesis data used to register a container chain.
+#[derive(Debug, clap::Parser)]
+pub struct ContainerChainGenesisDataCmd {
+    /// Raw chain spec of the container chain.
+    #[arg(long)]
+    pub chain_spec: PathBuf,
+
+    /// Output file name or stdout if unspecified.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+
+    /// Write output in binary. Default is to write in hex.
+    #[arg(short, long)]
+    pub raw: bool,
+}
+
+/// Command for exporting the chain spec of a registered container chain.
+#[derive(Debug, clap::Parser)]
+pub struct ExportContainerChainSpecCmd {
+    /// Para id of the container chain.
+    #[arg(long)]
+    pub para_id: u32,
+
+    /// Output file name or stdout if unspecified.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub shared_params: SharedParams,
+}
+
+impl CliConfiguration for ExportContainerChainSpecCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+}
+
 #[derive(Debug, clap::Parser)]
 #[group(skip)]
 pub struct RunCmd {
@@ -138,6 +247,11 @@ pub struct RunCmd {
     /// Id of the parachain this collator collates for.
     #[arg(long)]
     pub parachain_id: Option<u32>,
+
+    /// Index the rewards of pooled staking delegators, keeping the given number of
+    /// sessions, and serve them through the `tanssi_pooledStakingRewardHistory` RPC.
+    #[arg(long)]
+    pub reward_history_sessions: Option<u32>,
 }
 
 impl std::ops::Deref for RunCmd {
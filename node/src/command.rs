@@ -402,6 +402,7 @@ pub fn run() -> Result<()> {
             }
         }
         Some(Subcommand::Key(cmd)) => Ok(cmd.run(&cli)?),
+        Some(Subcommand::ContainerChain(cmd)) => cmd.run(&cli),
         Some(Subcommand::PrecompileWasm(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             runner.async_run(|config| {
@@ -442,7 +443,7 @@ pub fn run() -> Result<()> {
 					config.chain_spec.is_dev() || relay_chain_id == Some("dev-service".to_string()) || cli.run.dev_service;
 
 				if dev_service {
-					return crate::service::start_dev_node(config, cli.run.sealing, hwbench, id).map_err(Into::into)
+					return crate::service::start_dev_node(config, cli.run.sealing, hwbench, id, cli.run.reward_history_sessions).map_err(Into::into)
 				}
 
 				let parachain_account =
@@ -490,6 +491,7 @@ pub fn run() -> Result<()> {
 					collator_options,
 					id,
 					hwbench,
+					cli.run.reward_history_sessions,
 				)
 				.await
 				.map(|r| r.0)
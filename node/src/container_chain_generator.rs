@@ -0,0 +1,711 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Generation of the runtime and node crates of new container chains.
+//!
+//! The crates are copies of the templates in `container-chains`, with the crate names replaced
+//! and a small set of well known snippets patched according to the options of the
+//! `container-chain new` command. Every patch must match the template code exactly, so if a
+//! template changes in a way the generator does not expect, generation fails instead of silently
+//! producing a crate that ignores some option.
+
+use {
+    crate::{
+        cli::{
+            Cli, ContainerChainCli, ContainerChainCmd, ContainerChainGenesisDataCmd,
+            ExportContainerChainSpecCmd, NewContainerChainCmd,
+        },
+        container_chain_spawner::parse_boot_nodes_ignore_invalid,
+        service::{NodeConfig, ParachainClient},
+    },
+    cumulus_primitives_core::ParaId,
+    dp_container_chain_genesis_data::json::container_chain_genesis_data_from_path,
+    node_common::command::check_genesis_data_size,
+    pallet_registrar_runtime_api::RegistrarApi,
+    parity_scale_codec::Encode,
+    sc_chain_spec::ChainSpec,
+    sc_cli::SubstrateCli,
+    sp_api::ProvideRuntimeApi,
+    sp_blockchain::HeaderBackend,
+    sp_core::{hexdisplay::HexDisplay, Get},
+    std::{
+        fs,
+        io::Write,
+        path::{Path, PathBuf},
+    },
+};
+
+impl ContainerChainCmd {
+    /// Run the container chain subcommands
+    pub fn run(&self, cli: &Cli) -> sc_cli::Result<()> {
+        match self {
+            ContainerChainCmd::New(cmd) => {
+                let generated = generate(cmd).map_err(sc_cli::Error::Input)?;
+                println!(
+                    "Generated runtime `{}` at {}",
+                    generated.runtime_package,
+                    generated.runtime_path.display()
+                );
+                println!(
+                    "Generated node `{}` at {}",
+                    generated.node_package,
+                    generated.node_path.display()
+                );
+                println!("To obtain the genesis data to register the chain, run:");
+                println!("  cargo build --release -p {}", generated.node_package);
+                println!(
+                    "  ./target/release/{} build-spec --chain local --raw --parachain-id {} > {}-raw-spec.json",
+                    generated.node_package, cmd.para_id, cmd.name
+                );
+                println!(
+                    "  tanssi-node container-chain genesis-data --chain-spec {}-raw-spec.json",
+                    cmd.name
+                );
+
+                Ok(())
+            }
+            ContainerChainCmd::GenesisData(cmd) => export_genesis_data(cmd),
+            ContainerChainCmd::ExportChainSpec(cmd) => {
+                let runner = cli.create_runner(cmd)?;
+                runner.sync_run(|config| {
+                    let partials = NodeConfig::new_builder(&config, None)?;
+                    export_chain_spec(cmd, &*config.chain_spec, &partials.client)
+                        .map_err(sc_cli::Error::Application)
+                })
+            }
+        }
+    }
+}
+
+/// Template a new container chain is generated from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    Simple,
+    Frontier,
+    Governance,
+}
+
+impl Template {
+    pub fn new(evm: bool, governance: bool) -> Result<Self, String> {
+        match (evm, governance) {
+            (false, false) => Ok(Template::Simple),
+            (true, false) => Ok(Template::Frontier),
+            (false, true) => Ok(Template::Governance),
+            (true, true) => Err("There is no EVM template with governance yet".to_string()),
+        }
+    }
+
+    /// Directory of the runtime template, inside `runtime-templates`.
+    fn runtime_dir(&self) -> &'static str {
+        match self {
+            Template::Simple => "simple",
+            Template::Frontier => "frontier",
+            Template::Governance => "governance",
+        }
+    }
+
+    /// Directory of the node template, inside `nodes`.
+    fn node_dir(&self) -> &'static str {
+        match self {
+            Template::Simple | Template::Governance => "simple",
+            Template::Frontier => "frontier",
+        }
+    }
+
+    fn runtime_package(&self) -> &'static str {
+        match self {
+            Template::Simple => "container-chain-template-simple-runtime",
+            Template::Frontier => "container-chain-template-frontier-runtime",
+            Template::Governance => "container-chain-template-governance-runtime",
+        }
+    }
+
+    /// Runtime package used by the node template, which is not always the runtime of the template.
+    fn node_runtime_package(&self) -> &'static str {
+        match self {
+            Template::Simple | Template::Governance => "container-chain-template-simple-runtime",
+            Template::Frontier => "container-chain-template-frontier-runtime",
+        }
+    }
+
+    fn node_package(&self) -> &'static str {
+        match self {
+            Template::Simple | Template::Governance => "container-chain-simple-node",
+            Template::Frontier => "container-chain-frontier-node",
+        }
+    }
+}
+
+/// Replacement of a snippet of template code.
+struct Patch {
+    /// File to patch, relative to the root of the generated crate
+    file: &'static str,
+    from: String,
+    to: String,
+}
+
+impl Patch {
+    fn new(file: &'static str, from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            file,
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GeneratedChain {
+    pub runtime_package: String,
+    pub runtime_path: PathBuf,
+    pub node_package: String,
+    pub node_path: PathBuf,
+}
+
+/// Generate the runtime and node crates of a new container chain.
+pub fn generate(cmd: &NewContainerChainCmd) -> Result<GeneratedChain, String> {
+    validate_name(&cmd.name)?;
+    validate_token_symbol(&cmd.token_symbol)?;
+    let fee_shares = u32::from(cmd.treasury_fee_share)
+        + u32::from(cmd.author_fee_share)
+        + u32::from(cmd.tank_top_up_fee_share);
+    if fee_shares > 100 {
+        return Err(format!(
+            "Fee shares add up to {}%, they cannot be more than 100%",
+            fee_shares
+        ));
+    }
+    let template = Template::new(cmd.evm, cmd.governance)?;
+
+    let output = cmd.output.as_ref().unwrap_or(&cmd.templates_path);
+    let runtime_path = output.join("runtime-templates").join(&cmd.name);
+    let node_path = output.join("nodes").join(&cmd.name);
+    for path in [&runtime_path, &node_path] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+
+    let runtime_package = format!("{}-runtime", cmd.name);
+    let node_package = format!("{}-node", cmd.name);
+
+    copy_dir(
+        &cmd.templates_path
+            .join("runtime-templates")
+            .join(template.runtime_dir()),
+        &runtime_path,
+    )?;
+    copy_dir(
+        &cmd.templates_path.join("nodes").join(template.node_dir()),
+        &node_path,
+    )?;
+
+    // The generated node depends on the generated runtime by path, the workspace does not know
+    // about it
+    apply_patches(
+        &node_path,
+        &[Patch::new(
+            "Cargo.toml",
+            format!(
+                "{} = {{ workspace = true, features = [ \"std\" ] }}",
+                template.node_runtime_package()
+            ),
+            format!(
+                "{} = {{ path = \"../../runtime-templates/{}\", features = [ \"std\" ] }}",
+                runtime_package, cmd.name
+            ),
+        )],
+    )?;
+    rename_crate(&runtime_path, template.runtime_package(), &runtime_package)?;
+    rename_crate(
+        &node_path,
+        template.node_runtime_package(),
+        &runtime_package,
+    )?;
+    rename_crate(&node_path, template.node_package(), &node_package)?;
+
+    apply_patches(&runtime_path, &runtime_patches(cmd))?;
+    apply_patches(&node_path, &node_patches(cmd, template))?;
+
+    Ok(GeneratedChain {
+        runtime_package,
+        runtime_path,
+        node_package,
+        node_path,
+    })
+}
+
+fn runtime_patches(cmd: &NewContainerChainCmd) -> Vec<Patch> {
+    let mut patches = vec![
+        Patch::new(
+            "src/lib.rs",
+            "    pub const SS58Prefix: u16 = 42;",
+            format!("    pub const SS58Prefix: u16 = {};", cmd.ss58_prefix),
+        ),
+        Patch::new(
+            "src/lib.rs",
+            "    pub const TreasuryFeeShare: Percent = Percent::from_percent(20);",
+            format!(
+                "    pub const TreasuryFeeShare: Percent = Percent::from_percent({});",
+                cmd.treasury_fee_share
+            ),
+        ),
+        Patch::new(
+            "src/lib.rs",
+            "    pub const AuthorFeeShare: Percent = Percent::from_percent(80);",
+            format!(
+                "    pub const AuthorFeeShare: Percent = Percent::from_percent({});",
+                cmd.author_fee_share
+            ),
+        ),
+        Patch::new(
+            "src/lib.rs",
+            "    pub const TankTopUpFeeShare: Percent = Percent::from_percent(0);",
+            format!(
+                "    pub const TankTopUpFeeShare: Percent = Percent::from_percent({});",
+                cmd.tank_top_up_fee_share
+            ),
+        ),
+    ];
+
+    if cmd.no_foreign_assets {
+        // Foreign assets can only be created through the creator pallet, so disabling it is
+        // enough to keep the chain without assets
+        patches.push(Patch::new(
+            "src/xcm_config.rs",
+            "    type ForeignAssetCreatorOrigin = EnsureRoot<AccountId>;",
+            "    // Registering foreign assets is disabled\n    type ForeignAssetCreatorOrigin = frame_support::traits::NeverEnsureOrigin<AccountId>;",
+        ));
+        patches.push(Patch::new(
+            "src/lib.rs",
+            "        [pallet_foreign_asset_creator, ForeignAssetsCreator]\n",
+            "",
+        ));
+    }
+
+    patches
+}
+
+fn node_patches(cmd: &NewContainerChainCmd, template: Template) -> Vec<Patch> {
+    let mut patches = vec![
+        Patch::new(
+            "src/chain_spec.rs",
+            "    properties.insert(\"tokenSymbol\".into(), \"UNIT\".into());",
+            format!(
+                "    properties.insert(\"tokenSymbol\".into(), \"{}\".into());",
+                cmd.token_symbol
+            ),
+        ),
+        Patch::new(
+            "src/chain_spec.rs",
+            "    properties.insert(\"ss58Format\".into(), 42.into());",
+            format!(
+                "    properties.insert(\"ss58Format\".into(), {}.into());",
+                cmd.ss58_prefix
+            ),
+        ),
+        Patch::new(
+            "src/command.rs",
+            "self.para_id.unwrap_or(2000)",
+            format!("self.para_id.unwrap_or({})", cmd.para_id),
+        ),
+    ];
+
+    if template == Template::Governance {
+        // The simple node configures a sudo key in genesis, which the governance runtime does not
+        // have
+        let runtime_crate = format!("{}_runtime", cmd.name.replace('-', "_"));
+        patches.extend([
+            Patch::new(
+                "src/chain_spec.rs",
+                format!(
+                    "        sudo: {}::SudoConfig {{\n            key: Some(root_key),\n        }},\n",
+                    runtime_crate
+                ),
+                "        treasury: Default::default(),\n",
+            ),
+            Patch::new(
+                "src/chain_spec.rs",
+                "    id: ParaId,\n    root_key: AccountId,\n) -> serde_json::Value {",
+                "    id: ParaId,\n) -> serde_json::Value {",
+            ),
+            Patch::new(
+                "src/chain_spec.rs",
+                "        para_id,\n        get_account_id_from_seed::<sr25519::Public>(\"Alice\"),\n    ))",
+                "        para_id,\n    ))",
+            ),
+        ]);
+    }
+
+    patches
+}
+
+fn validate_name(name: &str) -> Result<(), String> {
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && !name.ends_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid {
+        return Err(format!(
+            "Invalid chain name {:?}: it must start with a lowercase letter and contain only \
+            lowercase letters, digits and dashes",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_token_symbol(symbol: &str) -> Result<(), String> {
+    if symbol.is_empty() || !symbol.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!(
+            "Invalid token symbol {:?}: it must be alphanumeric",
+            symbol
+        ));
+    }
+
+    Ok(())
+}
+
+/// Recursively copy a template crate, skipping build artifacts.
+fn copy_dir(from: &Path, to: &Path) -> Result<(), String> {
+    let entries = fs::read_dir(from)
+        .map_err(|e| format!("Failed to read template {}: {}", from.display(), e))?;
+    fs::create_dir_all(to).map_err(|e| format!("Failed to create {}: {}", to.display(), e))?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read template entry: {}", e))?;
+        let path = entry.path();
+        let target = to.join(entry.file_name());
+        if path.is_dir() {
+            if entry.file_name() == "target" {
+                continue;
+            }
+            copy_dir(&path, &target)?;
+        } else {
+            fs::copy(&path, &target)
+                .map_err(|e| format!("Failed to copy {}: {}", path.display(), e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace the package and crate names of `old_package` with the ones of `new_package` in all the
+/// sources and manifests of the crate at `root`.
+fn rename_crate(root: &Path, old_package: &str, new_package: &str) -> Result<(), String> {
+    let old_crate = old_package.replace('-', "_");
+    let new_crate = new_package.replace('-', "_");
+
+    for_each_file(root, &mut |path| {
+        if !matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("rs" | "toml")
+        ) {
+            return Ok(());
+        }
+        let content = read(path)?;
+        let renamed = content
+            .replace(old_package, new_package)
+            .replace(&old_crate, &new_crate);
+        if renamed != content {
+            write(path, &renamed)?;
+        }
+
+        Ok(())
+    })
+}
+
+fn apply_patches(root: &Path, patches: &[Patch]) -> Result<(), String> {
+    for patch in patches {
+        let path = root.join(patch.file);
+        let content = read(&path)?;
+        if !content.contains(&patch.from) {
+            return Err(format!(
+                "{} does not contain the expected template code, the generator needs to be \
+                updated:\n{}",
+                path.display(),
+                patch.from
+            ));
+        }
+        write(&path, &content.replace(&patch.from, &patch.to))?;
+    }
+
+    Ok(())
+}
+
+fn for_each_file(dir: &Path, f: &mut dyn FnMut(&Path) -> Result<(), String>) -> Result<(), String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("Failed to read entry: {}", e))?
+            .path();
+        if path.is_dir() {
+            for_each_file(&path, f)?;
+        } else {
+            f(&path)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Encode the genesis data of a raw container chain spec, checking that it can be registered.
+pub fn encode_genesis_data(chain_spec_path: &Path) -> Result<Vec<u8>, String> {
+    let path = chain_spec_path
+        .to_str()
+        .ok_or_else(|| format!("Invalid path {}", chain_spec_path.display()))?;
+    let (para_id, genesis_data, _boot_nodes) = container_chain_genesis_data_from_path(path)?;
+
+    check_genesis_data_size(
+        &genesis_data,
+        dancebox_runtime::MaxEncodedGenesisDataSize::get(),
+    )
+    .map_err(|e| format!("Para id {}: {}", u32::from(para_id), e))?;
+
+    Ok(genesis_data.encode())
+}
+
+fn export_genesis_data(cmd: &ContainerChainGenesisDataCmd) -> sc_cli::Result<()> {
+    let encoded = encode_genesis_data(&cmd.chain_spec).map_err(sc_cli::Error::Input)?;
+    let output_buf = if cmd.raw {
+        encoded
+    } else {
+        format!("0x{:?}", HexDisplay::from(&encoded)).into_bytes()
+    };
+
+    if let Some(output) = &cmd.output {
+        fs::write(output, output_buf)?;
+    } else {
+        std::io::stdout().write_all(&output_buf)?;
+    }
+
+    Ok(())
+}
+
+/// Build the raw chain spec of a container chain from the genesis data and boot nodes registered
+/// in the latest finalized block of the orchestrator.
+fn export_chain_spec(
+    cmd: &ExportContainerChainSpecCmd,
+    orchestrator_chain_spec: &dyn ChainSpec,
+    client: &ParachainClient,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let para_id: ParaId = cmd.para_id.into();
+    let block_hash = client.info().finalized_hash;
+
+    let genesis_data = client
+        .runtime_api()
+        .genesis_data(block_hash, para_id)
+        .map_err(|e| format!("Failed to call genesis_data runtime api: {}", e))?
+        .ok_or_else(|| {
+            format!(
+                "No genesis data registered for container chain id {}",
+                para_id
+            )
+        })?;
+    let boot_nodes_raw = client
+        .runtime_api()
+        .boot_nodes(block_hash, para_id)
+        .map_err(|e| format!("Failed to call boot_nodes runtime api: {}", e))?;
+    let boot_nodes = parse_boot_nodes_ignore_invalid(boot_nodes_raw, para_id);
+
+    let relay_chain = crate::chain_spec::Extensions::try_get(orchestrator_chain_spec)
+        .map(|e| e.relay_chain.clone())
+        .ok_or("Could not find relay_chain extension in chain-spec.")?;
+    let chain_spec = ContainerChainCli::chain_spec_from_genesis_data(
+        cmd.para_id,
+        genesis_data,
+        orchestrator_chain_spec.chain_type(),
+        relay_chain,
+        boot_nodes,
+    )?;
+    let output_buf = chain_spec.as_json(true)?.into_bytes();
+
+    if let Some(output) = &cmd.output {
+        fs::write(output, output_buf)?;
+    } else {
+        std::io::stdout().write_all(&output_buf)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, clap::Parser};
+
+    fn templates_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../container-chains")
+    }
+
+    fn new_cmd(args: &[&str], output: &Path) -> NewContainerChainCmd {
+        let templates_path = templates_path();
+        let mut full_args = vec![
+            "new",
+            "--templates-path",
+            templates_path.to_str().unwrap(),
+            "--output",
+            output.to_str().unwrap(),
+        ];
+        full_args.extend_from_slice(args);
+
+        NewContainerChainCmd::parse_from(full_args)
+    }
+
+    #[test]
+    fn generates_simple_chain() {
+        let output = tempfile::tempdir().unwrap();
+        let cmd = new_cmd(
+            &["my-chain", "--para-id", "2077", "--token-symbol", "MYC"],
+            output.path(),
+        );
+
+        let generated = generate(&cmd).unwrap();
+
+        assert_eq!(generated.runtime_package, "my-chain-runtime");
+        assert_eq!(generated.node_package, "my-chain-node");
+        let runtime_manifest = read(&generated.runtime_path.join("Cargo.toml")).unwrap();
+        assert!(runtime_manifest.contains("name = \"my-chain-runtime\""));
+        let node_manifest = read(&generated.node_path.join("Cargo.toml")).unwrap();
+        assert!(node_manifest.contains("name = \"my-chain-node\""));
+        assert!(node_manifest.contains(
+            "my-chain-runtime = { path = \"../../runtime-templates/my-chain\", features = [ \"std\" ] }"
+        ));
+        assert!(node_manifest.contains("\"my-chain-runtime/runtime-benchmarks\""));
+        let chain_spec = read(&generated.node_path.join("src/chain_spec.rs")).unwrap();
+        assert!(!chain_spec.contains("container_chain_template_simple_runtime"));
+        assert!(chain_spec.contains("my_chain_runtime::RuntimeGenesisConfig"));
+        assert!(chain_spec.contains("\"tokenSymbol\".into(), \"MYC\".into()"));
+        let command = read(&generated.node_path.join("src/command.rs")).unwrap();
+        assert!(command.contains("self.para_id.unwrap_or(2077)"));
+    }
+
+    #[test]
+    fn generates_chain_with_custom_runtime_options() {
+        let output = tempfile::tempdir().unwrap();
+        let cmd = new_cmd(
+            &[
+                "evm-chain",
+                "--para-id",
+                "2001",
+                "--evm",
+                "--ss58-prefix",
+                "1284",
+                "--treasury-fee-share",
+                "50",
+                "--author-fee-share",
+                "30",
+                "--tank-top-up-fee-share",
+                "20",
+                "--no-foreign-assets",
+            ],
+            output.path(),
+        );
+
+        let generated = generate(&cmd).unwrap();
+
+        let lib = read(&generated.runtime_path.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub const SS58Prefix: u16 = 1284;"));
+        assert!(lib.contains("TreasuryFeeShare: Percent = Percent::from_percent(50);"));
+        assert!(lib.contains("AuthorFeeShare: Percent = Percent::from_percent(30);"));
+        assert!(lib.contains("TankTopUpFeeShare: Percent = Percent::from_percent(20);"));
+        assert!(!lib.contains("[pallet_foreign_asset_creator, ForeignAssetsCreator]"));
+        let xcm_config = read(&generated.runtime_path.join("src/xcm_config.rs")).unwrap();
+        assert!(xcm_config.contains(
+            "type ForeignAssetCreatorOrigin = frame_support::traits::NeverEnsureOrigin<AccountId>;"
+        ));
+        let chain_spec = read(&generated.node_path.join("src/chain_spec.rs")).unwrap();
+        assert!(chain_spec.contains("evm_chain_runtime::RuntimeGenesisConfig"));
+        assert!(chain_spec.contains("\"ss58Format\".into(), 1284.into()"));
+    }
+
+    #[test]
+    fn governance_chain_has_no_sudo_key() {
+        let output = tempfile::tempdir().unwrap();
+        let cmd = new_cmd(
+            &["gov-chain", "--para-id", "2002", "--governance"],
+            output.path(),
+        );
+
+        let generated = generate(&cmd).unwrap();
+
+        let runtime_manifest = read(&generated.runtime_path.join("Cargo.toml")).unwrap();
+        assert!(runtime_manifest.contains("name = \"gov-chain-runtime\""));
+        let chain_spec = read(&generated.node_path.join("src/chain_spec.rs")).unwrap();
+        assert!(!chain_spec.contains("SudoConfig"));
+        assert!(!chain_spec.contains("root_key"));
+        assert!(chain_spec.contains("gov_chain_runtime::RuntimeGenesisConfig"));
+        assert!(chain_spec.contains("treasury: Default::default(),"));
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        let output = tempfile::tempdir().unwrap();
+
+        let cmd = new_cmd(&["My_Chain", "--para-id", "2000"], output.path());
+        assert!(generate(&cmd).unwrap_err().contains("Invalid chain name"));
+
+        let cmd = new_cmd(
+            &[
+                "my-chain",
+                "--para-id",
+                "2000",
+                "--treasury-fee-share",
+                "30",
+            ],
+            output.path(),
+        );
+        assert!(generate(&cmd)
+            .unwrap_err()
+            .contains("cannot be more than 100%"));
+
+        let cmd = new_cmd(
+            &["my-chain", "--para-id", "2000", "--token-symbol", ""],
+            output.path(),
+        );
+        assert!(generate(&cmd).unwrap_err().contains("Invalid token symbol"));
+
+        assert!(NewContainerChainCmd::try_parse_from([
+            "new",
+            "my-chain",
+            "--para-id",
+            "2000",
+            "--evm",
+            "--governance"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn does_not_overwrite_existing_crates() {
+        let output = tempfile::tempdir().unwrap();
+        let cmd = new_cmd(&["my-chain", "--para-id", "2000"], output.path());
+
+        generate(&cmd).unwrap();
+
+        assert!(generate(&cmd).unwrap_err().contains("already exists"));
+    }
+}
@@ -35,6 +35,9 @@ use {
     futures::FutureExt,
     node_common::command::generate_genesis_block,
     pallet_author_noting_runtime_api::AuthorNotingApi,
+    pallet_registrar::PARAS_CODE_BY_HASH_INDEX,
+    pallet_registrar_runtime_api::ContainerChainCodeInfo,
+    parity_scale_codec::Decode,
     polkadot_primitives::CollatorPair,
     sc_cli::{Database, SyncMode},
     sc_network::config::MultiaddrWithPeerId,
@@ -281,7 +284,8 @@ async fn try_spawn<SelectSyncMode: TSelectSyncMode>(
     let keep_db = container_chain_cli.base.keep_db;
 
     // Get a closure that checks if db_path exists.Need this to know when to use full sync instead of warp sync.
-    let check_db_exists = {
+    // Also get the path of the directory where the latest runtime of the container chain is cached.
+    let (check_db_exists, runtime_cache_path) = {
         // Get db_path from config
         let mut container_chain_cli_config = sc_cli::SubstrateCli::create_configuration(
             &container_chain_cli,
@@ -300,10 +304,42 @@ async fn try_spawn<SelectSyncMode: TSelectSyncMode>(
         db_path.set_file_name(format!("full-container-{}", container_chain_para_id));
         container_chain_cli_config.database.set_path(&db_path);
 
+        // So instead of "db/full-container-2000" we have "db/wasm-container-2000"
+        let mut runtime_cache_path = db_path.clone();
+        runtime_cache_path.set_file_name(format!("wasm-container-{}", container_chain_para_id));
+
         // Return a closure because we may need to check if the db exists multiple times
-        move || db_path.exists()
+        (move || db_path.exists(), runtime_cache_path)
     };
 
+    // Use the latest container chain runtime as a wasm override, so that it is compiled and cached
+    // when the node starts, instead of when syncing reaches the block of the last runtime upgrade.
+    match cache_latest_container_chain_runtime(
+        &orchestrator_chain_interface,
+        orchestrator_block_hash,
+        &relay_chain_interface,
+        container_chain_para_id,
+        &runtime_cache_path,
+    )
+    .await
+    {
+        Ok(true) => {
+            container_chain_cli
+                .base
+                .base
+                .import_params
+                .wasm_runtime_overrides = Some(runtime_cache_path);
+        }
+        Ok(false) => {}
+        Err(e) => {
+            log::warn!(
+                "Failed to cache latest runtime of container chain {}: {}",
+                container_chain_para_id,
+                e
+            );
+        }
+    }
+
     // Start container chain node. After starting, check if the database is good or needs to
     // be removed. If the db needs to be removed, this function will handle the node restart, and
     // return the components of a running container chain node.
@@ -860,6 +896,70 @@ async fn get_latest_container_block_number_from_orchestrator(
     last_container_block_from_orchestrator
 }
 
+/// Read the latest runtime code hash of a container chain from the orchestrator registrar, and
+/// download that code from the relay chain into `runtime_cache_path`. Only the latest runtime is
+/// kept in that directory, because wasm overrides cannot have two runtimes with the same spec
+/// version. Returns `false` if the registrar does not know the code hash of this container chain.
+async fn cache_latest_container_chain_runtime(
+    orchestrator_chain_interface: &Arc<dyn OrchestratorChainInterface>,
+    orchestrator_block_hash: PHash,
+    relay_chain_interface: &Arc<dyn RelayChainInterface>,
+    container_chain_para_id: ParaId,
+    runtime_cache_path: &Path,
+) -> Result<bool, String> {
+    let code_info_key = pallet_registrar::ParaCodeInfo::<dancebox_runtime::Runtime>::hashed_key_for(
+        container_chain_para_id,
+    );
+    let code_info = match orchestrator_chain_interface
+        .get_storage_by_key(orchestrator_block_hash, &code_info_key)
+        .await
+        .map_err(|e| format!("Failed to read code info from orchestrator chain: {}", e))?
+    {
+        Some(encoded) => ContainerChainCodeInfo::decode(&mut encoded.as_slice())
+            .map_err(|e| format!("Failed to decode code info: {}", e))?,
+        None => return Ok(false),
+    };
+    let code_hash = code_info.code_hash;
+
+    let wasm_path = runtime_cache_path.join(format!("{:?}.wasm", code_hash));
+    if wasm_path.exists() {
+        return Ok(true);
+    }
+
+    let relay_block_hash = relay_chain_interface
+        .best_block_hash()
+        .await
+        .map_err(|e| format!("Failed to get relay chain best block hash: {}", e))?;
+    let code_key = [PARAS_CODE_BY_HASH_INDEX, code_hash.as_bytes()].concat();
+    let encoded_code = relay_chain_interface
+        .get_storage_by_key(relay_block_hash, &code_key)
+        .await
+        .map_err(|e| format!("Failed to read code from relay chain: {}", e))?
+        .ok_or_else(|| format!("Code {:?} not found in relay chain", code_hash))?;
+    let code = Vec::<u8>::decode(&mut encoded_code.as_slice())
+        .map_err(|e| format!("Failed to decode code: {}", e))?;
+    if H256::from(sp_core::blake2_256(&code)) != code_hash {
+        return Err(format!("Code hash mismatch, expected {:?}", code_hash));
+    }
+
+    if runtime_cache_path.exists() {
+        std::fs::remove_dir_all(runtime_cache_path)
+            .map_err(|e| format!("Failed to remove old runtimes: {}", e))?;
+    }
+    std::fs::create_dir_all(runtime_cache_path)
+        .map_err(|e| format!("Failed to create runtime cache directory: {}", e))?;
+    std::fs::write(&wasm_path, code).map_err(|e| format!("Failed to write runtime: {}", e))?;
+
+    log::info!(
+        "Cached runtime {:?} of container chain {} at {:?}",
+        code_hash,
+        container_chain_para_id,
+        wasm_path
+    );
+
+    Ok(true)
+}
+
 #[derive(Debug)]
 #[allow(dead_code)]
 enum DbRemovalReason {
@@ -989,7 +1089,7 @@ fn delete_empty_folders_recursive(path: &Path) {
 }
 
 /// Parse a list of boot nodes in `Vec<u8>` format. Invalid boot nodes are filtered out.
-fn parse_boot_nodes_ignore_invalid(
+pub(crate) fn parse_boot_nodes_ignore_invalid(
     boot_nodes_raw: Vec<Vec<u8>>,
     container_chain_para_id: ParaId,
 ) -> Vec<MultiaddrWithPeerId> {
@@ -21,6 +21,7 @@
 mod chain_spec;
 mod cli;
 mod command;
+mod container_chain_generator;
 mod container_chain_monitor;
 mod container_chain_spawner;
 mod rpc;
@@ -24,9 +24,10 @@
 pub use sc_rpc::DenyUnsafe;
 use {
     cumulus_primitives_core::ParaId,
-    dancebox_runtime::{opaque::Block, AccountId, Index as Nonce},
+    dancebox_runtime::{opaque::Block, AccountId, Balance, Index as Nonce},
     manual_xcm_rpc::{ManualXcm, ManualXcmApiServer},
     polkadot_primitives::Hash,
+    pooled_staking_rewards_rpc::{PooledStakingRewards, PooledStakingRewardsApiServer as _},
     sc_client_api::{AuxStore, UsageProvider},
     sc_consensus_manual_seal::{
         rpc::{ManualSeal, ManualSealApiServer},
@@ -58,6 +59,8 @@ pub struct FullDeps<C, P> {
     pub command_sink: Option<futures::channel::mpsc::Sender<EngineCommand<Hash>>>,
     /// Channels for manual xcm messages (downward, hrmp)
     pub xcm_senders: Option<(flume::Sender<Vec<u8>>, flume::Sender<(ParaId, Vec<u8>)>)>,
+    /// Whether the reward history of pooled staking delegators is indexed
+    pub reward_history: bool,
 }
 
 /// Instantiate all RPC extensions.
@@ -88,11 +91,16 @@ where
         deny_unsafe,
         command_sink,
         xcm_senders,
+        reward_history,
     } = deps;
 
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
     module.merge(StreamPayment::<_, Block>::new(client.clone()).into_rpc())?;
-    module.merge(ServicesPayment::<_, Block>::new(client).into_rpc())?;
+    module.merge(ServicesPayment::<_, Block>::new(client.clone()).into_rpc())?;
+
+    if reward_history {
+        module.merge(PooledStakingRewards::<_, AccountId, Balance>::new(client).into_rpc())?;
+    }
 
     if let Some(command_sink) = command_sink {
         module.merge(
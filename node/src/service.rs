@@ -41,7 +41,7 @@ use {
     dancebox_runtime::AccountId,
     dancebox_runtime::{
         opaque::{Block, Hash},
-        RuntimeApi,
+        Balance, RuntimeApi,
     },
     dc_orchestrator_chain_interface::{
         OrchestratorChainError, OrchestratorChainInterface, OrchestratorChainResult, PHash, PHeader,
@@ -70,7 +70,10 @@ use {
     sp_api::StorageProof,
     sp_consensus::{EnableProofRecording, SyncOracle},
     sp_consensus_slots::{Slot, SlotDuration},
-    sp_core::{traits::SpawnEssentialNamed, H256},
+    sp_core::{
+        traits::{SpawnEssentialNamed, SpawnNamed},
+        H256,
+    },
     sp_keystore::KeystorePtr,
     sp_state_machine::{Backend as StateBackend, StorageValue},
     std::{pin::Pin, sync::Arc, time::Duration},
@@ -169,6 +172,34 @@ impl sp_inherents::InherentDataProvider for MockTimestampInherentDataProvider {
     }
 }
 
+/// Inherent data provider that only provides data when the inner provider exists.
+struct OptionalInherentDataProvider<T>(Option<T>);
+#[async_trait::async_trait]
+impl<T: sp_inherents::InherentDataProvider> sp_inherents::InherentDataProvider
+    for OptionalInherentDataProvider<T>
+{
+    async fn provide_inherent_data(
+        &self,
+        inherent_data: &mut sp_inherents::InherentData,
+    ) -> Result<(), sp_inherents::Error> {
+        match &self.0 {
+            Some(inner) => inner.provide_inherent_data(inherent_data).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn try_handle_error(
+        &self,
+        identifier: &sp_inherents::InherentIdentifier,
+        error: &[u8],
+    ) -> Option<Result<(), sp_inherents::Error>> {
+        match &self.0 {
+            Some(inner) => inner.try_handle_error(identifier, error).await,
+            None => None,
+        }
+    }
+}
+
 /// Background task used to detect changes to container chain assignment,
 /// and start/stop container chains on demand. The check runs on every new block.
 pub fn build_check_assigned_para_id(
@@ -206,6 +237,26 @@ pub fn build_check_assigned_para_id(
     );
 }
 
+/// Background task indexing the rewards of pooled staking delegators on every finalized
+/// block, served by the `tanssi_pooledStakingRewardHistory` RPC.
+pub fn build_reward_history_indexer(
+    client: Arc<ParachainClient>,
+    retained_sessions: u32,
+    spawner: impl SpawnNamed,
+) {
+    let reward_history_indexer_task =
+        pooled_staking_rewards_rpc::run_reward_history_indexer::<Block, _, AccountId, Balance>(
+            client,
+            retained_sessions,
+        );
+
+    spawner.spawn(
+        "reward-history-indexer",
+        None,
+        Box::pin(reward_history_indexer_task),
+    );
+}
+
 /// Check the parachain assignment using the orchestrator chain client, and send a `CcSpawnMsg` to
 /// start or stop the required container chains.
 ///
@@ -308,6 +359,7 @@ async fn start_node_impl(
     collator_options: CollatorOptions,
     para_id: ParaId,
     hwbench: Option<sc_sysinfo::HwBench>,
+    reward_history_sessions: Option<u32>,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient>)> {
     let parachain_config = prepare_node_config(orchestrator_config);
     if let Some((container_chain_cli, _)) = &mut container_chain_config {
@@ -368,6 +420,7 @@ async fn start_node_impl(
                 deny_unsafe,
                 command_sink: None,
                 xcm_senders: None,
+                reward_history: reward_history_sessions.is_some(),
             };
 
             crate::rpc::create_full(deps).map_err(Into::into)
@@ -376,6 +429,14 @@ async fn start_node_impl(
 
     let node_builder = node_builder.spawn_common_tasks(parachain_config, rpc_builder)?;
 
+    if let Some(retained_sessions) = reward_history_sessions {
+        build_reward_history_indexer(
+            node_builder.client.clone(),
+            retained_sessions,
+            node_builder.task_manager.spawn_handle(),
+        );
+    }
+
     let relay_chain_slot_duration = Duration::from_secs(6);
     let overseer_handle = relay_chain_interface
         .overseer_handle()
@@ -616,6 +677,7 @@ pub async fn start_node_impl_container(
                 deny_unsafe,
                 command_sink: None,
                 xcm_senders: None,
+                reward_history: false,
             };
 
             crate::rpc::create_full(deps).map_err(Into::into)
@@ -794,6 +856,16 @@ fn start_consensus_container(
                     )
                     .await;
 
+                let credits_top_up_inherent =
+                    tp_credits_top_up_inherent::OrchestratorCreditsInherentData::create_at(
+                        relay_parent,
+                        &relay_chain_interface,
+                        &orchestrator_chain_interface,
+                        orchestrator_para_id,
+                        para_id,
+                    )
+                    .await;
+
                 let slot_duration = {
                     // Default to 12s if runtime API does not exist
                     let slot_duration_ms = client
@@ -818,7 +890,17 @@ fn start_consensus_container(
                     )
                 })?;
 
-                Ok((slot, timestamp, authorities_noting_inherent))
+                // The tank top up is best effort, the block is still authored without it
+                if credits_top_up_inherent.is_none() {
+                    log::debug!("Failed to create credits top up inherent, skipping it");
+                }
+
+                Ok((
+                    slot,
+                    timestamp,
+                    authorities_noting_inherent,
+                    OptionalInherentDataProvider(credits_top_up_inherent),
+                ))
             }
         },
         get_orchestrator_aux_data: move |_block_hash, (relay_parent, _validation_data)| {
@@ -1068,6 +1150,7 @@ pub async fn start_parachain_node(
     collator_options: CollatorOptions,
     para_id: ParaId,
     hwbench: Option<sc_sysinfo::HwBench>,
+    reward_history_sessions: Option<u32>,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient>)> {
     start_node_impl(
         parachain_config,
@@ -1076,6 +1159,7 @@ pub async fn start_parachain_node(
         collator_options,
         para_id,
         hwbench,
+        reward_history_sessions,
     )
     .await
 }
@@ -1091,6 +1175,7 @@ pub fn start_dev_node(
     sealing: Sealing,
     hwbench: Option<sc_sysinfo::HwBench>,
     para_id: ParaId,
+    reward_history_sessions: Option<u32>,
 ) -> sc_service::error::Result<TaskManager> {
     let parachain_config = prepare_node_config(orchestrator_config);
 
@@ -1240,6 +1325,7 @@ pub fn start_dev_node(
                 deny_unsafe,
                 command_sink: command_sink.clone(),
                 xcm_senders: xcm_senders.clone(),
+                reward_history: reward_history_sessions.is_some(),
             };
 
             crate::rpc::create_full(deps).map_err(Into::into)
@@ -1249,6 +1335,14 @@ pub fn start_dev_node(
     // We spawn all the common substrate tasks to properly run a node.
     let node_builder = node_builder.spawn_common_tasks(parachain_config, rpc_builder)?;
 
+    if let Some(retained_sessions) = reward_history_sessions {
+        build_reward_history_indexer(
+            node_builder.client.clone(),
+            retained_sessions,
+            node_builder.task_manager.spawn_handle(),
+        );
+    }
+
     log::info!("Development Service Ready");
 
     // We start the networking part.
@@ -0,0 +1,80 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+use {
+    crate::{
+        mock_proof::build_credits_proof, Call, Config, LatestTankBalance, Pallet, TopUpConfig,
+        TopUpSettings,
+    },
+    cumulus_pallet_parachain_system::{RelayChainState, RelaychainStateProvider},
+    frame_benchmarking::v2::*,
+    frame_support::{
+        pallet_prelude::Weight,
+        traits::{EnsureOrigin, Get},
+    },
+    frame_system::RawOrigin,
+    sp_runtime::traits::Bounded,
+};
+
+fn top_up_config<T: Config>() -> TopUpConfig<frame_system::pallet_prelude::BlockNumberFor<T>> {
+    TopUpConfig {
+        threshold: u128::MAX,
+        amount: 1_000_000,
+        max_fee: 1_000,
+        transact_weight: Weight::from_parts(1_000_000_000, 100_000),
+        max_spent_per_period: u128::MAX,
+        period: Bounded::max_value(),
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    // Worst case: the tank balance is below the threshold and a top up is sent
+    #[benchmark]
+    fn set_orchestrator_credits() {
+        TopUpSettings::<T>::put(top_up_config::<T>());
+        let (relay_root, data) =
+            build_credits_proof(T::OrchestratorParaId::get(), T::SelfParaId::get(), 0);
+        T::RelayChainStateProvider::set_current_relay_chain_state(RelayChainState {
+            number: 0,
+            state_root: relay_root,
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::None, data);
+
+        assert_eq!(LatestTankBalance::<T>::get(), Some(0));
+    }
+
+    #[benchmark]
+    fn set_top_up_config() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(top_up_config::<T>()));
+
+        assert_eq!(TopUpSettings::<T>::get(), Some(top_up_config::<T>()));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
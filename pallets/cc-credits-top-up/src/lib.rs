@@ -0,0 +1,501 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! # Container Chain Credits Top Up Pallet
+//!
+//! Container chains pay for block production using the credits held in their tank account in the
+//! orchestrator chain. This pallet keeps that tank funded without manual intervention.
+//!
+//! In every block, the collator can include an optional inherent with a relay chain proof of the
+//! orchestrator head and an orchestrator proof of the tank account, rooted at that head. The
+//! pallet verifies both proofs and notes the tank balance.
+//!
+//! When the tank balance is below the threshold set by governance, the pallet sends an XCM
+//! message to the orchestrator that withdraws fees from the sovereign account of this chain and
+//! calls `purchase_credits` on its behalf. The sovereign account of this chain in the orchestrator
+//! must be funded for this to work.
+//!
+//! Top ups are limited by the amount spent per period set by governance, and by
+//! `MinTopUpInterval`, which gives previous top ups time to be reflected in the proofs.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use {
+    cumulus_pallet_parachain_system::RelaychainStateProvider,
+    cumulus_primitives_core::relay_chain::{
+        BlakeTwo256, BlockNumber as RelayBlockNumber, HeadData,
+    },
+    dp_chain_state_snapshot::GenericStateProof,
+    dp_core::{well_known_keys::para_id_head, ParaId},
+    frame_support::pallet_prelude::*,
+    frame_system::pallet_prelude::*,
+    parity_scale_codec::{Decode, Encode},
+    sp_inherents::{InherentIdentifier, IsFatalError},
+    sp_runtime::{
+        traits::{Saturating, Zero},
+        RuntimeString,
+    },
+    sp_std::vec::Vec,
+    staging_xcm::{
+        latest::{Asset, AssetFilter, Location, OriginKind, WildAsset, Xcm},
+        prelude::*,
+    },
+    tp_credits_top_up_inherent::{
+        orchestrator_account_key, orchestrator_tank_account, OrchestratorCreditsInherentData,
+        INHERENT_IDENTIFIER,
+    },
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod mock_proof;
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub use pallet::*;
+
+/// Top up settings, in orchestrator currency.
+#[derive(RuntimeDebug, PartialEq, Eq, Encode, Decode, Clone, TypeInfo, MaxEncodedLen)]
+pub struct TopUpConfig<BlockNumber> {
+    /// Buy credits when the tank balance of this chain is below this amount
+    pub threshold: u128,
+    /// Amount of credits bought with each top up
+    pub amount: u128,
+    /// Amount withdrawn from the sovereign account of this chain to pay for XCM execution in the
+    /// orchestrator. Unused fees are refunded.
+    pub max_fee: u128,
+    /// Max weight of the `purchase_credits` call in the orchestrator
+    pub transact_weight: Weight,
+    /// Max amount spent on top ups during a spending period, including fees
+    pub max_spent_per_period: u128,
+    /// Length of a spending period, in blocks
+    pub period: BlockNumber,
+}
+
+/// Amount spent on top ups during the current spending period, in orchestrator currency. Fees
+/// are accounted as `max_fee`, so this is an upper bound of what was actually spent.
+#[derive(RuntimeDebug, Default, PartialEq, Eq, Encode, Decode, Clone, TypeInfo, MaxEncodedLen)]
+pub struct TopUpSpending<BlockNumber> {
+    /// Spending period in which `spent` was accumulated, computed as `block_number / period`
+    pub period_index: BlockNumber,
+    pub spent: u128,
+}
+
+/// Balance of an orchestrator account, as stored in `System::Account`.
+pub type OrchestratorAccountInfo =
+    frame_system::AccountInfo<u32, pallet_balances::AccountData<u128>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// `ParaId` of this container chain, used to derive its tank account.
+        type SelfParaId: Get<ParaId>;
+
+        /// `ParaId` of the orchestrator chain.
+        type OrchestratorParaId: Get<ParaId>;
+
+        /// Provides the relay chain storage root used to verify the proofs.
+        type RelayChainStateProvider: RelaychainStateProvider;
+
+        type XcmSender: SendXcm;
+
+        /// Get encoded call to buy credits in the orchestrator chain. This will be passed to the
+        /// XCM `Transact` instruction.
+        type GetPurchaseCreditsCall: GetPurchaseCreditsCall;
+
+        /// Origin allowed to change the top up settings.
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Min number of blocks between two top ups. The tank balance in the proofs will not
+        /// change until the previous top up has been executed in the orchestrator, so this should
+        /// be long enough to avoid buying credits twice.
+        #[pallet::constant]
+        type MinTopUpInterval: Get<BlockNumberFor<Self>>;
+
+        /// Sends the funds accumulated by this chain to its tank in the orchestrator. This is
+        /// done in `on_idle`, so the weight of the XCM transfer is accounted for.
+        type SendTankTopUp: SendTankTopUp;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The top up settings have been updated. `None` disables automatic top ups.
+        TopUpConfigUpdated {
+            config: Option<TopUpConfig<BlockNumberFor<T>>>,
+        },
+        /// An XCM message to buy credits has been sent to the orchestrator.
+        TopUpSent {
+            tank_balance: u128,
+            amount: u128,
+            max_fee: u128,
+        },
+        /// Sending the XCM message to buy credits failed.
+        TopUpFailed { tank_balance: u128 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The spending period must not be zero
+        InvalidPeriod,
+        /// The relay chain proof is invalid
+        InvalidRelayProof,
+        /// The orchestrator head could not be read from the relay chain proof
+        FailedReadingOrchestratorHead,
+        /// The orchestrator head could not be decoded
+        FailedDecodingHeader,
+        /// The orchestrator proof is invalid
+        InvalidOrchestratorProof,
+        /// The tank account could not be read from the orchestrator proof
+        FailedReadingTankBalance,
+    }
+
+    /// Top up settings. Automatic top ups are disabled when this is `None`.
+    #[pallet::storage]
+    pub type TopUpSettings<T: Config> =
+        StorageValue<_, TopUpConfig<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Latest tank balance of this chain in the orchestrator.
+    #[pallet::storage]
+    pub type LatestTankBalance<T: Config> = StorageValue<_, u128, OptionQuery>;
+
+    /// Block number of the latest top up.
+    #[pallet::storage]
+    pub type LastTopUp<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type Spending<T: Config> = StorageValue<_, TopUpSpending<BlockNumberFor<T>>, ValueQuery>;
+
+    /// Was the orchestrator credits data set in this block?
+    #[pallet::storage]
+    pub(super) type DidSetOrchestratorCredits<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            // We clear this storage item to allow the inherent to be included again
+            DidSetOrchestratorCredits::<T>::kill();
+
+            T::DbWeight::get().writes(1)
+        }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let check_weight = T::SendTankTopUp::check_weight();
+            let max_weight = check_weight.saturating_add(T::SendTankTopUp::send_weight());
+            if remaining_weight.any_lt(max_weight) {
+                return Weight::zero();
+            }
+
+            if T::SendTankTopUp::send_if_ready() {
+                max_weight
+            } else {
+                check_weight
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight((T::WeightInfo::set_orchestrator_credits(), DispatchClass::Mandatory))]
+        pub fn set_orchestrator_credits(
+            origin: OriginFor<T>,
+            data: OrchestratorCreditsInherentData,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+
+            assert!(
+                !DidSetOrchestratorCredits::<T>::exists(),
+                "DidSetOrchestratorCredits must be updated only once in a block",
+            );
+            DidSetOrchestratorCredits::<T>::put(true);
+
+            // Failing here would make the block invalid, so errors are only logged
+            match Self::tank_balance_from_proof(data) {
+                Ok(tank_balance) => {
+                    LatestTankBalance::<T>::put(tank_balance);
+                    Self::maybe_top_up(tank_balance);
+                }
+                Err(e) => {
+                    log::warn!("Failed to read tank balance from proof: {:?}", e);
+                }
+            }
+
+            Ok(Pays::No.into())
+        }
+
+        /// Set the top up settings. `None` disables automatic top ups.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_top_up_config())]
+        pub fn set_top_up_config(
+            origin: OriginFor<T>,
+            config: Option<TopUpConfig<BlockNumberFor<T>>>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            if let Some(config) = &config {
+                ensure!(!config.period.is_zero(), Error::<T>::InvalidPeriod);
+            }
+            TopUpSettings::<T>::set(config.clone());
+
+            Self::deposit_event(Event::TopUpConfigUpdated { config });
+
+            Ok(())
+        }
+    }
+
+    #[pallet::inherent]
+    impl<T: Config> ProvideInherent for Pallet<T> {
+        type Call = Call<T>;
+        type Error = InherentError;
+        const INHERENT_IDENTIFIER: InherentIdentifier = INHERENT_IDENTIFIER;
+
+        fn is_inherent_required(_: &InherentData) -> Result<Option<Self::Error>, Self::Error> {
+            // Collators that do not provide the proofs can still author blocks, the tank will
+            // just not be topped up
+            Ok(None)
+        }
+
+        fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+            let data: OrchestratorCreditsInherentData =
+                data.get_data(&INHERENT_IDENTIFIER).ok().flatten()?;
+
+            Some(Call::set_orchestrator_credits { data })
+        }
+
+        fn is_inherent(call: &Self::Call) -> bool {
+            matches!(call, Call::set_orchestrator_credits { .. })
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Read the free balance of the tank account of this chain from the orchestrator proof,
+    /// after verifying it against the orchestrator head in the relay chain proof.
+    fn tank_balance_from_proof(data: OrchestratorCreditsInherentData) -> Result<u128, Error<T>> {
+        let OrchestratorCreditsInherentData {
+            relay_chain_state,
+            orchestrator_chain_state,
+        } = data;
+
+        let relay_storage_root = T::RelayChainStateProvider::current_relay_chain_state().state_root;
+        let relay_proof = GenericStateProof::<cumulus_primitives_core::relay_chain::Block>::new(
+            relay_storage_root,
+            relay_chain_state,
+        )
+        .map_err(|_| Error::<T>::InvalidRelayProof)?;
+
+        let head_data = relay_proof
+            .read_entry::<HeadData>(para_id_head(T::OrchestratorParaId::get()).as_slice(), None)
+            .map_err(|_| Error::<T>::FailedReadingOrchestratorHead)?;
+        let orchestrator_header =
+            sp_runtime::generic::Header::<RelayBlockNumber, BlakeTwo256>::decode(
+                &mut head_data.0.as_slice(),
+            )
+            .map_err(|_| Error::<T>::FailedDecodingHeader)?;
+
+        // The orchestrator uses the same hasher as the relay chain
+        let orchestrator_proof =
+            GenericStateProof::<cumulus_primitives_core::relay_chain::Block>::new(
+                orchestrator_header.state_root,
+                orchestrator_chain_state,
+            )
+            .map_err(|_| Error::<T>::InvalidOrchestratorProof)?;
+
+        // A tank below the existential deposit does not exist, so it has no credits
+        let tank = orchestrator_tank_account(T::SelfParaId::get());
+        let account = orchestrator_proof
+            .read_entry::<OrchestratorAccountInfo>(
+                orchestrator_account_key(&tank).as_slice(),
+                Some(Default::default()),
+            )
+            .map_err(|_| Error::<T>::FailedReadingTankBalance)?;
+
+        Ok(account.data.free)
+    }
+
+    /// Send an XCM message to buy credits if the tank balance is below the threshold, the
+    /// previous top up is old enough and the spending limit allows it.
+    fn maybe_top_up(tank_balance: u128) {
+        let Some(config) = TopUpSettings::<T>::get() else {
+            return;
+        };
+        if tank_balance >= config.threshold {
+            return;
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        if let Some(last_top_up) = LastTopUp::<T>::get() {
+            if now < last_top_up.saturating_add(T::MinTopUpInterval::get()) {
+                return;
+            }
+        }
+
+        let period_index = now / config.period;
+        let mut spending = Spending::<T>::get();
+        if spending.period_index != period_index {
+            spending = TopUpSpending {
+                period_index,
+                spent: 0,
+            };
+        }
+        let cost = config.amount.saturating_add(config.max_fee);
+        if spending.spent.saturating_add(cost) > config.max_spent_per_period {
+            log::debug!(
+                "Skipping top up, spending limit reached: spent {}, limit {}",
+                spending.spent,
+                config.max_spent_per_period
+            );
+            return;
+        }
+
+        // Do not retry in every block if sending fails
+        LastTopUp::<T>::put(now);
+
+        if let Err(e) = Self::send_top_up(&config) {
+            log::warn!("Failed to send top up XCM message: {:?}", e);
+            Self::deposit_event(Event::TopUpFailed { tank_balance });
+            return;
+        }
+
+        spending.spent = spending.spent.saturating_add(cost);
+        Spending::<T>::put(spending);
+
+        Self::deposit_event(Event::TopUpSent {
+            tank_balance,
+            amount: config.amount,
+            max_fee: config.max_fee,
+        });
+    }
+
+    /// Send an XCM message to the orchestrator that buys `config.amount` credits for this chain,
+    /// paid by the sovereign account of this chain.
+    fn send_top_up(config: &TopUpConfig<BlockNumberFor<T>>) -> Result<(), SendError> {
+        let self_para_id = T::SelfParaId::get();
+        let orchestrator = Location::new(1, Parachain(T::OrchestratorParaId::get().into()));
+        // The sovereign account of this chain, from the point of view of the orchestrator
+        let sovereign_account = Location::new(1, Parachain(self_para_id.into()));
+
+        let fee_asset: Asset = (Here, config.max_fee).into();
+        let refund_asset_filter: AssetFilter = AssetFilter::Wild(WildAsset::AllCounted(1));
+        let call = T::GetPurchaseCreditsCall::get_encoded(self_para_id, config.amount);
+
+        let message: Xcm<()> = Xcm::builder()
+            .withdraw_asset(fee_asset.clone())
+            .buy_execution(fee_asset, Unlimited)
+            // Both in case of error and in case of success, we want to refund the unused fees
+            .set_appendix(
+                Xcm::builder_unsafe()
+                    .refund_surplus()
+                    .deposit_asset(refund_asset_filter, sovereign_account)
+                    .build(),
+            )
+            .transact(OriginKind::SovereignAccount, config.transact_weight, call)
+            .build();
+
+        // Delivery fees are not charged, the message is sent by the chain itself
+        let (ticket, _price) = T::XcmSender::validate(&mut Some(orchestrator), &mut Some(message))?;
+        T::XcmSender::deliver(ticket)?;
+
+        Ok(())
+    }
+}
+
+/// Sends funds accumulated in this chain to its tank in the orchestrator.
+pub trait SendTankTopUp {
+    /// Weight of checking if enough funds have been accumulated.
+    fn check_weight() -> Weight;
+    /// Weight of sending the funds, not including the check.
+    fn send_weight() -> Weight;
+    /// Send the accumulated funds if they reach the threshold. Returns `true` if a transfer was
+    /// attempted.
+    fn send_if_ready() -> bool;
+}
+
+impl SendTankTopUp for () {
+    fn check_weight() -> Weight {
+        Weight::zero()
+    }
+    fn send_weight() -> Weight {
+        Weight::zero()
+    }
+    fn send_if_ready() -> bool {
+        false
+    }
+}
+
+/// Encodes the `purchase_credits` call of the orchestrator chain.
+pub trait GetPurchaseCreditsCall {
+    /// Get the encoded call to buy `credit` for `para_id`.
+    fn get_encoded(para_id: ParaId, credit: u128) -> Vec<u8>;
+}
+
+/// Encodes `pallet_services_payment::Call::purchase_credits`, given the index of that pallet in
+/// the orchestrator runtime.
+pub struct EncodePurchaseCreditsCall<ServicesPaymentIndex>(PhantomData<ServicesPaymentIndex>);
+
+impl<ServicesPaymentIndex: Get<u8>> GetPurchaseCreditsCall
+    for EncodePurchaseCreditsCall<ServicesPaymentIndex>
+{
+    fn get_encoded(para_id: ParaId, credit: u128) -> Vec<u8> {
+        // `purchase_credits` has call index 0
+        (ServicesPaymentIndex::get(), 0u8, para_id, credit).encode()
+    }
+}
+
+#[derive(Encode)]
+#[cfg_attr(feature = "std", derive(Debug, Decode))]
+pub enum InherentError {
+    Other(RuntimeString),
+}
+
+impl IsFatalError for InherentError {
+    fn is_fatal_error(&self) -> bool {
+        match *self {
+            InherentError::Other(_) => true,
+        }
+    }
+}
+
+impl InherentError {
+    /// Try to create an instance ouf of the given identifier and data.
+    #[cfg(feature = "std")]
+    pub fn try_from(id: &InherentIdentifier, data: &[u8]) -> Option<Self> {
+        if id == &INHERENT_IDENTIFIER {
+            <InherentError as parity_scale_codec::Decode>::decode(&mut &data[..]).ok()
+        } else {
+            None
+        }
+    }
+}
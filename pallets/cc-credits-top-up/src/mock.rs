@@ -0,0 +1,216 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    crate::{self as pallet_cc_credits_top_up, EncodePurchaseCreditsCall, SendTankTopUp},
+    cumulus_pallet_parachain_system::{RelayChainState, RelaychainStateProvider},
+    dp_core::ParaId,
+    frame_support::{
+        parameter_types,
+        traits::{ConstU32, ConstU64, Everything, Hooks},
+        weights::Weight,
+    },
+    frame_system::EnsureRoot,
+    sp_core::H256,
+    sp_runtime::{
+        traits::{BlakeTwo256, IdentityLookup},
+        BuildStorage,
+    },
+    staging_xcm::latest::{Assets, Location, SendError, SendResult, SendXcm, Xcm, XcmHash},
+    std::cell::RefCell,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type AccountId = u64;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        CreditsTopUp: pallet_cc_credits_top_up,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type Block = Block;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+pub const ORCHESTRATOR_PARA_ID: u32 = 1000;
+pub const SELF_PARA_ID: u32 = 2000;
+pub const SERVICES_PAYMENT_INDEX: u8 = 26;
+
+thread_local! {
+    pub static RELAY_CHAIN_STATE: RefCell<RelayChainState> = RefCell::new(RelayChainState {
+        number: 0,
+        state_root: H256::default(),
+    });
+    pub static SENT_XCM: RefCell<Vec<(Location, Xcm<()>)>> = const { RefCell::new(Vec::new()) };
+    pub static XCM_SEND_FAILS: RefCell<bool> = const { RefCell::new(false) };
+    pub static TANK_TOP_UP_READY: RefCell<bool> = const { RefCell::new(false) };
+    pub static TANK_TOP_UPS_SENT: RefCell<u32> = const { RefCell::new(0) };
+}
+
+pub struct MockRelayChainStateProvider;
+
+impl RelaychainStateProvider for MockRelayChainStateProvider {
+    fn current_relay_chain_state() -> RelayChainState {
+        RELAY_CHAIN_STATE.with(|s| s.borrow().clone())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_relay_chain_state(state: RelayChainState) {
+        RELAY_CHAIN_STATE.with(|s| *s.borrow_mut() = state);
+    }
+}
+
+/// Stores the sent messages so that tests can inspect them.
+pub struct MockXcmSender;
+
+impl SendXcm for MockXcmSender {
+    type Ticket = (Location, Xcm<()>);
+    fn validate(
+        destination: &mut Option<Location>,
+        message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        if XCM_SEND_FAILS.with(|f| *f.borrow()) {
+            return Err(SendError::Transport("mock failure"));
+        }
+        let ticket = (destination.take().unwrap(), message.take().unwrap());
+        Ok((ticket, Assets::new()))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        SENT_XCM.with(|sent| sent.borrow_mut().push(ticket));
+        Ok([0; 32])
+    }
+}
+
+pub fn sent_xcm() -> Vec<(Location, Xcm<()>)> {
+    SENT_XCM.with(|sent| sent.borrow().clone())
+}
+
+pub fn set_xcm_send_fails(fails: bool) {
+    XCM_SEND_FAILS.with(|f| *f.borrow_mut() = fails);
+}
+
+pub const TANK_TOP_UP_CHECK_WEIGHT: Weight = Weight::from_parts(1_000, 0);
+pub const TANK_TOP_UP_SEND_WEIGHT: Weight = Weight::from_parts(10_000, 100);
+
+/// Counts the top ups sent, which are ready when `TANK_TOP_UP_READY` is set.
+pub struct MockSendTankTopUp;
+
+impl SendTankTopUp for MockSendTankTopUp {
+    fn check_weight() -> Weight {
+        TANK_TOP_UP_CHECK_WEIGHT
+    }
+    fn send_weight() -> Weight {
+        TANK_TOP_UP_SEND_WEIGHT
+    }
+    fn send_if_ready() -> bool {
+        if !TANK_TOP_UP_READY.with(|r| *r.borrow()) {
+            return false;
+        }
+        TANK_TOP_UPS_SENT.with(|sent| *sent.borrow_mut() += 1);
+        true
+    }
+}
+
+pub fn set_tank_top_up_ready(ready: bool) {
+    TANK_TOP_UP_READY.with(|r| *r.borrow_mut() = ready);
+}
+
+pub fn tank_top_ups_sent() -> u32 {
+    TANK_TOP_UPS_SENT.with(|sent| *sent.borrow())
+}
+
+parameter_types! {
+    pub const OrchestratorParaId: ParaId = ParaId::new(ORCHESTRATOR_PARA_ID);
+    pub const SelfParaId: ParaId = ParaId::new(SELF_PARA_ID);
+    pub const ServicesPaymentIndex: u8 = SERVICES_PAYMENT_INDEX;
+    pub const MinTopUpInterval: u64 = 10;
+}
+
+impl pallet_cc_credits_top_up::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type SelfParaId = SelfParaId;
+    type OrchestratorParaId = OrchestratorParaId;
+    type RelayChainStateProvider = MockRelayChainStateProvider;
+    type XcmSender = MockXcmSender;
+    type GetPurchaseCreditsCall = EncodePurchaseCreditsCall<ServicesPaymentIndex>;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type MinTopUpInterval = MinTopUpInterval;
+    type SendTankTopUp = MockSendTankTopUp;
+    type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Set the relay chain storage root that the proofs will be verified against.
+pub fn set_relay_storage_root(state_root: H256) {
+    RELAY_CHAIN_STATE.with(|s| {
+        *s.borrow_mut() = RelayChainState {
+            number: 0,
+            state_root,
+        }
+    });
+}
+
+pub fn run_to_block(n: u64) {
+    let old_block_number = System::block_number();
+
+    for x in (old_block_number + 1)..=n {
+        System::reset_events();
+        System::set_block_number(x);
+        CreditsTopUp::on_initialize(x);
+    }
+}
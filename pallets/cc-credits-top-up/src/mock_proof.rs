@@ -0,0 +1,77 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Helpers to build the storage proofs expected by the credits top up inherent, used in tests and
+//! benchmarks.
+
+use {
+    crate::OrchestratorAccountInfo,
+    cumulus_primitives_core::relay_chain::{BlakeTwo256, BlockNumber, HeadData},
+    cumulus_test_relay_sproof_builder::RelayStateSproofBuilder,
+    dp_core::{well_known_keys::para_id_head, ParaId},
+    parity_scale_codec::Encode,
+    sp_core::H256,
+    sp_std::vec,
+    tp_credits_top_up_inherent::{
+        orchestrator_account_key, orchestrator_tank_account, OrchestratorCreditsInherentData,
+    },
+};
+
+/// Build the proofs of the tank of `para_id` having `tank_balance` in the orchestrator.
+/// Returns the relay chain storage root and the inherent data.
+pub fn build_credits_proof(
+    orchestrator_para_id: ParaId,
+    para_id: ParaId,
+    tank_balance: u128,
+) -> (H256, OrchestratorCreditsInherentData) {
+    let mut tank_info = OrchestratorAccountInfo::default();
+    tank_info.data.free = tank_balance;
+
+    let orchestrator_sproof_builder = RelayStateSproofBuilder {
+        additional_key_values: vec![(
+            orchestrator_account_key(&orchestrator_tank_account(para_id)),
+            tank_info.encode(),
+        )],
+        ..Default::default()
+    };
+    let (orchestrator_root, orchestrator_chain_state) =
+        orchestrator_sproof_builder.into_state_root_and_proof();
+
+    let orchestrator_header = sp_runtime::generic::Header::<BlockNumber, BlakeTwo256> {
+        parent_hash: Default::default(),
+        number: 1,
+        state_root: orchestrator_root,
+        extrinsics_root: Default::default(),
+        digest: Default::default(),
+    };
+
+    let relay_sproof_builder = RelayStateSproofBuilder {
+        additional_key_values: vec![(
+            para_id_head(orchestrator_para_id),
+            HeadData(orchestrator_header.encode()).encode(),
+        )],
+        ..Default::default()
+    };
+    let (relay_root, relay_chain_state) = relay_sproof_builder.into_state_root_and_proof();
+
+    (
+        relay_root,
+        OrchestratorCreditsInherentData {
+            relay_chain_state,
+            orchestrator_chain_state,
+        },
+    )
+}
@@ -0,0 +1,312 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    crate::{mock::*, mock_proof::build_credits_proof, *},
+    frame_support::{
+        assert_noop, assert_ok,
+        inherent::{InherentData, ProvideInherent},
+    },
+    parity_scale_codec::Encode,
+    sp_core::H256,
+    sp_runtime::traits::BadOrigin,
+    staging_xcm::latest::{prelude::Parachain, Instruction, Location, OriginKind},
+};
+
+const THRESHOLD: u128 = 1_000;
+const AMOUNT: u128 = 5_000;
+const MAX_FEE: u128 = 100;
+
+fn top_up_config() -> TopUpConfig<u64> {
+    TopUpConfig {
+        threshold: THRESHOLD,
+        amount: AMOUNT,
+        max_fee: MAX_FEE,
+        transact_weight: Weight::from_parts(1_000_000_000, 100_000),
+        max_spent_per_period: 2 * (AMOUNT + MAX_FEE),
+        period: 100,
+    }
+}
+
+fn note_tank_balance(tank_balance: u128) -> DispatchResultWithPostInfo {
+    let (relay_root, data) =
+        build_credits_proof(OrchestratorParaId::get(), SelfParaId::get(), tank_balance);
+    set_relay_storage_root(relay_root);
+    CreditsTopUp::set_orchestrator_credits(RuntimeOrigin::none(), data)
+}
+
+#[test]
+fn set_top_up_config_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditsTopUp::set_top_up_config(
+            RuntimeOrigin::root(),
+            Some(top_up_config())
+        ));
+        assert_eq!(TopUpSettings::<Test>::get(), Some(top_up_config()));
+        System::assert_last_event(
+            Event::TopUpConfigUpdated {
+                config: Some(top_up_config()),
+            }
+            .into(),
+        );
+
+        assert_ok!(CreditsTopUp::set_top_up_config(RuntimeOrigin::root(), None));
+        assert_eq!(TopUpSettings::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn set_top_up_config_bad_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CreditsTopUp::set_top_up_config(RuntimeOrigin::signed(1), Some(top_up_config())),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn set_top_up_config_zero_period() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CreditsTopUp::set_top_up_config(
+                RuntimeOrigin::root(),
+                Some(TopUpConfig {
+                    period: 0,
+                    ..top_up_config()
+                })
+            ),
+            Error::<Test>::InvalidPeriod
+        );
+    });
+}
+
+#[test]
+fn tank_balance_is_noted() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(note_tank_balance(12_345));
+        assert_eq!(LatestTankBalance::<Test>::get(), Some(12_345));
+        // Top ups are disabled by default
+        assert!(sent_xcm().is_empty());
+    });
+}
+
+#[test]
+fn invalid_proof_is_ignored() {
+    new_test_ext().execute_with(|| {
+        let (_relay_root, data) =
+            build_credits_proof(OrchestratorParaId::get(), SelfParaId::get(), 12_345);
+        // Proof does not match the relay chain storage root
+        set_relay_storage_root(H256::repeat_byte(1));
+        assert_ok!(CreditsTopUp::set_orchestrator_credits(
+            RuntimeOrigin::none(),
+            data
+        ));
+        assert_eq!(LatestTankBalance::<Test>::get(), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "DidSetOrchestratorCredits must be updated only once in a block")]
+fn inherent_can_only_be_included_once_per_block() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(note_tank_balance(12_345));
+        let _ = note_tank_balance(12_345);
+    });
+}
+
+#[test]
+fn inherent_is_optional() {
+    new_test_ext().execute_with(|| {
+        let inherent_data = InherentData::new();
+        assert!(matches!(
+            CreditsTopUp::is_inherent_required(&inherent_data),
+            Ok(None)
+        ));
+        assert!(CreditsTopUp::create_inherent(&inherent_data).is_none());
+    });
+}
+
+#[test]
+fn top_up_is_sent_below_threshold() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditsTopUp::set_top_up_config(
+            RuntimeOrigin::root(),
+            Some(top_up_config())
+        ));
+
+        assert_ok!(note_tank_balance(THRESHOLD - 1));
+
+        let sent = sent_xcm();
+        assert_eq!(sent.len(), 1);
+        let (destination, message) = &sent[0];
+        assert_eq!(
+            *destination,
+            Location::new(1, Parachain(ORCHESTRATOR_PARA_ID))
+        );
+        let expected_call: Vec<u8> = (
+            SERVICES_PAYMENT_INDEX,
+            0u8,
+            ParaId::new(SELF_PARA_ID),
+            AMOUNT,
+        )
+            .encode();
+        assert!(message.0.iter().any(|instruction| matches!(
+            instruction,
+            Instruction::Transact { origin_kind: OriginKind::SovereignAccount, call, .. }
+                if call.clone().into_encoded() == expected_call
+        )));
+
+        assert_eq!(LastTopUp::<Test>::get(), Some(1));
+        assert_eq!(
+            Spending::<Test>::get(),
+            TopUpSpending {
+                period_index: 0,
+                spent: AMOUNT + MAX_FEE,
+            }
+        );
+        System::assert_last_event(
+            Event::TopUpSent {
+                tank_balance: THRESHOLD - 1,
+                amount: AMOUNT,
+                max_fee: MAX_FEE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn no_top_up_above_threshold() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditsTopUp::set_top_up_config(
+            RuntimeOrigin::root(),
+            Some(top_up_config())
+        ));
+
+        assert_ok!(note_tank_balance(THRESHOLD));
+
+        assert!(sent_xcm().is_empty());
+        assert_eq!(LastTopUp::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn top_ups_wait_for_min_interval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditsTopUp::set_top_up_config(
+            RuntimeOrigin::root(),
+            Some(top_up_config())
+        ));
+
+        assert_ok!(note_tank_balance(0));
+        assert_eq!(sent_xcm().len(), 1);
+
+        // The tank balance has not been updated yet in the orchestrator
+        run_to_block(MinTopUpInterval::get());
+        assert_ok!(note_tank_balance(0));
+        assert_eq!(sent_xcm().len(), 1);
+
+        run_to_block(1 + MinTopUpInterval::get());
+        assert_ok!(note_tank_balance(0));
+        assert_eq!(sent_xcm().len(), 2);
+    });
+}
+
+#[test]
+fn top_ups_respect_spending_limit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditsTopUp::set_top_up_config(
+            RuntimeOrigin::root(),
+            Some(top_up_config())
+        ));
+
+        // The limit allows 2 top ups per period
+        for i in 0..3 {
+            run_to_block(1 + i * MinTopUpInterval::get());
+            assert_ok!(note_tank_balance(0));
+        }
+        assert_eq!(sent_xcm().len(), 2);
+
+        // Next period resets the spending
+        run_to_block(100);
+        assert_ok!(note_tank_balance(0));
+        assert_eq!(sent_xcm().len(), 3);
+        assert_eq!(
+            Spending::<Test>::get(),
+            TopUpSpending {
+                period_index: 1,
+                spent: AMOUNT + MAX_FEE,
+            }
+        );
+    });
+}
+
+#[test]
+fn failed_top_up_is_not_accounted() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditsTopUp::set_top_up_config(
+            RuntimeOrigin::root(),
+            Some(top_up_config())
+        ));
+        set_xcm_send_fails(true);
+
+        assert_ok!(note_tank_balance(0));
+
+        assert!(sent_xcm().is_empty());
+        assert_eq!(Spending::<Test>::get(), TopUpSpending::default());
+        // Sending is not retried until the min interval has passed
+        assert_eq!(LastTopUp::<Test>::get(), Some(1));
+        System::assert_last_event(Event::TopUpFailed { tank_balance: 0 }.into());
+    });
+}
+
+#[test]
+fn tank_top_up_is_sent_on_idle() {
+    new_test_ext().execute_with(|| {
+        set_tank_top_up_ready(true);
+
+        let weight = CreditsTopUp::on_idle(1, Weight::MAX);
+
+        assert_eq!(tank_top_ups_sent(), 1);
+        assert_eq!(
+            weight,
+            TANK_TOP_UP_CHECK_WEIGHT.saturating_add(TANK_TOP_UP_SEND_WEIGHT)
+        );
+    });
+}
+
+#[test]
+fn tank_top_up_not_ready_only_charges_check() {
+    new_test_ext().execute_with(|| {
+        let weight = CreditsTopUp::on_idle(1, Weight::MAX);
+
+        assert_eq!(tank_top_ups_sent(), 0);
+        assert_eq!(weight, TANK_TOP_UP_CHECK_WEIGHT);
+    });
+}
+
+#[test]
+fn tank_top_up_waits_for_enough_idle_weight() {
+    new_test_ext().execute_with(|| {
+        set_tank_top_up_ready(true);
+
+        let weight = CreditsTopUp::on_idle(1, TANK_TOP_UP_SEND_WEIGHT);
+
+        assert_eq!(tank_top_ups_sent(), 0);
+        assert_eq!(weight, Weight::zero());
+    });
+}
@@ -53,9 +53,9 @@ use {
     },
     sp_std::{collections::btree_set::BTreeSet, fmt::Debug, prelude::*, vec},
     tp_traits::{
-        CollatorAssignmentHook, CollatorAssignmentTip, GetContainerChainAuthor,
-        GetHostConfiguration, GetSessionContainerChains, ParaId, RemoveInvulnerables,
-        RemoveParaIdsWithNoCredits, ShouldRotateAllCollators, Slot,
+        CollatorAssignmentHook, CollatorAssignmentTip, GetCollatorsPerChain,
+        GetContainerChainAuthor, GetHostConfiguration, GetSessionContainerChains, ParaId,
+        RemoveInvulnerables, RemoveParaIdsWithNoCredits, ShouldRotateAllCollators, Slot,
     },
 };
 pub use {dp_collator_assignment::AssignedCollators, pallet::*};
@@ -491,6 +491,22 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> GetCollatorsPerChain for Pallet<T> {
+        fn orchestrator_collators() -> u32 {
+            Pallet::<T>::collator_container_chain()
+                .orchestrator_chain
+                .len() as u32
+        }
+
+        fn container_chain_collators(para_id: ParaId) -> u32 {
+            Pallet::<T>::collator_container_chain()
+                .container_chains
+                .get(&para_id)
+                .map(|collators| collators.len() as u32)
+                .unwrap_or_default()
+        }
+    }
+
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
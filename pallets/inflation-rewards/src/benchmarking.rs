@@ -0,0 +1,59 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Benchmarking setup for pallet-inflation-rewards
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as InflationRewards;
+use {
+    frame_benchmarking::{v2::*, BenchmarkError},
+    sp_runtime::{Perbill, Perquintill},
+};
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_inflation_schedule() -> Result<(), BenchmarkError> {
+        let origin = T::InflationScheduleOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let schedule = InflationSchedule {
+            max_inflation: Perquintill::from_parts(20_000_000_000),
+            min_inflation: Perquintill::from_parts(5_000_000_000),
+            target_staking_rate: Perbill::from_percent(50),
+            decay_per_year: Perbill::from_percent(10),
+            rewards_portion: Perbill::from_percent(70),
+        };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, schedule.clone());
+
+        assert_eq!(CurrentInflationSchedule::<T>::get(), schedule);
+
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        InflationRewards,
+        crate::mock::new_test_ext(),
+        crate::mock::Test,
+    );
+}
@@ -28,10 +28,28 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod policy;
+pub mod schedule;
+pub mod weights;
+
+pub use {
+    policy::{
+        CollatorsWeightedSplit, EqualSplit, PooledUntilSessionEnd, RewardsPolicy, RewardsSplit,
+        SlotFrequencySplit,
+    },
+    schedule::InflationSchedule,
+    weights::WeightInfo,
+};
+
 use {
     dp_core::{BlockNumber, ParaId},
     frame_support::{
+        dispatch::DispatchErrorWithPostInfo,
         pallet_prelude::*,
+        storage::with_storage_layer,
         traits::{
             fungible::{Balanced, Credit, Inspect},
             tokens::{Fortitude, Precision, Preservation},
@@ -40,10 +58,10 @@ use {
     },
     frame_system::pallet_prelude::*,
     sp_runtime::{
-        traits::{Get, Saturating},
-        Perbill,
+        traits::{Get, SaturatedConversion, Saturating, Zero},
+        Perbill, Perquintill,
     },
-    tp_traits::{AuthorNotingHook, DistributeRewards, GetCurrentContainerChains},
+    tp_traits::{AuthorNotingHook, DistributeRewards, GetCurrentContainerChains, GetSessionIndex},
 };
 
 #[frame_support::pallet]
@@ -70,8 +88,11 @@ pub mod pallet {
                 if let Some(chains_to_reward) = ChainsToReward::<T>::take() {
                     // Collect and sum all undistributed rewards
                     let rewards_not_distributed: BalanceOf<T> = chains_to_reward
-                        .rewards_per_chain
-                        .saturating_mul((chains_to_reward.para_ids.len() as u32).into());
+                        .rewards
+                        .iter()
+                        .fold(Zero::zero(), |total, (_, rewards)| {
+                            total.saturating_add(*rewards)
+                        });
                     T::Currency::withdraw(
                         &T::PendingRewardsAccount::get(),
                         rewards_not_distributed,
@@ -84,25 +105,49 @@ pub mod pallet {
                     CreditOf::<T>::zero()
                 };
 
-            // Get the number of chains at this block (tanssi + container chain blocks)
+            // Pay the rewards pooled during the previous session
+            weight += Self::distribute_pooled_rewards();
+
+            // Get the chains at this block (tanssi + container chain blocks)
             weight += T::DbWeight::get().reads_writes(1, 1);
             let registered_para_ids = T::ContainerChains::current_container_chains();
-            let number_of_chains: BalanceOf<T> =
-                ((registered_para_ids.len() as u32).saturating_add(1)).into();
+
+            // Compute inflation from the staking rate
+            weight += T::DbWeight::get().reads(3);
+            let schedule = CurrentInflationSchedule::<T>::get();
+            let total_issuance = T::Currency::total_issuance();
+            let staking_rate = Perbill::from_rational(T::StakedBalance::get(), total_issuance);
+            let inflation_rate = schedule.inflation_rate(staking_rate, Self::schedule_years());
 
             // Issue new supply
-            let new_supply =
-                T::Currency::issue(T::InflationRate::get() * T::Currency::total_issuance());
+            let new_supply_amount: BalanceOf<T> =
+                (inflation_rate * total_issuance.saturated_into::<u128>()).saturated_into();
+            let new_supply = T::Currency::issue(new_supply_amount);
+
+            Self::deposit_event(Event::InflationComputed {
+                staking_rate,
+                inflation_rate,
+                new_supply: new_supply_amount,
+            });
 
             // Split staking reward portion
-            let total_rewards = T::RewardsPortion::get() * new_supply.peek();
+            let total_rewards = schedule.rewards_portion * new_supply.peek();
             let (rewards_credit, reminder_credit) = new_supply.split(total_rewards);
 
-            let rewards_per_chain: BalanceOf<T> = rewards_credit.peek() / number_of_chains;
-            let (mut total_reminder, staking_rewards) = rewards_credit.split_merge(
-                total_rewards % number_of_chains,
-                (reminder_credit, CreditOf::<T>::zero()),
-            );
+            // The policy may read some data for each chain
+            weight +=
+                T::DbWeight::get().reads((registered_para_ids.len() as u64).saturating_add(1));
+            let rewards_split =
+                T::RewardsPolicy::split_rewards(total_rewards, &registered_para_ids);
+            let chains_rewards = rewards_split
+                .container_chains
+                .iter()
+                .fold(rewards_split.orchestrator, |total, (_, rewards)| {
+                    total.saturating_add(*rewards)
+                });
+
+            let (staking_rewards, reminder) = rewards_credit.split(chains_rewards);
+            let mut total_reminder = reminder_credit.merge(reminder);
 
             // Deposit the new supply dedicated to rewards in the pending rewards account
             if let Err(undistributed_rewards) =
@@ -113,14 +158,13 @@ pub mod pallet {
 
             // Keep track of chains to reward
             ChainsToReward::<T>::put(ChainsToRewardValue {
-                para_ids: registered_para_ids,
-                rewards_per_chain,
+                rewards: BoundedVec::truncate_from(rewards_split.container_chains),
             });
 
             // Let the runtime handle the non-staking part
             T::OnUnbalanced::on_unbalanced(not_distributed_rewards.merge(total_reminder));
 
-            weight += Self::reward_orchestrator_author();
+            weight += Self::reward_orchestrator_author(rewards_split.orchestrator);
 
             weight
         }
@@ -135,12 +179,21 @@ pub mod pallet {
 
         type ContainerChains: GetCurrentContainerChains;
 
-        /// Get block author for self chain
-        type GetSelfChainBlockAuthor: Get<Self::AccountId>;
+        /// Get block author for self chain, `None` if it cannot be found
+        type GetSelfChainBlockAuthor: Get<Option<Self::AccountId>>;
+
+        /// Inflation schedule used until one is set by `InflationScheduleOrigin`
+        type DefaultInflationSchedule: Get<InflationSchedule>;
+
+        /// Origin allowed to update the inflation schedule
+        type InflationScheduleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-        /// Inflation rate per orchestrator block (proportion of the total issuance)
+        /// Number of orchestrator blocks in a year, used to decay the inflation schedule
         #[pallet::constant]
-        type InflationRate: Get<Perbill>;
+        type BlocksPerYear: Get<u32>;
+
+        /// Amount of the total issuance which is staked
+        type StakedBalance: Get<BalanceOf<Self>>;
 
         /// What to do with the new supply not dedicated to staking
         type OnUnbalanced: OnUnbalanced<CreditOf<Self>>;
@@ -152,9 +205,24 @@ pub mod pallet {
         /// Staking rewards distribution implementation
         type StakingRewardsDistributor: DistributeRewards<Self::AccountId, CreditOf<Self>>;
 
-        /// Proportion of the new supply dedicated to staking
+        /// How the rewards of a block are split between the orchestrator and container chains
+        type RewardsPolicy: RewardsPolicy<BalanceOf<Self>>;
+
+        /// Current session index, used to pay pooled rewards at the end of each session
+        type CurrentSessionIndex: GetSessionIndex<u32>;
+
+        /// Maximum number of pooled rewards paid in a block, remaining ones are paid in the
+        /// following blocks
         #[pallet::constant]
-        type RewardsPortion: Get<Perbill>;
+        type MaxPooledRewardsPayouts: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The minimum inflation of the schedule is higher than its maximum inflation
+        InvalidInflationSchedule,
     }
 
     #[pallet::event]
@@ -171,65 +239,253 @@ pub mod pallet {
             para_id: ParaId,
             balance: BalanceOf<T>,
         },
+        /// Paying the rewards pooled by an author during a session
+        PooledRewardsPaid {
+            account_id: T::AccountId,
+            session: u32,
+            balance: BalanceOf<T>,
+        },
+        /// Inflation of this block, computed from the staking rate
+        InflationComputed {
+            staking_rate: Perbill,
+            inflation_rate: Perquintill,
+            new_supply: BalanceOf<T>,
+        },
+        /// The inflation schedule has been updated
+        InflationScheduleUpdated { schedule: InflationSchedule },
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Replace the inflation schedule. The yearly decay restarts from the current block.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_inflation_schedule())]
+        pub fn set_inflation_schedule(
+            origin: OriginFor<T>,
+            schedule: InflationSchedule,
+        ) -> DispatchResult {
+            T::InflationScheduleOrigin::ensure_origin(origin)?;
+            ensure!(schedule.is_valid(), Error::<T>::InvalidInflationSchedule);
+
+            CurrentInflationSchedule::<T>::put(&schedule);
+            InflationScheduleStart::<T>::put(frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::InflationScheduleUpdated { schedule });
+
+            Ok(())
+        }
     }
 
     /// Container chains to reward per block
     #[pallet::storage]
-    pub(super) type ChainsToReward<T: Config> =
-        StorageValue<_, ChainsToRewardValue<T>, OptionQuery>;
+    pub type ChainsToReward<T: Config> = StorageValue<_, ChainsToRewardValue<T>, OptionQuery>;
 
     #[derive(Clone, Encode, Decode, PartialEq, sp_core::RuntimeDebug, scale_info::TypeInfo)]
     #[scale_info(skip_type_params(T))]
     pub struct ChainsToRewardValue<T: Config> {
-        pub para_ids: BoundedVec<
-            ParaId,
+        /// Rewards of each container chain whose author has not been noted yet, sorted by para id
+        pub rewards: BoundedVec<
+            (ParaId, BalanceOf<T>),
             <T::ContainerChains as GetCurrentContainerChains>::MaxContainerChains,
         >,
-        pub rewards_per_chain: BalanceOf<T>,
     }
 
+    /// Rewards of each author waiting for the end of the session in which they were earned, if
+    /// the rewards policy pays at session end. They are kept in the pending rewards account
+    /// until then.
+    #[pallet::storage]
+    pub type PooledRewards<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Oldest session whose rewards in `PooledRewards` may not have been paid yet
+    #[pallet::storage]
+    pub type PooledRewardsSession<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultCurrentInflationSchedule<T: Config>() -> InflationSchedule {
+        T::DefaultInflationSchedule::get()
+    }
+
+    /// Inflation schedule, `DefaultInflationSchedule` until updated
+    #[pallet::storage]
+    pub type CurrentInflationSchedule<T: Config> =
+        StorageValue<_, InflationSchedule, ValueQuery, DefaultCurrentInflationSchedule<T>>;
+
+    /// Block from which the yearly decay of the inflation schedule is computed
+    #[pallet::storage]
+    pub type InflationScheduleStart<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
     impl<T: Config> Pallet<T> {
-        fn reward_orchestrator_author() -> Weight {
+        fn reward_orchestrator_author(rewards: BalanceOf<T>) -> Weight {
             let mut total_weight = T::DbWeight::get().reads(1);
-            let orchestrator_author = T::GetSelfChainBlockAuthor::get();
+            let Some(orchestrator_author) = T::GetSelfChainBlockAuthor::get() else {
+                log::warn!("Orchestrator block author not found, rewards not distributed");
+                return total_weight.saturating_add(Self::handle_undistributed_rewards(rewards));
+            };
+
+            match Self::reward_author(&orchestrator_author, rewards) {
+                Ok(weight) => {
+                    Self::deposit_event(Event::RewardedOrchestrator {
+                        account_id: orchestrator_author,
+                        balance: rewards,
+                    });
+                    total_weight += weight;
+                }
+                Err(e) => {
+                    log::debug!("Fail to distribute rewards: {:?}", e)
+                }
+            }
 
-            if let Some(chains_to_reward) = ChainsToReward::<T>::get() {
-                total_weight += T::DbWeight::get().reads(1);
-                match T::StakingRewardsDistributor::distribute_rewards(
-                    orchestrator_author.clone(),
-                    T::Currency::withdraw(
-                        &T::PendingRewardsAccount::get(),
-                        chains_to_reward.rewards_per_chain,
-                        Precision::BestEffort,
-                        Preservation::Expendable,
-                        Fortitude::Force,
-                    )
-                    .unwrap_or(CreditOf::<T>::zero()),
-                ) {
-                    Ok(frame_support::dispatch::PostDispatchInfo { actual_weight, .. }) => {
-                        Self::deposit_event(Event::RewardedOrchestrator {
-                            account_id: orchestrator_author,
-                            balance: chains_to_reward.rewards_per_chain,
-                        });
+            total_weight
+        }
+
+        /// Send `rewards` from the pending rewards account to `OnUnbalanced`, for rewards that
+        /// cannot be paid to their author.
+        fn handle_undistributed_rewards(rewards: BalanceOf<T>) -> Weight {
+            let credit = T::Currency::withdraw(
+                &T::PendingRewardsAccount::get(),
+                rewards,
+                Precision::BestEffort,
+                Preservation::Expendable,
+                Fortitude::Force,
+            )
+            .unwrap_or(CreditOf::<T>::zero());
+            T::OnUnbalanced::on_unbalanced(credit);
+
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        /// Pay `rewards` to `author`, or pool them until the end of the session if the rewards
+        /// policy requires it.
+        fn reward_author(
+            author: &T::AccountId,
+            rewards: BalanceOf<T>,
+        ) -> Result<Weight, DispatchErrorWithPostInfo> {
+            if T::RewardsPolicy::pay_at_session_end() {
+                let session = T::CurrentSessionIndex::session_index();
+                PooledRewards::<T>::mutate(session, author, |pooled| {
+                    pooled.saturating_accrue(rewards)
+                });
+                return Ok(T::DbWeight::get().reads_writes(2, 1));
+            }
+
+            Self::distribute_rewards(author, rewards)
+        }
 
-                        if let Some(weight) = actual_weight {
-                            total_weight += weight
+        fn distribute_rewards(
+            author: &T::AccountId,
+            rewards: BalanceOf<T>,
+        ) -> Result<Weight, DispatchErrorWithPostInfo> {
+            T::StakingRewardsDistributor::distribute_rewards(
+                author.clone(),
+                T::Currency::withdraw(
+                    &T::PendingRewardsAccount::get(),
+                    rewards,
+                    Precision::BestEffort,
+                    Preservation::Expendable,
+                    Fortitude::Force,
+                )
+                .unwrap_or(CreditOf::<T>::zero()),
+            )
+            .map(|info| info.actual_weight.unwrap_or_default())
+        }
+
+        /// Pay the pooled rewards once the session in which they were earned is over, at most
+        /// `MaxPooledRewardsPayouts` per block. This is done regardless of the current policy
+        /// so no rewards are left behind if the runtime stops pooling them. Rewards that cannot
+        /// be paid are handled by `OnUnbalanced`.
+        fn distribute_pooled_rewards() -> Weight {
+            let mut total_weight = T::DbWeight::get().reads(2);
+            let session = T::CurrentSessionIndex::session_index();
+            let mut pooled_session = PooledRewardsSession::<T>::get();
+
+            if pooled_session >= session {
+                return total_weight;
+            }
+
+            // Nothing left to pay, skip directly to the current session
+            total_weight += T::DbWeight::get().reads(1);
+            if PooledRewards::<T>::iter_keys().next().is_none() {
+                total_weight += T::DbWeight::get().writes(1);
+                PooledRewardsSession::<T>::put(session);
+                return total_weight;
+            }
+
+            // Moving to the next session counts as a payout, which bounds the work done when
+            // skipping sessions without rewards.
+            let mut payouts = T::MaxPooledRewardsPayouts::get();
+            while pooled_session < session && !payouts.is_zero() {
+                for (account_id, balance) in
+                    PooledRewards::<T>::drain_prefix(pooled_session).take(payouts as usize)
+                {
+                    payouts = payouts.saturating_sub(1);
+                    total_weight += T::DbWeight::get().reads_writes(1, 1);
+
+                    match with_storage_layer(|| Self::distribute_rewards(&account_id, balance)) {
+                        Ok(weight) => {
+                            Self::deposit_event(Event::PooledRewardsPaid {
+                                account_id,
+                                session: pooled_session,
+                                balance,
+                            });
+                            total_weight += weight;
+                        }
+                        Err(e) => {
+                            log::warn!(
+                                "Failed to pay pooled rewards of {:?} for session {}: {:?}",
+                                account_id,
+                                pooled_session,
+                                e
+                            );
+                            total_weight += Self::handle_undistributed_rewards(balance);
                         }
                     }
-                    Err(e) => {
-                        log::debug!("Fail to distribute rewards: {:?}", e)
-                    }
                 }
-            } else {
-                panic!("ChainsToReward not filled");
+
+                // Rewards of that session may not be fully paid yet
+                if payouts.is_zero() {
+                    break;
+                }
+
+                total_weight += T::DbWeight::get().reads(1);
+                payouts = payouts.saturating_sub(1);
+                pooled_session = pooled_session.saturating_add(1);
             }
 
+            total_weight += T::DbWeight::get().writes(1);
+            PooledRewardsSession::<T>::put(pooled_session);
+
             total_weight
         }
 
+        /// Number of full years since the inflation schedule was set.
+        fn schedule_years() -> u32 {
+            let elapsed = frame_system::Pallet::<T>::block_number()
+                .saturating_sub(InflationScheduleStart::<T>::get());
+            let blocks_per_year = T::BlocksPerYear::get().max(1);
+
+            (elapsed.saturated_into::<u64>() / u64::from(blocks_per_year)).saturated_into()
+        }
+
         pub fn container_chains_to_reward() -> Option<ChainsToRewardValue<T>> {
             ChainsToReward::<T>::get()
         }
+
+        pub fn inflation_schedule() -> InflationSchedule {
+            CurrentInflationSchedule::<T>::get()
+        }
+
+        /// Rewards earned by `author` in `session`, waiting to be paid at the end of it.
+        pub fn pooled_rewards(session: u32, author: &T::AccountId) -> BalanceOf<T> {
+            PooledRewards::<T>::get(session, author)
+        }
     }
 }
 
@@ -248,28 +504,20 @@ impl<T: Config> AuthorNotingHook<T::AccountId> for Pallet<T> {
         // We take chains to reward, to see what containers are left to reward
         if let Some(mut container_chains_to_reward) = ChainsToReward::<T>::get() {
             // If we find the index is because we still have not rewarded it
-            if let Ok(index) = container_chains_to_reward.para_ids.binary_search(&para_id) {
+            if let Ok(index) = container_chains_to_reward
+                .rewards
+                .binary_search_by_key(&para_id, |(para_id, _)| *para_id)
+            {
+                let (_, rewards) = container_chains_to_reward.rewards[index];
                 // we distribute rewards to the author
-                match T::StakingRewardsDistributor::distribute_rewards(
-                    author.clone(),
-                    T::Currency::withdraw(
-                        &T::PendingRewardsAccount::get(),
-                        container_chains_to_reward.rewards_per_chain,
-                        Precision::BestEffort,
-                        Preservation::Expendable,
-                        Fortitude::Force,
-                    )
-                    .unwrap_or(CreditOf::<T>::zero()),
-                ) {
-                    Ok(frame_support::dispatch::PostDispatchInfo { actual_weight, .. }) => {
+                match Self::reward_author(author, rewards) {
+                    Ok(weight) => {
                         Self::deposit_event(Event::RewardedContainer {
                             account_id: author.clone(),
-                            balance: container_chains_to_reward.rewards_per_chain,
+                            balance: rewards,
                             para_id,
                         });
-                        if let Some(weight) = actual_weight {
-                            total_weight += weight
-                        }
+                        total_weight += weight;
                     }
                     Err(e) => {
                         log::debug!("Fail to distribute rewards: {:?}", e)
@@ -277,7 +525,7 @@ impl<T: Config> AuthorNotingHook<T::AccountId> for Pallet<T> {
                 }
                 // we remove the para id from container-chains to reward
                 // this makes sure we dont reward it twice in the same block
-                container_chains_to_reward.para_ids.remove(index);
+                container_chains_to_reward.rewards.remove(index);
 
                 total_weight += T::DbWeight::get().writes(1);
                 // Keep track of chains to reward
@@ -15,7 +15,10 @@
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
 
 use {
-    crate::{self as pallet_inflation_rewards},
+    crate::{
+        self as pallet_inflation_rewards, CollatorsWeightedSplit, EqualSplit, InflationSchedule,
+        RewardsPolicy, RewardsSplit, SlotFrequencySplit,
+    },
     bounded_collections::bounded_vec,
     dp_core::ParaId,
     frame_support::{
@@ -29,8 +32,9 @@ use {
     sp_core::H256,
     sp_runtime::{
         traits::{BlakeTwo256, IdentityLookup},
-        BuildStorage, Perbill,
+        BuildStorage, Perbill, Perquintill,
     },
+    tp_traits::{ParathreadParams, SessionContainerChains, SlotFrequency},
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -145,18 +149,142 @@ impl mock_data::Config for Test {}
 )]
 pub struct Mocks {
     pub container_chains: BoundedVec<ParaId, ConstU32<5>>,
-    pub orchestrator_author: AccountId,
+    pub orchestrator_author: Option<AccountId>,
+    pub rewards_policy: MockRewardsPolicyKind,
+    pub session_index: u32,
+    pub orchestrator_collators: u32,
+    pub container_chain_collators: Vec<(ParaId, u32)>,
+    /// Parathreads and their minimum slot frequency
+    pub parathreads: Vec<(ParaId, u32)>,
+    /// Amount returned by `StakedBalance`
+    pub staked_balance: Balance,
+    /// Accounts to which distributing rewards fails
+    pub failing_rewards_accounts: Vec<AccountId>,
 }
 
 impl Default for Mocks {
     fn default() -> Self {
         Self {
             container_chains: bounded_vec![1001.into()],
-            orchestrator_author: 1,
+            orchestrator_author: Some(1),
+            rewards_policy: MockRewardsPolicyKind::EqualSplit,
+            session_index: 0,
+            orchestrator_collators: 2,
+            container_chain_collators: vec![],
+            parathreads: vec![],
+            staked_balance: 0,
+            failing_rewards_accounts: vec![],
         }
     }
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    PartialEq,
+    sp_core::RuntimeDebug,
+    scale_info::TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum MockRewardsPolicyKind {
+    EqualSplit,
+    CollatorsWeightedSplit,
+    SlotFrequencySplit,
+    PooledEqualSplit,
+}
+
+pub struct MockCollatorsPerChain;
+
+impl tp_traits::GetCollatorsPerChain for MockCollatorsPerChain {
+    fn orchestrator_collators() -> u32 {
+        MockData::mock().orchestrator_collators
+    }
+
+    fn container_chain_collators(para_id: ParaId) -> u32 {
+        MockData::mock()
+            .container_chain_collators
+            .iter()
+            .find(|(id, _)| *id == para_id)
+            .map(|(_, collators)| *collators)
+            .unwrap_or_default()
+    }
+}
+
+pub struct MockSessionContainerChains;
+
+impl tp_traits::GetSessionContainerChains<u32> for MockSessionContainerChains {
+    fn session_container_chains(_session_index: u32) -> SessionContainerChains {
+        let mock = MockData::mock();
+        let (parathreads, parachains): (Vec<_>, Vec<_>) = mock
+            .container_chains
+            .into_iter()
+            .partition(|para_id| mock.parathreads.iter().any(|(id, _)| id == para_id));
+
+        SessionContainerChains {
+            parachains,
+            parathreads: parathreads
+                .into_iter()
+                .map(|para_id| {
+                    let (_, min) = mock
+                        .parathreads
+                        .iter()
+                        .find(|(id, _)| *id == para_id)
+                        .expect("para id is a parathread");
+
+                    (
+                        para_id,
+                        ParathreadParams {
+                            slot_frequency: SlotFrequency {
+                                min: *min,
+                                max: *min,
+                            },
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_session_container_chains(_session_index: u32, _container_chains: &[ParaId]) {}
+}
+
+pub struct MockSessionIndex;
+
+impl tp_traits::GetSessionIndex<u32> for MockSessionIndex {
+    fn session_index() -> u32 {
+        MockData::mock().session_index
+    }
+}
+
+pub struct MockRewardsPolicy;
+
+type MockCollatorsWeightedSplit = CollatorsWeightedSplit<MockCollatorsPerChain>;
+type MockSlotFrequencySplit = SlotFrequencySplit<MockSessionContainerChains, MockSessionIndex>;
+
+impl RewardsPolicy<Balance> for MockRewardsPolicy {
+    fn split_rewards(rewards: Balance, container_chains: &[ParaId]) -> RewardsSplit<Balance> {
+        match MockData::mock().rewards_policy {
+            MockRewardsPolicyKind::EqualSplit | MockRewardsPolicyKind::PooledEqualSplit => {
+                EqualSplit::split_rewards(rewards, container_chains)
+            }
+            MockRewardsPolicyKind::CollatorsWeightedSplit => {
+                MockCollatorsWeightedSplit::split_rewards(rewards, container_chains)
+            }
+            MockRewardsPolicyKind::SlotFrequencySplit => {
+                MockSlotFrequencySplit::split_rewards(rewards, container_chains)
+            }
+        }
+    }
+
+    fn pay_at_session_end() -> bool {
+        MockData::mock().rewards_policy == MockRewardsPolicyKind::PooledEqualSplit
+    }
+}
+
 pub struct MockContainerChainGetter;
 
 impl tp_traits::GetCurrentContainerChains for MockContainerChainGetter {
@@ -176,8 +304,8 @@ impl tp_traits::GetCurrentContainerChains for MockContainerChainGetter {
 
 pub struct MockGetSelfChainBlockAuthor;
 
-impl Get<AccountId> for MockGetSelfChainBlockAuthor {
-    fn get() -> AccountId {
+impl Get<Option<AccountId>> for MockGetSelfChainBlockAuthor {
+    fn get() -> Option<AccountId> {
         MockData::mock().orchestrator_author
     }
 }
@@ -197,6 +325,12 @@ impl tp_traits::DistributeRewards<AccountId, Credit<AccountId, Balances>>
         rewarded: AccountId,
         amount: Credit<AccountId, Balances>,
     ) -> DispatchResultWithPostInfo {
+        if MockData::mock()
+            .failing_rewards_accounts
+            .contains(&rewarded)
+        {
+            return Err(DispatchError::Other("Rewards distribution failed").into());
+        }
         <<Test as pallet_inflation_rewards::Config>::Currency as Balanced<AccountId>>::resolve(
             &rewarded, amount,
         )
@@ -205,11 +339,26 @@ impl tp_traits::DistributeRewards<AccountId, Credit<AccountId, Balances>>
     }
 }
 
+pub struct MockStakedBalance;
+impl Get<Balance> for MockStakedBalance {
+    fn get() -> Balance {
+        MockData::mock().staked_balance
+    }
+}
+
 parameter_types! {
     pub OnUnbalancedInflationAccount: AccountId = 0;
     pub PendingRewardsAccount: AccountId = 99;
     pub const RewardsPortion: Perbill = Perbill::from_percent(70);
-    pub const InflationRate: Perbill = Perbill::from_percent(1);
+    pub const InflationRate: Perquintill = Perquintill::from_percent(1);
+    pub DefaultInflationSchedule: InflationSchedule = InflationSchedule {
+        max_inflation: InflationRate::get(),
+        min_inflation: InflationRate::get(),
+        target_staking_rate: Perbill::from_percent(50),
+        decay_per_year: Perbill::zero(),
+        rewards_portion: RewardsPortion::get(),
+    };
+    pub const BlocksPerYear: u32 = 10;
 }
 
 impl pallet_inflation_rewards::Config for Test {
@@ -217,11 +366,17 @@ impl pallet_inflation_rewards::Config for Test {
     type Currency = Balances;
     type ContainerChains = MockContainerChainGetter;
     type GetSelfChainBlockAuthor = MockGetSelfChainBlockAuthor;
-    type InflationRate = InflationRate;
+    type DefaultInflationSchedule = DefaultInflationSchedule;
+    type InflationScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+    type BlocksPerYear = BlocksPerYear;
+    type StakedBalance = MockStakedBalance;
     type OnUnbalanced = OnUnbalancedInflation;
     type PendingRewardsAccount = PendingRewardsAccount;
     type StakingRewardsDistributor = MockRewardsDistributor;
-    type RewardsPortion = RewardsPortion;
+    type RewardsPolicy = MockRewardsPolicy;
+    type CurrentSessionIndex = MockSessionIndex;
+    type MaxPooledRewardsPayouts = ConstU32<3>;
+    type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
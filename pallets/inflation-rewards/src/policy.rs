@@ -0,0 +1,176 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Policies deciding how the rewards of an orchestrator block are split between the
+//! orchestrator block author and the authors of the container chain blocks noted in it.
+//!
+//! The share of a chain whose author is not noted is handled by `OnUnbalanced` on the next
+//! block, so policies should give a share to a chain in proportion to how likely it is to
+//! produce a block.
+
+use {
+    core::marker::PhantomData,
+    dp_core::ParaId,
+    sp_runtime::{traits::AtLeast32BitUnsigned, Perbill},
+    sp_std::vec::Vec,
+    tp_traits::{GetCollatorsPerChain, GetSessionContainerChains, GetSessionIndex},
+};
+
+/// Rewards of each chain for an orchestrator block.
+#[derive(Clone, PartialEq, Eq, sp_core::RuntimeDebug)]
+pub struct RewardsSplit<Balance> {
+    /// Rewards of the orchestrator block author.
+    pub orchestrator: Balance,
+    /// Rewards of the author of each container chain, in the same order as the container chains
+    /// given to the policy.
+    pub container_chains: Vec<(ParaId, Balance)>,
+}
+
+/// Split the rewards of an orchestrator block between the chains.
+pub trait RewardsPolicy<Balance> {
+    /// Split `rewards` between the orchestrator and `container_chains`. Rewards which are not
+    /// assigned to any chain are handled by `OnUnbalanced`.
+    fn split_rewards(rewards: Balance, container_chains: &[ParaId]) -> RewardsSplit<Balance>;
+
+    /// Whether the rewards are accumulated and paid to the authors at the end of the session,
+    /// instead of being paid as soon as their block is noted.
+    fn pay_at_session_end() -> bool {
+        false
+    }
+}
+
+/// Split `rewards` in proportion to the weight of each chain.
+fn split_by_weight<Balance: AtLeast32BitUnsigned + Copy>(
+    rewards: Balance,
+    orchestrator_weight: u32,
+    container_chain_weights: Vec<(ParaId, u32)>,
+) -> RewardsSplit<Balance> {
+    let total_weight = container_chain_weights
+        .iter()
+        .fold(u64::from(orchestrator_weight), |total, (_, weight)| {
+            total.saturating_add(u64::from(*weight))
+        });
+    let share = |weight: u32| {
+        if total_weight == 0 {
+            // `from_rational` would return 100% when dividing by zero
+            return Balance::zero();
+        }
+        Perbill::from_rational(u64::from(weight), total_weight) * rewards
+    };
+
+    RewardsSplit {
+        orchestrator: share(orchestrator_weight),
+        container_chains: container_chain_weights
+            .into_iter()
+            .map(|(para_id, weight)| (para_id, share(weight)))
+            .collect(),
+    }
+}
+
+/// Every chain gets the same share of the rewards, regardless of how many blocks it can produce.
+pub struct EqualSplit;
+
+impl<Balance: AtLeast32BitUnsigned + Copy> RewardsPolicy<Balance> for EqualSplit {
+    fn split_rewards(rewards: Balance, container_chains: &[ParaId]) -> RewardsSplit<Balance> {
+        let number_of_chains: Balance = (container_chains.len() as u32).saturating_add(1).into();
+        let rewards_per_chain = rewards / number_of_chains;
+
+        RewardsSplit {
+            orchestrator: rewards_per_chain,
+            container_chains: container_chains
+                .iter()
+                .map(|para_id| (*para_id, rewards_per_chain))
+                .collect(),
+        }
+    }
+}
+
+/// Each chain gets a share of the rewards proportional to the number of collators assigned to
+/// it. Chains without collators don't get any rewards, as they cannot produce blocks.
+pub struct CollatorsWeightedSplit<Collators>(PhantomData<Collators>);
+
+impl<Balance, Collators> RewardsPolicy<Balance> for CollatorsWeightedSplit<Collators>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    Collators: GetCollatorsPerChain,
+{
+    fn split_rewards(rewards: Balance, container_chains: &[ParaId]) -> RewardsSplit<Balance> {
+        split_by_weight(
+            rewards,
+            Collators::orchestrator_collators(),
+            container_chains
+                .iter()
+                .map(|para_id| (*para_id, Collators::container_chain_collators(*para_id)))
+                .collect(),
+        )
+    }
+}
+
+/// Weight of a chain producing a block every slot in `SlotFrequencySplit`.
+pub const FULL_SLOT_WEIGHT: u32 = 1_000_000;
+
+/// Each chain gets a share of the rewards proportional to the number of blocks it is expected
+/// to produce per slot. The orchestrator and parachains produce a block every slot, while a
+/// parathread produces at most one block every `SlotFrequency::min` slots.
+pub struct SlotFrequencySplit<ContainerChains, SessionIndex>(
+    PhantomData<(ContainerChains, SessionIndex)>,
+);
+
+impl<Balance, ContainerChains, SessionIndex> RewardsPolicy<Balance>
+    for SlotFrequencySplit<ContainerChains, SessionIndex>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    ContainerChains: GetSessionContainerChains<u32>,
+    SessionIndex: GetSessionIndex<u32>,
+{
+    fn split_rewards(rewards: Balance, container_chains: &[ParaId]) -> RewardsSplit<Balance> {
+        let parathreads =
+            ContainerChains::session_container_chains(SessionIndex::session_index()).parathreads;
+
+        split_by_weight(
+            rewards,
+            FULL_SLOT_WEIGHT,
+            container_chains
+                .iter()
+                .map(|para_id| {
+                    let weight = parathreads
+                        .iter()
+                        .find(|(parathread_id, _)| parathread_id == para_id)
+                        .map(|(_, params)| FULL_SLOT_WEIGHT / params.slot_frequency.min.max(1))
+                        .unwrap_or(FULL_SLOT_WEIGHT);
+
+                    (*para_id, weight)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Split the rewards using `Split`, but accumulate the rewards of each author until the end of
+/// the session instead of paying them as soon as their block is noted.
+pub struct PooledUntilSessionEnd<Split>(PhantomData<Split>);
+
+impl<Balance, Split: RewardsPolicy<Balance>> RewardsPolicy<Balance>
+    for PooledUntilSessionEnd<Split>
+{
+    fn split_rewards(rewards: Balance, container_chains: &[ParaId]) -> RewardsSplit<Balance> {
+        Split::split_rewards(rewards, container_chains)
+    }
+
+    fn pay_at_session_end() -> bool {
+        true
+    }
+}
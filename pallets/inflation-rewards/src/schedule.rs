@@ -0,0 +1,73 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Inflation schedule, deciding the inflation of each block from the staking rate.
+//!
+//! Inflation decreases linearly from `max_inflation` when nothing is staked to `min_inflation`
+//! when the staking rate reaches `target_staking_rate`, and stays at `min_inflation` above it.
+//! Both bounds decay by `decay_per_year` every year since the schedule was set.
+
+use {
+    frame_support::pallet_prelude::*,
+    sp_runtime::{traits::Saturating, Perbill, Perquintill},
+};
+
+#[derive(
+    Clone, Encode, Decode, PartialEq, Eq, sp_core::RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub struct InflationSchedule {
+    /// Inflation per orchestrator block (proportion of the total issuance) when nothing is staked
+    pub max_inflation: Perquintill,
+    /// Inflation per orchestrator block (proportion of the total issuance) when the staking rate
+    /// is at or above `target_staking_rate`
+    pub min_inflation: Perquintill,
+    /// Proportion of the total issuance staked at which inflation reaches `min_inflation`
+    pub target_staking_rate: Perbill,
+    /// Yearly decrease of `max_inflation` and `min_inflation`, compounded every year
+    pub decay_per_year: Perbill,
+    /// Proportion of the new supply dedicated to staking
+    pub rewards_portion: Perbill,
+}
+
+impl InflationSchedule {
+    /// Whether the schedule bounds are consistent.
+    pub fn is_valid(&self) -> bool {
+        self.min_inflation <= self.max_inflation
+    }
+
+    /// Inflation per orchestrator block for the given staking rate, `years` after the schedule
+    /// was set.
+    pub fn inflation_rate(&self, staking_rate: Perbill, years: u32) -> Perquintill {
+        let remaining = Perbill::one()
+            .saturating_sub(self.decay_per_year)
+            .saturating_pow(years as usize);
+        let max_inflation = remaining * self.max_inflation.deconstruct();
+        let min_inflation = remaining * self.min_inflation.deconstruct();
+
+        if self.target_staking_rate.is_zero() || staking_rate >= self.target_staking_rate {
+            return Perquintill::from_parts(min_inflation);
+        }
+
+        let progress = Perbill::from_rational(
+            staking_rate.deconstruct(),
+            self.target_staking_rate.deconstruct(),
+        );
+
+        Perquintill::from_parts(
+            max_inflation.saturating_sub(progress * max_inflation.saturating_sub(min_inflation)),
+        )
+    }
+}
@@ -16,8 +16,8 @@
 
 use {
     crate::{mock::*, Config, *},
-    frame_support::{pallet_prelude::*, traits::fungible::Inspect},
-    sp_runtime::Permill,
+    frame_support::{assert_noop, assert_ok, pallet_prelude::*, traits::fungible::Inspect},
+    sp_runtime::{Perbill, Permill, Perquintill},
 };
 
 fn get_balance(who: &AccountId) -> Balance {
@@ -37,14 +37,14 @@ fn test_increase_supply() {
         let total_supply_1 = get_total_issuance();
         assert_eq!(
             total_supply_1,
-            total_supply_0 + (<Test as Config>::InflationRate::get() * total_supply_0),
+            total_supply_0 + (InflationRate::get() * total_supply_0),
         );
 
         <Pallet<Test> as Hooks<u64>>::on_initialize(2);
         let total_supply_2 = get_total_issuance();
         assert_eq!(
             total_supply_2,
-            total_supply_1 + (<Test as Config>::InflationRate::get() * total_supply_1),
+            total_supply_1 + (InflationRate::get() * total_supply_1),
         );
     });
 }
@@ -71,7 +71,7 @@ fn test_undistributed_rewards() {
 #[test]
 fn test_reward_orchestrator_author() {
     new_test_ext().execute_with(|| {
-        let author = <Test as Config>::GetSelfChainBlockAuthor::get();
+        let author = <Test as Config>::GetSelfChainBlockAuthor::get().unwrap();
         let author_balance = get_balance(&author);
 
         let total_supply_0 = get_total_issuance();
@@ -97,7 +97,7 @@ fn test_reward_orchestrator_author_less_if_more_chains() {
             data.container_chains.try_push(1003.into()).unwrap();
         });
 
-        let author = <Test as Config>::GetSelfChainBlockAuthor::get();
+        let author = <Test as Config>::GetSelfChainBlockAuthor::get().unwrap();
         let author_balance = get_balance(&author);
 
         let total_supply_0 = get_total_issuance();
@@ -247,3 +247,300 @@ fn test_non_claimed_rewards_go_to_on_unbalanced() {
         );
     });
 }
+
+#[test]
+fn test_orchestrator_rewards_without_author_go_to_on_unbalanced() {
+    new_test_ext().execute_with(|| {
+        MockData::mutate(|data| {
+            data.orchestrator_author = None;
+        });
+        let on_unbalanced_balance = get_balance(&OnUnbalancedInflationAccount::get());
+
+        let total_supply_0 = get_total_issuance();
+        <Pallet<Test> as Hooks<u64>>::on_initialize(1);
+        let new_supply = get_total_issuance() - total_supply_0;
+
+        // The non-staking portion plus the orchestrator rewards, 70% rewards for 2 chains
+        assert_eq!(
+            get_balance(&OnUnbalancedInflationAccount::get()),
+            on_unbalanced_balance
+                + (Permill::from_percent(30) * new_supply)
+                + (Permill::from_percent(35) * new_supply),
+        );
+        // Only the container chain rewards are pending
+        assert_eq!(
+            get_balance(&PendingRewardsAccount::get()),
+            Permill::from_percent(35) * new_supply,
+        );
+    });
+}
+
+#[test]
+fn test_collators_weighted_split() {
+    new_test_ext().execute_with(|| {
+        MockData::mutate(|data| {
+            data.rewards_policy = MockRewardsPolicyKind::CollatorsWeightedSplit;
+            data.container_chains.try_push(1002.into()).unwrap();
+            data.orchestrator_collators = 2;
+            data.container_chain_collators = vec![(1001.into(), 2)];
+        });
+
+        let author = <Test as Config>::GetSelfChainBlockAuthor::get().unwrap();
+        let author_balance = get_balance(&author);
+
+        <Pallet<Test> as Hooks<u64>>::on_initialize(1);
+
+        // 70 rewards, 1002 has no collators so the orchestrator and 1001 get half each
+        assert_eq!(get_balance(&author), author_balance + 35);
+        assert_eq!(
+            InflationRewards::container_chains_to_reward()
+                .unwrap()
+                .rewards
+                .into_inner(),
+            vec![(1001.into(), 35), (1002.into(), 0)]
+        );
+    });
+}
+
+#[test]
+fn test_slot_frequency_split() {
+    new_test_ext().execute_with(|| {
+        MockData::mutate(|data| {
+            data.rewards_policy = MockRewardsPolicyKind::SlotFrequencySplit;
+            data.container_chains.try_push(1002.into()).unwrap();
+            data.parathreads = vec![(1002.into(), 2)];
+        });
+
+        let author = <Test as Config>::GetSelfChainBlockAuthor::get().unwrap();
+        let author_balance = get_balance(&author);
+
+        <Pallet<Test> as Hooks<u64>>::on_initialize(1);
+
+        // 70 rewards, 1002 produces a block every 2 slots so it gets half the weight of other chains
+        assert_eq!(get_balance(&author), author_balance + 28);
+        assert_eq!(
+            InflationRewards::container_chains_to_reward()
+                .unwrap()
+                .rewards
+                .into_inner(),
+            vec![(1001.into(), 28), (1002.into(), 14)]
+        );
+    });
+}
+
+#[test]
+fn test_pooled_rewards_paid_at_session_end() {
+    new_test_ext().execute_with(|| {
+        MockData::mutate(|data| {
+            data.rewards_policy = MockRewardsPolicyKind::PooledEqualSplit;
+        });
+
+        let author = <Test as Config>::GetSelfChainBlockAuthor::get().unwrap();
+        let container_author = 2;
+
+        <Pallet<Test> as Hooks<u64>>::on_initialize(1);
+        <Pallet<Test> as AuthorNotingHook<AccountId>>::on_container_author_noted(
+            &container_author,
+            1,
+            1001.into(),
+        );
+
+        // 70 rewards for 2 chains, pooled until the end of the session
+        assert_eq!(get_balance(&author), 0);
+        assert_eq!(get_balance(&container_author), 0);
+        assert_eq!(InflationRewards::pooled_rewards(0, &author), 35);
+        assert_eq!(InflationRewards::pooled_rewards(0, &container_author), 35);
+
+        MockData::mutate(|data| {
+            data.session_index = 1;
+        });
+        <Pallet<Test> as Hooks<u64>>::on_initialize(2);
+
+        assert_eq!(get_balance(&author), 35);
+        assert_eq!(get_balance(&container_author), 35);
+        assert_eq!(InflationRewards::pooled_rewards(0, &container_author), 0);
+        // The orchestrator author is pooling the rewards of the new session
+        assert!(InflationRewards::pooled_rewards(1, &author) > 0);
+        assert_eq!(PooledRewardsSession::<Test>::get(), 1);
+    });
+}
+
+#[test]
+fn test_pooled_rewards_payouts_are_spread_across_blocks() {
+    new_test_ext().execute_with(|| {
+        MockData::mutate(|data| {
+            data.rewards_policy = MockRewardsPolicyKind::PooledEqualSplit;
+            data.container_chains.try_push(1002.into()).unwrap();
+            data.container_chains.try_push(1003.into()).unwrap();
+        });
+
+        <Pallet<Test> as Hooks<u64>>::on_initialize(1);
+        for (container_author, para_id) in [(2, 1001), (3, 1002), (4, 1003)] {
+            <Pallet<Test> as AuthorNotingHook<AccountId>>::on_container_author_noted(
+                &container_author,
+                1,
+                para_id.into(),
+            );
+        }
+
+        let authors = [1, 2, 3, 4];
+        let pooled: Vec<_> = authors
+            .iter()
+            .map(|author| InflationRewards::pooled_rewards(0, author))
+            .collect();
+        assert!(pooled.iter().all(|rewards| *rewards > 0));
+
+        MockData::mutate(|data| {
+            data.session_index = 1;
+        });
+
+        // At most 3 payouts per block
+        <Pallet<Test> as Hooks<u64>>::on_initialize(2);
+        let unpaid = authors
+            .iter()
+            .filter(|author| InflationRewards::pooled_rewards(0, author) > 0)
+            .count();
+        assert_eq!(unpaid, 1);
+        assert_eq!(PooledRewardsSession::<Test>::get(), 0);
+
+        <Pallet<Test> as Hooks<u64>>::on_initialize(3);
+        for (author, rewards) in authors.iter().zip(pooled) {
+            assert_eq!(InflationRewards::pooled_rewards(0, author), 0);
+            assert_eq!(get_balance(author), rewards);
+        }
+        assert_eq!(PooledRewardsSession::<Test>::get(), 1);
+    });
+}
+
+#[test]
+fn test_failed_pooled_rewards_go_to_on_unbalanced() {
+    new_test_ext().execute_with(|| {
+        let container_author = 2;
+        MockData::mutate(|data| {
+            data.rewards_policy = MockRewardsPolicyKind::PooledEqualSplit;
+            data.failing_rewards_accounts = vec![container_author];
+        });
+
+        <Pallet<Test> as Hooks<u64>>::on_initialize(1);
+        <Pallet<Test> as AuthorNotingHook<AccountId>>::on_container_author_noted(
+            &container_author,
+            1,
+            1001.into(),
+        );
+        assert_eq!(InflationRewards::pooled_rewards(0, &container_author), 35);
+
+        MockData::mutate(|data| {
+            data.session_index = 1;
+        });
+        let total_supply_1 = get_total_issuance();
+        let unbalanced_balance_1 = get_balance(&OnUnbalancedInflationAccount::get());
+
+        <Pallet<Test> as Hooks<u64>>::on_initialize(2);
+
+        assert_eq!(InflationRewards::pooled_rewards(0, &container_author), 0);
+        assert_eq!(get_balance(&container_author), 0);
+        // Rewards are not burned
+        assert_eq!(
+            get_total_issuance(),
+            total_supply_1 + (InflationRate::get() * total_supply_1),
+        );
+        assert!(get_balance(&OnUnbalancedInflationAccount::get()) >= unbalanced_balance_1 + 35);
+    });
+}
+
+fn schedule(max_percent: u64, min_percent: u64, decay_percent: u32) -> InflationSchedule {
+    InflationSchedule {
+        max_inflation: Perquintill::from_percent(max_percent),
+        min_inflation: Perquintill::from_percent(min_percent),
+        target_staking_rate: Perbill::from_percent(50),
+        decay_per_year: Perbill::from_percent(decay_percent),
+        rewards_portion: RewardsPortion::get(),
+    }
+}
+
+#[test]
+fn test_set_inflation_schedule() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            InflationRewards::set_inflation_schedule(RuntimeOrigin::signed(1), schedule(2, 1, 0)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            InflationRewards::set_inflation_schedule(RuntimeOrigin::root(), schedule(1, 2, 0)),
+            Error::<Test>::InvalidInflationSchedule
+        );
+
+        assert_ok!(InflationRewards::set_inflation_schedule(
+            RuntimeOrigin::root(),
+            schedule(2, 1, 0)
+        ));
+        assert_eq!(InflationRewards::inflation_schedule(), schedule(2, 1, 0));
+        System::assert_last_event(
+            Event::InflationScheduleUpdated {
+                schedule: schedule(2, 1, 0),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_inflation_decreases_with_staking_rate() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(InflationRewards::set_inflation_schedule(
+            RuntimeOrigin::root(),
+            schedule(2, 1, 0)
+        ));
+
+        // 25% staked, half way to the target
+        MockData::mutate(|data| data.staked_balance = 2_500);
+        let total_supply_0 = get_total_issuance();
+        <Pallet<Test> as Hooks<u64>>::on_initialize(1);
+        let total_supply_1 = get_total_issuance();
+
+        assert_eq!(total_supply_1, total_supply_0 + 150);
+        System::assert_has_event(
+            Event::InflationComputed {
+                staking_rate: Perbill::from_percent(25),
+                inflation_rate: Perquintill::from_rational(15u64, 1_000u64),
+                new_supply: 150,
+            }
+            .into(),
+        );
+
+        // Above the target, inflation stays at its minimum
+        MockData::mutate(|data| data.staked_balance = total_supply_1 * 3 / 4);
+        <Pallet<Test> as Hooks<u64>>::on_initialize(2);
+
+        assert_eq!(
+            get_total_issuance(),
+            total_supply_1 + (Perquintill::from_percent(1) * total_supply_1),
+        );
+    });
+}
+
+#[test]
+fn test_inflation_schedule_decays_every_year() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(InflationRewards::set_inflation_schedule(
+            RuntimeOrigin::root(),
+            schedule(1, 1, 50)
+        ));
+
+        // Not a full year yet
+        System::set_block_number(BlocksPerYear::get() as u64);
+        let total_supply_0 = get_total_issuance();
+        <Pallet<Test> as Hooks<u64>>::on_initialize(BlocksPerYear::get() as u64);
+        let total_supply_1 = get_total_issuance();
+        assert_eq!(total_supply_1, total_supply_0 + total_supply_0 / 100);
+
+        // After a year, inflation is halved
+        System::set_block_number(BlocksPerYear::get() as u64 + 1);
+        <Pallet<Test> as Hooks<u64>>::on_initialize(BlocksPerYear::get() as u64 + 1);
+        assert_eq!(get_total_issuance(), total_supply_1 + total_supply_1 / 200);
+    });
+}
@@ -0,0 +1,90 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+
+//! Autogenerated weights for pallet_inflation_rewards
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-06-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `girazoki-XPS-15-9530`, CPU: `13th Gen Intel(R) Core(TM) i9-13900H`
+//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/tanssi-node
+// benchmark
+// pallet
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_inflation_rewards
+// --extrinsic
+// *
+// --chain=dev
+// --steps
+// 50
+// --repeat
+// 20
+// --template=./benchmarking/frame-weight-template.hbs
+// --json-file
+// raw.json
+// --output
+// tmp/pallet_inflation_rewards.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_inflation_rewards.
+pub trait WeightInfo {
+	fn set_inflation_schedule() -> Weight;
+}
+
+/// Weights for pallet_inflation_rewards using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `InflationRewards::CurrentInflationSchedule` (r:0 w:1)
+	/// Proof: `InflationRewards::CurrentInflationSchedule` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `Measured`)
+	/// Storage: `InflationRewards::InflationScheduleStart` (r:0 w:1)
+	/// Proof: `InflationRewards::InflationScheduleStart` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `Measured`)
+	fn set_inflation_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_364_000 picoseconds.
+		Weight::from_parts(9_747_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `InflationRewards::CurrentInflationSchedule` (r:0 w:1)
+	/// Proof: `InflationRewards::CurrentInflationSchedule` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `Measured`)
+	/// Storage: `InflationRewards::InflationScheduleStart` (r:0 w:1)
+	/// Proof: `InflationRewards::InflationScheduleStart` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `Measured`)
+	fn set_inflation_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_364_000 picoseconds.
+		Weight::from_parts(9_747_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
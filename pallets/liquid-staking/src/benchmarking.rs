@@ -0,0 +1,158 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Benchmarking setup for pallet-liquid-staking
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as LiquidStaking;
+use {
+    frame_benchmarking::{account, v2::*, BenchmarkError},
+    frame_support::traits::{fungible::Mutate, Get},
+    frame_system::{EventRecord, RawOrigin},
+    pallet_pooled_staking::{
+        traits::Timer, AllTargetPool, PendingOperationKey, PendingOperationQuery, Pools, PoolsKey,
+        TargetPool,
+    },
+    sp_std::prelude::*,
+};
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+/// Create a delegator with AutoCompounding shares of a candidate.
+/// Returns the candidate, the delegator, its amount of shares and their value.
+fn delegator_with_shares<T: Config>() -> (T::AccountId, T::AccountId, BalanceOf<T>, BalanceOf<T>) {
+    let candidate: T::AccountId = account("candidate", 0, SEED);
+    let delegator: T::AccountId = account("delegator", 0, SEED);
+    let amount = T::MinimumSelfDelegation::get() * 10u32.into();
+    <T as pallet_pooled_staking::Config>::Currency::set_balance(&delegator, amount * 2u32.into());
+
+    let at = T::JoiningRequestTimer::now();
+    pallet_pooled_staking::Pallet::<T>::request_delegate(
+        RawOrigin::Signed(delegator.clone()).into(),
+        candidate.clone(),
+        TargetPool::AutoCompounding,
+        amount,
+    )
+    .expect("delegation failed");
+
+    T::JoiningRequestTimer::skip_to_elapsed();
+    pallet_pooled_staking::Pallet::<T>::execute_pending_operations(
+        RawOrigin::Signed(delegator.clone()).into(),
+        vec![PendingOperationQuery {
+            delegator: delegator.clone(),
+            operation: PendingOperationKey::JoiningAutoCompounding {
+                candidate: candidate.clone(),
+                at,
+            },
+        }],
+    )
+    .expect("execution failed");
+
+    let shares = Pools::<T>::get(
+        &candidate,
+        &PoolsKey::AutoCompoundingShares {
+            delegator: delegator.clone(),
+        },
+    );
+
+    let stake = pallet_pooled_staking::Pallet::<T>::computed_stake(
+        candidate.clone(),
+        delegator.clone(),
+        AllTargetPool::AutoCompounding,
+    )
+    .expect("delegator has shares");
+
+    (candidate, delegator, shares, stake)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn wrap() -> Result<(), BenchmarkError> {
+        // Worst case creates the asset of the candidate.
+        let (candidate, delegator, shares, stake) = delegator_with_shares::<T>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(delegator.clone()),
+            candidate.clone(),
+            shares,
+        );
+
+        let asset_id = CandidateAssets::<T>::get(&candidate).expect("asset was created");
+        assert_last_event::<T>(
+            Event::Wrapped {
+                candidate,
+                delegator,
+                asset_id,
+                shares,
+                stake,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn redeem() -> Result<(), BenchmarkError> {
+        let (candidate, delegator, shares, stake) = delegator_with_shares::<T>();
+        LiquidStaking::<T>::wrap(
+            RawOrigin::Signed(delegator.clone()).into(),
+            candidate.clone(),
+            shares,
+        )
+        .expect("wrap failed");
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(delegator.clone()),
+            candidate.clone(),
+            shares,
+        );
+
+        let asset_id = CandidateAssets::<T>::get(&candidate).expect("asset was created");
+        assert_last_event::<T>(
+            Event::Redeemed {
+                candidate,
+                delegator,
+                asset_id,
+                shares,
+                stake,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        LiquidStaking,
+        crate::mock::new_test_ext(),
+        crate::mock::Test,
+    );
+}
@@ -0,0 +1,250 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Liquid staking pallet.
+//!
+//! Wraps AutoCompounding shares of `pallet_pooled_staking` into fungible assets, one asset per
+//! candidate, so that staked positions can be transferred and used in other protocols.
+//!
+//! Wrapping moves the shares, and the stake they represent, from the delegator to
+//! `LiquidStakingAccount` and mints the same amount of asset units to the delegator. Redeeming
+//! burns asset units and moves the shares back to the caller. Wrapped shares keep receiving
+//! auto compounding rewards and remain slashable, so one asset unit is always worth one
+//! AutoCompounding share of its candidate.
+//!
+//! Assets are created the first time shares of a candidate are wrapped. The runtime should
+//! provide a dedicated `fungibles` implementation (such as an instance of `pallet_assets`) so
+//! that asset ids don't collide with other assets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub type BalanceOf<T> = <T as pallet_pooled_staking::Config>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+    pub use crate::weights::WeightInfo;
+
+    use {
+        super::*,
+        frame_support::{
+            pallet_prelude::*,
+            traits::{
+                fungibles::{Create, Mutate},
+                tokens::{Fortitude, Precision, Preservation},
+                Incrementable,
+            },
+        },
+        frame_system::pallet_prelude::*,
+        sp_runtime::traits::One,
+    };
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_pooled_staking::Config {
+        /// Overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Assets representing the AutoCompounding shares of each candidate.
+        type Assets: Create<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>
+            + Mutate<Self::AccountId>;
+
+        /// Identifier of the assets created by this pallet.
+        type AssetId: Member + Parameter + MaxEncodedLen + Copy + Incrementable;
+
+        /// Account holding the wrapped shares and their stake.
+        #[pallet::constant]
+        type LiquidStakingAccount: Get<Self::AccountId>;
+
+        /// The weight information of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    /// Asset representing the AutoCompounding shares of each candidate.
+    #[pallet::storage]
+    pub type CandidateAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AssetId, OptionQuery>;
+
+    /// Id of the next asset to create.
+    #[pallet::storage]
+    pub type NextAssetId<T: Config> = StorageValue<_, T::AssetId, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// An asset has been created to represent the shares of a candidate.
+        AssetCreated {
+            candidate: T::AccountId,
+            asset_id: T::AssetId,
+        },
+        /// Shares have been wrapped into asset units.
+        Wrapped {
+            candidate: T::AccountId,
+            delegator: T::AccountId,
+            asset_id: T::AssetId,
+            shares: BalanceOf<T>,
+            stake: BalanceOf<T>,
+        },
+        /// Asset units have been redeemed into shares.
+        Redeemed {
+            candidate: T::AccountId,
+            delegator: T::AccountId,
+            asset_id: T::AssetId,
+            shares: BalanceOf<T>,
+            stake: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// No asset has been created for this candidate.
+        NoAssetForCandidate,
+        /// No asset id is available to create a new asset.
+        NoAvailableAssetId,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Wrap `shares` AutoCompounding shares of `candidate` into the same amount of asset
+        /// units. The asset of the candidate is created if it doesn't exist yet.
+        ///
+        /// Candidates can only wrap shares above their `MinimumSelfDelegation`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::wrap())]
+        pub fn wrap(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            shares: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin)?;
+
+            let asset_id = match CandidateAssets::<T>::get(&candidate) {
+                Some(asset_id) => asset_id,
+                None => Self::create_asset(&candidate)?,
+            };
+
+            let stake = pallet_pooled_staking::Pallet::<T>::transfer_auto_compounding_shares(
+                candidate.clone(),
+                delegator.clone(),
+                T::LiquidStakingAccount::get(),
+                shares,
+            )?;
+
+            T::Assets::mint_into(asset_id, &delegator, shares)?;
+
+            Self::deposit_event(Event::Wrapped {
+                candidate,
+                delegator,
+                asset_id,
+                shares,
+                stake,
+            });
+
+            Ok(().into())
+        }
+
+        /// Burn `amount` asset units of the asset of `candidate` and receive the same amount
+        /// of AutoCompounding shares.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::redeem())]
+        pub fn redeem(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin)?;
+
+            let asset_id =
+                CandidateAssets::<T>::get(&candidate).ok_or(Error::<T>::NoAssetForCandidate)?;
+
+            T::Assets::burn_from(
+                asset_id,
+                &delegator,
+                amount,
+                Preservation::Expendable,
+                Precision::Exact,
+                Fortitude::Polite,
+            )?;
+
+            let stake = pallet_pooled_staking::Pallet::<T>::transfer_auto_compounding_shares(
+                candidate.clone(),
+                T::LiquidStakingAccount::get(),
+                delegator.clone(),
+                amount,
+            )?;
+
+            Self::deposit_event(Event::Redeemed {
+                candidate,
+                delegator,
+                asset_id,
+                shares: amount,
+                stake,
+            });
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Asset representing the AutoCompounding shares of that candidate, if any.
+        pub fn candidate_asset(candidate: T::AccountId) -> Option<T::AssetId> {
+            CandidateAssets::<T>::get(candidate)
+        }
+
+        fn create_asset(candidate: &T::AccountId) -> Result<T::AssetId, DispatchError> {
+            let asset_id = NextAssetId::<T>::get()
+                .or_else(T::AssetId::initial_value)
+                .ok_or(Error::<T>::NoAvailableAssetId)?;
+            let next_asset_id = asset_id.increment().ok_or(Error::<T>::NoAvailableAssetId)?;
+
+            let account = T::LiquidStakingAccount::get();
+
+            // The account holds the stake of all wrapped shares, it must not be reaped.
+            if !frame_system::Pallet::<T>::account_exists(&account) {
+                frame_system::Pallet::<T>::inc_providers(&account);
+            }
+
+            T::Assets::create(asset_id, account, false, One::one())?;
+
+            CandidateAssets::<T>::insert(candidate, asset_id);
+            NextAssetId::<T>::put(next_asset_id);
+
+            Self::deposit_event(Event::AssetCreated {
+                candidate: candidate.clone(),
+                asset_id,
+            });
+
+            Ok(asset_id)
+        }
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    crate as pallet_liquid_staking,
+    frame_support::{
+        parameter_types,
+        traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, Everything},
+    },
+    frame_system::{EnsureRoot, EnsureSigned},
+    pallet_pooled_staking::traits::BlockNumberTimer,
+    sp_core::H256,
+    sp_runtime::{
+        traits::{BlakeTwo256, IdentityLookup},
+        BuildStorage, Perbill,
+    },
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type AccountId = u64;
+pub type Balance = u128;
+
+pub const ACCOUNT_STAKING: u64 = 0;
+pub const ACCOUNT_CANDIDATE_1: u64 = 1;
+pub const ACCOUNT_CANDIDATE_2: u64 = 2;
+pub const ACCOUNT_DELEGATOR_1: u64 = 3;
+pub const ACCOUNT_DELEGATOR_2: u64 = 4;
+pub const ACCOUNT_LIQUID_STAKING: u64 = 10;
+
+pub const MEGA: u128 = 1_000_000;
+pub const SHARE_INIT: u128 = MEGA;
+pub const DEFAULT_BALANCE: u128 = 1_000_000 * MEGA;
+pub const BLOCKS_TO_WAIT: u64 = 2;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        PooledStaking: pallet_pooled_staking,
+        Assets: pallet_assets,
+        LiquidStaking: pallet_liquid_staking,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 4];
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const StakingAccount: u64 = ACCOUNT_STAKING;
+    pub const InitialManualClaimShareValue: u128 = SHARE_INIT;
+    pub const InitialAutoCompoundingShareValue: u128 = SHARE_INIT;
+    pub const MinimumSelfDelegation: u128 = 10 * MEGA;
+    pub const RewardsCollatorCommission: Perbill = Perbill::from_percent(20);
+    pub const BlocksToWait: u64 = BLOCKS_TO_WAIT;
+    pub const BlocksToExpire: u64 = 10;
+    pub const CandidateInfoDepositBaseFee: u128 = 10_000;
+    pub const CandidateInfoDepositByteFee: u128 = 100;
+}
+
+impl pallet_pooled_staking::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Balance = Balance;
+    type StakingAccount = StakingAccount;
+    type InitialManualClaimShareValue = InitialManualClaimShareValue;
+    type InitialAutoCompoundingShareValue = InitialAutoCompoundingShareValue;
+    type MinimumSelfDelegation = MinimumSelfDelegation;
+    type RewardsCollatorCommission = RewardsCollatorCommission;
+    type CommissionChangeTimer = BlockNumberTimer<Self, BlocksToWait>;
+    type CommissionChangeExpiryTimer = BlockNumberTimer<Self, BlocksToExpire>;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type JoiningRequestTimer = BlockNumberTimer<Self, BlocksToWait>;
+    type LeavingRequestTimer = BlockNumberTimer<Self, BlocksToWait>;
+    type EligibleCandidatesBufferSize = ConstU32<100>;
+    type EligibleCandidatesFilter = ();
+    type SaturatedRewardsHandler = ();
+    type MaxCandidateMetadataLength = ConstU32<32>;
+    type CandidateInfoDeposit =
+        tp_traits::BytesDeposit<CandidateInfoDepositBaseFee, CandidateInfoDepositByteFee>;
+    type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = ();
+    type RemoveItemsLimit = ConstU32<1000>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+parameter_types! {
+    pub const LiquidStakingAccount: u64 = ACCOUNT_LIQUID_STAKING;
+}
+
+impl pallet_liquid_staking::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = Assets;
+    type AssetId = u32;
+    type LiquidStakingAccount = LiquidStakingAccount;
+    type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ACCOUNT_STAKING, DEFAULT_BALANCE),
+            (ACCOUNT_CANDIDATE_1, DEFAULT_BALANCE),
+            (ACCOUNT_CANDIDATE_2, DEFAULT_BALANCE),
+            (ACCOUNT_DELEGATOR_1, DEFAULT_BALANCE),
+            (ACCOUNT_DELEGATOR_2, DEFAULT_BALANCE),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
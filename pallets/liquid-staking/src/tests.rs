@@ -0,0 +1,259 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    crate::{
+        mock::{
+            new_test_ext, Assets, LiquidStaking, PooledStaking, RuntimeEvent, RuntimeOrigin,
+            System, Test, ACCOUNT_CANDIDATE_1, ACCOUNT_CANDIDATE_2, ACCOUNT_DELEGATOR_1,
+            ACCOUNT_DELEGATOR_2, ACCOUNT_LIQUID_STAKING, BLOCKS_TO_WAIT, SHARE_INIT,
+        },
+        Error, Event,
+    },
+    frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::InspectHold, fungibles::Inspect},
+    },
+    pallet_pooled_staking::{
+        AllTargetPool, HoldReason, PendingOperationKey, PendingOperationQuery, TargetPool,
+    },
+};
+
+fn delegate(candidate: u64, delegator: u64, amount: u128) {
+    let at = System::block_number();
+    assert_ok!(PooledStaking::request_delegate(
+        RuntimeOrigin::signed(delegator),
+        candidate,
+        TargetPool::AutoCompounding,
+        amount,
+    ));
+
+    System::set_block_number(at + BLOCKS_TO_WAIT);
+    assert_ok!(PooledStaking::execute_pending_operations(
+        RuntimeOrigin::signed(delegator),
+        vec![PendingOperationQuery {
+            delegator,
+            operation: PendingOperationKey::JoiningAutoCompounding { candidate, at },
+        }],
+    ));
+}
+
+fn auto_compounding_stake(candidate: u64, delegator: u64) -> u128 {
+    PooledStaking::computed_stake(candidate, delegator, AllTargetPool::AutoCompounding)
+        .unwrap_or_default()
+}
+
+fn staking_hold(who: u64) -> u128 {
+    <crate::mock::Balances as InspectHold<u64>>::balance_on_hold(
+        &HoldReason::PooledStake.into(),
+        &who,
+    )
+}
+
+#[test]
+fn wrap_creates_asset_and_mints_shares() {
+    new_test_ext().execute_with(|| {
+        delegate(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_1, 4 * SHARE_INIT);
+
+        assert_ok!(LiquidStaking::wrap(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            ACCOUNT_CANDIDATE_1,
+            3
+        ));
+
+        System::assert_has_event(RuntimeEvent::LiquidStaking(Event::AssetCreated {
+            candidate: ACCOUNT_CANDIDATE_1,
+            asset_id: 0,
+        }));
+        System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Wrapped {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_DELEGATOR_1,
+            asset_id: 0,
+            shares: 3,
+            stake: 3 * SHARE_INIT,
+        }));
+
+        assert_eq!(LiquidStaking::candidate_asset(ACCOUNT_CANDIDATE_1), Some(0));
+        assert_eq!(Assets::balance(0, ACCOUNT_DELEGATOR_1), 3);
+        assert_eq!(
+            auto_compounding_stake(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_1),
+            SHARE_INIT
+        );
+        assert_eq!(
+            auto_compounding_stake(ACCOUNT_CANDIDATE_1, ACCOUNT_LIQUID_STAKING),
+            3 * SHARE_INIT
+        );
+        assert_eq!(staking_hold(ACCOUNT_DELEGATOR_1), SHARE_INIT);
+        assert_eq!(staking_hold(ACCOUNT_LIQUID_STAKING), 3 * SHARE_INIT);
+    });
+}
+
+#[test]
+fn assets_are_created_once_per_candidate() {
+    new_test_ext().execute_with(|| {
+        delegate(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_1, 4 * SHARE_INIT);
+        delegate(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_2, 4 * SHARE_INIT);
+        delegate(ACCOUNT_CANDIDATE_2, ACCOUNT_DELEGATOR_1, 4 * SHARE_INIT);
+
+        assert_ok!(LiquidStaking::wrap(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            ACCOUNT_CANDIDATE_1,
+            1
+        ));
+        assert_ok!(LiquidStaking::wrap(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_2),
+            ACCOUNT_CANDIDATE_1,
+            2
+        ));
+        assert_ok!(LiquidStaking::wrap(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            ACCOUNT_CANDIDATE_2,
+            4
+        ));
+
+        assert_eq!(LiquidStaking::candidate_asset(ACCOUNT_CANDIDATE_1), Some(0));
+        assert_eq!(LiquidStaking::candidate_asset(ACCOUNT_CANDIDATE_2), Some(1));
+        assert_eq!(Assets::total_issuance(0), 3);
+        assert_eq!(Assets::total_issuance(1), 4);
+        assert_eq!(Assets::balance(1, ACCOUNT_DELEGATOR_1), 4);
+        assert_eq!(
+            auto_compounding_stake(ACCOUNT_CANDIDATE_1, ACCOUNT_LIQUID_STAKING),
+            3 * SHARE_INIT
+        );
+        assert_eq!(
+            auto_compounding_stake(ACCOUNT_CANDIDATE_2, ACCOUNT_LIQUID_STAKING),
+            4 * SHARE_INIT
+        );
+    });
+}
+
+#[test]
+fn transferred_assets_can_be_redeemed() {
+    new_test_ext().execute_with(|| {
+        delegate(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_1, 4 * SHARE_INIT);
+
+        assert_ok!(LiquidStaking::wrap(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            ACCOUNT_CANDIDATE_1,
+            3
+        ));
+        assert_ok!(Assets::transfer(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            0,
+            ACCOUNT_DELEGATOR_2,
+            2
+        ));
+
+        assert_ok!(LiquidStaking::redeem(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_2),
+            ACCOUNT_CANDIDATE_1,
+            2
+        ));
+
+        System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Redeemed {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_DELEGATOR_2,
+            asset_id: 0,
+            shares: 2,
+            stake: 2 * SHARE_INIT,
+        }));
+
+        assert_eq!(Assets::balance(0, ACCOUNT_DELEGATOR_1), 1);
+        assert_eq!(Assets::balance(0, ACCOUNT_DELEGATOR_2), 0);
+        assert_eq!(
+            auto_compounding_stake(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_2),
+            2 * SHARE_INIT
+        );
+        assert_eq!(
+            auto_compounding_stake(ACCOUNT_CANDIDATE_1, ACCOUNT_LIQUID_STAKING),
+            SHARE_INIT
+        );
+        assert_eq!(staking_hold(ACCOUNT_DELEGATOR_2), 2 * SHARE_INIT);
+        assert_eq!(staking_hold(ACCOUNT_LIQUID_STAKING), SHARE_INIT);
+    });
+}
+
+#[test]
+fn redeem_without_asset_fails() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            LiquidStaking::redeem(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                ACCOUNT_CANDIDATE_1,
+                1
+            ),
+            Error::<Test>::NoAssetForCandidate
+        );
+    });
+}
+
+#[test]
+fn redeem_more_than_owned_fails() {
+    new_test_ext().execute_with(|| {
+        delegate(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_1, 4 * SHARE_INIT);
+        assert_ok!(LiquidStaking::wrap(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            ACCOUNT_CANDIDATE_1,
+            2
+        ));
+
+        assert!(LiquidStaking::redeem(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            ACCOUNT_CANDIDATE_1,
+            3
+        )
+        .is_err());
+    });
+}
+
+#[test]
+fn wrap_more_than_owned_fails() {
+    new_test_ext().execute_with(|| {
+        delegate(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_1, 4 * SHARE_INIT);
+
+        assert_noop!(
+            LiquidStaking::wrap(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                ACCOUNT_CANDIDATE_1,
+                5
+            ),
+            pallet_pooled_staking::Error::<Test>::UnsufficientSharesForTransfer
+        );
+    });
+}
+
+#[test]
+fn candidate_cannot_wrap_below_minimum_self_delegation() {
+    new_test_ext().execute_with(|| {
+        delegate(ACCOUNT_CANDIDATE_1, ACCOUNT_CANDIDATE_1, 12 * SHARE_INIT);
+
+        assert_noop!(
+            LiquidStaking::wrap(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+                ACCOUNT_CANDIDATE_1,
+                3
+            ),
+            pallet_pooled_staking::Error::<Test>::SelfDelegationBelowMinimum
+        );
+
+        assert_ok!(LiquidStaking::wrap(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            ACCOUNT_CANDIDATE_1,
+            2
+        ));
+        assert_eq!(Assets::balance(0, ACCOUNT_CANDIDATE_1), 2);
+    });
+}
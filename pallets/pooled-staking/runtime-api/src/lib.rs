@@ -0,0 +1,59 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Runtime API for Pooled Staking pallet
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use {
+    alloc::vec::Vec,
+    parity_scale_codec::{Decode, Encode},
+    serde::{Deserialize, Serialize},
+    sp_runtime::DispatchError,
+};
+
+/// Rewards accrued and manual rewards claimed by a delegator of a candidate in a block.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo, Serialize, Deserialize,
+)]
+pub struct RewardAccrual<AccountId, Balance> {
+    pub delegator: AccountId,
+    pub candidate: AccountId,
+    pub auto_compounding_rewards: Balance,
+    pub manual_claim_rewards: Balance,
+    pub claimed_manual_rewards: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait PooledStakingApi<AccountId, Balance>
+    where
+        AccountId: parity_scale_codec::Codec,
+        Balance: parity_scale_codec::Codec,
+    {
+        /// Maximum stake of a candidate counting toward its ranking and rewards, if any.
+        fn candidate_stake_cap() -> Option<Balance>;
+
+        /// Rewards accrued by delegators from the rewards distributed in the block, and
+        /// manual rewards they claimed in it, along with the index of the session the
+        /// block belongs to. Commissions are accounted as rewards of the candidate.
+        /// The split of rewards between delegators is approximate if their shares
+        /// changed in the block after the distribution.
+        fn block_reward_accruals()
+            -> Result<(u32, Vec<RewardAccrual<AccountId, Balance>>), DispatchError>;
+    }
+}
@@ -22,8 +22,10 @@ use {
     crate::{
         pools::Pool,
         traits::{IsCandidateEligible, Timer},
-        HoldReason,
+        AutoExecutionOptOut, CandidateInfo, CandidateMetadata, CandidateStakeCap, CommissionBounds,
+        CommissionRange, HoldReason, OfflineCandidates, OperationsQueue, PendingCommissionChanges,
         PendingOperationKey::{JoiningAutoCompounding, JoiningManualRewards},
+        QueueCursors,
     },
     frame_benchmarking::{account, v2::*, BenchmarkError},
     frame_support::{
@@ -31,10 +33,11 @@ use {
         traits::{
             fungible::{InspectHold, Mutate, MutateHold},
             tokens::{fungible::Balanced, Precision},
-            Get,
+            EnsureOrigin, Get,
         },
     },
     frame_system::EventRecord,
+    sp_runtime::{traits::Zero, Perbill},
     sp_std::prelude::*,
 };
 
@@ -67,6 +70,38 @@ fn create_funded_user<T: Config>(
     (user, total)
 }
 
+/// Candidate metadata with all fields of maximum length.
+fn max_candidate_metadata<T: Config>() -> CandidateMetadata<T> {
+    let field = || {
+        vec![b'a'; T::MaxCandidateMetadataLength::get() as usize]
+            .try_into()
+            .expect("to fit in BoundedVec")
+    };
+
+    CandidateMetadata {
+        name: field(),
+        website: field(),
+        commission_policy: field(),
+        location: field(),
+    }
+}
+
+/// Create a candidate with enough self delegation to be eligible.
+fn create_eligible_candidate<T: Config>(n: u32) -> Result<T::AccountId, BenchmarkError> {
+    let (candidate, _deposit) = create_funded_user::<T>("candidate", n, min_candidate_stk::<T>());
+
+    T::EligibleCandidatesFilter::make_candidate_eligible(&candidate, true);
+
+    PooledStaking::<T>::request_delegate(
+        RawOrigin::Signed(candidate.clone()).into(),
+        candidate.clone(),
+        TargetPool::AutoCompounding,
+        min_candidate_stk::<T>(),
+    )?;
+
+    Ok(candidate)
+}
+
 pub(crate) fn currency_issue<T: Config + frame_system::Config>(
     amount: T::Balance,
 ) -> crate::CreditOf<T> {
@@ -610,6 +645,364 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn request_commission_change() -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1;
+        let caller = create_eligible_candidate::<T>(USER_SEED)?;
+
+        CommissionBounds::<T>::put(CommissionRange {
+            min: Perbill::zero(),
+            max: Perbill::one(),
+        });
+        let commission = Perbill::from_percent(10);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), commission);
+
+        assert_last_event::<T>(
+            Event::RequestedCommissionChange {
+                candidate: caller,
+                commission,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn execute_commission_change() -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1;
+        let caller = create_eligible_candidate::<T>(USER_SEED)?;
+
+        CommissionBounds::<T>::put(CommissionRange {
+            min: Perbill::zero(),
+            max: Perbill::one(),
+        });
+        let commission = Perbill::from_percent(10);
+
+        PooledStaking::<T>::request_commission_change(
+            RawOrigin::Signed(caller.clone()).into(),
+            commission,
+        )?;
+
+        T::CommissionChangeTimer::skip_to_elapsed();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), caller.clone());
+
+        assert_eq!(
+            PooledStaking::<T>::candidate_commission(caller.clone()),
+            commission
+        );
+        assert!(PendingCommissionChanges::<T>::get(&caller).is_none());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_commission_bounds() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let min = Perbill::from_percent(5);
+        let max = Perbill::from_percent(30);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, min, max);
+
+        assert_eq!(CommissionBounds::<T>::get(), CommissionRange { min, max });
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn redelegate() -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1;
+        let (caller, _deposit_amount) =
+            create_funded_user::<T>("caller", USER_SEED, min_candidate_stk::<T>());
+        let (target, _deposit_amount) =
+            create_funded_user::<T>("target", USER_SEED, min_candidate_stk::<T>());
+
+        T::EligibleCandidatesFilter::make_candidate_eligible(&caller, true);
+        T::EligibleCandidatesFilter::make_candidate_eligible(&target, true);
+
+        PooledStaking::<T>::request_delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            caller.clone(),
+            TargetPool::AutoCompounding,
+            min_candidate_stk::<T>(),
+        )?;
+
+        PooledStaking::<T>::request_delegate(
+            RawOrigin::Signed(target.clone()).into(),
+            target.clone(),
+            TargetPool::AutoCompounding,
+            min_candidate_stk::<T>(),
+        )?;
+
+        let timer = T::JoiningRequestTimer::now();
+
+        T::JoiningRequestTimer::skip_to_elapsed();
+
+        PooledStaking::<T>::execute_pending_operations(
+            RawOrigin::Signed(caller.clone()).into(),
+            vec![
+                PendingOperationQuery {
+                    delegator: caller.clone(),
+                    operation: JoiningAutoCompounding {
+                        candidate: caller.clone(),
+                        at: timer.clone(),
+                    },
+                },
+                PendingOperationQuery {
+                    delegator: target.clone(),
+                    operation: JoiningAutoCompounding {
+                        candidate: target.clone(),
+                        at: timer.clone(),
+                    },
+                },
+            ],
+        )?;
+
+        let stake_to_move = min_candidate_stk::<T>() / 2u32.into();
+
+        // We now have a working delegation, and we can request to move part of it
+        // to another candidate. This should take the candidate out from being eligible
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            caller.clone(),
+            target.clone(),
+            TargetPool::AutoCompounding,
+            TargetPool::AutoCompounding,
+            SharesOrStake::Stake(stake_to_move),
+        );
+
+        // lets get the hold amount to know dust
+        let on_hold = T::Currency::balance_on_hold(&HoldReason::PooledStake.into(), &caller);
+        // dust gets released immediatly
+        let dust = min_candidate_stk::<T>() - on_hold;
+
+        assert_last_event::<T>(
+            Event::RequestedRedelegate {
+                candidate: caller.clone(),
+                target,
+                delegator: caller,
+                from: TargetPool::AutoCompounding,
+                pool: TargetPool::AutoCompounding,
+                pending: stake_to_move - dust,
+                released: dust,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn execute_queued_operations(
+        b: Linear<1, { T::EligibleCandidatesBufferSize::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1000;
+        let (caller, _deposit_amount) =
+            create_funded_user::<T>("caller", USER_SEED, min_candidate_stk::<T>() * b.into());
+
+        let mut candidates = vec![];
+
+        T::Currency::set_balance(&T::StakingAccount::get(), min_candidate_stk::<T>());
+
+        // Create as many delegations as one can, which are queued
+        for i in 0..b {
+            let (candidate, _deposit) = create_funded_user::<T>(
+                "candidate",
+                USER_SEED - i - 1,
+                min_candidate_stk::<T>() * 2u32.into(),
+            );
+            T::EligibleCandidatesFilter::make_candidate_eligible(&candidate, true);
+
+            PooledStaking::<T>::request_delegate(
+                RawOrigin::Signed(caller.clone()).into(),
+                candidate.clone(),
+                TargetPool::AutoCompounding,
+                min_candidate_stk::<T>(),
+            )?;
+
+            candidates.push(candidate);
+        }
+
+        T::JoiningRequestTimer::skip_to_elapsed();
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), b);
+
+        let cursor = QueueCursors::<T>::get(OperationsQueue::Joining);
+        assert_eq!(cursor.head, cursor.tail);
+
+        let last_candidate = &candidates[candidates.len() - 1];
+        assert_last_event::<T>(
+            Event::ExecutedDelegate {
+                candidate: last_candidate.clone(),
+                delegator: caller,
+                pool: TargetPool::AutoCompounding,
+                staked: min_candidate_stk::<T>(),
+                released: 0u32.into(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_auto_execution(
+        b: Linear<0, { T::EligibleCandidatesBufferSize::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1000;
+        let (caller, _deposit_amount) =
+            create_funded_user::<T>("caller", USER_SEED, min_candidate_stk::<T>() * b.into());
+
+        T::Currency::set_balance(&T::StakingAccount::get(), min_candidate_stk::<T>());
+
+        // Opted out delegators operations are not kept in the queue
+        PooledStaking::<T>::set_auto_execution(RawOrigin::Signed(caller.clone()).into(), false, 0)?;
+
+        // Create as many pending operations as one can, which must be queued again
+        for i in 0..b {
+            let (candidate, _deposit) = create_funded_user::<T>(
+                "candidate",
+                USER_SEED - i - 1,
+                min_candidate_stk::<T>() * 2u32.into(),
+            );
+            T::EligibleCandidatesFilter::make_candidate_eligible(&candidate, true);
+
+            PooledStaking::<T>::request_delegate(
+                RawOrigin::Signed(caller.clone()).into(),
+                candidate,
+                TargetPool::AutoCompounding,
+                min_candidate_stk::<T>(),
+            )?;
+        }
+
+        let tail_before = QueueCursors::<T>::get(OperationsQueue::Joining).tail;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), true, b);
+
+        assert!(!AutoExecutionOptOut::<T>::contains_key(&caller));
+        assert_eq!(
+            QueueCursors::<T>::get(OperationsQueue::Joining).tail,
+            tail_before + u64::from(b)
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_candidate_stake_cap(
+        b: Linear<1, { T::EligibleCandidatesBufferSize::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1000;
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        // Fill the eligible candidates list
+        for i in 0..b {
+            let (candidate, _deposit) = create_funded_user::<T>(
+                "candidate",
+                USER_SEED - i - 1,
+                min_candidate_stk::<T>() * 2u32.into(),
+            );
+
+            T::EligibleCandidatesFilter::make_candidate_eligible(&candidate, true);
+
+            // self delegation
+            PooledStaking::<T>::request_delegate(
+                RawOrigin::Signed(candidate.clone()).into(),
+                candidate.clone(),
+                TargetPool::AutoCompounding,
+                min_candidate_stk::<T>() + i.into(),
+            )?;
+        }
+
+        let cap = Some(min_candidate_stk::<T>());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, cap);
+
+        assert_eq!(CandidateStakeCap::<T>::get(), cap);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_candidate_info() -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1;
+        let (caller, _deposit_amount) =
+            create_funded_user::<T>("caller", USER_SEED, min_candidate_stk::<T>());
+        let metadata = max_candidate_metadata::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), metadata.clone());
+
+        assert_eq!(
+            CandidateInfo::<T>::get(&caller).map(|info| info.metadata),
+            Some(metadata)
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn clear_candidate_info() -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1;
+        let (caller, _deposit_amount) =
+            create_funded_user::<T>("caller", USER_SEED, min_candidate_stk::<T>());
+
+        PooledStaking::<T>::set_candidate_info(
+            RawOrigin::Signed(caller.clone()).into(),
+            max_candidate_metadata::<T>(),
+        )?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(!CandidateInfo::<T>::contains_key(&caller));
+        assert!(
+            T::Currency::balance_on_hold(&HoldReason::CandidateInfoDeposit.into(), &caller)
+                .is_zero()
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn go_offline() -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1;
+        let candidate = create_eligible_candidate::<T>(USER_SEED)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(candidate.clone()));
+
+        assert!(OfflineCandidates::<T>::contains_key(&candidate));
+        assert!(SortedEligibleCandidates::<T>::get().is_empty());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn go_online() -> Result<(), BenchmarkError> {
+        const USER_SEED: u32 = 1;
+        let candidate = create_eligible_candidate::<T>(USER_SEED)?;
+
+        PooledStaking::<T>::go_offline(RawOrigin::Signed(candidate.clone()).into())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(candidate.clone()));
+
+        assert!(!OfflineCandidates::<T>::contains_key(&candidate));
+        assert_eq!(SortedEligibleCandidates::<T>::get().len(), 1);
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         PooledStaking,
         crate::mock::ExtBuilder::default().build(),
@@ -18,22 +18,31 @@ use {
     crate::{
         candidate::Candidates,
         pools::{self, Pool},
+        queue,
         traits::Timer,
-        AllTargetPool, Candidate, Config, Delegator, Error, Event, HoldReason, Pallet,
-        PendingOperationKey, PendingOperationQuery, PendingOperationQueryOf, PendingOperations,
-        Shares, SharesOrStake, Stake, TargetPool,
+        AllTargetPool, AutoExecutionOptOut, Candidate, CandidateCommission, CandidateInfo,
+        CandidateMetadata, CandidateStakeCap, CommissionBounds, CommissionRange, Config, Delegator,
+        Error, Event, HoldReason, OfflineCandidates, Pallet, PendingCommissionChange,
+        PendingCommissionChanges, PendingOperationKey, PendingOperationKeyOf,
+        PendingOperationQuery, PendingOperationQueryOf, PendingOperations, RedelegatedStake,
+        RegisteredCandidateInfo, Shares, SharesOrStake, SortedEligibleCandidates, Stake,
+        TargetPool,
     },
     frame_support::{
         dispatch::DispatchErrorWithPostInfo,
         pallet_prelude::*,
         traits::{
             fungible::{Mutate, MutateHold},
-            tokens::{Precision, Preservation},
+            tokens::{Fortitude, Precision, Preservation, Restriction},
         },
     },
-    sp_runtime::traits::{CheckedSub, Zero},
+    sp_runtime::{
+        traits::{CheckedSub, Zero},
+        Perbill,
+    },
     sp_std::vec::Vec,
     tp_maths::{ErrAdd, ErrSub},
+    tp_traits::StorageDeposit,
 };
 
 pub struct Calls<T>(PhantomData<T>);
@@ -133,6 +142,7 @@ impl<T: Config> Calls<T> {
         T::Currency::hold(&HoldReason::PooledStake.into(), &delegator, stake.0)?;
         pools::Joining::<T>::increase_hold(&candidate, &delegator, &stake)?;
         Candidates::<T>::add_total_stake(&candidate, &stake)?;
+        Candidates::<T>::warn_if_saturated(&candidate, &delegator);
 
         // We create/mutate a request for joining.
         let now = T::JoiningRequestTimer::now();
@@ -148,11 +158,7 @@ impl<T: Config> Calls<T> {
         };
 
         // We store/mutate the operation in storage.
-        let operation = PendingOperations::<T>::get(&delegator, &operation_key);
-        let operation = operation
-            .err_add(&shares.0)
-            .map_err(|_| Error::<T>::MathOverflow)?;
-        PendingOperations::<T>::set(&delegator, &operation_key, operation);
+        Self::add_to_pending_operation(&delegator, &operation_key, shares)?;
 
         pools::check_candidate_consistency::<T>(&candidate)?;
 
@@ -223,19 +229,19 @@ impl<T: Config> Calls<T> {
                 operation,
             } = query;
 
-            let value = PendingOperations::<T>::get(&delegator, &operation);
+            let value = Self::pending_operation_value(&delegator, &operation);
 
             if value.is_zero() {
                 continue;
             }
 
-            match &operation {
-                PendingOperationKey::JoiningAutoCompounding { candidate, at } => {
-                    ensure!(
-                        T::JoiningRequestTimer::is_elapsed(at),
-                        Error::<T>::RequestCannotBeExecuted(index as u16)
-                    );
+            ensure!(
+                Self::is_operation_matured(&operation),
+                Error::<T>::RequestCannotBeExecuted(index as u16)
+            );
 
+            match &operation {
+                PendingOperationKey::JoiningAutoCompounding { candidate, .. } => {
                     Self::execute_joining(
                         candidate.clone(),
                         delegator.clone(),
@@ -243,12 +249,7 @@ impl<T: Config> Calls<T> {
                         Shares(value),
                     )?;
                 }
-                PendingOperationKey::JoiningManualRewards { candidate, at } => {
-                    ensure!(
-                        T::JoiningRequestTimer::is_elapsed(at),
-                        Error::<T>::RequestCannotBeExecuted(index as u16)
-                    );
-
+                PendingOperationKey::JoiningManualRewards { candidate, .. } => {
                     Self::execute_joining(
                         candidate.clone(),
                         delegator.clone(),
@@ -256,14 +257,26 @@ impl<T: Config> Calls<T> {
                         Shares(value),
                     )?;
                 }
-                PendingOperationKey::Leaving { candidate, at } => {
-                    ensure!(
-                        T::LeavingRequestTimer::is_elapsed(at),
-                        Error::<T>::RequestCannotBeExecuted(index as u16)
-                    );
-
+                PendingOperationKey::Leaving { candidate, .. } => {
                     Self::execute_leaving(candidate.clone(), delegator.clone(), Shares(value))?;
                 }
+                PendingOperationKey::Redelegating {
+                    candidate,
+                    target,
+                    at,
+                } => {
+                    RedelegatedStake::<T>::remove(
+                        &delegator,
+                        (candidate.clone(), target.clone(), at.clone()),
+                    );
+                    Self::execute_redelegating(
+                        candidate.clone(),
+                        target.clone(),
+                        delegator.clone(),
+                        Stake(value),
+                    )?;
+                    continue;
+                }
             }
 
             PendingOperations::<T>::remove(&delegator, &operation);
@@ -272,6 +285,42 @@ impl<T: Config> Calls<T> {
         Ok(().into())
     }
 
+    /// Amount of a pending operation, in shares for joining and leaving operations
+    /// and in stake for redelegations.
+    pub fn pending_operation_value(
+        delegator: &Delegator<T>,
+        operation: &PendingOperationKeyOf<T>,
+    ) -> T::Balance {
+        match operation {
+            PendingOperationKey::Redelegating {
+                candidate,
+                target,
+                at,
+            } => {
+                RedelegatedStake::<T>::get(
+                    delegator,
+                    (candidate.clone(), target.clone(), at.clone()),
+                )
+                .0
+            }
+            _ => PendingOperations::<T>::get(delegator, operation),
+        }
+    }
+
+    /// Returns whether the timer of the operation is elapsed.
+    pub fn is_operation_matured(operation: &PendingOperationKeyOf<T>) -> bool {
+        match operation {
+            PendingOperationKey::JoiningAutoCompounding { at, .. }
+            | PendingOperationKey::JoiningManualRewards { at, .. } => {
+                T::JoiningRequestTimer::is_elapsed(at)
+            }
+            PendingOperationKey::Leaving { at, .. }
+            | PendingOperationKey::Redelegating { at, .. } => {
+                T::LeavingRequestTimer::is_elapsed(at)
+            }
+        }
+    }
+
     fn execute_joining(
         candidate: Candidate<T>,
         delegator: Delegator<T>,
@@ -402,6 +451,24 @@ impl<T: Config> Calls<T> {
         Ok(().into())
     }
 
+    fn execute_redelegating(
+        candidate: Candidate<T>,
+        target: Candidate<T>,
+        delegator: Delegator<T>,
+        stake: Stake<T::Balance>,
+    ) -> DispatchResultWithPostInfo {
+        // Funds were moved to the target when the redelegation was requested, the
+        // stake is simply no longer slashable by the source candidate.
+        Pallet::<T>::deposit_event(Event::<T>::ExecutedRedelegate {
+            candidate,
+            target,
+            delegator,
+            stake: stake.0,
+        });
+
+        Ok(().into())
+    }
+
     pub fn claim_manual_rewards(
         pairs: &[(Candidate<T>, Delegator<T>)],
     ) -> DispatchResultWithPostInfo {
@@ -525,6 +592,422 @@ impl<T: Config> Calls<T> {
         Ok(().into())
     }
 
+    pub fn redelegate(
+        candidate: Candidate<T>,
+        target: Candidate<T>,
+        delegator: Delegator<T>,
+        source_pool: TargetPool,
+        target_pool: TargetPool,
+        amount: SharesOrStake<T::Balance>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(candidate != target, Error::<T>::RedelegatingToSameCandidate);
+
+        // Converts amount to shares of the correct pool
+        let shares = match (amount, source_pool) {
+            (SharesOrStake::Shares(s), _) => s,
+            (SharesOrStake::Stake(s), TargetPool::AutoCompounding) => {
+                pools::AutoCompounding::<T>::stake_to_shares(&candidate, Stake(s))?.0
+            }
+            (SharesOrStake::Stake(s), TargetPool::ManualRewards) => {
+                pools::ManualRewards::<T>::stake_to_shares(&candidate, Stake(s))?.0
+            }
+        };
+
+        // Any change in the amount of Manual Rewards shares requires to claim manual rewards.
+        if let TargetPool::ManualRewards = source_pool {
+            Self::claim_manual_rewards(&[(candidate.clone(), delegator.clone())])?;
+        }
+
+        // Destroy shares
+        let removed_stake =
+            Self::destroy_shares(&candidate, &delegator, source_pool, Shares(shares))?;
+
+        // All this stake no longer contribute to the election of the candidate.
+        Candidates::<T>::sub_total_stake(&candidate, removed_stake)?;
+
+        pools::check_candidate_consistency::<T>(&candidate)?;
+
+        // Funds stay held and are placed in the joining pool of the target right
+        // away, thus its joining delay starts now.
+        let joining_shares = pools::Joining::<T>::stake_to_shares_or_init(&target, removed_stake)?;
+        let joining_stake = if joining_shares.0.is_zero() {
+            Stake(Zero::zero())
+        } else {
+            let joining_stake =
+                pools::Joining::<T>::add_shares(&target, &delegator, joining_shares)?;
+            pools::Joining::<T>::increase_hold(&target, &delegator, &joining_stake)?;
+            Candidates::<T>::add_total_stake(&target, &joining_stake)?;
+            Candidates::<T>::warn_if_saturated(&target, &delegator);
+
+            let now = T::JoiningRequestTimer::now();
+            let operation_key = match target_pool {
+                TargetPool::AutoCompounding => PendingOperationKey::JoiningAutoCompounding {
+                    candidate: target.clone(),
+                    at: now,
+                },
+                TargetPool::ManualRewards => PendingOperationKey::JoiningManualRewards {
+                    candidate: target.clone(),
+                    at: now,
+                },
+            };
+            Self::add_to_pending_operation(&delegator, &operation_key, joining_shares)?;
+
+            pools::check_candidate_consistency::<T>(&target)?;
+
+            // The stake should stay slashable by the source candidate until its leaving
+            // delay is elapsed. Slashing is not implemented yet, the stake is only
+            // recorded until the `Redelegating` operation clears it.
+            let at = T::LeavingRequestTimer::now();
+            let record_key = (candidate.clone(), target.clone(), at.clone());
+            let Stake(recorded) = RedelegatedStake::<T>::get(&delegator, &record_key);
+            if recorded.is_zero() {
+                let operation_key = PendingOperationKey::Redelegating {
+                    candidate: candidate.clone(),
+                    target: target.clone(),
+                    at,
+                };
+                queue::push::<T>(&delegator, &operation_key);
+            }
+            let recorded = recorded
+                .err_add(&joining_stake.0)
+                .map_err(Error::<T>::from)?;
+            RedelegatedStake::<T>::insert(&delegator, &record_key, Stake(recorded));
+
+            joining_stake
+        };
+
+        // We release currency that couldn't be converted to shares due to rounding.
+        let release = removed_stake
+            .0
+            .err_sub(&joining_stake.0)
+            .map_err(Error::<T>::from)?;
+        if !release.is_zero() {
+            T::Currency::release(
+                &HoldReason::PooledStake.into(),
+                &delegator,
+                release,
+                Precision::Exact,
+            )?;
+        }
+
+        Pallet::<T>::deposit_event(Event::<T>::RequestedRedelegate {
+            candidate,
+            target,
+            delegator,
+            from: source_pool,
+            pool: target_pool,
+            pending: joining_stake.0,
+            released: release,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn request_commission_change(
+        candidate: Candidate<T>,
+        commission: Perbill,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(
+            CommissionBounds::<T>::get().contains(&commission),
+            Error::<T>::CommissionOutOfBounds
+        );
+        ensure!(
+            !Candidates::<T>::total_stake(&candidate).0.is_zero(),
+            Error::<T>::NotACandidate
+        );
+
+        PendingCommissionChanges::<T>::insert(
+            &candidate,
+            PendingCommissionChange {
+                commission,
+                at: T::CommissionChangeTimer::now(),
+            },
+        );
+
+        Pallet::<T>::deposit_event(Event::<T>::RequestedCommissionChange {
+            candidate,
+            commission,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn execute_commission_change(candidate: Candidate<T>) -> DispatchResultWithPostInfo {
+        let request = PendingCommissionChanges::<T>::get(&candidate)
+            .ok_or(Error::<T>::NoPendingCommissionChange)?;
+
+        ensure!(
+            T::CommissionChangeTimer::is_elapsed(&request.at),
+            Error::<T>::CommissionChangeTooSoon
+        );
+        ensure!(
+            !T::CommissionChangeExpiryTimer::is_elapsed(&request.at),
+            Error::<T>::CommissionChangeExpired
+        );
+
+        // Bounds could have been changed since the request was made.
+        ensure!(
+            CommissionBounds::<T>::get().contains(&request.commission),
+            Error::<T>::CommissionOutOfBounds
+        );
+
+        let old_commission = Candidates::<T>::commission(&candidate);
+        CandidateCommission::<T>::insert(&candidate, request.commission);
+        PendingCommissionChanges::<T>::remove(&candidate);
+
+        Pallet::<T>::deposit_event(Event::<T>::ExecutedCommissionChange {
+            candidate,
+            old_commission,
+            new_commission: request.commission,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn set_commission_bounds(bounds: CommissionRange) -> DispatchResultWithPostInfo {
+        ensure!(
+            bounds.min <= bounds.max,
+            Error::<T>::InvalidCommissionBounds
+        );
+
+        CommissionBounds::<T>::put(bounds);
+
+        Pallet::<T>::deposit_event(Event::<T>::CommissionBoundsUpdated {
+            min: bounds.min,
+            max: bounds.max,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn set_auto_execution(
+        delegator: Delegator<T>,
+        enabled: bool,
+        pending_operations: u32,
+    ) -> DispatchResultWithPostInfo {
+        if enabled {
+            // Operations of opted out delegators are dropped from the queues, so the
+            // remaining ones must be queued again.
+            if AutoExecutionOptOut::<T>::take(&delegator).is_some() {
+                let redelegations = RedelegatedStake::<T>::iter_key_prefix(&delegator).map(
+                    |(candidate, target, at)| PendingOperationKey::Redelegating {
+                        candidate,
+                        target,
+                        at,
+                    },
+                );
+                let operations: Vec<_> = PendingOperations::<T>::iter_key_prefix(&delegator)
+                    .chain(redelegations)
+                    .take(pending_operations.saturating_add(1) as usize)
+                    .collect();
+                ensure!(
+                    operations.len() <= pending_operations as usize,
+                    Error::<T>::TooManyPendingOperations
+                );
+
+                for operation in operations {
+                    queue::push::<T>(&delegator, &operation);
+                }
+            }
+        } else {
+            AutoExecutionOptOut::<T>::insert(&delegator, ());
+        }
+
+        Pallet::<T>::deposit_event(Event::<T>::UpdatedAutoExecution { delegator, enabled });
+
+        Ok(().into())
+    }
+
+    pub fn set_candidate_stake_cap(cap: Option<T::Balance>) -> DispatchResultWithPostInfo {
+        ensure!(
+            !matches!(cap, Some(cap) if cap.is_zero()),
+            Error::<T>::InvalidCandidateStakeCap
+        );
+
+        CandidateStakeCap::<T>::set(cap);
+
+        // Eligible candidates are sorted by their capped stake, which must be
+        // computed again with the new cap.
+        let list = SortedEligibleCandidates::<T>::get()
+            .try_mutate(|list| {
+                for entry in list.iter_mut() {
+                    let stake = Candidates::<T>::total_stake(&entry.candidate).0;
+                    entry.stake = Candidates::<T>::capped_stake(stake, cap);
+                }
+                list.sort();
+            })
+            // This should not occur as the length of the list is unchanged.
+            .ok_or(Error::<T>::InconsistentState)?;
+
+        SortedEligibleCandidates::<T>::set(list);
+
+        Pallet::<T>::deposit_event(Event::<T>::CandidateStakeCapUpdated { cap });
+
+        Ok(().into())
+    }
+
+    pub fn set_candidate_info(
+        candidate: Candidate<T>,
+        metadata: CandidateMetadata<T>,
+    ) -> DispatchResultWithPostInfo {
+        let deposit = T::CandidateInfoDeposit::compute_deposit(&metadata)?;
+        let old_deposit = CandidateInfo::<T>::get(&candidate)
+            .map(|info| info.deposit)
+            .unwrap_or_else(Zero::zero);
+
+        if let Some(diff) = deposit.checked_sub(&old_deposit) {
+            T::Currency::hold(&HoldReason::CandidateInfoDeposit.into(), &candidate, diff)?;
+        } else if let Some(diff) = old_deposit.checked_sub(&deposit) {
+            T::Currency::release(
+                &HoldReason::CandidateInfoDeposit.into(),
+                &candidate,
+                diff,
+                Precision::Exact,
+            )?;
+        }
+
+        CandidateInfo::<T>::insert(&candidate, RegisteredCandidateInfo { metadata, deposit });
+
+        Pallet::<T>::deposit_event(Event::<T>::CandidateInfoSet { candidate, deposit });
+
+        Ok(().into())
+    }
+
+    pub fn clear_candidate_info(candidate: Candidate<T>) -> DispatchResultWithPostInfo {
+        let info = CandidateInfo::<T>::take(&candidate).ok_or(Error::<T>::NoCandidateInfo)?;
+
+        T::Currency::release(
+            &HoldReason::CandidateInfoDeposit.into(),
+            &candidate,
+            info.deposit,
+            Precision::Exact,
+        )?;
+
+        Pallet::<T>::deposit_event(Event::<T>::CandidateInfoCleared {
+            candidate,
+            released_deposit: info.deposit,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn go_offline(candidate: Candidate<T>) -> DispatchResultWithPostInfo {
+        ensure!(
+            !OfflineCandidates::<T>::contains_key(&candidate),
+            Error::<T>::CandidateAlreadyOffline
+        );
+
+        let stake = Candidates::<T>::total_stake(&candidate);
+        ensure!(
+            !stake.0.is_zero() || CandidateInfo::<T>::contains_key(&candidate),
+            Error::<T>::NotACandidate
+        );
+
+        OfflineCandidates::<T>::insert(&candidate, ());
+
+        // Offline candidates are not eligible, updating its position removes it
+        // from the list.
+        Candidates::<T>::update_total_stake(&candidate, stake)?;
+
+        Pallet::<T>::deposit_event(Event::<T>::CandidateWentOffline { candidate });
+
+        Ok(().into())
+    }
+
+    pub fn go_online(candidate: Candidate<T>) -> DispatchResultWithPostInfo {
+        ensure!(
+            OfflineCandidates::<T>::contains_key(&candidate),
+            Error::<T>::CandidateNotOffline
+        );
+
+        OfflineCandidates::<T>::remove(&candidate);
+
+        let stake = Candidates::<T>::total_stake(&candidate);
+        Candidates::<T>::update_total_stake(&candidate, stake)?;
+
+        Pallet::<T>::deposit_event(Event::<T>::CandidateWentOnline { candidate });
+
+        Ok(().into())
+    }
+
+    /// Transfer AutoCompounding shares, and the held stake they represent, from one
+    /// account to another. The total stake of the candidate is unchanged.
+    /// Returns the value of the transferred shares.
+    pub fn transfer_auto_compounding_shares(
+        candidate: &Candidate<T>,
+        from: &Delegator<T>,
+        to: &Delegator<T>,
+        shares: Shares<T::Balance>,
+    ) -> Result<Stake<T::Balance>, DispatchErrorWithPostInfo> {
+        ensure!(!shares.0.is_zero(), Error::<T>::StakeMustBeNonZero);
+
+        let stake = pools::AutoCompounding::<T>::shares_to_stake(candidate, shares)?;
+
+        let from_shares = pools::AutoCompounding::<T>::shares(candidate, from)
+            .0
+            .err_sub(&shares.0)
+            .map_err(|_| Error::<T>::UnsufficientSharesForTransfer)?;
+        let to_shares = pools::AutoCompounding::<T>::shares(candidate, to)
+            .0
+            .err_add(&shares.0)
+            .map_err(Error::<T>::from)?;
+
+        // Candidates cannot transfer away the self delegation they need to stay eligible.
+        if from == candidate {
+            let self_delegation = Candidates::<T>::self_delegation(candidate)?
+                .0
+                .err_sub(&stake.0)
+                .map_err(Error::<T>::from)?;
+            ensure!(
+                self_delegation >= T::MinimumSelfDelegation::get(),
+                Error::<T>::SelfDelegationBelowMinimum
+            );
+        }
+
+        if stake.0 > pools::AutoCompounding::<T>::hold(candidate, from).0 {
+            Self::rebalance_hold(
+                candidate.clone(),
+                from.clone(),
+                AllTargetPool::AutoCompounding,
+            )?;
+        }
+
+        pools::AutoCompounding::<T>::set_shares(candidate, from, Shares(from_shares));
+        pools::AutoCompounding::<T>::decrease_hold(candidate, from, &stake)?;
+        pools::AutoCompounding::<T>::set_shares(candidate, to, Shares(to_shares));
+        pools::AutoCompounding::<T>::increase_hold(candidate, to, &stake)?;
+
+        T::Currency::transfer_on_hold(
+            &HoldReason::PooledStake.into(),
+            from,
+            to,
+            stake.0,
+            Precision::Exact,
+            Restriction::OnHold,
+            Fortitude::Polite,
+        )?;
+
+        // Self delegation changed, which can affect the eligibility of the candidate.
+        if from == candidate || to == candidate {
+            Candidates::<T>::update_total_stake(
+                candidate,
+                Candidates::<T>::total_stake(candidate),
+            )?;
+        }
+
+        pools::check_candidate_consistency::<T>(candidate)?;
+
+        Pallet::<T>::deposit_event(Event::<T>::TransferredShares {
+            candidate: candidate.clone(),
+            from: from.clone(),
+            to: to.clone(),
+            shares: shares.0,
+            stake: stake.0,
+        });
+
+        Ok(stake)
+    }
+
     /// Destory ManualReward or AutoCompounding shares while performing hold rebalancing if
     /// necessary.
     fn destroy_shares(
@@ -590,16 +1073,11 @@ impl<T: Config> Calls<T> {
         pools::Leaving::<T>::increase_hold(candidate, delegator, &leaving_stake)?;
 
         // We create/mutate a request for leaving.
-        let now = T::LeavingRequestTimer::now();
         let operation_key = PendingOperationKey::Leaving {
             candidate: candidate.clone(),
-            at: now,
+            at: T::LeavingRequestTimer::now(),
         };
-        let operation = PendingOperations::<T>::get(delegator, &operation_key);
-        let operation = operation
-            .err_add(&leaving_shares.0)
-            .map_err(|_| Error::<T>::MathOverflow)?;
-        PendingOperations::<T>::set(delegator, &operation_key, operation);
+        Self::add_to_pending_operation(delegator, &operation_key, leaving_shares)?;
 
         // We release the dust if non-zero.
         let dust = stake
@@ -618,4 +1096,25 @@ impl<T: Config> Calls<T> {
 
         Ok((leaving_stake, Stake(dust)))
     }
+
+    /// Add shares to a pending operation. Operations that are created are pushed to
+    /// their queue so they can be executed automatically once matured.
+    fn add_to_pending_operation(
+        delegator: &Delegator<T>,
+        operation_key: &PendingOperationKeyOf<T>,
+        shares: Shares<T::Balance>,
+    ) -> Result<(), Error<T>> {
+        let operation = PendingOperations::<T>::get(delegator, operation_key);
+
+        if operation.is_zero() {
+            queue::push::<T>(delegator, operation_key);
+        }
+
+        let operation = operation
+            .err_add(&shares.0)
+            .map_err(|_| Error::<T>::MathOverflow)?;
+        PendingOperations::<T>::set(delegator, operation_key, operation);
+
+        Ok(())
+    }
 }
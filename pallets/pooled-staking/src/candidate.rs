@@ -18,14 +18,19 @@ use {
     crate::{
         pools::{self, Pool},
         traits::IsCandidateEligible,
-        Candidate, Config, Error, Event, Pallet, Pools, PoolsKey, SortedEligibleCandidates, Stake,
+        Candidate, CandidateCommission, CandidateStakeCap, CommissionBounds, Config, Delegator,
+        Error, Event, OfflineCandidates, Pallet, Pools, PoolsKey, SortedEligibleCandidates, Stake,
+        TotalStake,
     },
     core::{cmp::Ordering, marker::PhantomData},
     parity_scale_codec::{Decode, Encode},
     scale_info::TypeInfo,
     serde::{Deserialize, Serialize},
     sp_core::{Get, RuntimeDebug},
-    sp_runtime::traits::Zero,
+    sp_runtime::{
+        traits::{Saturating, Zero},
+        Perbill,
+    },
     tp_maths::{ErrAdd, ErrSub},
 };
 
@@ -58,6 +63,41 @@ impl<T: Config> Candidates<T> {
         Stake(Pools::<T>::get(candidate, &PoolsKey::CandidateTotalStake))
     }
 
+    /// Commission applied to the rewards of the candidate. Falls back to
+    /// `RewardsCollatorCommission` and is kept within `CommissionBounds` even if
+    /// they changed since the candidate configured it.
+    pub fn commission(candidate: &Candidate<T>) -> Perbill {
+        let bounds = CommissionBounds::<T>::get();
+        CandidateCommission::<T>::get(candidate)
+            .unwrap_or_else(T::RewardsCollatorCommission::get)
+            .clamp(bounds.min, bounds.max)
+    }
+
+    /// Part of `stake` counting toward the ranking and rewards of a candidate.
+    pub fn capped_stake(stake: T::Balance, cap: Option<T::Balance>) -> T::Balance {
+        match cap {
+            Some(cap) => stake.min(cap),
+            None => stake,
+        }
+    }
+
+    /// Warns `delegator` if `candidate` has more stake than `CandidateStakeCap`.
+    pub fn warn_if_saturated(candidate: &Candidate<T>, delegator: &Delegator<T>) {
+        let Some(cap) = CandidateStakeCap::<T>::get() else {
+            return;
+        };
+
+        let stake = Self::total_stake(candidate).0;
+        if stake > cap {
+            Pallet::<T>::deposit_event(Event::<T>::DelegatedToSaturatedCandidate {
+                candidate: candidate.clone(),
+                delegator: delegator.clone(),
+                stake,
+                cap,
+            });
+        }
+    }
+
     pub fn add_total_stake(
         candidate: &Candidate<T>,
         stake: &Stake<T::Balance>,
@@ -98,14 +138,9 @@ impl<T: Config> Candidates<T> {
         Ok(())
     }
 
-    pub fn update_total_stake(
-        candidate: &Candidate<T>,
-        new_stake: Stake<T::Balance>,
-    ) -> Result<(), Error<T>> {
-        let stake_before = Pools::<T>::get(candidate, &PoolsKey::CandidateTotalStake);
-        Pools::<T>::set(candidate, &PoolsKey::CandidateTotalStake, new_stake.0);
-
-        // Compute self delegation.
+    /// Stake the candidate delegated to itself, in all pools contributing to
+    /// its total stake.
+    pub fn self_delegation(candidate: &Candidate<T>) -> Result<Stake<T::Balance>, Error<T>> {
         let ac_self = if pools::AutoCompounding::<T>::shares_supply(candidate)
             .0
             .is_zero()
@@ -133,14 +168,32 @@ impl<T: Config> Candidates<T> {
             pools::Joining::shares_to_stake(candidate, shares)?.0
         };
 
-        let self_delegation = ac_self.err_add(&mr_self)?.err_add(&joining_self)?;
+        Ok(Stake(ac_self.err_add(&mr_self)?.err_add(&joining_self)?))
+    }
+
+    pub fn update_total_stake(
+        candidate: &Candidate<T>,
+        new_stake: Stake<T::Balance>,
+    ) -> Result<(), Error<T>> {
+        let stake_before = Pools::<T>::get(candidate, &PoolsKey::CandidateTotalStake);
+        Pools::<T>::set(candidate, &PoolsKey::CandidateTotalStake, new_stake.0);
+        TotalStake::<T>::mutate(|total| {
+            *total = total
+                .saturating_sub(stake_before)
+                .saturating_add(new_stake.0)
+        });
+
+        let self_delegation = Self::self_delegation(candidate)?.0;
+
+        // Stake above the cap doesn't count toward the ranking.
+        let cap = CandidateStakeCap::<T>::get();
 
         let mut list = SortedEligibleCandidates::<T>::get();
 
         // Remove old data if it exists.
         let old_position = match list.binary_search(&EligibleCandidate {
             candidate: candidate.clone(),
-            stake: stake_before,
+            stake: Self::capped_stake(stake_before, cap),
         }) {
             Ok(pos) => {
                 let _ = list.remove(pos);
@@ -150,6 +203,7 @@ impl<T: Config> Candidates<T> {
         };
 
         let eligible = self_delegation >= T::MinimumSelfDelegation::get()
+            && !OfflineCandidates::<T>::contains_key(candidate)
             && T::EligibleCandidatesFilter::is_candidate_eligible(candidate);
 
         // Find new position in the sorted list.
@@ -157,7 +211,7 @@ impl<T: Config> Candidates<T> {
         let new_position = if eligible {
             let entry = EligibleCandidate {
                 candidate: candidate.clone(),
-                stake: new_stake.0,
+                stake: Self::capped_stake(new_stake.0, cap),
             };
 
             // Candidate should not appear in the list, we're instead searching where
@@ -36,6 +36,7 @@
 mod calls;
 mod candidate;
 mod pools;
+mod queue;
 pub mod traits;
 
 #[cfg(test)]
@@ -64,10 +65,12 @@ pub mod pallet {
         calls::Calls,
         core::marker::PhantomData,
         frame_support::{
+            dispatch::DispatchErrorWithPostInfo,
             pallet_prelude::*,
             storage::types::{StorageDoubleMap, StorageValue, ValueQuery},
-            traits::{fungible, tokens::Balance, IsType},
-            Blake2_128Concat,
+            traits::{fungible, tokens::Balance, IsType, OnUnbalanced},
+            weights::WeightMeter,
+            Blake2_128Concat, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
         },
         frame_system::pallet_prelude::*,
         parity_scale_codec::{Decode, Encode, FullCodec},
@@ -77,12 +80,14 @@ pub mod pallet {
         sp_runtime::{BoundedVec, Perbill},
         sp_std::vec::Vec,
         tp_maths::MulDiv,
+        tp_traits::StorageDeposit,
     };
 
     /// A reason for this pallet placing a hold on funds.
     #[pallet::composite_enum]
     pub enum HoldReason {
         PooledStake,
+        CandidateInfoDeposit,
     }
 
     // Type aliases for better readability.
@@ -161,6 +166,11 @@ pub mod pallet {
         JoiningManualRewards { candidate: A, at: J },
         /// Candidate requested to to leave a pool of a candidate.
         Leaving { candidate: A, at: L },
+        /// Delegator moved its stake from `candidate` to the joining pool of `target`.
+        /// The stake is recorded in `RedelegatedStake` rather than in `PendingOperations`.
+        /// Funds are already in the pools of `target`, executing this operation only
+        /// clears the record.
+        Redelegating { candidate: A, target: A, at: L },
     }
 
     pub type PendingOperationKeyOf<T> = PendingOperationKey<
@@ -183,6 +193,97 @@ pub mod pallet {
         <<T as Config>::LeavingRequestTimer as Timer>::Instant,
     >;
 
+    /// Commission requested by a candidate, which will be applicable once the
+    /// `CommissionChangeTimer` started `at` is elapsed.
+    #[derive(
+        RuntimeDebug, PartialEq, Eq, Encode, Decode, Clone, TypeInfo, Serialize, Deserialize,
+    )]
+    pub struct PendingCommissionChange<I: FullCodec> {
+        pub commission: Perbill,
+        pub at: I,
+    }
+
+    pub type PendingCommissionChangeOf<T> =
+        PendingCommissionChange<<<T as Config>::CommissionChangeTimer as Timer>::Instant>;
+
+    /// Range in which candidates are allowed to set their commission.
+    #[derive(
+        RuntimeDebug, PartialEq, Eq, Encode, Decode, Copy, Clone, TypeInfo, Serialize, Deserialize,
+    )]
+    pub struct CommissionRange {
+        pub min: Perbill,
+        pub max: Perbill,
+    }
+
+    impl CommissionRange {
+        pub fn contains(&self, commission: &Perbill) -> bool {
+            self.min <= *commission && *commission <= self.max
+        }
+    }
+
+    /// Metadata published by a candidate.
+    #[derive(
+        RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Encode, Decode, CloneNoBound, TypeInfo,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct CandidateMetadata<T: Config> {
+        pub name: BoundedVec<u8, T::MaxCandidateMetadataLength>,
+        pub website: BoundedVec<u8, T::MaxCandidateMetadataLength>,
+        pub commission_policy: BoundedVec<u8, T::MaxCandidateMetadataLength>,
+        pub location: BoundedVec<u8, T::MaxCandidateMetadataLength>,
+    }
+
+    /// Metadata of a candidate along with the deposit held for it.
+    #[derive(
+        RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Encode, Decode, CloneNoBound, TypeInfo,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct RegisteredCandidateInfo<T: Config> {
+        pub metadata: CandidateMetadata<T>,
+        pub deposit: T::Balance,
+    }
+
+    /// Rewards accrued and manual rewards claimed by a delegator of a candidate.
+    #[derive(RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, CloneNoBound)]
+    pub struct RewardAccrual<T: Config> {
+        pub delegator: Delegator<T>,
+        pub candidate: Candidate<T>,
+        pub auto_compounding_rewards: T::Balance,
+        pub manual_claim_rewards: T::Balance,
+        pub claimed_manual_rewards: T::Balance,
+    }
+
+    /// Queues in which pending operations are stored in the order they are requested.
+    /// As all the operations of a queue use the same timer, they mature in that order.
+    #[derive(
+        RuntimeDebug, PartialEq, Eq, Encode, Decode, Copy, Clone, TypeInfo, Serialize, Deserialize,
+    )]
+    pub enum OperationsQueue {
+        /// Joining requests, using `JoiningRequestTimer`.
+        Joining,
+        /// Leaving and redelegating requests, using `LeavingRequestTimer`.
+        Leaving,
+    }
+
+    /// Position of the first and next free entries of an `OperationsQueue`.
+    #[derive(
+        RuntimeDebug,
+        Default,
+        PartialEq,
+        Eq,
+        Encode,
+        Decode,
+        Copy,
+        Clone,
+        TypeInfo,
+        Serialize,
+        Deserialize,
+    )]
+    pub struct QueueCursor {
+        pub head: u64,
+        pub tail: u64,
+    }
+
     #[derive(
         RuntimeDebug, PartialEq, Eq, Encode, Decode, Copy, Clone, TypeInfo, Serialize, Deserialize,
     )]
@@ -295,9 +396,23 @@ pub mod pallet {
         /// this minimum prevents from being elected.
         #[pallet::constant]
         type MinimumSelfDelegation: Get<Self::Balance>;
-        /// Part of the rewards that will be sent exclusively to the collator.
+        /// Part of the rewards that will be sent exclusively to the collator, unless it
+        /// configured its own commission with `request_commission_change`. It is also used as
+        /// both bounds of `CommissionBounds` until they are set.
         #[pallet::constant]
         type RewardsCollatorCommission: Get<Perbill>;
+        /// Condition for when a commission change request can be executed. It should leave
+        /// enough time for delegators to leave the candidate if they disagree with the new rate.
+        type CommissionChangeTimer: Timer;
+        /// Condition for when a commission change request expires and can no longer be
+        /// executed, started at the same instant as `CommissionChangeTimer`. It must be longer
+        /// than `CommissionChangeTimer`, the difference being the window in which the request
+        /// can be executed.
+        type CommissionChangeExpiryTimer: Timer<
+            Instant = <Self::CommissionChangeTimer as Timer>::Instant,
+        >;
+        /// Origin allowed to update the bounds in which candidates can set their commission.
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         /// The overarching runtime hold reason.
         type RuntimeHoldReason: From<HoldReason>;
@@ -315,6 +430,15 @@ pub mod pallet {
         type EligibleCandidatesBufferSize: Get<u32>;
         /// Additional filter for candidates to be eligible.
         type EligibleCandidatesFilter: IsCandidateEligible<Self::AccountId>;
+        /// Handler for the part of the rewards of a candidate that is not distributed
+        /// because its stake is above `CandidateStakeCap`.
+        type SaturatedRewardsHandler: OnUnbalanced<CreditOf<Self>>;
+
+        /// Maximum length in bytes of each field of the metadata of a candidate.
+        #[pallet::constant]
+        type MaxCandidateMetadataLength: Get<u32>;
+        /// How much must be deposited to publish the metadata of a candidate.
+        type CandidateInfoDeposit: StorageDeposit<CandidateMetadata<Self>, Self::Balance>;
 
         type WeightInfo: WeightInfo;
     }
@@ -344,6 +468,51 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Commission configured by a candidate. Candidates without an entry use
+    /// `RewardsCollatorCommission`.
+    #[pallet::storage]
+    pub type CandidateCommission<T: Config> =
+        StorageMap<_, Blake2_128Concat, Candidate<T>, Perbill, OptionQuery>;
+
+    /// Commission changes requested by candidates and waiting for the
+    /// `CommissionChangeTimer` to elapse.
+    #[pallet::storage]
+    pub type PendingCommissionChanges<T: Config> =
+        StorageMap<_, Blake2_128Concat, Candidate<T>, PendingCommissionChangeOf<T>, OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultCommissionBounds<T: Config>() -> CommissionRange {
+        CommissionRange {
+            min: T::RewardsCollatorCommission::get(),
+            max: T::RewardsCollatorCommission::get(),
+        }
+    }
+
+    /// Range in which candidates can set their commission, updated by `UpdateOrigin`.
+    #[pallet::storage]
+    pub type CommissionBounds<T: Config> =
+        StorageValue<_, CommissionRange, ValueQuery, DefaultCommissionBounds<T>>;
+
+    /// Maximum stake of a candidate counting toward its position in `SortedEligibleCandidates`
+    /// and its rewards, updated by `UpdateOrigin`. Candidates are not capped if unset.
+    #[pallet::storage]
+    pub type CandidateStakeCap<T: Config> = StorageValue<_, T::Balance, OptionQuery>;
+
+    /// Sum of the total stake of all candidates, i.e. all the funds staked in this pallet.
+    #[pallet::storage]
+    pub type TotalStake<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+    /// Metadata published by candidates, along with the deposit held for it.
+    #[pallet::storage]
+    pub type CandidateInfo<T: Config> =
+        StorageMap<_, Blake2_128Concat, Candidate<T>, RegisteredCandidateInfo<T>, OptionQuery>;
+
+    /// Candidates which went offline. They are not in `SortedEligibleCandidates`
+    /// until they go online again, but their stake is kept.
+    #[pallet::storage]
+    pub type OfflineCandidates<T: Config> =
+        StorageMap<_, Blake2_128Concat, Candidate<T>, (), OptionQuery>;
+
     /// Pending operations balances.
     /// Balances are expressed in joining/leaving shares amounts.
     #[pallet::storage]
@@ -357,6 +526,48 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Stake redelegated by a delegator from a candidate to a target, which is meant to
+    /// stay slashable by the source candidate until the leaving delay is elapsed.
+    /// Slashing is not implemented yet, so this record is not read by any slashing
+    /// logic and is only cleared once the `Redelegating` operation is executed.
+    #[pallet::storage]
+    pub type RedelegatedStake<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Delegator<T>,
+        Blake2_128Concat,
+        (
+            Candidate<T>,
+            Candidate<T>,
+            <<T as Config>::LeavingRequestTimer as Timer>::Instant,
+        ),
+        Stake<T::Balance>,
+        ValueQuery,
+    >;
+
+    /// Pending operations waiting to be executed automatically, indexed by their
+    /// position in their queue.
+    #[pallet::storage]
+    pub type QueuedOperations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        OperationsQueue,
+        Twox64Concat,
+        u64,
+        PendingOperationQueryOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    pub type QueueCursors<T: Config> =
+        StorageMap<_, Twox64Concat, OperationsQueue, QueueCursor, ValueQuery>;
+
+    /// Delegators which don't want their pending operations to be executed automatically.
+    /// They can still be executed with `execute_pending_operations`.
+    #[pallet::storage]
+    pub type AutoExecutionOptOut<T: Config> =
+        StorageMap<_, Blake2_128Concat, Delegator<T>, (), OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -404,6 +615,28 @@ pub mod pallet {
             delegator: Delegator<T>,
             released: T::Balance,
         },
+        /// User requested to move its stake from `candidate` to `target`.
+        /// Stake was removed from the `from` pool of `candidate` and is `pending`
+        /// in the joining pool of `target`. It is still slashable by `candidate` until
+        /// the leaving delay is elapsed. The rounding when converting to joining shares
+        /// has been `released` immediately.
+        RequestedRedelegate {
+            candidate: Candidate<T>,
+            target: Candidate<T>,
+            delegator: Delegator<T>,
+            from: TargetPool,
+            pool: TargetPool,
+            pending: T::Balance,
+            released: T::Balance,
+        },
+        /// Leaving delay of a redelegation is elapsed, `stake` moved to `target` is no
+        /// longer slashable by `candidate`.
+        ExecutedRedelegate {
+            candidate: Candidate<T>,
+            target: Candidate<T>,
+            delegator: Delegator<T>,
+            stake: T::Balance,
+        },
 
         /// Stake of that Candidate increased.
         IncreasedStake {
@@ -443,10 +676,13 @@ pub mod pallet {
             shares: T::Balance,
             stake: T::Balance,
         },
-        /// Collator has been rewarded.
+        /// Collator has been rewarded, keeping `commission` of the rewards.
+        /// `auto_compounding_shares` are the AutoCompounding shares minted for it.
         RewardedCollator {
             collator: Candidate<T>,
+            commission: Perbill,
             auto_compounding_rewards: T::Balance,
+            auto_compounding_shares: T::Balance,
             manual_claim_rewards: T::Balance,
         },
         /// Delegators have been rewarded.
@@ -473,6 +709,64 @@ pub mod pallet {
             pending_leaving: T::Balance,
             released: T::Balance,
         },
+        /// Candidate requested to change its commission, which can be executed
+        /// once the `CommissionChangeTimer` is elapsed.
+        RequestedCommissionChange {
+            candidate: Candidate<T>,
+            commission: Perbill,
+        },
+        /// Commission change request was executed and is now applied to rewards.
+        ExecutedCommissionChange {
+            candidate: Candidate<T>,
+            old_commission: Perbill,
+            new_commission: Perbill,
+        },
+        /// Bounds in which candidates can set their commission have been updated.
+        CommissionBoundsUpdated { min: Perbill, max: Perbill },
+        /// Maximum stake of a candidate counting toward its ranking and rewards has been updated.
+        CandidateStakeCapUpdated { cap: Option<T::Balance> },
+        /// Delegator staked towards a candidate whose stake is above `CandidateStakeCap`.
+        /// Stake above the cap doesn't improve the ranking of the candidate and dilutes
+        /// the rewards of its delegators.
+        DelegatedToSaturatedCandidate {
+            candidate: Candidate<T>,
+            delegator: Delegator<T>,
+            stake: T::Balance,
+            cap: T::Balance,
+        },
+        /// Part of the rewards of a saturated candidate has not been distributed.
+        SaturatedRewards {
+            candidate: Candidate<T>,
+            undistributed: T::Balance,
+        },
+        /// Candidate published or updated its metadata, holding `deposit`.
+        CandidateInfoSet {
+            candidate: Candidate<T>,
+            deposit: T::Balance,
+        },
+        /// Candidate removed its metadata, releasing its deposit.
+        CandidateInfoCleared {
+            candidate: Candidate<T>,
+            released_deposit: T::Balance,
+        },
+        /// Candidate went offline and is no longer eligible.
+        CandidateWentOffline { candidate: Candidate<T> },
+        /// Candidate went online and is eligible again if it meets the requirements.
+        CandidateWentOnline { candidate: Candidate<T> },
+        /// Delegator enabled or disabled the automatic execution of its pending operations.
+        UpdatedAutoExecution {
+            delegator: Delegator<T>,
+            enabled: bool,
+        },
+        /// AutoCompounding shares and the stake they represent have been transferred
+        /// to another account.
+        TransferredShares {
+            candidate: Candidate<T>,
+            from: Delegator<T>,
+            to: Delegator<T>,
+            shares: T::Balance,
+            stake: T::Balance,
+        },
     }
 
     #[pallet::error]
@@ -491,6 +785,19 @@ pub mod pallet {
         CandidateTransferingOwnSharesForbidden,
         RequestCannotBeExecuted(u16),
         SwapResultsInZeroShares,
+        CommissionOutOfBounds,
+        InvalidCommissionBounds,
+        NoPendingCommissionChange,
+        CommissionChangeTooSoon,
+        CommissionChangeExpired,
+        NotACandidate,
+        TooManyPendingOperations,
+        RedelegatingToSameCandidate,
+        SelfDelegationBelowMinimum,
+        InvalidCandidateStakeCap,
+        NoCandidateInfo,
+        CandidateAlreadyOffline,
+        CandidateNotOffline,
     }
 
     impl<T: Config> From<tp_maths::OverflowError> for Error<T> {
@@ -507,6 +814,12 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+            queue::execute_matured_operations::<T>(&mut meter);
+            meter.consumed()
+        }
+
         #[cfg(feature = "try-runtime")]
         fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
             use sp_std::collections::btree_set::BTreeSet;
@@ -532,6 +845,26 @@ pub mod pallet {
                 "SortedEligibleCandidates",
             );
 
+            // Eligible candidates are sorted by their capped stake
+            let cap = CandidateStakeCap::<T>::get();
+            for entry in SortedEligibleCandidates::<T>::get() {
+                let stake = candidate::Candidates::<T>::total_stake(&entry.candidate).0;
+                assert_eq!(
+                    entry.stake,
+                    candidate::Candidates::<T>::capped_stake(stake, cap),
+                    "eligible candidate stake doesn't match its capped total stake",
+                );
+            }
+
+            for queue in [OperationsQueue::Joining, OperationsQueue::Leaving] {
+                let cursor = QueueCursors::<T>::get(queue);
+                assert!(
+                    cursor.head <= cursor.tail,
+                    "queue head is after its tail: {:?}",
+                    queue,
+                );
+            }
+
             Ok(())
         }
     }
@@ -628,6 +961,161 @@ pub mod pallet {
 
             Calls::<T>::swap_pool(candidate, delegator, source_pool, amount)
         }
+
+        /// Request to change the commission of the caller, which must be a candidate with stake.
+        /// The commission must be within `CommissionBounds`. Overrides any previous pending
+        /// request, which must be executed before `CommissionChangeExpiryTimer` is elapsed.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::request_commission_change())]
+        pub fn request_commission_change(
+            origin: OriginFor<T>,
+            commission: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            let candidate = ensure_signed(origin)?;
+
+            Calls::<T>::request_commission_change(candidate, commission)
+        }
+
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::execute_commission_change())]
+        pub fn execute_commission_change(
+            origin: OriginFor<T>,
+            candidate: Candidate<T>,
+        ) -> DispatchResultWithPostInfo {
+            // We don't care about the sender.
+            let _ = ensure_signed(origin)?;
+
+            Calls::<T>::execute_commission_change(candidate)
+        }
+
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_commission_bounds())]
+        pub fn set_commission_bounds(
+            origin: OriginFor<T>,
+            min: Perbill,
+            max: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            Calls::<T>::set_commission_bounds(CommissionRange { min, max })
+        }
+
+        /// Move stake from `candidate` to the joining pool of `target`, whose joining delay
+        /// starts immediately. The stake is still slashable by `candidate` until the leaving
+        /// delay is elapsed.
+        /// Redelegate can incur in either claim manual rewards or hold rebalances, we simply add the worst case
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::redelegate().saturating_add(T::WeightInfo::claim_manual_rewards(1).max(T::WeightInfo::rebalance_hold())))]
+        pub fn redelegate(
+            origin: OriginFor<T>,
+            candidate: Candidate<T>,
+            target: Candidate<T>,
+            source_pool: TargetPool,
+            target_pool: TargetPool,
+            amount: SharesOrStake<T::Balance>,
+        ) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin)?;
+
+            Calls::<T>::redelegate(
+                candidate,
+                target,
+                delegator,
+                source_pool,
+                target_pool,
+                amount,
+            )
+        }
+
+        /// Execute up to `max_operations` matured operations from the queues, without
+        /// having to provide their keys. Operations of delegators which opted out of
+        /// automatic execution are skipped.
+        #[pallet::call_index(11)]
+        #[pallet::weight(queue::operation_weight::<T>().saturating_mul((*max_operations).into()))]
+        pub fn execute_queued_operations(
+            origin: OriginFor<T>,
+            max_operations: u32,
+        ) -> DispatchResultWithPostInfo {
+            // We don't care about the sender.
+            let _ = ensure_signed(origin)?;
+
+            let mut meter = WeightMeter::with_limit(
+                queue::operation_weight::<T>().saturating_mul(max_operations.into()),
+            );
+            queue::execute_matured_operations::<T>(&mut meter);
+
+            Ok(Some(meter.consumed()).into())
+        }
+
+        /// Enable or disable the automatic execution of the pending operations of the caller.
+        /// When enabling it, the pending operations of the caller are queued again, and
+        /// `pending_operations` must be at least their count.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_auto_execution(*pending_operations))]
+        pub fn set_auto_execution(
+            origin: OriginFor<T>,
+            enabled: bool,
+            pending_operations: u32,
+        ) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin)?;
+
+            Calls::<T>::set_auto_execution(delegator, enabled, pending_operations)
+        }
+
+        /// Set the maximum stake of a candidate counting toward its ranking and rewards,
+        /// or remove it with `None`. Eligible candidates are sorted again accordingly.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_candidate_stake_cap(
+            T::EligibleCandidatesBufferSize::get()
+        ))]
+        pub fn set_candidate_stake_cap(
+            origin: OriginFor<T>,
+            cap: Option<T::Balance>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            Calls::<T>::set_candidate_stake_cap(cap)
+        }
+
+        /// Publish or update the metadata of the caller. The deposit is adjusted to
+        /// the size of the new metadata.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_candidate_info())]
+        pub fn set_candidate_info(
+            origin: OriginFor<T>,
+            metadata: CandidateMetadata<T>,
+        ) -> DispatchResultWithPostInfo {
+            let candidate = ensure_signed(origin)?;
+
+            Calls::<T>::set_candidate_info(candidate, metadata)
+        }
+
+        /// Remove the metadata of the caller and release its deposit.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::clear_candidate_info())]
+        pub fn clear_candidate_info(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let candidate = ensure_signed(origin)?;
+
+            Calls::<T>::clear_candidate_info(candidate)
+        }
+
+        /// Remove the caller from the eligible candidates without unstaking, for example
+        /// during a planned maintenance. Delegations are kept and can still be modified.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::go_offline())]
+        pub fn go_offline(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let candidate = ensure_signed(origin)?;
+
+            Calls::<T>::go_offline(candidate)
+        }
+
+        /// Make the caller eligible again after going offline.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::go_online())]
+        pub fn go_online(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let candidate = ensure_signed(origin)?;
+
+            Calls::<T>::go_online(candidate)
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -654,6 +1142,54 @@ pub mod pallet {
             .ok()
             .map(|x| x.0)
         }
+
+        /// Commission currently applied to the rewards of that candidate.
+        pub fn candidate_commission(candidate: Candidate<T>) -> Perbill {
+            candidate::Candidates::<T>::commission(&candidate)
+        }
+
+        /// Maximum stake of a candidate counting toward its ranking and rewards, if any.
+        pub fn candidate_stake_cap() -> Option<T::Balance> {
+            CandidateStakeCap::<T>::get()
+        }
+
+        /// Sum of the total stake of all candidates.
+        pub fn total_stake() -> T::Balance {
+            TotalStake::<T>::get()
+        }
+
+        /// Rewards accrued and manual rewards claimed by each delegator from the given
+        /// events of this pallet. Must be called in the state of the block in which the
+        /// events were emitted.
+        pub fn reward_accruals(
+            events: impl IntoIterator<Item = Event<T>>,
+        ) -> Result<Vec<RewardAccrual<T>>, Error<T>> {
+            pools::reward_accruals::<T>(events)
+        }
+
+        /// Transfer AutoCompounding shares of `candidate` from `from` to `to`, along with
+        /// the held stake they represent. This allows other pallets to make shares
+        /// transferable. Returns the value of the transferred shares.
+        pub fn transfer_auto_compounding_shares(
+            candidate: Candidate<T>,
+            from: Delegator<T>,
+            to: Delegator<T>,
+            shares: T::Balance,
+        ) -> Result<T::Balance, DispatchErrorWithPostInfo> {
+            frame_support::storage::with_storage_layer(|| {
+                Calls::<T>::transfer_auto_compounding_shares(&candidate, &from, &to, Shares(shares))
+            })
+            .map(|stake| stake.0)
+        }
+
+        /// Add an existing pending operation at the end of its queue, allowing it to be
+        /// executed automatically. Operations must be added in the order they mature.
+        pub fn queue_pending_operation(
+            delegator: Delegator<T>,
+            operation: PendingOperationKeyOf<T>,
+        ) {
+            queue::push::<T>(&delegator, &operation)
+        }
     }
 
     impl<T: Config> tp_traits::DistributeRewards<Candidate<T>, CreditOf<T>> for Pallet<T> {
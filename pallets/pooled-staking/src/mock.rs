@@ -150,6 +150,7 @@ impl pallet_balances::Config for Runtime {
 
 pub const SHARE_INIT: u128 = MEGA;
 pub const BLOCKS_TO_WAIT: u64 = 2;
+pub const BLOCKS_TO_EXPIRE: u64 = 10;
 
 parameter_types! {
     pub const StakingAccount: u64 = ACCOUNT_STAKING;
@@ -158,6 +159,9 @@ parameter_types! {
     pub const MinimumSelfDelegation: u128 = 10 * MEGA;
     pub const RewardsCollatorCommission: Perbill = Perbill::from_percent(20);
     pub const BlocksToWait: u64 = BLOCKS_TO_WAIT;
+    pub const BlocksToExpire: u64 = BLOCKS_TO_EXPIRE;
+    pub const CandidateInfoDepositBaseFee: u128 = 10 * KILO;
+    pub const CandidateInfoDepositByteFee: u128 = 100;
 }
 
 impl pallet_pooled_staking::Config for Runtime {
@@ -169,11 +173,18 @@ impl pallet_pooled_staking::Config for Runtime {
     type InitialAutoCompoundingShareValue = InitialAutoCompoundingShareValue;
     type MinimumSelfDelegation = MinimumSelfDelegation;
     type RewardsCollatorCommission = RewardsCollatorCommission;
+    type CommissionChangeTimer = BlockNumberTimer<Self, BlocksToWait>;
+    type CommissionChangeExpiryTimer = BlockNumberTimer<Self, BlocksToExpire>;
+    type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
     type JoiningRequestTimer = BlockNumberTimer<Self, BlocksToWait>;
     type LeavingRequestTimer = BlockNumberTimer<Self, BlocksToWait>;
     // low value so we can test vec bounding, in practice it should be bigger
     type EligibleCandidatesBufferSize = ConstU32<3>;
     type EligibleCandidatesFilter = ();
+    type SaturatedRewardsHandler = ();
+    type MaxCandidateMetadataLength = ConstU32<32>;
+    type CandidateInfoDeposit =
+        tp_traits::BytesDeposit<CandidateInfoDepositBaseFee, CandidateInfoDepositByteFee>;
     type WeightInfo = ();
     type RuntimeHoldReason = RuntimeHoldReason;
 }
@@ -16,17 +16,18 @@
 
 use {
     crate::{
-        candidate::Candidates, weights::WeightInfo, Candidate, Config, CreditOf, Delegator, Error,
-        Event, Pallet, Pools, PoolsKey, Shares, Stake,
+        candidate::Candidates, weights::WeightInfo, Candidate, CandidateStakeCap, Config, CreditOf,
+        Delegator, Error, Event, Pallet, Pools, PoolsKey, RewardAccrual, Shares, Stake,
     },
     core::marker::PhantomData,
     frame_support::{
         ensure,
         pallet_prelude::*,
-        traits::{fungible::Balanced, Imbalance},
+        traits::{fungible::Balanced, Imbalance, OnUnbalanced},
     },
     sp_core::Get,
     sp_runtime::traits::{CheckedAdd, CheckedDiv, Zero},
+    sp_std::{collections::btree_map::BTreeMap, vec::Vec},
     tp_maths::{ErrAdd, ErrMul, ErrSub, MulDiv},
 };
 
@@ -437,8 +438,8 @@ impl<T: Config> ManualRewards<T> {
 /// and it is the responsibility of the caller to transfer or mint the currency
 /// to the staking pallet account.
 ///
-/// Rewards are split using `RewardsCollatorCommission` between the candidate
-/// and all the delegators (including the candidate self-delegation). For each,
+/// Rewards are split using the candidate commission (see `Candidates::commission`)
+/// between the candidate and all the delegators (including the candidate self-delegation). For each,
 /// the rewards are then split according to the value of all the ManualRewards
 /// and AutoCompounding shares.
 ///
@@ -461,6 +462,20 @@ pub fn distribute_rewards<T: Config>(
     candidate: &Candidate<T>,
     rewards: CreditOf<T>,
 ) -> DispatchResultWithPostInfo {
+    // Stake above the cap doesn't earn rewards, which dilutes the rewards of saturated
+    // candidates. The part of the rewards it would have earned is not distributed.
+    let total_stake = Candidates::<T>::total_stake(candidate).0;
+    let capped_stake = Candidates::<T>::capped_stake(total_stake, CandidateStakeCap::<T>::get());
+    let distributed_rewards = if capped_stake < total_stake {
+        rewards
+            .peek()
+            .mul_div(capped_stake, total_stake)
+            .map_err(Error::<T>::from)?
+    } else {
+        rewards.peek()
+    };
+    let (rewards, undistributed_rewards) = rewards.split(distributed_rewards);
+
     let candidate_manual_rewards = distribute_rewards_inner::<T>(candidate, rewards.peek())?;
 
     let (candidate_manual_rewards, other_rewards) = rewards.split(candidate_manual_rewards);
@@ -473,15 +488,144 @@ pub fn distribute_rewards<T: Config>(
     T::Currency::resolve(&T::StakingAccount::get(), other_rewards)
         .map_err(|_| DispatchError::NoProviders)?;
 
+    if !undistributed_rewards.peek().is_zero() {
+        Pallet::<T>::deposit_event(Event::<T>::SaturatedRewards {
+            candidate: candidate.clone(),
+            undistributed: undistributed_rewards.peek(),
+        });
+        T::SaturatedRewardsHandler::on_unbalanced(undistributed_rewards);
+    }
+
     Ok(Some(T::WeightInfo::distribute_rewards()).into())
 }
 
+/// Rewards accrued and manual rewards claimed by each delegator from the given events.
+///
+/// Rewards of delegators are split according to their current shares, which must
+/// thus be read in the state of the block of the distribution. Candidates commissions
+/// are accounted as rewards of the candidate, and the AutoCompounding shares minted
+/// for them are excluded from the split as they didn't exist when delegators were
+/// rewarded.
+///
+/// As rewards are distributed in `on_initialize`, shares can still change later in
+/// the block (delegating, undelegating, swapping pools ...), in which case the split
+/// between delegators of that candidate is approximate. Their sum still matches the
+/// distributed rewards, up to rounding.
+pub fn reward_accruals<T: Config>(
+    events: impl IntoIterator<Item = Event<T>>,
+) -> Result<Vec<RewardAccrual<T>>, Error<T>> {
+    let mut accruals = Vec::new();
+    let mut commission_shares = BTreeMap::<Candidate<T>, T::Balance>::new();
+
+    for event in events {
+        match event {
+            Event::RewardedCollator {
+                collator,
+                auto_compounding_rewards,
+                auto_compounding_shares,
+                manual_claim_rewards,
+                ..
+            } => {
+                let shares = commission_shares.entry(collator.clone()).or_default();
+                *shares = shares.err_add(&auto_compounding_shares)?;
+
+                accruals.push(RewardAccrual {
+                    delegator: collator.clone(),
+                    candidate: collator,
+                    auto_compounding_rewards,
+                    manual_claim_rewards,
+                    claimed_manual_rewards: Zero::zero(),
+                })
+            }
+            Event::RewardedDelegators {
+                collator,
+                auto_compounding_rewards,
+                manual_claim_rewards,
+            } => {
+                let candidate_new_shares = commission_shares
+                    .get(&collator)
+                    .copied()
+                    .unwrap_or_default();
+
+                for (delegator, (auto, manual)) in delegators_reward_accruals::<T>(
+                    &collator,
+                    auto_compounding_rewards,
+                    manual_claim_rewards,
+                    candidate_new_shares,
+                )? {
+                    accruals.push(RewardAccrual {
+                        delegator,
+                        candidate: collator.clone(),
+                        auto_compounding_rewards: auto,
+                        manual_claim_rewards: manual,
+                        claimed_manual_rewards: Zero::zero(),
+                    });
+                }
+            }
+            Event::ClaimedManualRewards {
+                candidate,
+                delegator,
+                rewards,
+            } => accruals.push(RewardAccrual {
+                delegator,
+                candidate,
+                auto_compounding_rewards: Zero::zero(),
+                manual_claim_rewards: Zero::zero(),
+                claimed_manual_rewards: rewards,
+            }),
+            _ => (),
+        }
+    }
+
+    Ok(accruals)
+}
+
+/// Split rewards distributed to the delegators of `candidate` according to their
+/// current shares in each pool, which may differ from the ones at distribution time.
+/// `candidate_new_shares` are the AutoCompounding shares minted for the candidate
+/// commission after delegators were rewarded, which must not be part of the split.
+fn delegators_reward_accruals<T: Config>(
+    candidate: &Candidate<T>,
+    auto_compounding_rewards: T::Balance,
+    manual_claim_rewards: T::Balance,
+    candidate_new_shares: T::Balance,
+) -> Result<BTreeMap<Delegator<T>, (T::Balance, T::Balance)>, Error<T>> {
+    let auto_supply = AutoCompounding::<T>::shares_supply(candidate)
+        .0
+        .saturating_sub(candidate_new_shares);
+    let Shares(manual_supply) = ManualRewards::<T>::shares_supply(candidate);
+
+    let mut accruals = BTreeMap::<Delegator<T>, (T::Balance, T::Balance)>::new();
+
+    for (key, shares) in Pools::<T>::iter_prefix(candidate) {
+        match key {
+            PoolsKey::AutoCompoundingShares { delegator } if !auto_supply.is_zero() => {
+                let shares = if &delegator == candidate {
+                    shares.saturating_sub(candidate_new_shares)
+                } else {
+                    shares
+                };
+                let rewards = auto_compounding_rewards.mul_div(shares, auto_supply)?;
+                accruals.entry(delegator).or_default().0 = rewards;
+            }
+            PoolsKey::ManualRewardsShares { delegator } if !manual_supply.is_zero() => {
+                let rewards = manual_claim_rewards.mul_div(shares, manual_supply)?;
+                accruals.entry(delegator).or_default().1 = rewards;
+            }
+            _ => (),
+        }
+    }
+
+    Ok(accruals)
+}
+
 fn distribute_rewards_inner<T: Config>(
     candidate: &Candidate<T>,
     rewards: T::Balance,
 ) -> Result<T::Balance, Error<T>> {
-    // `RewardsCollatorCommission` is a `Perbill` so we're not worried about overflow.
-    let candidate_rewards = T::RewardsCollatorCommission::get() * rewards;
+    // Commission is a `Perbill` so we're not worried about overflow.
+    let commission = Candidates::<T>::commission(candidate);
+    let candidate_rewards = commission * rewards;
     let delegators_rewards = rewards.err_sub(&candidate_rewards)?;
 
     let Stake(auto_total_stake) = AutoCompounding::<T>::total_staked(candidate);
@@ -516,8 +660,8 @@ fn distribute_rewards_inner<T: Config>(
     };
 
     // Distribute candidate AutoCompounding rewards, it implies some rounding.
-    let candidate_auto_rewards = if auto_total_stake.is_zero() {
-        Zero::zero()
+    let (candidate_auto_rewards, candidate_auto_shares) = if auto_total_stake.is_zero() {
+        (Zero::zero(), Zero::zero())
     } else {
         'a: {
             let candidate_auto_stake =
@@ -525,7 +669,7 @@ fn distribute_rewards_inner<T: Config>(
             let candidate_combined_stake = candidate_manual_stake.err_add(&candidate_auto_stake)?;
 
             if candidate_combined_stake.is_zero() {
-                break 'a Zero::zero();
+                break 'a (Zero::zero(), Zero::zero());
             }
 
             let rewards =
@@ -533,10 +677,11 @@ fn distribute_rewards_inner<T: Config>(
             let new_shares = AutoCompounding::<T>::stake_to_shares(candidate, Stake(rewards))?;
 
             if new_shares.0.is_zero() {
-                break 'a Zero::zero();
+                break 'a (Zero::zero(), Zero::zero());
             }
 
-            AutoCompounding::<T>::add_shares(candidate, candidate, new_shares)?.0
+            let stake = AutoCompounding::<T>::add_shares(candidate, candidate, new_shares)?.0;
+            (stake, new_shares.0)
         }
     };
 
@@ -551,7 +696,9 @@ fn distribute_rewards_inner<T: Config>(
 
     Pallet::<T>::deposit_event(Event::<T>::RewardedCollator {
         collator: candidate.clone(),
+        commission,
         auto_compounding_rewards: candidate_auto_rewards,
+        auto_compounding_shares: candidate_auto_shares,
         manual_claim_rewards: candidate_manual_rewards,
     });
     Pallet::<T>::deposit_event(Event::<T>::RewardedDelegators {
@@ -0,0 +1,139 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Pending operations are added to a queue when they are requested, allowing
+//! them to be executed automatically once matured (in `on_idle` or with the
+//! permissionless `execute_queued_operations`) without having to know their keys.
+//! There is one queue per timer, as operations using the same timer mature in
+//! the order they are requested.
+
+use {
+    crate::{
+        calls::Calls, weights::WeightInfo, AutoExecutionOptOut, Config, Delegator, OperationsQueue,
+        PendingOperationKey, PendingOperationKeyOf, PendingOperationQuery, QueueCursors,
+        QueuedOperations,
+    },
+    frame_support::{
+        storage::with_storage_layer,
+        traits::Get,
+        weights::{Weight, WeightMeter},
+    },
+    sp_runtime::traits::Zero,
+    sp_std::vec,
+};
+
+/// Maximum weight of processing a single queued operation.
+pub fn operation_weight<T: Config>() -> Weight {
+    peek_weight::<T>()
+        .saturating_add(execution_weight::<T>())
+        .saturating_add(pop_weight::<T>())
+}
+
+/// Maximum weight of executing a single matured operation.
+fn execution_weight<T: Config>() -> Weight {
+    T::WeightInfo::execute_queued_operations(1)
+        .saturating_add(T::WeightInfo::claim_manual_rewards(1))
+}
+
+/// Weight of reading the queue cursor, the queued operation, its pending value and
+/// the opt out of its delegator.
+fn peek_weight<T: Config>() -> Weight {
+    T::DbWeight::get().reads(4)
+}
+
+/// Weight of removing the queued operation and advancing the queue cursor.
+fn pop_weight<T: Config>() -> Weight {
+    T::DbWeight::get().writes(2)
+}
+
+fn queue_of<T: Config>(operation: &PendingOperationKeyOf<T>) -> OperationsQueue {
+    match operation {
+        PendingOperationKey::JoiningAutoCompounding { .. }
+        | PendingOperationKey::JoiningManualRewards { .. } => OperationsQueue::Joining,
+        PendingOperationKey::Leaving { .. } | PendingOperationKey::Redelegating { .. } => {
+            OperationsQueue::Leaving
+        }
+    }
+}
+
+/// Add a newly created pending operation at the end of its queue.
+pub fn push<T: Config>(delegator: &Delegator<T>, operation: &PendingOperationKeyOf<T>) {
+    let queue = queue_of::<T>(operation);
+    let mut cursor = QueueCursors::<T>::get(queue);
+
+    QueuedOperations::<T>::insert(
+        queue,
+        cursor.tail,
+        PendingOperationQuery {
+            delegator: delegator.clone(),
+            operation: operation.clone(),
+        },
+    );
+
+    cursor.tail = cursor.tail.saturating_add(1);
+    QueueCursors::<T>::insert(queue, cursor);
+}
+
+/// Execute matured operations from all queues while `meter` allows it.
+/// Operations already executed, or of delegators who opted out of automatic
+/// execution, are removed from the queue without being executed. Only the weight
+/// actually used is consumed from `meter`: skipping an operation costs much less
+/// than executing it, and an immature head only costs reading it.
+pub fn execute_matured_operations<T: Config>(meter: &mut WeightMeter) {
+    for queue in [OperationsQueue::Joining, OperationsQueue::Leaving] {
+        loop {
+            if !meter.can_consume(peek_weight::<T>().saturating_add(pop_weight::<T>())) {
+                return;
+            }
+            meter.consume(peek_weight::<T>());
+
+            let mut cursor = QueueCursors::<T>::get(queue);
+            if cursor.head >= cursor.tail {
+                break;
+            }
+
+            if let Some(query) = QueuedOperations::<T>::get(queue, cursor.head) {
+                let value = Calls::<T>::pending_operation_value(&query.delegator, &query.operation);
+                let skip =
+                    value.is_zero() || AutoExecutionOptOut::<T>::contains_key(&query.delegator);
+
+                if !skip {
+                    // Following operations in this queue are not matured either.
+                    if !Calls::<T>::is_operation_matured(&query.operation) {
+                        break;
+                    }
+
+                    if !meter.can_consume(execution_weight::<T>().saturating_add(pop_weight::<T>()))
+                    {
+                        return;
+                    }
+                    meter.consume(execution_weight::<T>());
+
+                    if let Err(e) = with_storage_layer(|| {
+                        Calls::<T>::execute_pending_operations(vec![query.clone()])
+                    }) {
+                        log::warn!("Failed to execute queued operation {:?}: {:?}", query, e);
+                    }
+                }
+            }
+
+            meter.consume(pop_weight::<T>());
+            QueuedOperations::<T>::remove(queue, cursor.head);
+            cursor.head = cursor.head.saturating_add(1);
+            QueueCursors::<T>::insert(queue, cursor);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    super::*,
+    crate::{
+        assert_last_event, CandidateInfo, CandidateMetadata, EligibleCandidate, HoldReason,
+        OfflineCandidates, SortedEligibleCandidates,
+    },
+    frame_support::traits::fungible::InspectHold,
+};
+
+fn metadata(name: &[u8], website: &[u8]) -> CandidateMetadata<Runtime> {
+    CandidateMetadata {
+        name: name.to_vec().try_into().unwrap(),
+        website: website.to_vec().try_into().unwrap(),
+        commission_policy: Default::default(),
+        location: Default::default(),
+    }
+}
+
+fn info_deposit_hold(who: &AccountId) -> Balance {
+    Balances::balance_on_hold(&HoldReason::CandidateInfoDeposit.into(), who)
+}
+
+fn self_delegate(candidate: AccountId, stake: Balance) {
+    assert_ok!(Staking::request_delegate(
+        RuntimeOrigin::signed(candidate),
+        candidate,
+        TargetPool::AutoCompounding,
+        stake,
+    ));
+}
+
+fn sorted_eligible_candidates() -> Vec<(AccountId, Balance)> {
+    SortedEligibleCandidates::<Runtime>::get()
+        .into_iter()
+        .map(|EligibleCandidate { candidate, stake }| (candidate, stake))
+        .collect()
+}
+
+#[test]
+fn set_candidate_info_holds_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        // 9 encoded bytes
+        let info = metadata(b"Alice", b"");
+        assert_ok!(Staking::set_candidate_info(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            info.clone()
+        ));
+
+        assert_last_event!(RuntimeEvent::Staking(Event::CandidateInfoSet {
+            candidate: ACCOUNT_CANDIDATE_1,
+            deposit: 10_900,
+        }));
+        assert_eq!(info_deposit_hold(&ACCOUNT_CANDIDATE_1), 10_900);
+
+        let registered = CandidateInfo::<Runtime>::get(ACCOUNT_CANDIDATE_1).unwrap();
+        assert_eq!(registered.metadata, info);
+        assert_eq!(registered.deposit, 10_900);
+    })
+}
+
+#[test]
+fn updating_candidate_info_adjusts_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        // 21 encoded bytes
+        assert_ok!(Staking::set_candidate_info(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            metadata(b"Alice Staking", b"a.io")
+        ));
+        assert_eq!(info_deposit_hold(&ACCOUNT_CANDIDATE_1), 12_100);
+
+        // 9 encoded bytes
+        assert_ok!(Staking::set_candidate_info(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            metadata(b"Alice", b"")
+        ));
+        assert_eq!(info_deposit_hold(&ACCOUNT_CANDIDATE_1), 10_900);
+        assert_eq!(
+            CandidateInfo::<Runtime>::get(ACCOUNT_CANDIDATE_1)
+                .unwrap()
+                .deposit,
+            10_900
+        );
+    })
+}
+
+#[test]
+fn clear_candidate_info_releases_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        let balance_before = total_balance(&ACCOUNT_CANDIDATE_1);
+
+        assert_ok!(Staking::set_candidate_info(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            metadata(b"Alice", b"")
+        ));
+        assert_ok!(Staking::clear_candidate_info(RuntimeOrigin::signed(
+            ACCOUNT_CANDIDATE_1
+        )));
+
+        assert_last_event!(RuntimeEvent::Staking(Event::CandidateInfoCleared {
+            candidate: ACCOUNT_CANDIDATE_1,
+            released_deposit: 10_900,
+        }));
+        assert!(!CandidateInfo::<Runtime>::contains_key(ACCOUNT_CANDIDATE_1));
+        assert_eq!(info_deposit_hold(&ACCOUNT_CANDIDATE_1), 0);
+        assert_eq!(total_balance(&ACCOUNT_CANDIDATE_1), balance_before);
+    })
+}
+
+#[test]
+fn clear_candidate_info_without_info_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Staking::clear_candidate_info(RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1)),
+            Error::<Runtime>::NoCandidateInfo
+        );
+    })
+}
+
+#[test]
+fn offline_candidate_is_not_eligible() {
+    ExtBuilder::default().build().execute_with(|| {
+        self_delegate(ACCOUNT_CANDIDATE_1, 20 * MEGA);
+        self_delegate(ACCOUNT_CANDIDATE_2, 30 * MEGA);
+
+        assert_ok!(Staking::go_offline(RuntimeOrigin::signed(
+            ACCOUNT_CANDIDATE_2
+        )));
+        assert_last_event!(RuntimeEvent::Staking(Event::CandidateWentOffline {
+            candidate: ACCOUNT_CANDIDATE_2,
+        }));
+        assert!(OfflineCandidates::<Runtime>::contains_key(
+            ACCOUNT_CANDIDATE_2
+        ));
+
+        // Stake is kept while offline.
+        assert_eq!(
+            sorted_eligible_candidates(),
+            vec![(ACCOUNT_CANDIDATE_1, 20 * MEGA)]
+        );
+        assert_eq!(
+            Candidates::<Runtime>::total_stake(&ACCOUNT_CANDIDATE_2).0,
+            30 * MEGA
+        );
+
+        assert_ok!(Staking::go_online(RuntimeOrigin::signed(
+            ACCOUNT_CANDIDATE_2
+        )));
+        assert_last_event!(RuntimeEvent::Staking(Event::CandidateWentOnline {
+            candidate: ACCOUNT_CANDIDATE_2,
+        }));
+        assert_eq!(
+            sorted_eligible_candidates(),
+            vec![
+                (ACCOUNT_CANDIDATE_2, 30 * MEGA),
+                (ACCOUNT_CANDIDATE_1, 20 * MEGA)
+            ]
+        );
+    })
+}
+
+#[test]
+fn offline_candidate_stays_out_after_delegation() {
+    ExtBuilder::default().build().execute_with(|| {
+        self_delegate(ACCOUNT_CANDIDATE_1, 20 * MEGA);
+
+        assert_ok!(Staking::go_offline(RuntimeOrigin::signed(
+            ACCOUNT_CANDIDATE_1
+        )));
+
+        assert_ok!(Staking::request_delegate(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            ACCOUNT_CANDIDATE_1,
+            TargetPool::ManualRewards,
+            10 * MEGA,
+        ));
+
+        assert_eq!(sorted_eligible_candidates(), vec![]);
+        assert_eq!(
+            Candidates::<Runtime>::total_stake(&ACCOUNT_CANDIDATE_1).0,
+            30 * MEGA
+        );
+    })
+}
+
+#[test]
+fn go_offline_requires_candidate() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Staking::go_offline(RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1)),
+            Error::<Runtime>::NotACandidate
+        );
+    })
+}
+
+#[test]
+fn candidate_with_info_can_go_offline() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Staking::set_candidate_info(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            metadata(b"Alice", b"")
+        ));
+
+        assert_ok!(Staking::go_offline(RuntimeOrigin::signed(
+            ACCOUNT_CANDIDATE_1
+        )));
+        assert!(OfflineCandidates::<Runtime>::contains_key(
+            ACCOUNT_CANDIDATE_1
+        ));
+    })
+}
+
+#[test]
+fn go_offline_twice_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        self_delegate(ACCOUNT_CANDIDATE_1, 20 * MEGA);
+
+        assert_ok!(Staking::go_offline(RuntimeOrigin::signed(
+            ACCOUNT_CANDIDATE_1
+        )));
+        assert_noop!(
+            Staking::go_offline(RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1)),
+            Error::<Runtime>::CandidateAlreadyOffline
+        );
+    })
+}
+
+#[test]
+fn go_online_when_not_offline_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Staking::go_online(RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1)),
+            Error::<Runtime>::CandidateNotOffline
+        );
+    })
+}
@@ -508,3 +508,47 @@ fn many_candidates_mixed_pools() {
         );
     })
 }
+
+#[test]
+fn total_stake_tracks_all_candidates() {
+    ExtBuilder::default().build().execute_with(|| {
+        let share = InitialAutoCompoundingShareValue::get();
+
+        FullDelegation {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_CANDIDATE_1,
+            request_amount: share * 10,
+            expected_increase: share * 10,
+            ..default()
+        }
+        .test::<pools::AutoCompounding<Runtime>>();
+
+        FullDelegation {
+            candidate: ACCOUNT_CANDIDATE_2,
+            delegator: ACCOUNT_DELEGATOR_1,
+            request_amount: share * 5,
+            expected_increase: share * 5,
+            ..default()
+        }
+        .test::<pools::AutoCompounding<Runtime>>();
+
+        assert_eq!(Staking::total_stake(), share * 15);
+
+        FullUndelegation {
+            candidate: ACCOUNT_CANDIDATE_2,
+            delegator: ACCOUNT_DELEGATOR_1,
+            request_amount: SharesOrStake::Stake(share * 5),
+            expected_removed: share * 5,
+            expected_leaving: round_down(share * 5, 3),
+            ..default()
+        }
+        .test::<pools::AutoCompounding<Runtime>>();
+
+        assert_eq!(Staking::total_stake(), share * 10);
+        assert_eq!(
+            Staking::total_stake(),
+            Candidates::<Runtime>::total_stake(&ACCOUNT_CANDIDATE_1).0
+                + Candidates::<Runtime>::total_stake(&ACCOUNT_CANDIDATE_2).0
+        );
+    })
+}
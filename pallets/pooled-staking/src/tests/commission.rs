@@ -0,0 +1,326 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    super::*,
+    crate::{
+        assert_last_event, CandidateCommission, CommissionBounds, CommissionRange,
+        PendingCommissionChange, PendingCommissionChanges,
+    },
+    sp_runtime::{DispatchError, Perbill},
+};
+
+fn set_bounds(min: u32, max: u32) {
+    assert_ok!(Staking::set_commission_bounds(
+        RuntimeOrigin::root(),
+        Perbill::from_percent(min),
+        Perbill::from_percent(max),
+    ));
+}
+
+fn self_delegate(candidate: AccountId) {
+    assert_ok!(Staking::request_delegate(
+        RuntimeOrigin::signed(candidate),
+        candidate,
+        TargetPool::AutoCompounding,
+        MinimumSelfDelegation::get(),
+    ));
+}
+
+#[test]
+fn default_commission_is_rewards_collator_commission() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(
+            CommissionBounds::<Runtime>::get(),
+            CommissionRange {
+                min: RewardsCollatorCommission::get(),
+                max: RewardsCollatorCommission::get(),
+            }
+        );
+        assert_eq!(
+            Staking::candidate_commission(ACCOUNT_CANDIDATE_1),
+            RewardsCollatorCommission::get()
+        );
+    })
+}
+
+#[test]
+fn set_commission_bounds_requires_update_origin() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Staking::set_commission_bounds(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+                Perbill::from_percent(5),
+                Perbill::from_percent(30),
+            ),
+            DispatchError::BadOrigin
+        );
+
+        set_bounds(5, 30);
+        assert_last_event!(RuntimeEvent::Staking(Event::CommissionBoundsUpdated {
+            min: Perbill::from_percent(5),
+            max: Perbill::from_percent(30),
+        }));
+    })
+}
+
+#[test]
+fn set_commission_bounds_rejects_min_above_max() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Staking::set_commission_bounds(
+                RuntimeOrigin::root(),
+                Perbill::from_percent(30),
+                Perbill::from_percent(5),
+            ),
+            Error::<Runtime>::InvalidCommissionBounds
+        );
+    })
+}
+
+#[test]
+fn request_commission_change_out_of_bounds() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_bounds(5, 30);
+
+        assert_noop!(
+            Staking::request_commission_change(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+                Perbill::from_percent(31),
+            ),
+            Error::<Runtime>::CommissionOutOfBounds
+        );
+        assert_noop!(
+            Staking::request_commission_change(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+                Perbill::from_percent(4),
+            ),
+            Error::<Runtime>::CommissionOutOfBounds
+        );
+    })
+}
+
+#[test]
+fn request_commission_change_requires_stake() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_bounds(5, 30);
+
+        assert_noop!(
+            Staking::request_commission_change(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+                Perbill::from_percent(10),
+            ),
+            Error::<Runtime>::NotACandidate
+        );
+
+        self_delegate(ACCOUNT_CANDIDATE_1);
+        assert_ok!(Staking::request_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(10),
+        ));
+    })
+}
+
+#[test]
+fn commission_change_is_applied_after_delay() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_bounds(5, 30);
+        self_delegate(ACCOUNT_CANDIDATE_1);
+        let at = block_number();
+
+        assert_ok!(Staking::request_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(10),
+        ));
+        assert_last_event!(RuntimeEvent::Staking(Event::RequestedCommissionChange {
+            candidate: ACCOUNT_CANDIDATE_1,
+            commission: Perbill::from_percent(10),
+        }));
+        assert_eq!(
+            PendingCommissionChanges::<Runtime>::get(ACCOUNT_CANDIDATE_1),
+            Some(PendingCommissionChange {
+                commission: Perbill::from_percent(10),
+                at,
+            })
+        );
+
+        // Request is not applied yet.
+        assert_eq!(
+            Staking::candidate_commission(ACCOUNT_CANDIDATE_1),
+            RewardsCollatorCommission::get()
+        );
+        assert_noop!(
+            Staking::execute_commission_change(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                ACCOUNT_CANDIDATE_1,
+            ),
+            Error::<Runtime>::CommissionChangeTooSoon
+        );
+
+        roll_to(at + BLOCKS_TO_WAIT);
+
+        // Anyone can execute the request.
+        assert_ok!(Staking::execute_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            ACCOUNT_CANDIDATE_1,
+        ));
+        assert_last_event!(RuntimeEvent::Staking(Event::ExecutedCommissionChange {
+            candidate: ACCOUNT_CANDIDATE_1,
+            old_commission: RewardsCollatorCommission::get(),
+            new_commission: Perbill::from_percent(10),
+        }));
+        assert_eq!(
+            Staking::candidate_commission(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(10)
+        );
+        assert_eq!(
+            PendingCommissionChanges::<Runtime>::get(ACCOUNT_CANDIDATE_1),
+            None
+        );
+
+        assert_noop!(
+            Staking::execute_commission_change(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                ACCOUNT_CANDIDATE_1,
+            ),
+            Error::<Runtime>::NoPendingCommissionChange
+        );
+    })
+}
+
+#[test]
+fn new_commission_request_restarts_delay() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_bounds(5, 30);
+        self_delegate(ACCOUNT_CANDIDATE_1);
+
+        assert_ok!(Staking::request_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(10),
+        ));
+
+        roll_one_block();
+        let at = block_number();
+
+        assert_ok!(Staking::request_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(25),
+        ));
+
+        roll_to(at + BLOCKS_TO_WAIT - 1);
+        assert_noop!(
+            Staking::execute_commission_change(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+                ACCOUNT_CANDIDATE_1,
+            ),
+            Error::<Runtime>::CommissionChangeTooSoon
+        );
+
+        roll_one_block();
+        assert_ok!(Staking::execute_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            ACCOUNT_CANDIDATE_1,
+        ));
+        assert_eq!(
+            Staking::candidate_commission(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(25)
+        );
+    })
+}
+
+#[test]
+fn commission_change_expires() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_bounds(5, 30);
+        self_delegate(ACCOUNT_CANDIDATE_1);
+        let at = block_number();
+
+        assert_ok!(Staking::request_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(10),
+        ));
+
+        roll_to(at + BLOCKS_TO_EXPIRE);
+        assert_noop!(
+            Staking::execute_commission_change(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+                ACCOUNT_CANDIDATE_1,
+            ),
+            Error::<Runtime>::CommissionChangeExpired
+        );
+
+        // A new request can be made and executed.
+        let at = block_number();
+        assert_ok!(Staking::request_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(10),
+        ));
+        roll_to(at + BLOCKS_TO_EXPIRE - 1);
+        assert_ok!(Staking::execute_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            ACCOUNT_CANDIDATE_1,
+        ));
+        assert_eq!(
+            Staking::candidate_commission(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(10)
+        );
+    })
+}
+
+#[test]
+fn commission_change_fails_if_bounds_changed() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_bounds(5, 30);
+        self_delegate(ACCOUNT_CANDIDATE_1);
+        let at = block_number();
+
+        assert_ok!(Staking::request_commission_change(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(30),
+        ));
+
+        set_bounds(5, 15);
+        roll_to(at + BLOCKS_TO_WAIT);
+
+        assert_noop!(
+            Staking::execute_commission_change(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+                ACCOUNT_CANDIDATE_1,
+            ),
+            Error::<Runtime>::CommissionOutOfBounds
+        );
+    })
+}
+
+#[test]
+fn commission_is_kept_within_updated_bounds() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_bounds(5, 30);
+        CandidateCommission::<Runtime>::insert(ACCOUNT_CANDIDATE_1, Perbill::from_percent(25));
+        CandidateCommission::<Runtime>::insert(ACCOUNT_CANDIDATE_2, Perbill::from_percent(5));
+
+        set_bounds(10, 15);
+
+        assert_eq!(
+            Staking::candidate_commission(ACCOUNT_CANDIDATE_1),
+            Perbill::from_percent(15)
+        );
+        assert_eq!(
+            Staking::candidate_commission(ACCOUNT_CANDIDATE_2),
+            Perbill::from_percent(10)
+        );
+    })
+}
@@ -14,11 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
 
+mod candidate_info;
 mod candidates;
+mod commission;
 mod delegator_flow;
 mod manual_rewards;
+mod queue;
 mod rebalance;
+mod redelegate;
 mod rewards;
+mod saturation;
+mod timer;
+mod transfer;
 
 use {
     crate::{
@@ -0,0 +1,305 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    super::*,
+    crate::{
+        queue, AutoExecutionOptOut, OperationsQueue, PendingOperationKeyOf, QueueCursors,
+        QueuedOperations,
+    },
+    frame_support::{traits::OnIdle, weights::Weight},
+};
+
+fn on_idle(weight: Weight) {
+    Staking::on_idle(block_number(), weight);
+}
+
+fn pending(delegator: AccountId, operation: PendingOperationKeyOf<Runtime>) -> Balance {
+    PendingOperations::<Runtime>::get(delegator, operation)
+}
+
+fn queue_len(queue: OperationsQueue) -> u64 {
+    let cursor = QueueCursors::<Runtime>::get(queue);
+    cursor.tail - cursor.head
+}
+
+fn request_delegate<P: PoolExt<Runtime>>(delegator: AccountId, amount: Balance) {
+    assert_ok!(Staking::request_delegate(
+        RuntimeOrigin::signed(delegator),
+        ACCOUNT_CANDIDATE_1,
+        P::target_pool(),
+        amount,
+    ));
+}
+
+pool_test!(
+    fn requests_are_queued<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            let block_number = block_number();
+
+            request_delegate::<P>(ACCOUNT_DELEGATOR_1, 2 * SHARE_INIT);
+            // Increasing an existing request doesn't queue it again.
+            request_delegate::<P>(ACCOUNT_DELEGATOR_1, 2 * SHARE_INIT);
+            request_delegate::<P>(ACCOUNT_DELEGATOR_2, 2 * SHARE_INIT);
+
+            assert_eq!(queue_len(OperationsQueue::Joining), 2);
+            assert_eq!(queue_len(OperationsQueue::Leaving), 0);
+            assert_eq!(
+                QueuedOperations::<Runtime>::get(OperationsQueue::Joining, 0),
+                Some(PendingOperationQuery {
+                    delegator: ACCOUNT_DELEGATOR_1,
+                    operation: P::joining_operation_key(ACCOUNT_CANDIDATE_1, block_number),
+                })
+            );
+            assert_eq!(
+                QueuedOperations::<Runtime>::get(OperationsQueue::Joining, 1),
+                Some(PendingOperationQuery {
+                    delegator: ACCOUNT_DELEGATOR_2,
+                    operation: P::joining_operation_key(ACCOUNT_CANDIDATE_1, block_number),
+                })
+            );
+        })
+    }
+);
+
+pool_test!(
+    fn matured_delegation_is_executed_on_idle<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            let block_number = block_number();
+            let operation = P::joining_operation_key(ACCOUNT_CANDIDATE_1, block_number);
+
+            request_delegate::<P>(ACCOUNT_DELEGATOR_1, 2 * SHARE_INIT);
+
+            roll_to(block_number + BLOCKS_TO_WAIT - 1); // too soon
+            on_idle(Weight::MAX);
+            assert_ne!(pending(ACCOUNT_DELEGATOR_1, operation.clone()), 0);
+            assert_eq!(queue_len(OperationsQueue::Joining), 1);
+
+            roll_one_block();
+            on_idle(Weight::MAX);
+            assert_eq!(pending(ACCOUNT_DELEGATOR_1, operation), 0);
+            assert_eq!(queue_len(OperationsQueue::Joining), 0);
+            assert_eq!(
+                P::computed_stake(&ACCOUNT_CANDIDATE_1, &ACCOUNT_DELEGATOR_1)
+                    .unwrap()
+                    .0,
+                2 * SHARE_INIT
+            );
+        })
+    }
+);
+
+pool_test!(
+    fn matured_undelegation_is_executed_on_idle<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            FullDelegation {
+                candidate: ACCOUNT_CANDIDATE_1,
+                delegator: ACCOUNT_DELEGATOR_1,
+                request_amount: 3 * SHARE_INIT,
+                expected_increase: 3 * SHARE_INIT,
+                ..default()
+            }
+            .test::<P>();
+
+            let block_number = block_number();
+            let operation = PendingOperationKey::Leaving {
+                candidate: ACCOUNT_CANDIDATE_1,
+                at: block_number,
+            };
+
+            assert_ok!(Staking::request_undelegate(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                ACCOUNT_CANDIDATE_1,
+                P::target_pool(),
+                SharesOrStake::Stake(3 * SHARE_INIT),
+            ));
+            assert_eq!(queue_len(OperationsQueue::Leaving), 1);
+
+            roll_to(block_number + BLOCKS_TO_WAIT);
+            on_idle(Weight::MAX);
+
+            assert_eq!(pending(ACCOUNT_DELEGATOR_1, operation), 0);
+            assert_eq!(queue_len(OperationsQueue::Leaving), 0);
+            assert_eq!(balance_hold(&ACCOUNT_DELEGATOR_1), 0);
+        })
+    }
+);
+
+pool_test!(
+    fn on_idle_respects_weight_limit<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            let block_number = block_number();
+            let operation = P::joining_operation_key(ACCOUNT_CANDIDATE_1, block_number);
+
+            request_delegate::<P>(ACCOUNT_DELEGATOR_1, 2 * SHARE_INIT);
+            request_delegate::<P>(ACCOUNT_DELEGATOR_2, 2 * SHARE_INIT);
+
+            roll_to(block_number + BLOCKS_TO_WAIT);
+            on_idle(queue::operation_weight::<Runtime>());
+
+            assert_eq!(pending(ACCOUNT_DELEGATOR_1, operation.clone()), 0);
+            assert_ne!(pending(ACCOUNT_DELEGATOR_2, operation.clone()), 0);
+            assert_eq!(queue_len(OperationsQueue::Joining), 1);
+
+            on_idle(Weight::MAX);
+            assert_eq!(pending(ACCOUNT_DELEGATOR_2, operation), 0);
+            assert_eq!(queue_len(OperationsQueue::Joining), 0);
+        })
+    }
+);
+
+pool_test!(
+    fn opted_out_delegator_operations_are_not_executed<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            let block_number = block_number();
+            let operation = P::joining_operation_key(ACCOUNT_CANDIDATE_1, block_number);
+
+            assert_ok!(Staking::set_auto_execution(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                false,
+                0
+            ));
+            assert!(AutoExecutionOptOut::<Runtime>::contains_key(
+                ACCOUNT_DELEGATOR_1
+            ));
+            assert_eq_last_events!(vec![Event::<Runtime>::UpdatedAutoExecution {
+                delegator: ACCOUNT_DELEGATOR_1,
+                enabled: false,
+            }]);
+
+            request_delegate::<P>(ACCOUNT_DELEGATOR_1, 2 * SHARE_INIT);
+            request_delegate::<P>(ACCOUNT_DELEGATOR_2, 2 * SHARE_INIT);
+
+            roll_to(block_number + BLOCKS_TO_WAIT);
+            on_idle(Weight::MAX);
+
+            // Operation is removed from the queue but can still be executed manually.
+            assert_ne!(pending(ACCOUNT_DELEGATOR_1, operation.clone()), 0);
+            assert_eq!(pending(ACCOUNT_DELEGATOR_2, operation.clone()), 0);
+            assert_eq!(queue_len(OperationsQueue::Joining), 0);
+
+            assert_ok!(Staking::execute_pending_operations(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                vec![PendingOperationQuery {
+                    delegator: ACCOUNT_DELEGATOR_1,
+                    operation: operation.clone(),
+                }]
+            ));
+            assert_eq!(pending(ACCOUNT_DELEGATOR_1, operation), 0);
+
+            assert_ok!(Staking::set_auto_execution(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                true,
+                0
+            ));
+            assert!(!AutoExecutionOptOut::<Runtime>::contains_key(
+                ACCOUNT_DELEGATOR_1
+            ));
+        })
+    }
+);
+
+pool_test!(
+    fn opting_in_again_queues_pending_operations<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            let block_number = block_number();
+            let operation = P::joining_operation_key(ACCOUNT_CANDIDATE_1, block_number);
+
+            assert_ok!(Staking::set_auto_execution(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                false,
+                0
+            ));
+            request_delegate::<P>(ACCOUNT_DELEGATOR_1, 2 * SHARE_INIT);
+
+            roll_to(block_number + BLOCKS_TO_WAIT);
+            on_idle(Weight::MAX);
+
+            // Operation was dropped from the queue while opted out.
+            assert_ne!(pending(ACCOUNT_DELEGATOR_1, operation.clone()), 0);
+            assert_eq!(queue_len(OperationsQueue::Joining), 0);
+
+            assert_noop!(
+                Staking::set_auto_execution(RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1), true, 0),
+                Error::<Runtime>::TooManyPendingOperations
+            );
+
+            assert_ok!(Staking::set_auto_execution(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                true,
+                1
+            ));
+            assert_eq!(queue_len(OperationsQueue::Joining), 1);
+
+            on_idle(Weight::MAX);
+
+            assert_eq!(pending(ACCOUNT_DELEGATOR_1, operation), 0);
+            assert_eq!(queue_len(OperationsQueue::Joining), 0);
+        })
+    }
+);
+
+pool_test!(
+    fn manually_executed_operations_are_skipped<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            let block_number = block_number();
+            let operation = P::joining_operation_key(ACCOUNT_CANDIDATE_1, block_number);
+
+            request_delegate::<P>(ACCOUNT_DELEGATOR_1, 2 * SHARE_INIT);
+
+            roll_to(block_number + BLOCKS_TO_WAIT);
+            assert_ok!(Staking::execute_pending_operations(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                vec![PendingOperationQuery {
+                    delegator: ACCOUNT_DELEGATOR_1,
+                    operation,
+                }]
+            ));
+            assert_eq!(queue_len(OperationsQueue::Joining), 1);
+
+            on_idle(Weight::MAX);
+            assert_eq!(queue_len(OperationsQueue::Joining), 0);
+            assert_eq!(
+                P::computed_stake(&ACCOUNT_CANDIDATE_1, &ACCOUNT_DELEGATOR_1)
+                    .unwrap()
+                    .0,
+                2 * SHARE_INIT
+            );
+        })
+    }
+);
+
+pool_test!(
+    fn anyone_can_execute_queued_operations<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            let block_number = block_number();
+            let operation = P::joining_operation_key(ACCOUNT_CANDIDATE_1, block_number);
+
+            request_delegate::<P>(ACCOUNT_DELEGATOR_1, 2 * SHARE_INIT);
+            request_delegate::<P>(ACCOUNT_DELEGATOR_2, 2 * SHARE_INIT);
+
+            roll_to(block_number + BLOCKS_TO_WAIT);
+            assert_ok!(Staking::execute_queued_operations(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_2),
+                1
+            ));
+
+            assert_eq!(pending(ACCOUNT_DELEGATOR_1, operation.clone()), 0);
+            assert_ne!(pending(ACCOUNT_DELEGATOR_2, operation), 0);
+            assert_eq!(queue_len(OperationsQueue::Joining), 1);
+        })
+    }
+);
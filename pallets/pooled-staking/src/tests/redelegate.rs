@@ -0,0 +1,221 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    super::*,
+    crate::{assert_eq_last_events, RedelegatedStake},
+};
+
+fn redelegating_key(at: u64) -> crate::PendingOperationKeyOf<Runtime> {
+    PendingOperationKey::Redelegating {
+        candidate: ACCOUNT_CANDIDATE_1,
+        target: ACCOUNT_CANDIDATE_2,
+        at,
+    }
+}
+
+fn redelegated_stake(at: u64) -> Balance {
+    RedelegatedStake::<Runtime>::get(
+        ACCOUNT_DELEGATOR_1,
+        (ACCOUNT_CANDIDATE_1, ACCOUNT_CANDIDATE_2, at),
+    )
+    .0
+}
+
+#[test]
+fn redelegate_to_same_candidate_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Staking::redelegate(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                ACCOUNT_CANDIDATE_1,
+                ACCOUNT_CANDIDATE_1,
+                TargetPool::AutoCompounding,
+                TargetPool::ManualRewards,
+                SharesOrStake::Stake(SHARE_INIT),
+            ),
+            Error::<Runtime>::RedelegatingToSameCandidate
+        );
+    })
+}
+
+pool_test!(
+    fn redelegate_works<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            let amount = 6 * SHARE_INIT;
+            let target_pool = P::OppositePool::target_pool();
+
+            FullDelegation {
+                candidate: ACCOUNT_CANDIDATE_1,
+                delegator: ACCOUNT_DELEGATOR_1,
+                request_amount: amount,
+                expected_increase: amount,
+                ..default()
+            }
+            .test::<P>();
+
+            let block_number = block_number();
+            let hold_before = balance_hold(&ACCOUNT_DELEGATOR_1);
+
+            assert_ok!(Staking::redelegate(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                ACCOUNT_CANDIDATE_1,
+                ACCOUNT_CANDIDATE_2,
+                P::target_pool(),
+                target_pool,
+                SharesOrStake::Stake(amount),
+            ));
+
+            assert_eq_last_events!(vec![Event::<Runtime>::RequestedRedelegate {
+                candidate: ACCOUNT_CANDIDATE_1,
+                target: ACCOUNT_CANDIDATE_2,
+                delegator: ACCOUNT_DELEGATOR_1,
+                from: P::target_pool(),
+                pool: target_pool,
+                pending: amount,
+                released: 0,
+            }]);
+
+            // Stake is moved right away to the joining pool of the target.
+            assert_eq!(
+                Candidates::<Runtime>::total_stake(&ACCOUNT_CANDIDATE_1).0,
+                0
+            );
+            assert_eq!(
+                Candidates::<Runtime>::total_stake(&ACCOUNT_CANDIDATE_2).0,
+                amount
+            );
+            assert_eq!(
+                PoolState::extract::<Leaving>(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_1),
+                PoolState { hold: 0, stake: 0 }
+            );
+            assert_eq!(
+                PoolState::extract::<Joining>(ACCOUNT_CANDIDATE_2, ACCOUNT_DELEGATOR_1),
+                PoolState {
+                    hold: amount,
+                    stake: amount,
+                }
+            );
+            assert_eq!(balance_hold(&ACCOUNT_DELEGATOR_1), hold_before);
+
+            // The stake redelegated from the source candidate is recorded as stake,
+            // outside of the pending operations balances which are in shares.
+            assert_eq!(redelegated_stake(block_number), amount);
+            assert_eq!(
+                PendingOperations::<Runtime>::get(
+                    ACCOUNT_DELEGATOR_1,
+                    redelegating_key(block_number)
+                ),
+                0
+            );
+
+            roll_to(block_number + BLOCKS_TO_WAIT - 1); // too soon
+            assert_noop!(
+                Staking::execute_pending_operations(
+                    RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                    vec![PendingOperationQuery {
+                        delegator: ACCOUNT_DELEGATOR_1,
+                        operation: redelegating_key(block_number),
+                    }]
+                ),
+                Error::<Runtime>::RequestCannotBeExecuted(0)
+            );
+
+            roll_one_block();
+            assert_ok!(Staking::execute_pending_operations(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                vec![PendingOperationQuery {
+                    delegator: ACCOUNT_DELEGATOR_1,
+                    operation: redelegating_key(block_number),
+                }]
+            ));
+
+            assert_eq_last_events!(vec![Event::<Runtime>::ExecutedRedelegate {
+                candidate: ACCOUNT_CANDIDATE_1,
+                target: ACCOUNT_CANDIDATE_2,
+                delegator: ACCOUNT_DELEGATOR_1,
+                stake: amount,
+            }]);
+            assert_eq!(redelegated_stake(block_number), 0);
+
+            // Clearing the slashable stake doesn't move funds.
+            assert_eq!(
+                PoolState::extract::<Joining>(ACCOUNT_CANDIDATE_2, ACCOUNT_DELEGATOR_1),
+                PoolState {
+                    hold: amount,
+                    stake: amount,
+                }
+            );
+            assert_eq!(balance_hold(&ACCOUNT_DELEGATOR_1), hold_before);
+
+            // Joining delay started with the redelegation request.
+            ExecuteDelegation {
+                candidate: ACCOUNT_CANDIDATE_2,
+                delegator: ACCOUNT_DELEGATOR_1,
+                block_number,
+                expected_increase: amount,
+                ..default()
+            }
+            .test::<P::OppositePool>();
+        })
+    }
+);
+
+pool_test!(
+    fn redelegated_stake_joins_target_independently<P>() {
+        ExtBuilder::default().build().execute_with(|| {
+            let amount = 2 * SHARE_INIT;
+
+            FullDelegation {
+                candidate: ACCOUNT_CANDIDATE_1,
+                delegator: ACCOUNT_DELEGATOR_1,
+                request_amount: amount,
+                expected_increase: amount,
+                ..default()
+            }
+            .test::<P>();
+
+            let block_number = block_number();
+
+            assert_ok!(Staking::redelegate(
+                RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+                ACCOUNT_CANDIDATE_1,
+                ACCOUNT_CANDIDATE_2,
+                P::target_pool(),
+                P::target_pool(),
+                SharesOrStake::Stake(amount),
+            ));
+
+            // Both delays run independently, the joining can be executed while
+            // the slashable stake record of the source candidate is pending.
+            roll_to(block_number + BLOCKS_TO_WAIT);
+            ExecuteDelegation {
+                candidate: ACCOUNT_CANDIDATE_2,
+                delegator: ACCOUNT_DELEGATOR_1,
+                block_number,
+                expected_increase: amount,
+                ..default()
+            }
+            .test::<P>();
+
+            assert_eq!(redelegated_stake(block_number), amount);
+            assert_eq!(
+                Candidates::<Runtime>::total_stake(&ACCOUNT_CANDIDATE_2).0,
+                amount
+            );
+        })
+    }
+);
@@ -19,10 +19,10 @@ use {
     crate::{
         assert_eq_last_events,
         pools::{AutoCompounding, ManualRewards},
-        Pallet, TargetPool,
+        CandidateCommission, CommissionBounds, CommissionRange, Pallet, RewardAccrual, TargetPool,
     },
     frame_support::assert_err,
-    sp_runtime::DispatchError,
+    sp_runtime::{DispatchError, Perbill},
     tp_traits::DistributeRewards,
 };
 
@@ -108,17 +108,23 @@ fn test_distribution(
 
     // Distribute rewards
     let candidate_balance_before = total_balance(&ACCOUNT_CANDIDATE_1);
+    let candidate_shares_before =
+        AutoCompounding::<Runtime>::shares(&reward.collator, &reward.collator).0;
     assert_ok!(Pallet::<Runtime>::distribute_rewards(
         reward.collator,
         new_supply
     ));
     let candidate_balance_after = total_balance(&ACCOUNT_CANDIDATE_1);
+    let candidate_shares_after =
+        AutoCompounding::<Runtime>::shares(&reward.collator, &reward.collator).0;
 
     // Check events matches the expected distribution.
     assert_eq_last_events!(vec![
         Event::<Runtime>::RewardedCollator {
             collator: reward.collator,
+            commission: Staking::candidate_commission(reward.collator),
             auto_compounding_rewards: distribution.collator_auto,
+            auto_compounding_shares: candidate_shares_after - candidate_shares_before,
             manual_claim_rewards: distribution.collator_manual,
         },
         Event::RewardedDelegators {
@@ -237,6 +243,45 @@ fn candidate_only_manual_only() {
     });
 }
 
+#[test]
+fn candidate_only_manual_only_custom_commission() {
+    ExtBuilder::default().build().execute_with(|| {
+        CommissionBounds::<Runtime>::put(CommissionRange {
+            min: Perbill::zero(),
+            max: Perbill::from_percent(50),
+        });
+        CandidateCommission::<Runtime>::insert(ACCOUNT_CANDIDATE_1, Perbill::from_percent(35));
+
+        test_distribution(
+            &[Delegation {
+                candidate: ACCOUNT_CANDIDATE_1,
+                delegator: ACCOUNT_CANDIDATE_1,
+                pool: TargetPool::ManualRewards,
+                stake: 1_000_000_000,
+            }],
+            RewardRequest {
+                collator: ACCOUNT_CANDIDATE_1,
+                rewards: 1_000_000,
+            },
+            &[DelegatorState {
+                candidate: ACCOUNT_CANDIDATE_1,
+                delegator: ACCOUNT_CANDIDATE_1,
+                auto_shares: 0,
+                auto_stake: 0,
+                manual_shares: 1_000,
+                manual_stake: 1_000_000_000,
+                pending_rewards: 650_000,
+            }],
+            Distribution {
+                collator_auto: 0,
+                collator_manual: 350_000, // 35% of rewards
+                delegators_auto: 0,
+                delegators_manual: 650_000, // 65% of rewards
+            },
+        )
+    });
+}
+
 #[test]
 fn candidate_only_auto_only() {
     ExtBuilder::default().build().execute_with(|| {
@@ -635,3 +680,153 @@ fn reward_distribution_is_transactional() {
         );
     })
 }
+
+#[test]
+fn reward_accruals_split_delegators_rewards_by_shares() {
+    ExtBuilder::default().build().execute_with(|| {
+        FullDelegation {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_CANDIDATE_1,
+            request_amount: 1 * MEGA,
+            expected_increase: 1 * MEGA,
+            ..default()
+        }
+        .test::<AutoCompounding<Runtime>>();
+
+        FullDelegation {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_DELEGATOR_1,
+            request_amount: 3 * MEGA,
+            expected_increase: 3 * MEGA,
+            ..default()
+        }
+        .test::<AutoCompounding<Runtime>>();
+
+        FullDelegation {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_DELEGATOR_2,
+            request_amount: 2 * MEGA,
+            expected_increase: 2 * MEGA,
+            ..default()
+        }
+        .test::<ManualRewards<Runtime>>();
+
+        let accruals = Staking::reward_accruals(vec![
+            Event::<Runtime>::RewardedCollator {
+                collator: ACCOUNT_CANDIDATE_1,
+                commission: Perbill::from_percent(10),
+                auto_compounding_rewards: 10,
+                auto_compounding_shares: 0,
+                manual_claim_rewards: 20,
+            },
+            Event::RewardedDelegators {
+                collator: ACCOUNT_CANDIDATE_1,
+                auto_compounding_rewards: 4_000,
+                manual_claim_rewards: 1_000,
+            },
+            Event::ClaimedManualRewards {
+                candidate: ACCOUNT_CANDIDATE_1,
+                delegator: ACCOUNT_DELEGATOR_2,
+                rewards: 500,
+            },
+            // Other events are ignored.
+            Event::CandidateStakeCapUpdated { cap: None },
+        ])
+        .unwrap();
+
+        let accrual = |delegator, auto_compounding_rewards, manual_claim_rewards| RewardAccrual {
+            delegator,
+            candidate: ACCOUNT_CANDIDATE_1,
+            auto_compounding_rewards,
+            manual_claim_rewards,
+            claimed_manual_rewards: 0,
+        };
+
+        assert_eq!(
+            accruals,
+            vec![
+                accrual(ACCOUNT_CANDIDATE_1, 10, 20),
+                accrual(ACCOUNT_CANDIDATE_1, 1_000, 0),
+                accrual(ACCOUNT_DELEGATOR_1, 3_000, 0),
+                accrual(ACCOUNT_DELEGATOR_2, 0, 1_000),
+                RewardAccrual {
+                    delegator: ACCOUNT_DELEGATOR_2,
+                    candidate: ACCOUNT_CANDIDATE_1,
+                    auto_compounding_rewards: 0,
+                    manual_claim_rewards: 0,
+                    claimed_manual_rewards: 500,
+                },
+            ]
+        );
+    })
+}
+
+#[test]
+fn reward_accruals_exclude_candidate_commission_shares() {
+    ExtBuilder::default().build().execute_with(|| {
+        FullDelegation {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_CANDIDATE_1,
+            request_amount: 100 * MEGA,
+            expected_increase: 100 * MEGA,
+            ..default()
+        }
+        .test::<AutoCompounding<Runtime>>();
+
+        FullDelegation {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_DELEGATOR_1,
+            request_amount: 300 * MEGA,
+            expected_increase: 300 * MEGA,
+            ..default()
+        }
+        .test::<AutoCompounding<Runtime>>();
+
+        let shares_before =
+            AutoCompounding::<Runtime>::shares(&ACCOUNT_CANDIDATE_1, &ACCOUNT_CANDIDATE_1);
+
+        assert_ok!(Staking::distribute_rewards(
+            ACCOUNT_CANDIDATE_1,
+            currency_issue(40 * MEGA)
+        ));
+
+        // Commission is compounded into new shares of the candidate.
+        let shares_after =
+            AutoCompounding::<Runtime>::shares(&ACCOUNT_CANDIDATE_1, &ACCOUNT_CANDIDATE_1);
+        assert!(shares_after.0 > shares_before.0);
+
+        let delegators_auto_rewards = events()
+            .into_iter()
+            .find_map(|event| match event {
+                Event::RewardedDelegators {
+                    auto_compounding_rewards,
+                    ..
+                } => Some(auto_compounding_rewards),
+                _ => None,
+            })
+            .expect("delegators to be rewarded");
+        assert_eq!(delegators_auto_rewards, 32 * MEGA);
+
+        let accruals = Staking::reward_accruals(events()).unwrap();
+        let delegators_accruals: Vec<_> = accruals
+            .iter()
+            .skip(1)
+            .map(|accrual| (accrual.delegator, accrual.auto_compounding_rewards))
+            .collect();
+
+        assert_eq!(
+            delegators_accruals,
+            vec![
+                (ACCOUNT_CANDIDATE_1, 8 * MEGA),
+                (ACCOUNT_DELEGATOR_1, 24 * MEGA),
+            ]
+        );
+        assert_eq!(
+            delegators_accruals
+                .iter()
+                .map(|(_, rewards)| rewards)
+                .sum::<Balance>(),
+            delegators_auto_rewards
+        );
+    })
+}
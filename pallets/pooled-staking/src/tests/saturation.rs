@@ -0,0 +1,211 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    super::*,
+    crate::{
+        assert_eq_last_events, assert_event_emitted, assert_event_not_emitted, assert_last_event,
+        CandidateStakeCap, EligibleCandidate, SortedEligibleCandidates,
+    },
+    frame_support::traits::{fungible::Inspect, Imbalance},
+    sp_runtime::DispatchError,
+    tp_traits::DistributeRewards,
+};
+
+fn set_cap(cap: Option<Balance>) {
+    assert_ok!(Staking::set_candidate_stake_cap(RuntimeOrigin::root(), cap));
+}
+
+fn self_delegate(candidate: AccountId, stake: Balance) {
+    assert_ok!(Staking::request_delegate(
+        RuntimeOrigin::signed(candidate),
+        candidate,
+        TargetPool::AutoCompounding,
+        stake,
+    ));
+}
+
+fn sorted_eligible_candidates() -> Vec<(AccountId, Balance)> {
+    SortedEligibleCandidates::<Runtime>::get()
+        .into_iter()
+        .map(|EligibleCandidate { candidate, stake }| (candidate, stake))
+        .collect()
+}
+
+#[test]
+fn set_candidate_stake_cap_requires_update_origin() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Staking::set_candidate_stake_cap(
+                RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+                Some(10 * MEGA),
+            ),
+            DispatchError::BadOrigin
+        );
+
+        set_cap(Some(10 * MEGA));
+        assert_last_event!(RuntimeEvent::Staking(Event::CandidateStakeCapUpdated {
+            cap: Some(10 * MEGA),
+        }));
+        assert_eq!(Staking::candidate_stake_cap(), Some(10 * MEGA));
+
+        set_cap(None);
+        assert_eq!(CandidateStakeCap::<Runtime>::get(), None);
+    })
+}
+
+#[test]
+fn zero_candidate_stake_cap_is_rejected() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Staking::set_candidate_stake_cap(RuntimeOrigin::root(), Some(0)),
+            Error::<Runtime>::InvalidCandidateStakeCap
+        );
+    })
+}
+
+#[test]
+fn stake_above_cap_does_not_count_toward_ranking() {
+    ExtBuilder::default().build().execute_with(|| {
+        self_delegate(ACCOUNT_CANDIDATE_1, 20 * MEGA);
+        self_delegate(ACCOUNT_CANDIDATE_2, 30 * MEGA);
+
+        assert_eq!(
+            sorted_eligible_candidates(),
+            vec![
+                (ACCOUNT_CANDIDATE_2, 30 * MEGA),
+                (ACCOUNT_CANDIDATE_1, 20 * MEGA)
+            ]
+        );
+
+        // Both candidates are saturated, ties are sorted by account.
+        set_cap(Some(15 * MEGA));
+        assert_eq!(
+            sorted_eligible_candidates(),
+            vec![
+                (ACCOUNT_CANDIDATE_1, 15 * MEGA),
+                (ACCOUNT_CANDIDATE_2, 15 * MEGA)
+            ]
+        );
+
+        // Stake changes keep the capped stake in the list.
+        self_delegate(ACCOUNT_CANDIDATE_1, 10 * MEGA);
+        assert_eq!(
+            sorted_eligible_candidates(),
+            vec![
+                (ACCOUNT_CANDIDATE_1, 15 * MEGA),
+                (ACCOUNT_CANDIDATE_2, 15 * MEGA)
+            ]
+        );
+        assert_eq!(
+            Candidates::<Runtime>::total_stake(&ACCOUNT_CANDIDATE_1).0,
+            30 * MEGA
+        );
+
+        set_cap(None);
+        assert_eq!(
+            sorted_eligible_candidates(),
+            vec![
+                (ACCOUNT_CANDIDATE_1, 30 * MEGA),
+                (ACCOUNT_CANDIDATE_2, 30 * MEGA)
+            ]
+        );
+    })
+}
+
+#[test]
+fn delegating_to_saturated_candidate_emits_warning() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_cap(Some(15 * MEGA));
+
+        self_delegate(ACCOUNT_CANDIDATE_1, 10 * MEGA);
+        assert_event_not_emitted!(Event::<Runtime>::DelegatedToSaturatedCandidate {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_CANDIDATE_1,
+            stake: 10 * MEGA,
+            cap: 15 * MEGA,
+        });
+
+        assert_ok!(Staking::request_delegate(
+            RuntimeOrigin::signed(ACCOUNT_DELEGATOR_1),
+            ACCOUNT_CANDIDATE_1,
+            TargetPool::ManualRewards,
+            10 * MEGA,
+        ));
+        assert_event_emitted!(Event::<Runtime>::DelegatedToSaturatedCandidate {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_DELEGATOR_1,
+            stake: 20 * MEGA,
+            cap: 15 * MEGA,
+        });
+    })
+}
+
+#[test]
+fn saturated_candidate_rewards_are_diluted() {
+    ExtBuilder::default().build().execute_with(|| {
+        let request_time = block_number();
+        self_delegate(ACCOUNT_CANDIDATE_1, 1_000_000_000);
+
+        roll_to(request_time + BLOCKS_TO_WAIT);
+
+        assert_ok!(Staking::execute_pending_operations(
+            RuntimeOrigin::signed(ACCOUNT_CANDIDATE_1),
+            vec![PendingOperationQuery {
+                delegator: ACCOUNT_CANDIDATE_1,
+                operation: PendingOperationKey::JoiningAutoCompounding {
+                    candidate: ACCOUNT_CANDIDATE_1,
+                    at: request_time,
+                },
+            }]
+        ));
+
+        // Only half of the stake of the candidate earns rewards.
+        set_cap(Some(500_000_000));
+
+        let rewards = currency_issue(20_000_000);
+        let issuance_before = <Balances as Inspect<AccountId>>::total_issuance();
+        assert_eq!(rewards.peek(), 20_000_000);
+
+        assert_ok!(Staking::distribute_rewards(ACCOUNT_CANDIDATE_1, rewards));
+
+        // Same distribution as rewarding an unsaturated candidate with 10_000_000.
+        assert_eq_last_events!(vec![
+            Event::<Runtime>::RewardedCollator {
+                collator: ACCOUNT_CANDIDATE_1,
+                commission: Staking::candidate_commission(ACCOUNT_CANDIDATE_1),
+                auto_compounding_rewards: 1_008_000,
+                auto_compounding_shares: 1,
+                manual_claim_rewards: 992_000,
+            },
+            Event::RewardedDelegators {
+                collator: ACCOUNT_CANDIDATE_1,
+                auto_compounding_rewards: 8_000_000,
+                manual_claim_rewards: 0,
+            },
+            Event::SaturatedRewards {
+                candidate: ACCOUNT_CANDIDATE_1,
+                undistributed: 10_000_000,
+            },
+        ]);
+
+        // Undistributed rewards are burned by the mock handler.
+        assert_eq!(
+            <Balances as Inspect<AccountId>>::total_issuance(),
+            issuance_before - 10_000_000
+        );
+    })
+}
@@ -0,0 +1,76 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    crate::traits::{SessionTimer, Timer},
+    frame_support::parameter_types,
+    sp_core::ConstU32,
+    tp_traits::GetSessionIndex,
+};
+
+parameter_types! {
+    pub static CurrentSession: u32 = 0;
+}
+
+pub struct MockSessionIndex;
+impl GetSessionIndex<u32> for MockSessionIndex {
+    fn session_index() -> u32 {
+        CurrentSession::get()
+    }
+}
+
+type SessionsTimer = SessionTimer<MockSessionIndex, ConstU32<3>>;
+
+#[test]
+fn session_timer_now_is_current_session() {
+    CurrentSession::set(7);
+    assert_eq!(SessionsTimer::now(), 7);
+
+    CurrentSession::set(8);
+    assert_eq!(SessionsTimer::now(), 8);
+}
+
+#[test]
+fn session_timer_is_elapsed_after_delay() {
+    CurrentSession::set(5);
+    let start = SessionsTimer::now();
+
+    assert!(!SessionsTimer::is_elapsed(&start));
+
+    CurrentSession::set(7);
+    assert!(!SessionsTimer::is_elapsed(&start));
+
+    CurrentSession::set(8);
+    assert!(SessionsTimer::is_elapsed(&start));
+
+    CurrentSession::set(9);
+    assert!(SessionsTimer::is_elapsed(&start));
+}
+
+#[test]
+fn session_timer_with_zero_delay_is_elapsed_immediately() {
+    CurrentSession::set(5);
+    assert!(SessionTimer::<MockSessionIndex, ConstU32<0>>::is_elapsed(
+        &5
+    ));
+}
+
+#[test]
+fn session_timer_overflowing_delay_is_never_elapsed() {
+    CurrentSession::set(u32::MAX);
+    assert!(!SessionsTimer::is_elapsed(&(u32::MAX - 2)));
+    assert!(SessionsTimer::is_elapsed(&(u32::MAX - 3)));
+}
@@ -0,0 +1,142 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {super::*, crate::assert_eq_last_events};
+
+type AutoCompounding = pools::AutoCompounding<Runtime>;
+
+fn delegate(delegator: AccountId, amount: Balance) {
+    FullDelegation {
+        candidate: ACCOUNT_CANDIDATE_1,
+        delegator,
+        request_amount: amount,
+        expected_increase: amount,
+        ..default()
+    }
+    .test::<AutoCompounding>();
+}
+
+#[test]
+fn transfer_auto_compounding_shares_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        delegate(ACCOUNT_DELEGATOR_1, 4 * SHARE_INIT);
+
+        assert_ok!(Staking::transfer_auto_compounding_shares(
+            ACCOUNT_CANDIDATE_1,
+            ACCOUNT_DELEGATOR_1,
+            ACCOUNT_DELEGATOR_2,
+            3,
+        ));
+
+        assert_eq_last_events!(vec![Event::<Runtime>::TransferredShares {
+            candidate: ACCOUNT_CANDIDATE_1,
+            from: ACCOUNT_DELEGATOR_1,
+            to: ACCOUNT_DELEGATOR_2,
+            shares: 3,
+            stake: 3 * SHARE_INIT,
+        }]);
+
+        assert_eq!(
+            PoolState::extract::<AutoCompounding>(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_1),
+            PoolState {
+                hold: SHARE_INIT,
+                stake: SHARE_INIT,
+            }
+        );
+        assert_eq!(
+            PoolState::extract::<AutoCompounding>(ACCOUNT_CANDIDATE_1, ACCOUNT_DELEGATOR_2),
+            PoolState {
+                hold: 3 * SHARE_INIT,
+                stake: 3 * SHARE_INIT,
+            }
+        );
+        assert_eq!(balance_hold(&ACCOUNT_DELEGATOR_1), SHARE_INIT);
+        assert_eq!(balance_hold(&ACCOUNT_DELEGATOR_2), 3 * SHARE_INIT);
+        assert_eq!(
+            total_balance(&ACCOUNT_DELEGATOR_1),
+            DEFAULT_BALANCE - 3 * SHARE_INIT
+        );
+        assert_eq!(
+            total_balance(&ACCOUNT_DELEGATOR_2),
+            DEFAULT_BALANCE + 3 * SHARE_INIT
+        );
+        assert_eq!(
+            Candidates::<Runtime>::total_stake(&ACCOUNT_CANDIDATE_1).0,
+            4 * SHARE_INIT
+        );
+    })
+}
+
+#[test]
+fn transfer_more_shares_than_owned_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        delegate(ACCOUNT_DELEGATOR_1, 4 * SHARE_INIT);
+
+        assert_noop!(
+            Staking::transfer_auto_compounding_shares(
+                ACCOUNT_CANDIDATE_1,
+                ACCOUNT_DELEGATOR_1,
+                ACCOUNT_DELEGATOR_2,
+                5,
+            ),
+            Error::<Runtime>::UnsufficientSharesForTransfer
+        );
+    })
+}
+
+#[test]
+fn candidate_cannot_transfer_below_minimum_self_delegation() {
+    ExtBuilder::default().build().execute_with(|| {
+        FullDelegation {
+            candidate: ACCOUNT_CANDIDATE_1,
+            delegator: ACCOUNT_CANDIDATE_1,
+            request_amount: 12 * SHARE_INIT,
+            expected_increase: 12 * SHARE_INIT,
+            ..default()
+        }
+        .test::<AutoCompounding>();
+
+        assert_noop!(
+            Staking::transfer_auto_compounding_shares(
+                ACCOUNT_CANDIDATE_1,
+                ACCOUNT_CANDIDATE_1,
+                ACCOUNT_DELEGATOR_1,
+                3,
+            ),
+            Error::<Runtime>::SelfDelegationBelowMinimum
+        );
+
+        assert_ok!(Staking::transfer_auto_compounding_shares(
+            ACCOUNT_CANDIDATE_1,
+            ACCOUNT_CANDIDATE_1,
+            ACCOUNT_DELEGATOR_1,
+            2,
+        ));
+        assert_eq!(
+            Candidates::<Runtime>::self_delegation(&ACCOUNT_CANDIDATE_1)
+                .unwrap()
+                .0,
+            10 * SHARE_INIT
+        );
+        assert_eq!(
+            crate::SortedEligibleCandidates::<Runtime>::get()
+                .into_iter()
+                .map(|c| c.candidate)
+                .collect::<Vec<_>>(),
+            vec![ACCOUNT_CANDIDATE_1]
+        );
+    })
+}
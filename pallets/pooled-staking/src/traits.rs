@@ -20,13 +20,15 @@ use {
     parity_scale_codec::FullCodec,
     scale_info::TypeInfo,
     sp_runtime::traits::{CheckedAdd, Get},
+    tp_traits::GetSessionIndex,
 };
 
 /// Allows to get the current instant and check if some duration is elapsed.
 pub trait Timer {
     /// Type for the instant. Must implement some traits to be used easily with
-    /// the Pooled Staking pallet.
-    type Instant: FullCodec + TypeInfo + Clone + Debug + Eq;
+    /// the Pooled Staking pallet. Ordering allows to sort operations in the order
+    /// they mature.
+    type Instant: FullCodec + TypeInfo + Clone + Debug + Eq + Ord;
 
     /// Get the current instant.
     fn now() -> Self::Instant;
@@ -80,6 +82,44 @@ where
     }
 }
 
+/// A timer using session indexes.
+/// `S` provides the current session index while `G` is a getter for the delay
+/// in sessions.
+pub struct SessionTimer<S, G>(PhantomData<(S, G)>);
+
+impl<S, G> Timer for SessionTimer<S, G>
+where
+    S: GetSessionIndex<u32>,
+    G: Get<u32>,
+{
+    type Instant = u32;
+
+    fn now() -> Self::Instant {
+        S::session_index()
+    }
+
+    fn is_elapsed(start: &Self::Instant) -> bool {
+        let delay = G::get();
+        let Some(end) = start.checked_add(delay) else {
+            return false;
+        };
+        end <= Self::now()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn elapsed_instant() -> Self::Instant {
+        let delay = G::get();
+        Self::now()
+            .checked_add(delay)
+            .expect("overflow when computing valid elapsed instant")
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn skip_to_elapsed() {
+        S::skip_to_session(Self::elapsed_instant());
+    }
+}
+
 /// Allows knowing if some account is eligible to be a candidate.
 pub trait IsCandidateEligible<AccountId> {
     /// Is the provided account id eligible?
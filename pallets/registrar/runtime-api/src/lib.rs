@@ -18,7 +18,10 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-pub use dp_container_chain_genesis_data::ContainerChainGenesisData;
+pub use {
+    dp_container_chain_genesis_data::ContainerChainGenesisData,
+    tp_traits::{ContainerChainCodeInfo, PauseWindow},
+};
 use {scale_info::prelude::vec::Vec, tp_traits::SlotFrequency};
 
 sp_api::decl_runtime_apis! {
@@ -33,6 +36,12 @@ sp_api::decl_runtime_apis! {
 
         /// Fetch boot_nodes for this para id
         fn boot_nodes(para_id: ParaId) -> Vec<Vec<u8>>;
+
+        /// Fetch the latest known runtime code hash and head for this para id
+        fn code_info(para_id: ParaId) -> Option<ContainerChainCodeInfo>;
+
+        /// Fetch the scheduled pause window of this para id, in session indexes
+        fn pause_window(para_id: ParaId) -> Option<PauseWindow<u32>>;
     }
 }
 
@@ -20,7 +20,7 @@
 use {
     crate::{
         benchmark_blob::benchmark_blob, Call, Config, DepositBalanceOf, EnsureSignedByManager,
-        Pallet, RegistrarHooks,
+        Pallet, PauseWindows, RegistrarHooks,
     },
     dp_container_chain_genesis_data::{ContainerChainGenesisData, ContainerChainGenesisDataItem},
     frame_benchmarking::{account, v2::*},
@@ -29,9 +29,10 @@ use {
         traits::{fungible::Mutate, EnsureOrigin, EnsureOriginWithArg},
     },
     frame_system::RawOrigin,
-    sp_core::Get,
+    sp_core::{Get, H256},
+    sp_runtime::Saturating,
     sp_std::{vec, vec::Vec},
-    tp_traits::{ParaId, RelayStorageRootProvider, SlotFrequency},
+    tp_traits::{GetSessionIndex, ParaId, PauseWindow, RelayStorageRootProvider, SlotFrequency},
 };
 
 /// Create a funded user.
@@ -106,6 +107,40 @@ mod benchmarks {
         genesis_data
     }
 
+    /// Creates a relay chain storage proof with the code hash and head of `para_id`. Proofs cannot be
+    /// generated in a no-std environment, but the trie is small enough to include all of its nodes.
+    fn code_info_relay_proof(para_id: ParaId) -> (H256, sp_trie::StorageProof) {
+        use {
+            frame_support::Hashable,
+            sp_runtime::traits::BlakeTwo256,
+            sp_trie::{LayoutV1, MemoryDB, TrieDBMutBuilder, TrieMut},
+        };
+
+        let bytes = para_id.twox_64_concat();
+        let code_hash_key = [crate::PARAS_CURRENT_CODE_HASH_INDEX, bytes.as_slice()].concat();
+        let head_key = cumulus_primitives_core::relay_chain::well_known_keys::para_head(para_id);
+        // A container chain header with some digests is usually smaller than this
+        let head = cumulus_primitives_core::relay_chain::HeadData(vec![1; 512]);
+
+        let mut db = MemoryDB::<BlakeTwo256>::default();
+        let mut root = Default::default();
+        {
+            let mut trie =
+                TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+            trie.insert(&code_hash_key, &H256::repeat_byte(1).encode())
+                .expect("failed to insert code hash");
+            trie.insert(&head_key, &head.encode())
+                .expect("failed to insert head");
+        }
+        let nodes = db
+            .drain()
+            .into_iter()
+            .filter(|(_key, (_node, rc))| *rc > 0)
+            .map(|(_key, (node, _rc))| node);
+
+        (root, sp_trie::StorageProof::new(nodes))
+    }
+
     // Returns number of para ids in pending verification (registered but not marked as valid)
     fn pending_verification_len<T: Config>() -> usize {
         crate::PendingVerification::<T>::iter_keys().count()
@@ -174,6 +209,59 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn register_with_xcm(
+        x: Linear<100, 3_000_000>,
+        z: Linear<1, 10>,
+    ) -> Result<(), BenchmarkError> {
+        // This extrinsic is disabled in flashbox runtime, return 0 weight there
+        let origin = T::RegisterWithXcmOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let (para_id, sovereign_account) = T::RegisterWithXcmOrigin::ensure_origin(origin.clone())
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let storage = max_size_genesis_data(z, x);
+
+        // Twice the deposit just in case
+        assert_ok!(T::Currency::mint_into(
+            &sovereign_account,
+            T::DepositAmount::get() * 2u32.into()
+        ));
+
+        #[extrinsic_call]
+        Pallet::<T>::register_with_xcm(origin as T::RuntimeOrigin, None, storage);
+
+        // verification code
+        assert_eq!(pending_verification_len::<T>(), 1usize);
+        assert!(Pallet::<T>::registrar_deposit(para_id).is_some());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn schedule_pause_window() {
+        let para_id = 1001u32.into();
+        let manager = Pallet::<T>::benchmarks_get_or_create_para_manager(&para_id);
+        let start = T::CurrentSessionIndex::session_index()
+            .saturating_add(T::SessionDelay::get())
+            .saturating_add(1u32.into());
+        let end = start.saturating_add(1u32.into());
+        // Worst case: replace an existing window
+        PauseWindows::<T>::insert(para_id, PauseWindow { start, end });
+        let new_end = end.saturating_add(1u32.into());
+
+        #[extrinsic_call]
+        Pallet::<T>::schedule_pause_window(RawOrigin::Signed(manager), para_id, start, new_end);
+
+        // verification code
+        assert_eq!(
+            Pallet::<T>::pause_window(para_id),
+            Some(PauseWindow {
+                start,
+                end: new_end
+            })
+        );
+    }
+
     #[benchmark]
     fn deregister_immediate() {
         let x = T::MaxGenesisDataSize::get();
@@ -646,5 +734,77 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn propose_para_ownership_transfer() {
+        let para_id = 1001u32.into();
+
+        let owner = Pallet::<T>::benchmarks_get_or_create_para_manager(&para_id);
+        let new_owner: T::AccountId = account("new_owner", 0, 1000);
+
+        #[extrinsic_call]
+        Pallet::<T>::propose_para_ownership_transfer(
+            RawOrigin::Signed(owner),
+            para_id,
+            new_owner.clone(),
+        );
+
+        assert_eq!(Pallet::<T>::pending_para_owner(para_id), Some(new_owner));
+    }
+
+    #[benchmark]
+    fn accept_para_ownership_transfer() {
+        let para_id = 1001u32.into();
+
+        let owner = Pallet::<T>::benchmarks_get_or_create_para_manager(&para_id);
+        let (new_owner, _) = create_funded_user::<T>("new_owner", 0, T::DepositAmount::get());
+        Pallet::<T>::propose_para_ownership_transfer(
+            RawOrigin::Signed(owner).into(),
+            para_id,
+            new_owner.clone(),
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        Pallet::<T>::accept_para_ownership_transfer(RawOrigin::Signed(new_owner.clone()), para_id);
+
+        assert_eq!(
+            Pallet::<T>::registrar_deposit(para_id).map(|deposit_info| deposit_info.creator),
+            Some(new_owner.clone())
+        );
+        assert_eq!(crate::ParaManager::<T>::get(para_id), Some(new_owner));
+    }
+
+    #[benchmark]
+    fn update_para_code_with_relay_proof() -> Result<(), BenchmarkError> {
+        // This extrinsic is disabled in flashbox runtime, return 0 weight there
+        let origin = T::RegisterWithRelayProofOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let para_id = 1001u32.into();
+        Pallet::<T>::benchmarks_get_or_create_para_manager(&para_id);
+
+        let (relay_parent_storage_root, proof) = code_info_relay_proof(para_id);
+        T::RelayStorageRootProvider::set_relay_storage_root(1, Some(relay_parent_storage_root));
+        T::RelayStorageRootProvider::set_relay_storage_root(2, Some(relay_parent_storage_root));
+
+        // Worst case: the code info is being updated, not inserted
+        Pallet::<T>::update_para_code_with_relay_proof(origin.clone(), para_id, 1, proof.clone())
+            .expect("failed to update code info");
+
+        #[extrinsic_call]
+        Pallet::<T>::update_para_code_with_relay_proof(
+            origin as T::RuntimeOrigin,
+            para_id,
+            2,
+            proof,
+        );
+
+        assert_eq!(
+            Pallet::<T>::para_code_info(para_id).map(|x| x.relay_block_number),
+            Some(2)
+        );
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -63,9 +63,9 @@ use {
     },
     sp_std::{collections::btree_set::BTreeSet, prelude::*},
     tp_traits::{
-        GetCurrentContainerChains, GetSessionContainerChains, GetSessionIndex, ParaId,
-        ParathreadParams as ParathreadParamsTy, RelayStorageRootProvider, SessionContainerChains,
-        SlotFrequency,
+        ContainerChainCodeInfo, GetCurrentContainerChains, GetSessionContainerChains,
+        GetSessionIndex, ParaId, ParathreadParams as ParathreadParamsTy, PauseWindow,
+        RelayStorageRootProvider, SessionContainerChains, SlotFrequency,
     },
 };
 
@@ -150,6 +150,14 @@ pub mod pallet {
 
         type RelayStorageRootProvider: RelayStorageRootProvider;
 
+        /// Origin of a container chain registering itself, usually an XCM `Transact` sent by that
+        /// chain. Returns the para id of the container chain and its sovereign account in this
+        /// chain, which pays the deposit and becomes the para manager.
+        type RegisterWithXcmOrigin: EnsureOrigin<
+            Self::RuntimeOrigin,
+            Success = (ParaId, Self::AccountId),
+        >;
+
         type SessionIndex: parity_scale_codec::FullCodec + TypeInfo + Copy + AtLeast32BitUnsigned;
 
         #[pallet::constant]
@@ -200,6 +208,12 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Pause windows scheduled with `schedule_pause_window`. The pause and the unpause are added
+    /// to `PendingPaused` `SessionDelay` sessions before they take effect.
+    #[pallet::storage]
+    pub type PauseWindows<T: Config> =
+        StorageMap<_, Blake2_128Concat, ParaId, PauseWindow<T::SessionIndex>, OptionQuery>;
+
     #[pallet::storage]
     pub type PendingToRemove<T: Config> = StorageValue<
         _,
@@ -241,6 +255,18 @@ pub mod pallet {
     pub type ParaManager<T: Config> =
         StorageMap<_, Blake2_128Concat, ParaId, T::AccountId, OptionQuery>;
 
+    /// Pending ownership transfers, a mapping from paraId to the account that has been
+    /// proposed as the new owner. The transfer only takes place once that account accepts it.
+    #[pallet::storage]
+    pub type PendingParaOwner<T: Config> =
+        StorageMap<_, Blake2_128Concat, ParaId, T::AccountId, OptionQuery>;
+
+    /// Latest runtime code hash and head of each container chain, read from the relay chain using
+    /// a storage proof. Collators use it to fetch the latest runtime before syncing a container chain.
+    #[pallet::storage]
+    pub type ParaCodeInfo<T: Config> =
+        StorageMap<_, Blake2_128Concat, ParaId, ContainerChainCodeInfo, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -254,6 +280,12 @@ pub mod pallet {
         ParaIdPaused { para_id: ParaId },
         /// A para id has been unpaused.
         ParaIdUnpaused { para_id: ParaId },
+        /// A para id will be paused at session `start` and unpaused at session `end`.
+        PauseWindowScheduled {
+            para_id: ParaId,
+            start: T::SessionIndex,
+            end: T::SessionIndex,
+        },
         /// Parathread params changed
         ParathreadParamsChanged { para_id: ParaId },
         /// Para manager has changed
@@ -261,6 +293,24 @@ pub mod pallet {
             para_id: ParaId,
             manager_address: T::AccountId,
         },
+        /// The owner of a para id has proposed to transfer its ownership to a new account
+        ParaOwnershipTransferProposed {
+            para_id: ParaId,
+            owner: T::AccountId,
+            new_owner: T::AccountId,
+        },
+        /// The ownership of a para id and its deposit have been transferred to a new account
+        ParaOwnershipTransferred {
+            para_id: ParaId,
+            old_owner: T::AccountId,
+            new_owner: T::AccountId,
+        },
+        /// The runtime code hash and head of a para id have been updated
+        ParaCodeInfoUpdated {
+            para_id: ParaId,
+            code_hash: H256,
+            relay_block_number: u32,
+        },
     }
 
     #[pallet::error]
@@ -295,6 +345,20 @@ pub mod pallet {
         InvalidRelayManagerSignature,
         /// Tried to deregister a parachain that was not deregistered from the relay chain
         ParaStillExistsInRelay,
+        /// Tried to accept an ownership transfer for a para id that has no pending transfer
+        NoPendingOwnershipTransfer,
+        /// Attempted to accept an ownership transfer that was proposed to a different account
+        NotPendingParaOwner,
+        /// Tried to propose an ownership transfer to the current owner
+        AlreadyParaOwner,
+        /// The provided relay storage proof is not newer than the one used in the last code info update
+        RelayStorageProofTooOld,
+        /// The end session of a pause window must be after its start session
+        InvalidPauseWindow,
+        /// The start session of a pause window must be more than `SessionDelay` sessions in the future
+        PauseWindowTooSoon,
+        /// Tried to replace a pause window that has already been applied
+        PauseWindowAlreadyStarted,
     }
 
     #[pallet::composite_enum]
@@ -365,6 +429,14 @@ pub mod pallet {
                     u32::from(para_id)
                 );
             }
+            let entries: Vec<_> = PauseWindows::<T>::iter().map(|(k, _v)| k).collect();
+            for para_id in entries {
+                assert!(
+                    para_id_set.contains(&para_id),
+                    "Found PauseWindows for unknown para id: {}",
+                    u32::from(para_id)
+                );
+            }
 
             // Sorted storage items are sorted
             fn assert_is_sorted_and_unique<T: Ord>(x: &[T], name: &str) {
@@ -458,29 +530,7 @@ pub mod pallet {
         pub fn pause_container_chain(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
             T::RegistrarOrigin::ensure_origin(origin)?;
 
-            Self::schedule_paused_parachain_change(|para_ids, paused| {
-                match paused.binary_search(&para_id) {
-                    Ok(_) => return Err(Error::<T>::ParaIdAlreadyPaused.into()),
-                    Err(index) => {
-                        paused
-                            .try_insert(index, para_id)
-                            .map_err(|_e| Error::<T>::ParaIdListFull)?;
-                    }
-                }
-                match para_ids.binary_search(&para_id) {
-                    Ok(index) => {
-                        para_ids.remove(index);
-                    }
-                    // We can only pause para ids that are marked as valid,
-                    // otherwise unpausing them later would cause problems
-                    Err(_) => return Err(Error::<T>::ParaIdNotRegistered.into()),
-                }
-                Self::deposit_event(Event::ParaIdPaused { para_id });
-
-                Ok(())
-            })?;
-
-            Ok(())
+            Self::do_pause_container_chain(para_id)
         }
 
         /// Unpause container-chain.
@@ -490,28 +540,7 @@ pub mod pallet {
         pub fn unpause_container_chain(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
             T::RegistrarOrigin::ensure_origin(origin)?;
 
-            Self::schedule_paused_parachain_change(|para_ids, paused| {
-                match paused.binary_search(&para_id) {
-                    Ok(index) => {
-                        paused.remove(index);
-                    }
-                    Err(_) => return Err(Error::<T>::ParaIdNotPaused.into()),
-                }
-                match para_ids.binary_search(&para_id) {
-                    // This Ok is unreachable, a para id cannot be in "RegisteredParaIds" and "Paused" at the same time
-                    Ok(_) => return Err(Error::<T>::ParaIdAlreadyRegistered.into()),
-                    Err(index) => {
-                        para_ids
-                            .try_insert(index, para_id)
-                            .map_err(|_e| Error::<T>::ParaIdListFull)?;
-                    }
-                }
-                Self::deposit_event(Event::ParaIdUnpaused { para_id });
-
-                Ok(())
-            })?;
-
-            Ok(())
+            Self::do_unpause_container_chain(para_id)
         }
 
         /// Register parathread
@@ -678,12 +707,221 @@ pub mod pallet {
                     Restriction::Free,
                     Fortitude::Force,
                 );
+                // The deposit is gone, so there is no ownership left to transfer
+                PendingParaOwner::<T>::remove(para_id);
             }
 
             Self::do_deregister(para_id)?;
 
             Ok(())
         }
+
+        /// Propose to transfer the ownership of a para id to `new_owner`. Only the current owner
+        /// (the account holding the registrar deposit) can call this. The transfer does not take
+        /// place until `new_owner` calls `accept_para_ownership_transfer`.
+        /// Proposing again replaces any previous proposal.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::propose_para_ownership_transfer())]
+        pub fn propose_para_ownership_transfer(
+            origin: OriginFor<T>,
+            para_id: ParaId,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            let origin = ensure_signed(origin)?;
+
+            let owner = RegistrarDeposit::<T>::get(para_id)
+                .map(|deposit_info| deposit_info.creator)
+                .filter(|creator| *creator == origin)
+                .ok_or(Error::<T>::NotParaCreator)?;
+
+            ensure!(new_owner != owner, Error::<T>::AlreadyParaOwner);
+
+            PendingParaOwner::<T>::insert(para_id, new_owner.clone());
+
+            Self::deposit_event(Event::<T>::ParaOwnershipTransferProposed {
+                para_id,
+                owner,
+                new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Accept a pending ownership transfer. The registrar deposit is moved from the old owner
+        /// to the caller, who becomes the new owner and para manager of the para id. When the para
+        /// id is deregistered, the deposit will be returned to the new owner.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::accept_para_ownership_transfer())]
+        pub fn accept_para_ownership_transfer(
+            origin: OriginFor<T>,
+            para_id: ParaId,
+        ) -> DispatchResult {
+            let new_owner = ensure_signed(origin)?;
+
+            let pending_owner = PendingParaOwner::<T>::get(para_id)
+                .ok_or(Error::<T>::NoPendingOwnershipTransfer)?;
+            ensure!(pending_owner == new_owner, Error::<T>::NotPendingParaOwner);
+
+            // The pending transfer is removed when the para id is cleaned up, so the deposit
+            // must exist here
+            let mut deposit_info =
+                RegistrarDeposit::<T>::get(para_id).ok_or(Error::<T>::NotParaCreator)?;
+            let old_owner = deposit_info.creator.clone();
+
+            // Move the held deposit from the old owner to the new owner, keeping it on hold
+            T::Currency::transfer_on_hold(
+                &HoldReason::RegistrarDeposit.into(),
+                &old_owner,
+                &new_owner,
+                deposit_info.deposit,
+                Precision::Exact,
+                Restriction::OnHold,
+                Fortitude::Polite,
+            )?;
+
+            deposit_info.creator = new_owner.clone();
+            RegistrarDeposit::<T>::insert(para_id, deposit_info);
+            PendingParaOwner::<T>::remove(para_id);
+            ParaManager::<T>::insert(para_id, new_owner.clone());
+
+            Self::deposit_event(Event::<T>::ParaOwnershipTransferred {
+                para_id,
+                old_owner,
+                new_owner: new_owner.clone(),
+            });
+            Self::deposit_event(Event::<T>::ParaManagerChanged {
+                para_id,
+                manager_address: new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Update the runtime code hash and head of a container chain using a relay chain storage
+        /// proof. The proof must be newer than the one used in the previous update.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::update_para_code_with_relay_proof())]
+        pub fn update_para_code_with_relay_proof(
+            origin: OriginFor<T>,
+            para_id: ParaId,
+            relay_proof_block_number: u32,
+            relay_storage_proof: sp_trie::StorageProof,
+        ) -> DispatchResult {
+            let _account = T::RegisterWithRelayProofOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                ParaGenesisData::<T>::contains_key(para_id),
+                Error::<T>::ParaIdNotRegistered
+            );
+            if let Some(code_info) = ParaCodeInfo::<T>::get(para_id) {
+                ensure!(
+                    relay_proof_block_number > code_info.relay_block_number,
+                    Error::<T>::RelayStorageProofTooOld
+                );
+            }
+
+            let relay_storage_root =
+                T::RelayStorageRootProvider::get_relay_storage_root(relay_proof_block_number)
+                    .ok_or(Error::<T>::RelayStorageRootNotFound)?;
+            let relay_state_proof =
+                GenericStateProof::<cumulus_primitives_core::relay_chain::Block>::new(
+                    relay_storage_root,
+                    relay_storage_proof,
+                )
+                .map_err(|_| Error::<T>::InvalidRelayStorageProof)?;
+
+            let bytes = para_id.twox_64_concat();
+            let key = [PARAS_CURRENT_CODE_HASH_INDEX, bytes.as_slice()].concat();
+            let code_hash = relay_state_proof
+                .read_entry::<H256>(key.as_slice(), None)
+                .map_err(|_| Error::<T>::InvalidRelayStorageProof)?;
+            let key = cumulus_primitives_core::relay_chain::well_known_keys::para_head(para_id);
+            let head = relay_state_proof
+                .read_entry::<cumulus_primitives_core::relay_chain::HeadData>(key.as_slice(), None)
+                .map_err(|_| Error::<T>::InvalidRelayStorageProof)?;
+
+            ParaCodeInfo::<T>::insert(
+                para_id,
+                ContainerChainCodeInfo {
+                    code_hash,
+                    head: head.0,
+                    relay_block_number: relay_proof_block_number,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::ParaCodeInfoUpdated {
+                para_id,
+                code_hash,
+                relay_block_number: relay_proof_block_number,
+            });
+
+            Ok(())
+        }
+
+        /// Register a container chain using an origin from that same chain, proving ownership
+        /// without a relay chain registration. Meant for chains migrating from a standalone
+        /// deployment. The deposit is taken from the sovereign account of the container chain,
+        /// which is also set as the para manager.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::register_with_xcm(genesis_data.encoded_size() as u32, genesis_data.storage.len() as u32))]
+        pub fn register_with_xcm(
+            origin: OriginFor<T>,
+            parathread_params: Option<ParathreadParamsTy>,
+            genesis_data: ContainerChainGenesisData,
+        ) -> DispatchResult {
+            let (para_id, sovereign_account) = T::RegisterWithXcmOrigin::ensure_origin(origin)?;
+            Self::do_register(sovereign_account, para_id, genesis_data)?;
+            // Insert parathread params
+            if let Some(parathread_params) = parathread_params {
+                ParathreadParams::<T>::insert(para_id, parathread_params);
+            }
+            Self::deposit_event(Event::ParaIdRegistered { para_id });
+
+            Ok(())
+        }
+
+        /// Schedule a pause window for a container chain: it will be paused at session `start` and
+        /// unpaused at session `end`, without further calls. Can be called by the para manager or
+        /// by `RegistrarOrigin`. Scheduling a new window replaces the previous one, unless it has
+        /// already started.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::schedule_pause_window())]
+        pub fn schedule_pause_window(
+            origin: OriginFor<T>,
+            para_id: ParaId,
+            start: T::SessionIndex,
+            end: T::SessionIndex,
+        ) -> DispatchResult {
+            if let Err(origin) = T::RegistrarOrigin::try_origin(origin) {
+                EnsureSignedByManager::<T>::ensure_origin(origin, &para_id)?;
+            }
+
+            ensure!(
+                ParaGenesisData::<T>::contains_key(para_id),
+                Error::<T>::ParaIdNotRegistered
+            );
+            ensure!(start < end, Error::<T>::InvalidPauseWindow);
+            // The pause is scheduled `SessionDelay` sessions in advance, at the start of a session,
+            // so the earliest session that can still be scheduled is the next one.
+            let scheduled_session = Self::scheduled_session();
+            ensure!(start > scheduled_session, Error::<T>::PauseWindowTooSoon);
+            if let Some(window) = PauseWindows::<T>::get(para_id) {
+                ensure!(
+                    window.start > scheduled_session,
+                    Error::<T>::PauseWindowAlreadyStarted
+                );
+            }
+
+            PauseWindows::<T>::insert(para_id, PauseWindow { start, end });
+
+            Self::deposit_event(Event::PauseWindowScheduled {
+                para_id,
+                start,
+                end,
+            });
+
+            Ok(())
+        }
     }
 
     pub struct SessionChangeOutcome<T: Config> {
@@ -876,6 +1114,87 @@ pub mod pallet {
             Ok(())
         }
 
+        fn do_pause_container_chain(para_id: ParaId) -> DispatchResult {
+            Self::schedule_paused_parachain_change(|para_ids, paused| {
+                match paused.binary_search(&para_id) {
+                    Ok(_) => return Err(Error::<T>::ParaIdAlreadyPaused.into()),
+                    Err(index) => {
+                        paused
+                            .try_insert(index, para_id)
+                            .map_err(|_e| Error::<T>::ParaIdListFull)?;
+                    }
+                }
+                match para_ids.binary_search(&para_id) {
+                    Ok(index) => {
+                        para_ids.remove(index);
+                    }
+                    // We can only pause para ids that are marked as valid,
+                    // otherwise unpausing them later would cause problems
+                    Err(_) => return Err(Error::<T>::ParaIdNotRegistered.into()),
+                }
+                Self::deposit_event(Event::ParaIdPaused { para_id });
+
+                Ok(())
+            })
+        }
+
+        fn do_unpause_container_chain(para_id: ParaId) -> DispatchResult {
+            Self::schedule_paused_parachain_change(|para_ids, paused| {
+                match paused.binary_search(&para_id) {
+                    Ok(index) => {
+                        paused.remove(index);
+                    }
+                    Err(_) => return Err(Error::<T>::ParaIdNotPaused.into()),
+                }
+                match para_ids.binary_search(&para_id) {
+                    // This Ok is unreachable, a para id cannot be in "RegisteredParaIds" and "Paused" at the same time
+                    Ok(_) => return Err(Error::<T>::ParaIdAlreadyRegistered.into()),
+                    Err(index) => {
+                        para_ids
+                            .try_insert(index, para_id)
+                            .map_err(|_e| Error::<T>::ParaIdListFull)?;
+                    }
+                }
+                Self::deposit_event(Event::ParaIdUnpaused { para_id });
+
+                Ok(())
+            })
+        }
+
+        /// Schedule the pause or unpause of the container chains whose pause window starts or ends
+        /// `SessionDelay` sessions after `session_index`.
+        fn apply_pause_windows(session_index: &T::SessionIndex) {
+            let scheduled_session = (*session_index).saturating_add(T::SessionDelay::get());
+            // Bounded by the number of registered para ids
+            let windows: Vec<_> = PauseWindows::<T>::iter().collect();
+
+            for (para_id, window) in windows {
+                if window.end <= scheduled_session {
+                    PauseWindows::<T>::remove(para_id);
+                    // May fail if the container chain was manually unpaused during the window
+                    if let Err(e) = Self::do_unpause_container_chain(para_id) {
+                        log::warn!(
+                            target: LOG_TARGET,
+                            "Failed to unpause para id {} at the end of its pause window: {:?}",
+                            u32::from(para_id),
+                            e,
+                        );
+                    }
+                } else if window.start == scheduled_session {
+                    if let Err(e) = Self::do_pause_container_chain(para_id) {
+                        // The pause was not applied, so it must not be undone at the end of the window
+                        PauseWindows::<T>::remove(para_id);
+                        log::warn!(
+                            target: LOG_TARGET,
+                            "Failed to pause para id {} at the start of its pause window: {:?}",
+                            u32::from(para_id),
+                            e,
+                        );
+                    }
+                }
+            }
+        }
+
         /// Relay parachain manager signature message. Includes:
         /// * para_id, in case the manager has more than 1 para in the relay
         /// * accountid in tanssi, to ensure that the creator role is assigned to the desired account
@@ -1168,6 +1487,8 @@ pub mod pallet {
                 }
             }
 
+            Self::apply_pause_windows(session_index);
+
             SessionChangeOutcome {
                 prev_paras,
                 new_paras,
@@ -1192,6 +1513,9 @@ pub mod pallet {
             }
 
             ParaManager::<T>::remove(para_id);
+            PendingParaOwner::<T>::remove(para_id);
+            ParaCodeInfo::<T>::remove(para_id);
+            PauseWindows::<T>::remove(para_id);
 
             T::RegistrarHooks::para_deregistered(para_id);
         }
@@ -1272,6 +1596,18 @@ pub mod pallet {
         pub fn registrar_deposit(para_id: ParaId) -> Option<DepositInfo<T>> {
             RegistrarDeposit::<T>::get(para_id)
         }
+
+        pub fn pending_para_owner(para_id: ParaId) -> Option<T::AccountId> {
+            PendingParaOwner::<T>::get(para_id)
+        }
+
+        pub fn para_code_info(para_id: ParaId) -> Option<ContainerChainCodeInfo> {
+            ParaCodeInfo::<T>::get(para_id)
+        }
+
+        pub fn pause_window(para_id: ParaId) -> Option<PauseWindow<T::SessionIndex>> {
+            PauseWindows::<T>::get(para_id)
+        }
     }
 
     impl<T: Config> GetCurrentContainerChains for Pallet<T> {
@@ -1383,6 +1719,14 @@ where
 pub const REGISTRAR_PARAS_INDEX: &[u8] =
     &hex_literal::hex!["3fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa194"];
 
+// TODO: import this from dancekit
+pub const PARAS_CURRENT_CODE_HASH_INDEX: &[u8] =
+    &hex_literal::hex!["cd710b30bd2eab0352ddcc26417aa194e2d1c22ba0a888147714a3487bd51c63"];
+
+// TODO: import this from dancekit
+pub const PARAS_CODE_BY_HASH_INDEX: &[u8] =
+    &hex_literal::hex!["cd710b30bd2eab0352ddcc26417aa194383e6dcb39e0be0a2e6aeb8b94951ab6"];
+
 // Need to copy ParaInfo from
 // polkadot-sdk/polkadot/runtime/common/src/paras_registrar/mod.rs
 // Because its fields are not public...
@@ -18,7 +18,7 @@ use {
     crate::{self as pallet_registrar, RegistrarHooks},
     dp_container_chain_genesis_data::ContainerChainGenesisData,
     frame_support::{
-        traits::{ConstU16, ConstU64},
+        traits::{ConstU16, ConstU64, EnsureOrigin},
         weights::Weight,
     },
     parity_scale_codec::{Decode, Encode},
@@ -130,6 +130,38 @@ impl RelayStorageRootProvider for MockRelayStorageRootProvider {
     }
 }
 
+/// Offset added to a para id to get the sovereign account of that container chain in tests.
+pub const SOVEREIGN_ACCOUNT_OFFSET: u64 = 1_000_000;
+
+pub fn container_chain_sovereign_account(para_id: ParaId) -> u64 {
+    SOVEREIGN_ACCOUNT_OFFSET + u64::from(u32::from(para_id))
+}
+
+/// Mock of a container chain XCM origin: a signed origin from a sovereign account, see
+/// `container_chain_sovereign_account`.
+pub struct MockContainerChainOrigin;
+
+impl EnsureOrigin<RuntimeOrigin> for MockContainerChainOrigin {
+    type Success = (ParaId, u64);
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        let account = frame_system::EnsureSigned::<u64>::try_origin(o.clone())?;
+        if account < SOVEREIGN_ACCOUNT_OFFSET {
+            return Err(o);
+        }
+        let para_id = ParaId::from((account - SOVEREIGN_ACCOUNT_OFFSET) as u32);
+
+        Ok((para_id, account))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(container_chain_sovereign_account(
+            1001.into(),
+        )))
+    }
+}
+
 parameter_types! {
     pub const DepositAmount: Balance = 100;
 }
@@ -141,6 +173,7 @@ impl pallet_registrar::Config for Test {
     type MaxGenesisDataSize = ConstU32<5_000_000>;
     type RegisterWithRelayProofOrigin = frame_system::EnsureSigned<u64>;
     type RelayStorageRootProvider = MockRelayStorageRootProvider;
+    type RegisterWithXcmOrigin = MockContainerChainOrigin;
     type SessionDelay = ConstU32<2>;
     type SessionIndex = u32;
     type CurrentSessionIndex = CurrentSessionIndexGetter;
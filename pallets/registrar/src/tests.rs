@@ -1921,3 +1921,806 @@ fn weights_assigned_to_extrinsics_are_correct() {
         );
     });
 }
+
+mod para_ownership_transfer {
+    use super::*;
+
+    #[test]
+    fn transfer_moves_deposit_to_new_owner() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+
+            assert_ok!(ParaRegistrar::propose_para_ownership_transfer(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                BOB,
+            ));
+            System::assert_last_event(
+                Event::ParaOwnershipTransferProposed {
+                    para_id: 42.into(),
+                    owner: ALICE,
+                    new_owner: BOB,
+                }
+                .into(),
+            );
+            // Nothing changes until the transfer is accepted
+            assert_eq!(
+                Balances::balance_on_hold(&HoldReason::RegistrarDeposit.into(), &ALICE),
+                DepositAmount::get()
+            );
+            assert_eq!(ParaRegistrar::pending_para_owner(42.into()), Some(BOB));
+
+            assert_ok!(ParaRegistrar::accept_para_ownership_transfer(
+                RuntimeOrigin::signed(BOB),
+                42.into(),
+            ));
+            System::assert_has_event(
+                Event::ParaOwnershipTransferred {
+                    para_id: 42.into(),
+                    old_owner: ALICE,
+                    new_owner: BOB,
+                }
+                .into(),
+            );
+            System::assert_last_event(
+                Event::ParaManagerChanged {
+                    para_id: 42.into(),
+                    manager_address: BOB,
+                }
+                .into(),
+            );
+
+            assert_eq!(
+                Balances::balance_on_hold(&HoldReason::RegistrarDeposit.into(), &ALICE),
+                0
+            );
+            assert_eq!(
+                Balances::balance_on_hold(&HoldReason::RegistrarDeposit.into(), &BOB),
+                DepositAmount::get()
+            );
+            assert_eq!(
+                ParaRegistrar::registrar_deposit(ParaId::from(42)).map(|x| x.creator),
+                Some(BOB)
+            );
+            assert_eq!(crate::ParaManager::<Test>::get(ParaId::from(42)), Some(BOB));
+            assert_eq!(ParaRegistrar::pending_para_owner(42.into()), None);
+        });
+    }
+
+    #[test]
+    fn only_owner_can_propose_transfer() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+            // The para manager is not the owner
+            assert_ok!(ParaRegistrar::set_para_manager(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                BOB,
+            ));
+
+            assert_noop!(
+                ParaRegistrar::propose_para_ownership_transfer(
+                    RuntimeOrigin::signed(BOB),
+                    42.into(),
+                    BOB,
+                ),
+                Error::<Test>::NotParaCreator
+            );
+            assert_noop!(
+                ParaRegistrar::propose_para_ownership_transfer(
+                    RuntimeOrigin::signed(ALICE),
+                    42.into(),
+                    ALICE,
+                ),
+                Error::<Test>::AlreadyParaOwner
+            );
+        });
+    }
+
+    #[test]
+    fn only_proposed_owner_can_accept_transfer() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+
+            assert_noop!(
+                ParaRegistrar::accept_para_ownership_transfer(
+                    RuntimeOrigin::signed(BOB),
+                    42.into()
+                ),
+                Error::<Test>::NoPendingOwnershipTransfer
+            );
+
+            assert_ok!(ParaRegistrar::propose_para_ownership_transfer(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                BOB,
+            ));
+
+            assert_noop!(
+                ParaRegistrar::accept_para_ownership_transfer(
+                    RuntimeOrigin::signed(ALICE),
+                    42.into()
+                ),
+                Error::<Test>::NotPendingParaOwner
+            );
+        });
+    }
+
+    #[test]
+    fn deposit_is_returned_to_new_owner_on_deregister() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+            assert_ok!(ParaRegistrar::mark_valid_for_collating(
+                RuntimeOrigin::root(),
+                42.into(),
+            ));
+            assert_ok!(ParaRegistrar::propose_para_ownership_transfer(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                BOB,
+            ));
+            assert_ok!(ParaRegistrar::accept_para_ownership_transfer(
+                RuntimeOrigin::signed(BOB),
+                42.into(),
+            ));
+
+            let alice_balance_before = System::account(ALICE).data;
+            let bob_balance_before = System::account(BOB).data;
+
+            assert_ok!(ParaRegistrar::deregister(RuntimeOrigin::root(), 42.into()));
+            run_to_session(4);
+            assert!(ParaRegistrar::registrar_deposit(ParaId::from(42)).is_none());
+
+            let alice_balance_after = System::account(ALICE).data;
+            let bob_balance_after = System::account(BOB).data;
+            // Alice does not get the deposit back
+            assert_eq!(alice_balance_after, alice_balance_before);
+            // Bob gets the deposit back
+            assert_eq!(
+                Balances::balance_on_hold(&HoldReason::RegistrarDeposit.into(), &BOB),
+                0
+            );
+            assert_eq!(
+                bob_balance_after.free,
+                bob_balance_before.free + DepositAmount::get()
+            );
+        });
+    }
+
+    #[test]
+    fn pending_transfer_is_removed_on_deregister() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+            assert_ok!(ParaRegistrar::propose_para_ownership_transfer(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                BOB,
+            ));
+
+            // Not marked as valid for collating, so it is cleaned up immediately
+            assert_ok!(ParaRegistrar::deregister(RuntimeOrigin::root(), 42.into()));
+            assert_eq!(ParaRegistrar::pending_para_owner(42.into()), None);
+
+            assert_noop!(
+                ParaRegistrar::accept_para_ownership_transfer(
+                    RuntimeOrigin::signed(BOB),
+                    42.into()
+                ),
+                Error::<Test>::NoPendingOwnershipTransfer
+            );
+        });
+    }
+}
+
+mod update_para_code_with_relay_proof {
+    use {
+        super::*,
+        crate::PARAS_CURRENT_CODE_HASH_INDEX,
+        cumulus_primitives_core::relay_chain::{well_known_keys, HeadData},
+        sp_core::H256,
+        tp_traits::ContainerChainCodeInfo,
+    };
+
+    fn code_info_proof(
+        para_id: ParaId,
+        code_hash: Option<H256>,
+        head: Vec<u8>,
+    ) -> (H256, sp_trie::StorageProof) {
+        let mut sproof = RelayStateSproofBuilder::default();
+        let bytes = para_id.twox_64_concat();
+        if let Some(code_hash) = code_hash {
+            let key = [PARAS_CURRENT_CODE_HASH_INDEX, bytes.as_slice()].concat();
+            sproof.additional_key_values.push((key, code_hash.encode()));
+        }
+        sproof
+            .additional_key_values
+            .push((well_known_keys::para_head(para_id), HeadData(head).encode()));
+
+        sproof.into_state_root_and_proof()
+    }
+
+    #[test]
+    fn can_update_code_info() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+
+            let code_hash = H256::repeat_byte(1);
+            let (relay_parent_storage_root, proof) =
+                code_info_proof(42.into(), Some(code_hash), vec![1, 2, 3]);
+            Mock::mutate(|m| {
+                m.relay_storage_roots.insert(1, relay_parent_storage_root);
+            });
+
+            assert_ok!(ParaRegistrar::update_para_code_with_relay_proof(
+                RuntimeOrigin::signed(BOB),
+                42.into(),
+                1,
+                proof,
+            ));
+            System::assert_last_event(
+                Event::ParaCodeInfoUpdated {
+                    para_id: 42.into(),
+                    code_hash,
+                    relay_block_number: 1,
+                }
+                .into(),
+            );
+            assert_eq!(
+                ParaRegistrar::para_code_info(42.into()),
+                Some(ContainerChainCodeInfo {
+                    code_hash,
+                    head: vec![1, 2, 3],
+                    relay_block_number: 1,
+                })
+            );
+
+            // A newer proof updates the code info
+            let new_code_hash = H256::repeat_byte(2);
+            let (relay_parent_storage_root, proof) =
+                code_info_proof(42.into(), Some(new_code_hash), vec![4, 5, 6]);
+            Mock::mutate(|m| {
+                m.relay_storage_roots.insert(2, relay_parent_storage_root);
+            });
+
+            assert_ok!(ParaRegistrar::update_para_code_with_relay_proof(
+                RuntimeOrigin::signed(BOB),
+                42.into(),
+                2,
+                proof,
+            ));
+            assert_eq!(
+                ParaRegistrar::para_code_info(42.into()),
+                Some(ContainerChainCodeInfo {
+                    code_hash: new_code_hash,
+                    head: vec![4, 5, 6],
+                    relay_block_number: 2,
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_update_code_info_with_old_proof() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+
+            let (relay_parent_storage_root, proof) =
+                code_info_proof(42.into(), Some(H256::repeat_byte(1)), vec![1, 2, 3]);
+            Mock::mutate(|m| {
+                m.relay_storage_roots.insert(1, relay_parent_storage_root);
+                m.relay_storage_roots.insert(2, relay_parent_storage_root);
+            });
+
+            assert_ok!(ParaRegistrar::update_para_code_with_relay_proof(
+                RuntimeOrigin::signed(BOB),
+                42.into(),
+                2,
+                proof.clone(),
+            ));
+            assert_noop!(
+                ParaRegistrar::update_para_code_with_relay_proof(
+                    RuntimeOrigin::signed(BOB),
+                    42.into(),
+                    2,
+                    proof.clone(),
+                ),
+                Error::<Test>::RelayStorageProofTooOld
+            );
+            assert_noop!(
+                ParaRegistrar::update_para_code_with_relay_proof(
+                    RuntimeOrigin::signed(BOB),
+                    42.into(),
+                    1,
+                    proof,
+                ),
+                Error::<Test>::RelayStorageProofTooOld
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_update_code_info_without_code_hash_in_proof() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+
+            let (relay_parent_storage_root, proof) =
+                code_info_proof(42.into(), None, vec![1, 2, 3]);
+            Mock::mutate(|m| {
+                m.relay_storage_roots.insert(1, relay_parent_storage_root);
+            });
+
+            assert_noop!(
+                ParaRegistrar::update_para_code_with_relay_proof(
+                    RuntimeOrigin::signed(BOB),
+                    42.into(),
+                    1,
+                    proof,
+                ),
+                Error::<Test>::InvalidRelayStorageProof
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_update_code_info_of_unregistered_para() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+
+            let (relay_parent_storage_root, proof) =
+                code_info_proof(42.into(), Some(H256::repeat_byte(1)), vec![1, 2, 3]);
+            Mock::mutate(|m| {
+                m.relay_storage_roots.insert(1, relay_parent_storage_root);
+            });
+
+            assert_noop!(
+                ParaRegistrar::update_para_code_with_relay_proof(
+                    RuntimeOrigin::signed(BOB),
+                    42.into(),
+                    1,
+                    proof,
+                ),
+                Error::<Test>::ParaIdNotRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn code_info_removed_on_deregister() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+
+            let (relay_parent_storage_root, proof) =
+                code_info_proof(42.into(), Some(H256::repeat_byte(1)), vec![1, 2, 3]);
+            Mock::mutate(|m| {
+                m.relay_storage_roots.insert(1, relay_parent_storage_root);
+            });
+            assert_ok!(ParaRegistrar::update_para_code_with_relay_proof(
+                RuntimeOrigin::signed(BOB),
+                42.into(),
+                1,
+                proof,
+            ));
+            assert!(ParaRegistrar::para_code_info(42.into()).is_some());
+
+            assert_ok!(ParaRegistrar::deregister(RuntimeOrigin::root(), 42.into()));
+            assert!(ParaRegistrar::para_code_info(42.into()).is_none());
+        });
+    }
+}
+
+mod register_with_xcm {
+    use super::*;
+
+    fn fund_sovereign_account(para_id: ParaId) -> u64 {
+        let sovereign_account = container_chain_sovereign_account(para_id);
+        assert_ok!(Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            sovereign_account,
+            1_000
+        ));
+
+        sovereign_account
+    }
+
+    #[test]
+    fn can_register_using_container_chain_origin() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            let sovereign_account = fund_sovereign_account(42.into());
+
+            assert_ok!(ParaRegistrar::register_with_xcm(
+                RuntimeOrigin::signed(sovereign_account),
+                None,
+                empty_genesis_data(),
+            ));
+
+            System::assert_last_event(Event::ParaIdRegistered { para_id: 42.into() }.into());
+            assert_eq!(
+                ParaRegistrar::pending_verification(ParaId::from(42)),
+                Some(())
+            );
+            assert_eq!(
+                ParaRegistrar::registrar_deposit(ParaId::from(42)).map(|x| x.creator),
+                Some(sovereign_account)
+            );
+            assert_eq!(
+                Balances::balance_on_hold(&HoldReason::RegistrarDeposit.into(), &sovereign_account),
+                DepositAmount::get()
+            );
+            assert_eq!(
+                crate::ParaManager::<Test>::get(ParaId::from(42)),
+                Some(sovereign_account)
+            );
+            assert!(ParaRegistrar::is_para_manager(
+                &42.into(),
+                &sovereign_account
+            ));
+        });
+    }
+
+    #[test]
+    fn can_register_parathread_using_container_chain_origin() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            let sovereign_account = fund_sovereign_account(42.into());
+
+            assert_ok!(ParaRegistrar::register_with_xcm(
+                RuntimeOrigin::signed(sovereign_account),
+                Some(tp_traits::ParathreadParams {
+                    slot_frequency: SlotFrequency { min: 1, max: 1 }
+                }),
+                empty_genesis_data(),
+            ));
+
+            assert_eq!(
+                ParaRegistrar::parathread_params(ParaId::from(42)).map(|x| x.slot_frequency),
+                Some(SlotFrequency { min: 1, max: 1 })
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_register_with_signed_origin() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+
+            assert_noop!(
+                ParaRegistrar::register_with_xcm(
+                    RuntimeOrigin::signed(ALICE),
+                    None,
+                    empty_genesis_data(),
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_register_if_sovereign_account_has_no_funds() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+
+            assert_noop!(
+                ParaRegistrar::register_with_xcm(
+                    RuntimeOrigin::signed(container_chain_sovereign_account(42.into())),
+                    None,
+                    empty_genesis_data(),
+                ),
+                Error::<Test>::NotSufficientDeposit
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_register_para_id_already_registered() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            let sovereign_account = fund_sovereign_account(42.into());
+            assert_ok!(ParaRegistrar::register(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                empty_genesis_data()
+            ));
+
+            assert_noop!(
+                ParaRegistrar::register_with_xcm(
+                    RuntimeOrigin::signed(sovereign_account),
+                    None,
+                    empty_genesis_data(),
+                ),
+                Error::<Test>::ParaIdAlreadyRegistered
+            );
+        });
+    }
+}
+
+mod pause_window {
+    use {super::*, tp_traits::PauseWindow};
+
+    fn register_and_enable_para_42() {
+        assert_ok!(ParaRegistrar::register(
+            RuntimeOrigin::signed(ALICE),
+            42.into(),
+            empty_genesis_data()
+        ));
+        assert_ok!(ParaRegistrar::mark_valid_for_collating(
+            RuntimeOrigin::root(),
+            42.into(),
+        ));
+        run_to_session(2);
+        assert_eq!(ParaRegistrar::registered_para_ids(), vec![42.into()]);
+    }
+
+    #[test]
+    fn pause_window_pauses_and_unpauses_container_chain() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            register_and_enable_para_42();
+
+            assert_ok!(ParaRegistrar::schedule_pause_window(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                5,
+                7,
+            ));
+            System::assert_last_event(
+                Event::PauseWindowScheduled {
+                    para_id: 42.into(),
+                    start: 5,
+                    end: 7,
+                }
+                .into(),
+            );
+            assert_eq!(
+                ParaRegistrar::pause_window(42.into()),
+                Some(PauseWindow { start: 5, end: 7 })
+            );
+
+            // The pause is scheduled `SessionDelay` sessions before the window starts
+            run_to_session(3);
+            assert_eq!(
+                ParaRegistrar::pending_paused(),
+                vec![(5u32, BoundedVec::try_from(vec![42u32.into()]).unwrap())]
+            );
+            run_to_session(4);
+            assert_eq!(ParaRegistrar::registered_para_ids(), vec![42.into()]);
+
+            run_to_session(5);
+            assert_eq!(ParaRegistrar::registered_para_ids(), vec![]);
+            assert_eq!(ParaRegistrar::paused(), vec![42.into()]);
+            // The unpause has been scheduled, so the window is no longer needed
+            assert_eq!(ParaRegistrar::pause_window(42.into()), None);
+
+            run_to_session(6);
+            assert_eq!(ParaRegistrar::registered_para_ids(), vec![]);
+
+            run_to_session(7);
+            assert_eq!(ParaRegistrar::registered_para_ids(), vec![42.into()]);
+            assert_eq!(ParaRegistrar::paused(), vec![]);
+        });
+    }
+
+    #[test]
+    fn registrar_origin_can_schedule_pause_window() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            register_and_enable_para_42();
+
+            assert_ok!(ParaRegistrar::schedule_pause_window(
+                RuntimeOrigin::root(),
+                42.into(),
+                5,
+                7,
+            ));
+        });
+    }
+
+    #[test]
+    fn cannot_schedule_pause_window_if_not_para_manager() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            register_and_enable_para_42();
+
+            assert_noop!(
+                ParaRegistrar::schedule_pause_window(RuntimeOrigin::signed(BOB), 42.into(), 5, 7),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_schedule_pause_window_for_unregistered_para() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+
+            assert_noop!(
+                ParaRegistrar::schedule_pause_window(RuntimeOrigin::root(), 42.into(), 5, 7),
+                Error::<Test>::ParaIdNotRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_schedule_invalid_pause_window() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            register_and_enable_para_42();
+
+            assert_noop!(
+                ParaRegistrar::schedule_pause_window(RuntimeOrigin::signed(ALICE), 42.into(), 5, 5),
+                Error::<Test>::InvalidPauseWindow
+            );
+            assert_noop!(
+                ParaRegistrar::schedule_pause_window(RuntimeOrigin::signed(ALICE), 42.into(), 6, 5),
+                Error::<Test>::InvalidPauseWindow
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_schedule_pause_window_too_soon() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            register_and_enable_para_42();
+
+            // Current session is 2, so changes scheduled now apply at session 4
+            assert_noop!(
+                ParaRegistrar::schedule_pause_window(RuntimeOrigin::signed(ALICE), 42.into(), 4, 7),
+                Error::<Test>::PauseWindowTooSoon
+            );
+            assert_ok!(ParaRegistrar::schedule_pause_window(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                5,
+                7
+            ));
+        });
+    }
+
+    #[test]
+    fn can_replace_pause_window_before_it_starts() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            register_and_enable_para_42();
+
+            assert_ok!(ParaRegistrar::schedule_pause_window(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                6,
+                8,
+            ));
+            run_to_session(3);
+            assert_ok!(ParaRegistrar::schedule_pause_window(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                10,
+                12,
+            ));
+            assert_eq!(
+                ParaRegistrar::pause_window(42.into()),
+                Some(PauseWindow { start: 10, end: 12 })
+            );
+
+            // The old window is not applied
+            run_to_session(6);
+            assert_eq!(ParaRegistrar::registered_para_ids(), vec![42.into()]);
+        });
+    }
+
+    #[test]
+    fn cannot_replace_pause_window_after_it_starts() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            register_and_enable_para_42();
+
+            assert_ok!(ParaRegistrar::schedule_pause_window(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                5,
+                8,
+            ));
+            run_to_session(3);
+
+            assert_noop!(
+                ParaRegistrar::schedule_pause_window(
+                    RuntimeOrigin::signed(ALICE),
+                    42.into(),
+                    10,
+                    12
+                ),
+                Error::<Test>::PauseWindowAlreadyStarted
+            );
+        });
+    }
+
+    #[test]
+    fn pause_window_is_dropped_if_container_chain_is_already_paused() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            register_and_enable_para_42();
+
+            assert_ok!(ParaRegistrar::schedule_pause_window(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                5,
+                7,
+            ));
+            assert_ok!(ParaRegistrar::pause_container_chain(
+                RuntimeOrigin::root(),
+                42.into(),
+            ));
+
+            run_to_session(3);
+            assert_eq!(ParaRegistrar::pause_window(42.into()), None);
+
+            // The manual pause is not undone when the window would have ended
+            run_to_session(7);
+            assert_eq!(ParaRegistrar::registered_para_ids(), vec![]);
+            assert_eq!(ParaRegistrar::paused(), vec![42.into()]);
+        });
+    }
+
+    #[test]
+    fn pause_window_removed_on_deregister() {
+        new_test_ext().execute_with(|| {
+            run_to_block(1);
+            register_and_enable_para_42();
+
+            assert_ok!(ParaRegistrar::schedule_pause_window(
+                RuntimeOrigin::signed(ALICE),
+                42.into(),
+                5,
+                7,
+            ));
+            assert_ok!(ParaRegistrar::deregister(RuntimeOrigin::root(), 42.into()));
+
+            run_to_session(4);
+            assert_eq!(ParaRegistrar::pause_window(42.into()), None);
+        });
+    }
+}
@@ -65,6 +65,11 @@ pub trait WeightInfo {
 	fn register_parathread(x: u32, z: u32, ) -> Weight;
 	fn set_parathread_params() -> Weight;
 	fn set_para_manager() -> Weight;
+	fn propose_para_ownership_transfer() -> Weight;
+	fn accept_para_ownership_transfer() -> Weight;
+	fn update_para_code_with_relay_proof() -> Weight;
+	fn register_with_xcm(x: u32, z: u32, ) -> Weight;
+	fn schedule_pause_window() -> Weight;
 }
 
 /// Weights for pallet_registrar using the Substrate node and recommended hardware.
@@ -364,6 +369,93 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Registrar::RegistrarDeposit` (r:1 w:0)
+	/// Proof: `Registrar::RegistrarDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::PendingParaOwner` (r:0 w:1)
+	/// Proof: `Registrar::PendingParaOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_para_ownership_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `237`
+		//  Estimated: `3702`
+		// Minimum execution time: 14_102_000 picoseconds.
+		Weight::from_parts(14_102_000, 3702)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Registrar::PendingParaOwner` (r:1 w:1)
+	/// Proof: `Registrar::PendingParaOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::RegistrarDeposit` (r:1 w:1)
+	/// Proof: `Registrar::RegistrarDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Registrar::ParaManager` (r:0 w:1)
+	/// Proof: `Registrar::ParaManager` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_para_ownership_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_338_000 picoseconds.
+		Weight::from_parts(61_338_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Registrar::ParaGenesisData` (r:1 w:0)
+	/// Proof: `Registrar::ParaGenesisData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::ParaCodeInfo` (r:1 w:1)
+	/// Proof: `Registrar::ParaCodeInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `RelayStorageRoots::RelayStorageRoot` (r:1 w:0)
+	/// Proof: `RelayStorageRoots::RelayStorageRoot` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn update_para_code_with_relay_proof() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1325`
+		//  Estimated: `4790`
+		// Minimum execution time: 52_611_000 picoseconds.
+		Weight::from_parts(52_611_000, 4790)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Registrar::ParaGenesisData` (r:1 w:1)
+	/// Proof: `Registrar::ParaGenesisData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::PendingVerification` (r:1 w:1)
+	/// Proof: `Registrar::PendingVerification` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::RegistrarDeposit` (r:0 w:1)
+	/// Proof: `Registrar::RegistrarDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::ParaManager` (r:0 w:1)
+	/// Proof: `Registrar::ParaManager` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[100, 3000000]`.
+	/// The range of component `z` is `[1, 10]`.
+	fn register_with_xcm(x: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1512`
+		//  Estimated: `4977`
+		// Minimum execution time: 46_213_000 picoseconds.
+		Weight::from_parts(65_102_417, 4977)
+			// Standard Error: 13
+			.saturating_add(Weight::from_parts(1_029, 0).saturating_mul(x.into()))
+			// Standard Error: 4_195_327
+			.saturating_add(Weight::from_parts(2_184_512, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Registrar::ParaManager` (r:1 w:0)
+	/// Proof: `Registrar::ParaManager` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::ParaGenesisData` (r:1 w:0)
+	/// Proof: `Registrar::ParaGenesisData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::PauseWindows` (r:1 w:1)
+	/// Proof: `Registrar::PauseWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_pause_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3814`
+		// Minimum execution time: 19_583_000 picoseconds.
+		Weight::from_parts(19_583_000, 3814)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -662,4 +754,91 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Registrar::RegistrarDeposit` (r:1 w:0)
+	/// Proof: `Registrar::RegistrarDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::PendingParaOwner` (r:0 w:1)
+	/// Proof: `Registrar::PendingParaOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_para_ownership_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `237`
+		//  Estimated: `3702`
+		// Minimum execution time: 14_102_000 picoseconds.
+		Weight::from_parts(14_102_000, 3702)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Registrar::PendingParaOwner` (r:1 w:1)
+	/// Proof: `Registrar::PendingParaOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::RegistrarDeposit` (r:1 w:1)
+	/// Proof: `Registrar::RegistrarDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Registrar::ParaManager` (r:0 w:1)
+	/// Proof: `Registrar::ParaManager` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_para_ownership_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_338_000 picoseconds.
+		Weight::from_parts(61_338_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Registrar::ParaGenesisData` (r:1 w:0)
+	/// Proof: `Registrar::ParaGenesisData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::ParaCodeInfo` (r:1 w:1)
+	/// Proof: `Registrar::ParaCodeInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `RelayStorageRoots::RelayStorageRoot` (r:1 w:0)
+	/// Proof: `RelayStorageRoots::RelayStorageRoot` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn update_para_code_with_relay_proof() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1325`
+		//  Estimated: `4790`
+		// Minimum execution time: 52_611_000 picoseconds.
+		Weight::from_parts(52_611_000, 4790)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Registrar::ParaGenesisData` (r:1 w:1)
+	/// Proof: `Registrar::ParaGenesisData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::PendingVerification` (r:1 w:1)
+	/// Proof: `Registrar::PendingVerification` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::RegistrarDeposit` (r:0 w:1)
+	/// Proof: `Registrar::RegistrarDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::ParaManager` (r:0 w:1)
+	/// Proof: `Registrar::ParaManager` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[100, 3000000]`.
+	/// The range of component `z` is `[1, 10]`.
+	fn register_with_xcm(x: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1512`
+		//  Estimated: `4977`
+		// Minimum execution time: 46_213_000 picoseconds.
+		Weight::from_parts(65_102_417, 4977)
+			// Standard Error: 13
+			.saturating_add(Weight::from_parts(1_029, 0).saturating_mul(x.into()))
+			// Standard Error: 4_195_327
+			.saturating_add(Weight::from_parts(2_184_512, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Registrar::ParaManager` (r:1 w:0)
+	/// Proof: `Registrar::ParaManager` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::ParaGenesisData` (r:1 w:0)
+	/// Proof: `Registrar::ParaGenesisData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::PauseWindows` (r:1 w:1)
+	/// Proof: `Registrar::PauseWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_pause_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3814`
+		// Minimum execution time: 19_583_000 picoseconds.
+		Weight::from_parts(19_583_000, 3814)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
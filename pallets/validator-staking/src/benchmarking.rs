@@ -0,0 +1,238 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Benchmarking setup for pallet-validator-staking
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ValidatorStaking;
+use {
+    frame_benchmarking::{account, v2::*, BenchmarkError},
+    frame_support::{
+        pallet_prelude::*,
+        traits::{fungible::Mutate, EnsureOrigin, Get},
+    },
+    frame_system::{EventRecord, RawOrigin},
+    pallet_session::{self as session, SessionManager},
+    sp_runtime::traits::TrailingZeroInput,
+    sp_std::prelude::*,
+};
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+/// Create a funded account with session keys registered.
+fn validator<T: Config>(string: &'static str, n: u32) -> T::AccountId
+where
+    T::Currency: Mutate<T::AccountId>,
+{
+    let who: T::AccountId = account(string, n, SEED);
+    T::Currency::set_balance(&who, T::MinimumBond::get() * 1000u32.into());
+
+    // Derive unique keys from the account id
+    let keys =
+        <T as session::Config>::Keys::decode(&mut TrailingZeroInput::new(&who.encode().repeat(8)))
+            .expect("decoding from trailing zero input never fails");
+    <session::Pallet<T>>::set_keys(RawOrigin::Signed(who.clone()).into(), keys, Vec::new())
+        .unwrap();
+
+    who
+}
+
+/// Fill the candidate list with `count` candidates with increasing bonds.
+fn candidates<T: Config>(count: u32) -> Vec<T::AccountId>
+where
+    T::Currency: Mutate<T::AccountId>,
+{
+    (0..count)
+        .map(|c| {
+            let who = validator::<T>("candidate", c);
+            ValidatorStaking::<T>::bond(
+                RawOrigin::Signed(who.clone()).into(),
+                T::MinimumBond::get() + c.into(),
+            )
+            .expect("bond failed");
+            who
+        })
+        .collect()
+}
+
+/// Fill the whitelist with `count` validators.
+fn whitelisted<T: Config>(count: u32) -> Vec<T::AccountId>
+where
+    T::Currency: Mutate<T::AccountId>,
+{
+    let whitelisted: Vec<_> = (0..count)
+        .map(|c| validator::<T>("whitelisted", c))
+        .collect();
+    let bounded: BoundedVec<_, T::MaxWhitelistedValidators> =
+        BoundedVec::try_from(whitelisted.clone()).unwrap();
+    <WhitelistedValidators<T>>::put(bounded);
+
+    whitelisted
+}
+
+#[benchmarks(where T::Currency: Mutate<T::AccountId>)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn bond(c: Linear<0, { T::MaxCandidates::get() - 1 }>) -> Result<(), BenchmarkError> {
+        candidates::<T>(c);
+
+        // The new candidate has the lowest bond, so it is inserted at the end of the list
+        let caller = validator::<T>("caller", 0);
+        let amount = T::MinimumBond::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), amount);
+
+        assert_last_event::<T>(
+            Event::Bonded {
+                who: caller,
+                amount,
+                total_bond: amount,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unbond(c: Linear<1, { T::MaxCandidates::get() }>) -> Result<(), BenchmarkError> {
+        let candidates = candidates::<T>(c);
+        // The first candidate has the lowest bond, so it is at the end of the list
+        let caller = candidates.first().unwrap().clone();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(Unbonding::<T>::contains_key(&caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn withdraw_unbonded() -> Result<(), BenchmarkError> {
+        let caller = candidates::<T>(1)[0].clone();
+        ValidatorStaking::<T>::unbond(RawOrigin::Signed(caller.clone()).into())
+            .expect("unbond failed");
+        Unbonding::<T>::mutate(&caller, |request| {
+            if let Some(request) = request {
+                request.unlock_session = 0;
+            }
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_last_event::<T>(
+            Event::Withdrawn {
+                who: caller,
+                amount: T::MinimumBond::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_whitelisted(
+        b: Linear<1, { T::MaxWhitelistedValidators::get() - 1 }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        whitelisted::<T>(b);
+        let new_whitelisted = validator::<T>("new_whitelisted", 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, new_whitelisted.clone());
+
+        assert_last_event::<T>(
+            Event::WhitelistedValidatorAdded {
+                who: new_whitelisted,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_whitelisted(
+        b: Linear<1, { T::MaxWhitelistedValidators::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        let to_remove = whitelisted::<T>(b).last().unwrap().clone();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, to_remove.clone());
+
+        assert_last_event::<T>(Event::WhitelistedValidatorRemoved { who: to_remove }.into());
+        Ok(())
+    }
+
+    // worst case for new session.
+    #[benchmark]
+    fn new_session(c: Linear<1, { T::MaxCandidates::get() }>) -> Result<(), BenchmarkError> {
+        whitelisted::<T>(T::MaxWhitelistedValidators::get());
+        candidates::<T>(c);
+
+        #[block]
+        {
+            <ValidatorStaking<T> as SessionManager<_>>::new_session(0);
+        }
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_offence(c: Linear<1, { T::MaxCandidates::get() }>) -> Result<(), BenchmarkError> {
+        let offender = candidates::<T>(c).first().unwrap().clone();
+
+        #[block]
+        {
+            <ValidatorStaking<T> as OnOffenceHandler<_, (T::AccountId, ()), Weight>>::on_offence(
+                &[OffenceDetails {
+                    offender: (offender.clone(), ()),
+                    reporters: Vec::new(),
+                }],
+                &[Perbill::from_percent(10)],
+                0,
+                DisableStrategy::Always,
+            );
+        }
+
+        assert!(Unbonding::<T>::contains_key(&offender));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        ValidatorStaking,
+        crate::mock::new_test_ext(),
+        crate::mock::Test,
+    );
+}
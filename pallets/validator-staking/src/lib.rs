@@ -0,0 +1,577 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Validator staking pallet.
+//!
+//! A pallet that elects the validator set of a solochain at every session change.
+//!
+//! ## Terminology
+//!
+//! - Whitelisted validator: An account appointed by governance and always elected first.
+//! - Candidate: An account that bonded funds to be elected as a validator.
+//!
+//! Whitelisted validators are elected first, and the remaining `MaxValidators` slots are filled
+//! with the candidates with the highest bond. Accounts without session keys are skipped.
+//!
+//! Offences reported by `pallet_offences` remove the offender from the candidate list, start the
+//! unbonding of its funds and burn the slashed fraction of them. Unbonding funds stay slashable
+//! until `UnbondingDelay` sessions have passed and they are withdrawn.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+use {
+    frame_support::{
+        pallet_prelude::Weight,
+        traits::{
+            fungible::{Inspect, MutateHold},
+            tokens::{Fortitude, Precision},
+        },
+    },
+    sp_runtime::{traits::Zero, Perbill},
+    sp_staking::{
+        offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
+        SessionIndex,
+    },
+    sp_std::vec::Vec,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+    pub use crate::weights::WeightInfo;
+
+    use {
+        super::*,
+        frame_support::{
+            pallet_prelude::*,
+            traits::{EnsureOrigin, ValidatorRegistration},
+            BoundedVec, DefaultNoBound,
+        },
+        frame_system::pallet_prelude::*,
+        sp_runtime::{traits::CheckedAdd, ArithmeticError},
+    };
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+    /// A reason for this pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        ValidatorBond,
+    }
+
+    /// A candidate and the amount it bonded.
+    #[derive(RuntimeDebug, PartialEq, Eq, Encode, Decode, Clone, TypeInfo, MaxEncodedLen)]
+    pub struct CandidateBond<AccountId, Balance> {
+        pub who: AccountId,
+        pub bond: Balance,
+    }
+
+    /// Funds of a former candidate that can be withdrawn once `unlock_session` starts.
+    #[derive(RuntimeDebug, PartialEq, Eq, Encode, Decode, Clone, TypeInfo, MaxEncodedLen)]
+    pub struct UnbondingRequest<Balance> {
+        pub amount: Balance,
+        pub unlock_session: SessionIndex,
+    }
+
+    #[pallet::config]
+    pub trait Config:
+        frame_system::Config
+        + pallet_session::Config<ValidatorId = <Self as frame_system::Config>::AccountId>
+    {
+        /// Overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency used for bonds.
+        type Currency: Inspect<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// The overarching runtime hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Origin that can add or remove whitelisted validators.
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of whitelisted validators.
+        #[pallet::constant]
+        type MaxWhitelistedValidators: Get<u32>;
+
+        /// Maximum number of candidates that can bond at the same time.
+        #[pallet::constant]
+        type MaxCandidates: Get<u32>;
+
+        /// Maximum number of validators elected for a session.
+        #[pallet::constant]
+        type MaxValidators: Get<u32>;
+
+        /// Minimum amount a candidate must bond.
+        #[pallet::constant]
+        type MinimumBond: Get<BalanceOf<Self>>;
+
+        /// Number of sessions unbonding funds remain slashable before they can be withdrawn.
+        #[pallet::constant]
+        type UnbondingDelay: Get<SessionIndex>;
+
+        /// The weight information of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    /// Validators appointed by governance, elected before any candidate.
+    #[pallet::storage]
+    pub type WhitelistedValidators<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxWhitelistedValidators>, ValueQuery>;
+
+    /// Candidates that bonded funds, sorted by bond in descending order.
+    #[pallet::storage]
+    pub type Candidates<T: Config> = StorageValue<
+        _,
+        BoundedVec<CandidateBond<T::AccountId, BalanceOf<T>>, T::MaxCandidates>,
+        ValueQuery,
+    >;
+
+    /// Funds of former candidates waiting for the unbonding delay to pass.
+    #[pallet::storage]
+    pub type Unbonding<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, UnbondingRequest<BalanceOf<T>>, OptionQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        pub whitelisted_validators: Vec<T::AccountId>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            let duplicate_validators = self
+                .whitelisted_validators
+                .iter()
+                .collect::<sp_std::collections::btree_set::BTreeSet<_>>();
+            assert!(
+                duplicate_validators.len() == self.whitelisted_validators.len(),
+                "duplicate whitelisted validators in genesis."
+            );
+
+            let bounded_validators = BoundedVec::<_, T::MaxWhitelistedValidators>::try_from(
+                self.whitelisted_validators.clone(),
+            )
+            .expect("genesis whitelisted validators are more than T::MaxWhitelistedValidators");
+
+            <WhitelistedValidators<T>>::put(bounded_validators);
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A candidate bonded funds.
+        Bonded {
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+            total_bond: BalanceOf<T>,
+        },
+        /// A candidate stopped being a candidate and its bond started unbonding.
+        Unbonding {
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+            unlock_session: SessionIndex,
+        },
+        /// Unbonded funds were released.
+        Withdrawn {
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Bonded funds of an offender were burned.
+        Slashed {
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A validator was added to the whitelist.
+        WhitelistedValidatorAdded { who: T::AccountId },
+        /// A validator was removed from the whitelist.
+        WhitelistedValidatorRemoved { who: T::AccountId },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account does not have keys registered.
+        NoKeysRegistered,
+        /// Bond would be lower than `MinimumBond`.
+        BondBelowMinimum,
+        /// There are too many candidates.
+        TooManyCandidates,
+        /// Account is not a candidate.
+        NotCandidate,
+        /// Account has unbonding funds, they must be withdrawn before bonding again.
+        UnbondingInProgress,
+        /// Account does not have unbonding funds.
+        NoUnbondingFunds,
+        /// The unbonding delay has not passed yet.
+        UnbondingDelayNotPassed,
+        /// There are too many whitelisted validators.
+        TooManyWhitelistedValidators,
+        /// Account is already whitelisted.
+        AlreadyWhitelisted,
+        /// Account is not whitelisted.
+        NotWhitelisted,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Bond `amount` to become a candidate, or increase the bond of an existing candidate.
+        ///
+        /// The account must have session keys registered and the total bond must reach
+        /// `MinimumBond`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::bond(T::MaxCandidates::get()))]
+        pub fn bond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                <pallet_session::Pallet<T> as ValidatorRegistration<_>>::is_registered(&who),
+                Error::<T>::NoKeysRegistered
+            );
+            ensure!(
+                !Unbonding::<T>::contains_key(&who),
+                Error::<T>::UnbondingInProgress
+            );
+
+            let total_bond = Candidates::<T>::try_mutate(|candidates| {
+                let previous_bond = match candidates.iter().position(|c| c.who == who) {
+                    Some(pos) => candidates.remove(pos).bond,
+                    None => Zero::zero(),
+                };
+                let total_bond = previous_bond
+                    .checked_add(&amount)
+                    .ok_or(ArithmeticError::Overflow)?;
+                ensure!(
+                    total_bond >= T::MinimumBond::get(),
+                    Error::<T>::BondBelowMinimum
+                );
+
+                // Candidates with the same bond keep the order in which they reached it.
+                let pos = candidates
+                    .iter()
+                    .position(|c| c.bond < total_bond)
+                    .unwrap_or(candidates.len());
+                candidates
+                    .try_insert(
+                        pos,
+                        CandidateBond {
+                            who: who.clone(),
+                            bond: total_bond,
+                        },
+                    )
+                    .map_err(|_| Error::<T>::TooManyCandidates)?;
+
+                T::Currency::hold(&HoldReason::ValidatorBond.into(), &who, amount)?;
+
+                Ok::<_, DispatchError>(total_bond)
+            })?;
+
+            Self::deposit_event(Event::Bonded {
+                who,
+                amount,
+                total_bond,
+            });
+            Ok(())
+        }
+
+        /// Stop being a candidate. The whole bond can be withdrawn after `UnbondingDelay`
+        /// sessions.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::unbond(T::MaxCandidates::get()))]
+        pub fn unbond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let bond = Self::remove_candidate(&who).ok_or(Error::<T>::NotCandidate)?;
+            Self::start_unbonding(&who, bond);
+
+            Ok(())
+        }
+
+        /// Release the unbonded funds of the caller once the unbonding delay has passed.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonded())]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let request = Unbonding::<T>::get(&who).ok_or(Error::<T>::NoUnbondingFunds)?;
+            ensure!(
+                pallet_session::Pallet::<T>::current_index() >= request.unlock_session,
+                Error::<T>::UnbondingDelayNotPassed
+            );
+            Unbonding::<T>::remove(&who);
+
+            let amount = T::Currency::release(
+                &HoldReason::ValidatorBond.into(),
+                &who,
+                request.amount,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::Withdrawn { who, amount });
+            Ok(())
+        }
+
+        /// Add `who` to the whitelisted validators.
+        ///
+        /// The origin for this call must be the `UpdateOrigin`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::add_whitelisted(
+			T::MaxWhitelistedValidators::get().saturating_sub(1),
+		))]
+        pub fn add_whitelisted(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            // don't let one unprepared validator ruin things for everyone.
+            ensure!(
+                <pallet_session::Pallet<T> as ValidatorRegistration<_>>::is_registered(&who),
+                Error::<T>::NoKeysRegistered
+            );
+
+            <WhitelistedValidators<T>>::try_mutate(|validators| -> DispatchResult {
+                ensure!(!validators.contains(&who), Error::<T>::AlreadyWhitelisted);
+                validators
+                    .try_push(who.clone())
+                    .map_err(|_| Error::<T>::TooManyWhitelistedValidators)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::WhitelistedValidatorAdded { who });
+            Ok(())
+        }
+
+        /// Remove `who` from the whitelisted validators.
+        ///
+        /// The origin for this call must be the `UpdateOrigin`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_whitelisted(
+			T::MaxWhitelistedValidators::get(),
+		))]
+        pub fn remove_whitelisted(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            <WhitelistedValidators<T>>::try_mutate(|validators| -> DispatchResult {
+                let pos = validators
+                    .iter()
+                    .position(|x| x == &who)
+                    .ok_or(Error::<T>::NotWhitelisted)?;
+                validators.remove(pos);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::WhitelistedValidatorRemoved { who });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        pub fn whitelisted_validators() -> BoundedVec<T::AccountId, T::MaxWhitelistedValidators> {
+            WhitelistedValidators::<T>::get()
+        }
+
+        pub fn candidates(
+        ) -> BoundedVec<CandidateBond<T::AccountId, BalanceOf<T>>, T::MaxCandidates> {
+            Candidates::<T>::get()
+        }
+
+        /// Whitelisted validators first, then candidates by bond, skipping accounts without
+        /// session keys, up to `MaxValidators`.
+        pub fn elect_validators() -> Vec<T::AccountId> {
+            let max_validators = T::MaxValidators::get() as usize;
+            let mut elected = Vec::new();
+
+            let whitelisted = WhitelistedValidators::<T>::get().into_iter();
+            let candidates = Candidates::<T>::get().into_iter().map(|c| c.who);
+            for who in whitelisted.chain(candidates) {
+                if elected.len() >= max_validators {
+                    break;
+                }
+                if !elected.contains(&who)
+                    && <pallet_session::Pallet<T> as ValidatorRegistration<_>>::is_registered(&who)
+                {
+                    elected.push(who);
+                }
+            }
+
+            elected
+        }
+
+        /// Remove `who` from the candidates, returning its bond.
+        pub(crate) fn remove_candidate(who: &T::AccountId) -> Option<BalanceOf<T>> {
+            Candidates::<T>::mutate(|candidates| {
+                let pos = candidates.iter().position(|c| &c.who == who)?;
+                Some(candidates.remove(pos).bond)
+            })
+        }
+
+        /// Move `amount` of held funds of `who` to the unbonding queue.
+        pub(crate) fn start_unbonding(who: &T::AccountId, amount: BalanceOf<T>) {
+            let unlock_session = pallet_session::Pallet::<T>::current_index()
+                .saturating_add(T::UnbondingDelay::get());
+            let request = Unbonding::<T>::mutate(who, |maybe_request| {
+                let request = maybe_request.get_or_insert(UnbondingRequest {
+                    amount: Zero::zero(),
+                    unlock_session,
+                });
+                request.amount = request.amount.saturating_add(amount);
+                request.unlock_session = unlock_session;
+                request.clone()
+            });
+
+            Self::deposit_event(Event::Unbonding {
+                who: who.clone(),
+                amount: request.amount,
+                unlock_session: request.unlock_session,
+            });
+        }
+
+        /// Remove an offender from the candidates and burn `slash_fraction` of its bond.
+        pub(crate) fn slash_offender(who: &T::AccountId, slash_fraction: Perbill) {
+            if let Some(bond) = Self::remove_candidate(who) {
+                Self::start_unbonding(who, bond);
+            }
+
+            if slash_fraction.is_zero() {
+                return;
+            }
+
+            Unbonding::<T>::mutate(who, |maybe_request| {
+                let Some(request) = maybe_request else {
+                    return;
+                };
+                let to_slash = slash_fraction * request.amount;
+                let slashed = T::Currency::burn_held(
+                    &HoldReason::ValidatorBond.into(),
+                    who,
+                    to_slash,
+                    Precision::BestEffort,
+                    Fortitude::Force,
+                )
+                .unwrap_or_else(|e| {
+                    log::warn!("Failed to slash validator bond: {:?}", e);
+                    Zero::zero()
+                });
+
+                if !slashed.is_zero() {
+                    request.amount = request.amount.saturating_sub(slashed);
+                    Self::deposit_event(Event::Slashed {
+                        who: who.clone(),
+                        amount: slashed,
+                    });
+                }
+            });
+        }
+    }
+
+    /// Play the role of the session manager.
+    impl<T: Config> pallet_session::SessionManager<T::AccountId> for Pallet<T> {
+        fn new_session(index: SessionIndex) -> Option<Vec<T::AccountId>> {
+            log::info!(
+                "electing new validators for new session {} at #{:?}",
+                index,
+                <frame_system::Pallet<T>>::block_number(),
+            );
+
+            let validators = Self::elect_validators();
+            frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                <T as Config>::WeightInfo::new_session(
+                    Candidates::<T>::decode_len().unwrap_or_default() as u32,
+                ),
+                DispatchClass::Mandatory,
+            );
+
+            // Keep the current validators rather than stalling the chain.
+            if validators.is_empty() {
+                log::warn!("no validators elected for session {}", index);
+                return None;
+            }
+
+            Some(validators)
+        }
+        fn start_session(_: SessionIndex) {
+            // we don't care.
+        }
+        fn end_session(_: SessionIndex) {
+            // we don't care.
+        }
+    }
+
+    impl<T: Config> pallet_session::historical::SessionManager<T::AccountId, ()> for Pallet<T> {
+        fn new_session(new_index: SessionIndex) -> Option<Vec<(T::AccountId, ())>> {
+            <Self as pallet_session::SessionManager<_>>::new_session(new_index)
+                .map(|r| r.into_iter().map(|v| (v, Default::default())).collect())
+        }
+
+        fn start_session(start_index: SessionIndex) {
+            <Self as pallet_session::SessionManager<_>>::start_session(start_index)
+        }
+
+        fn end_session(end_index: SessionIndex) {
+            <Self as pallet_session::SessionManager<_>>::end_session(end_index)
+        }
+    }
+}
+
+impl<T: Config, FullIdentification>
+    OnOffenceHandler<T::AccountId, (T::AccountId, FullIdentification), Weight> for Pallet<T>
+{
+    fn on_offence(
+        offenders: &[OffenceDetails<T::AccountId, (T::AccountId, FullIdentification)>],
+        slash_fraction: &[Perbill],
+        _session: SessionIndex,
+        disable_strategy: DisableStrategy,
+    ) -> Weight {
+        let mut total_weight = Weight::zero();
+
+        for (details, slash_fraction) in offenders.iter().zip(slash_fraction) {
+            let (who, _) = &details.offender;
+            Self::slash_offender(who, *slash_fraction);
+
+            let disable = match disable_strategy {
+                DisableStrategy::Never => false,
+                DisableStrategy::WhenSlashed => !slash_fraction.is_zero(),
+                DisableStrategy::Always => true,
+            };
+            if disable {
+                pallet_session::Pallet::<T>::disable(who);
+            }
+
+            total_weight = total_weight.saturating_add(<T as Config>::WeightInfo::on_offence(
+                T::MaxCandidates::get(),
+            ));
+        }
+
+        total_weight
+    }
+}
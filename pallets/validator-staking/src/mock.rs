@@ -0,0 +1,215 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    super::*,
+    crate as validator_staking,
+    frame_support::{
+        ord_parameter_types, parameter_types,
+        traits::{ConstU32, Everything},
+    },
+    frame_system::{self as system, EnsureSignedBy},
+    pallet_balances::AccountData,
+    sp_core::H256,
+    sp_runtime::{
+        testing::UintAuthorityId,
+        traits::{BlakeTwo256, ConvertInto, IdentityLookup, OpaqueKeys},
+        BuildStorage, RuntimeAppPublic,
+    },
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        Session: pallet_session,
+        ValidatorStaking: validator_staking,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type Nonce = u64;
+    type Block = Block;
+    type RuntimeTask = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 5;
+    pub const MaxReserves: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Balance = u64;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type ReserveIdentifier = [u8; 8];
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type FreezeIdentifier = ();
+    type MaxLocks = ();
+    type MaxReserves = MaxReserves;
+    type MaxFreezes = ConstU32<0>;
+}
+
+ord_parameter_types! {
+    pub const RootAccount: u64 = 777;
+}
+
+parameter_types! {
+    pub const MinimumBond: u64 = 100;
+    pub const UnbondingDelay: u32 = 2;
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type UpdateOrigin = EnsureSignedBy<RootAccount, u64>;
+    type MaxWhitelistedValidators = ConstU32<20>;
+    type MaxCandidates = ConstU32<3>;
+    type MaxValidators = ConstU32<4>;
+    type MinimumBond = MinimumBond;
+    type UnbondingDelay = UnbondingDelay;
+    type WeightInfo = ();
+}
+
+sp_runtime::impl_opaque_keys! {
+    pub struct MockSessionKeys {
+        // a key for babe authoring
+        pub babe: UintAuthorityId,
+    }
+}
+
+impl From<UintAuthorityId> for MockSessionKeys {
+    fn from(babe: sp_runtime::testing::UintAuthorityId) -> Self {
+        Self { babe }
+    }
+}
+
+pub struct TestSessionHandler;
+impl pallet_session::SessionHandler<u64> for TestSessionHandler {
+    const KEY_TYPE_IDS: &'static [sp_runtime::KeyTypeId] = &[UintAuthorityId::ID];
+    fn on_genesis_session<Ks: OpaqueKeys>(_: &[(u64, Ks)]) {}
+    fn on_new_session<Ks: OpaqueKeys>(_: bool, _: &[(u64, Ks)], _: &[(u64, Ks)]) {}
+    fn on_before_session_ending() {}
+    fn on_disabled(_: u32) {}
+}
+
+parameter_types! {
+    pub const Offset: u64 = 0;
+    pub const Period: u64 = 10;
+}
+
+impl pallet_session::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = <Self as frame_system::Config>::AccountId;
+    // we don't have stash and controller, thus we don't need the convert as well.
+    type ValidatorIdOf = ConvertInto;
+    type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+    type SessionManager = ValidatorStaking;
+    type SessionHandler = TestSessionHandler;
+    type Keys = MockSessionKeys;
+    type WeightInfo = ();
+}
+
+/// Account that has funds but no session keys.
+pub const NO_KEYS: u64 = 6;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let whitelisted_validators = vec![1, 2];
+
+    let balances = vec![
+        (1, 1000),
+        (2, 1000),
+        (3, 1000),
+        (4, 1000),
+        (5, 1000),
+        (6, 1000),
+    ];
+    let keys = balances
+        .iter()
+        .filter(|&&(i, _)| i != NO_KEYS)
+        .map(|&(i, _)| {
+            (
+                i,
+                i,
+                MockSessionKeys {
+                    babe: UintAuthorityId(i),
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    let session = pallet_session::GenesisConfig::<Test> { keys };
+    pallet_balances::GenesisConfig::<Test> { balances }
+        .assimilate_storage(&mut t)
+        .unwrap();
+    validator_staking::GenesisConfig::<Test> {
+        whitelisted_validators,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    session.assimilate_storage(&mut t).unwrap();
+
+    t.into()
+}
+
+pub fn initialize_to_block(n: u64) {
+    for i in System::block_number() + 1..=n {
+        System::set_block_number(i);
+        <AllPalletsWithSystem as frame_support::traits::OnInitialize<u64>>::on_initialize(i);
+    }
+}
@@ -0,0 +1,406 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    crate::{
+        mock::{
+            initialize_to_block, new_test_ext, Balances, RootAccount, RuntimeEvent, RuntimeOrigin,
+            Session, System, Test, ValidatorStaking, NO_KEYS,
+        },
+        CandidateBond, Error, HoldReason, Unbonding, UnbondingRequest,
+    },
+    frame_support::{
+        assert_noop, assert_ok,
+        traits::fungible::{Inspect, InspectHold},
+        weights::Weight,
+    },
+    sp_runtime::{traits::BadOrigin, Perbill},
+    sp_staking::offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
+};
+
+fn bonded(who: u64) -> u64 {
+    Balances::balance_on_hold(&HoldReason::ValidatorBond.into(), &who)
+}
+
+fn report_offence(offender: u64, slash_fraction: Perbill, disable_strategy: DisableStrategy) {
+    <ValidatorStaking as OnOffenceHandler<u64, (u64, ()), Weight>>::on_offence(
+        &[OffenceDetails {
+            offender: (offender, ()),
+            reporters: vec![],
+        }],
+        &[slash_fraction],
+        Session::current_index(),
+        disable_strategy,
+    );
+}
+
+#[test]
+fn basic_setup_works() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(ValidatorStaking::whitelisted_validators(), vec![1, 2]);
+        assert_eq!(ValidatorStaking::candidates(), vec![]);
+        // Genesis validators are the whitelisted ones
+        assert_eq!(Session::validators(), vec![1, 2]);
+    });
+}
+
+#[test]
+fn bond_works() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 100));
+
+        System::assert_last_event(RuntimeEvent::ValidatorStaking(crate::Event::Bonded {
+            who: 3,
+            amount: 100,
+            total_bond: 100,
+        }));
+        assert_eq!(
+            ValidatorStaking::candidates(),
+            vec![CandidateBond { who: 3, bond: 100 }]
+        );
+        assert_eq!(bonded(3), 100);
+        assert_eq!(Balances::balance(&3), 900);
+    });
+}
+
+#[test]
+fn bond_requires_session_keys() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_noop!(
+            ValidatorStaking::bond(RuntimeOrigin::signed(NO_KEYS), 100),
+            Error::<Test>::NoKeysRegistered
+        );
+    });
+}
+
+#[test]
+fn bond_below_minimum_fails() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_noop!(
+            ValidatorStaking::bond(RuntimeOrigin::signed(3), 99),
+            Error::<Test>::BondBelowMinimum
+        );
+    });
+}
+
+#[test]
+fn bond_more_than_free_balance_fails() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 2000).is_err());
+        assert_eq!(ValidatorStaking::candidates(), vec![]);
+    });
+}
+
+#[test]
+fn candidates_are_sorted_by_bond() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 100));
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(4), 200));
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(5), 100));
+        assert_eq!(
+            ValidatorStaking::candidates(),
+            vec![
+                CandidateBond { who: 4, bond: 200 },
+                CandidateBond { who: 3, bond: 100 },
+                CandidateBond { who: 5, bond: 100 },
+            ]
+        );
+
+        // Increasing the bond moves the candidate up
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(5), 150));
+        System::assert_last_event(RuntimeEvent::ValidatorStaking(crate::Event::Bonded {
+            who: 5,
+            amount: 150,
+            total_bond: 250,
+        }));
+        assert_eq!(
+            ValidatorStaking::candidates(),
+            vec![
+                CandidateBond { who: 5, bond: 250 },
+                CandidateBond { who: 4, bond: 200 },
+                CandidateBond { who: 3, bond: 100 },
+            ]
+        );
+        assert_eq!(bonded(5), 250);
+    });
+}
+
+#[test]
+fn bond_too_many_candidates_fails() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 100));
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(4), 100));
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(5), 100));
+        assert_noop!(
+            ValidatorStaking::bond(RuntimeOrigin::signed(1), 100),
+            Error::<Test>::TooManyCandidates
+        );
+
+        // Existing candidates can still increase their bond
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 100));
+    });
+}
+
+#[test]
+fn candidates_are_elected_after_whitelisted_validators() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 100));
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(4), 200));
+        assert_eq!(ValidatorStaking::elect_validators(), vec![1, 2, 4, 3]);
+
+        // The new validators are queued at the next session and active the one after
+        initialize_to_block(10);
+        assert_eq!(Session::validators(), vec![1, 2]);
+        initialize_to_block(20);
+        assert_eq!(Session::validators(), vec![1, 2, 4, 3]);
+    });
+}
+
+#[test]
+fn election_is_limited_to_max_validators() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 100));
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(4), 200));
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(5), 300));
+
+        assert_eq!(ValidatorStaking::elect_validators(), vec![1, 2, 5, 4]);
+    });
+}
+
+#[test]
+fn whitelisted_candidate_is_elected_once() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(1), 100));
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 100));
+
+        assert_eq!(ValidatorStaking::elect_validators(), vec![1, 2, 3]);
+    });
+}
+
+#[test]
+fn empty_election_keeps_current_validators() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::remove_whitelisted(
+            RuntimeOrigin::signed(RootAccount::get()),
+            1
+        ));
+        assert_ok!(ValidatorStaking::remove_whitelisted(
+            RuntimeOrigin::signed(RootAccount::get()),
+            2
+        ));
+        assert_eq!(ValidatorStaking::elect_validators(), vec![]);
+
+        initialize_to_block(20);
+        assert_eq!(Session::validators(), vec![1, 2]);
+    });
+}
+
+#[test]
+fn unbond_and_withdraw_works() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 100));
+        assert_ok!(ValidatorStaking::unbond(RuntimeOrigin::signed(3)));
+
+        System::assert_last_event(RuntimeEvent::ValidatorStaking(crate::Event::Unbonding {
+            who: 3,
+            amount: 100,
+            unlock_session: 2,
+        }));
+        assert_eq!(ValidatorStaking::candidates(), vec![]);
+        assert_eq!(
+            Unbonding::<Test>::get(3),
+            Some(UnbondingRequest {
+                amount: 100,
+                unlock_session: 2
+            })
+        );
+        // Funds are still held while unbonding
+        assert_eq!(bonded(3), 100);
+
+        // Cannot bond again until the funds are withdrawn
+        assert_noop!(
+            ValidatorStaking::bond(RuntimeOrigin::signed(3), 100),
+            Error::<Test>::UnbondingInProgress
+        );
+
+        initialize_to_block(10);
+        assert_noop!(
+            ValidatorStaking::withdraw_unbonded(RuntimeOrigin::signed(3)),
+            Error::<Test>::UnbondingDelayNotPassed
+        );
+
+        initialize_to_block(20);
+        assert_ok!(ValidatorStaking::withdraw_unbonded(RuntimeOrigin::signed(
+            3
+        )));
+        System::assert_last_event(RuntimeEvent::ValidatorStaking(crate::Event::Withdrawn {
+            who: 3,
+            amount: 100,
+        }));
+        assert_eq!(Unbonding::<Test>::get(3), None);
+        assert_eq!(bonded(3), 0);
+        assert_eq!(Balances::balance(&3), 1000);
+    });
+}
+
+#[test]
+fn unbond_not_candidate_fails() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_noop!(
+            ValidatorStaking::unbond(RuntimeOrigin::signed(3)),
+            Error::<Test>::NotCandidate
+        );
+        assert_noop!(
+            ValidatorStaking::withdraw_unbonded(RuntimeOrigin::signed(3)),
+            Error::<Test>::NoUnbondingFunds
+        );
+    });
+}
+
+#[test]
+fn add_and_remove_whitelisted_works() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_noop!(
+            ValidatorStaking::add_whitelisted(RuntimeOrigin::signed(1), 3),
+            BadOrigin
+        );
+        assert_noop!(
+            ValidatorStaking::add_whitelisted(RuntimeOrigin::signed(RootAccount::get()), NO_KEYS),
+            Error::<Test>::NoKeysRegistered
+        );
+        assert_noop!(
+            ValidatorStaking::add_whitelisted(RuntimeOrigin::signed(RootAccount::get()), 1),
+            Error::<Test>::AlreadyWhitelisted
+        );
+
+        assert_ok!(ValidatorStaking::add_whitelisted(
+            RuntimeOrigin::signed(RootAccount::get()),
+            3
+        ));
+        System::assert_last_event(RuntimeEvent::ValidatorStaking(
+            crate::Event::WhitelistedValidatorAdded { who: 3 },
+        ));
+        assert_eq!(ValidatorStaking::whitelisted_validators(), vec![1, 2, 3]);
+
+        assert_noop!(
+            ValidatorStaking::remove_whitelisted(RuntimeOrigin::signed(1), 3),
+            BadOrigin
+        );
+        assert_noop!(
+            ValidatorStaking::remove_whitelisted(RuntimeOrigin::signed(RootAccount::get()), 4),
+            Error::<Test>::NotWhitelisted
+        );
+
+        assert_ok!(ValidatorStaking::remove_whitelisted(
+            RuntimeOrigin::signed(RootAccount::get()),
+            1
+        ));
+        System::assert_last_event(RuntimeEvent::ValidatorStaking(
+            crate::Event::WhitelistedValidatorRemoved { who: 1 },
+        ));
+        assert_eq!(ValidatorStaking::whitelisted_validators(), vec![2, 3]);
+    });
+}
+
+#[test]
+fn offence_slashes_and_removes_candidate() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 500));
+        initialize_to_block(20);
+        assert_eq!(Session::validators(), vec![1, 2, 3]);
+
+        let issuance_before = Balances::total_issuance();
+        report_offence(3, Perbill::from_percent(10), DisableStrategy::WhenSlashed);
+
+        System::assert_last_event(RuntimeEvent::ValidatorStaking(crate::Event::Slashed {
+            who: 3,
+            amount: 50,
+        }));
+        assert_eq!(ValidatorStaking::candidates(), vec![]);
+        assert_eq!(
+            Unbonding::<Test>::get(3),
+            Some(UnbondingRequest {
+                amount: 450,
+                unlock_session: 4
+            })
+        );
+        assert_eq!(bonded(3), 450);
+        assert_eq!(Balances::total_issuance(), issuance_before - 50);
+        // Validator index 2 is disabled for the rest of the session
+        assert_eq!(Session::disabled_validators(), vec![2]);
+
+        // Unbonding funds are still slashable
+        report_offence(3, Perbill::from_percent(10), DisableStrategy::Never);
+        assert_eq!(bonded(3), 405);
+    });
+}
+
+#[test]
+fn offence_without_slash_does_not_disable_when_slashed() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(ValidatorStaking::bond(RuntimeOrigin::signed(3), 500));
+        initialize_to_block(20);
+
+        report_offence(3, Perbill::zero(), DisableStrategy::WhenSlashed);
+
+        // The candidate is removed but keeps all its funds
+        assert_eq!(ValidatorStaking::candidates(), vec![]);
+        assert_eq!(bonded(3), 500);
+        assert_eq!(Session::disabled_validators(), Vec::<u32>::new());
+    });
+}
+
+#[test]
+fn offence_by_whitelisted_validator_only_disables() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        report_offence(1, Perbill::from_percent(10), DisableStrategy::Always);
+
+        assert_eq!(ValidatorStaking::whitelisted_validators(), vec![1, 2]);
+        assert_eq!(Unbonding::<Test>::get(1), None);
+        assert_eq!(Session::disabled_validators(), vec![0]);
+    });
+}
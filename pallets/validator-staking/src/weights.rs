@@ -0,0 +1,344 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+
+//! Autogenerated weights for pallet_validator_staking
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-06-11, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `girazoki-XPS-15-9530`, CPU: `13th Gen Intel(R) Core(TM) i9-13900H`
+//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/tanssi-node
+// benchmark
+// pallet
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// pallet_validator_staking
+// --extrinsic
+// *
+// --chain=dev
+// --steps
+// 50
+// --repeat
+// 20
+// --template=./benchmarking/frame-weight-template.hbs
+// --json-file
+// raw.json
+// --output
+// tmp/pallet_validator_staking.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_validator_staking.
+pub trait WeightInfo {
+	fn bond(_c: u32) -> Weight;
+	fn unbond(_c: u32) -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn add_whitelisted(_b: u32) -> Weight;
+	fn remove_whitelisted(_b: u32) -> Weight;
+	fn new_session(_c: u32) -> Weight;
+	fn on_offence(_c: u32) -> Weight;
+}
+
+/// Weights for pallet_validator_staking using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ValidatorStaking::Unbonding` (r:1 w:0)
+	/// Proof: `ValidatorStaking::Unbonding` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `ValidatorStaking::Candidates` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Candidates` (`max_values`: Some(1), `max_size`: Some(48002), added: 48497, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 999]`.
+	fn bond(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1017 + c * (48 ±0)`
+		//  Estimated: `49487`
+		// Minimum execution time: 46_512_000 picoseconds.
+		Weight::from_parts(48_204_113, 49487)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(61_322, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ValidatorStaking::Candidates` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Candidates` (`max_values`: Some(1), `max_size`: Some(48002), added: 48497, mode: `MaxEncodedLen`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ValidatorStaking::Unbonding` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Unbonding` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 1000]`.
+	fn unbond(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154 + c * (48 ±0)`
+		//  Estimated: `49487`
+		// Minimum execution time: 16_891_000 picoseconds.
+		Weight::from_parts(17_442_020, 49487)
+			// Standard Error: 871
+			.saturating_add(Weight::from_parts(48_914, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ValidatorStaking::Unbonding` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Unbonding` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429`
+		//  Estimated: `3676`
+		// Minimum execution time: 41_006_000 picoseconds.
+		Weight::from_parts(41_006_000, 3676)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ValidatorStaking::WhitelistedValidators` (r:1 w:1)
+	/// Proof: `ValidatorStaking::WhitelistedValidators` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 99]`.
+	fn add_whitelisted(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `549 + b * (36 ±0)`
+		//  Estimated: `4687 + b * (37 ±0)`
+		// Minimum execution time: 14_155_000 picoseconds.
+		Weight::from_parts(17_230_418, 4687)
+			// Standard Error: 1_498
+			.saturating_add(Weight::from_parts(77_012, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 37).saturating_mul(b.into()))
+	}
+	/// Storage: `ValidatorStaking::WhitelistedValidators` (r:1 w:1)
+	/// Proof: `ValidatorStaking::WhitelistedValidators` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 100]`.
+	fn remove_whitelisted(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `70 + b * (32 ±0)`
+		//  Estimated: `4687`
+		// Minimum execution time: 8_701_000 picoseconds.
+		Weight::from_parts(10_622_935, 4687)
+			// Standard Error: 1_003
+			.saturating_add(Weight::from_parts(52_877, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ValidatorStaking::WhitelistedValidators` (r:1 w:0)
+	/// Proof: `ValidatorStaking::WhitelistedValidators` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `ValidatorStaking::Candidates` (r:1 w:0)
+	/// Proof: `ValidatorStaking::Candidates` (`max_values`: Some(1), `max_size`: Some(48002), added: 48497, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockWeight` (r:1 w:1)
+	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `Session::NextKeys` (r:1000 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 1000]`.
+	fn new_session(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212 + c * (170 ±0)`
+		//  Estimated: `49487 + c * (2645 ±0)`
+		// Minimum execution time: 13_487_000 picoseconds.
+		Weight::from_parts(14_011_230, 49487)
+			// Standard Error: 6_027
+			.saturating_add(Weight::from_parts(3_611_402, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2645).saturating_mul(c.into()))
+	}
+	/// Storage: `ValidatorStaking::Candidates` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Candidates` (`max_values`: Some(1), `max_size`: Some(48002), added: 48497, mode: `MaxEncodedLen`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ValidatorStaking::Unbonding` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Unbonding` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Session::Validators` (r:1 w:0)
+	/// Proof: `Session::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::DisabledValidators` (r:1 w:1)
+	/// Proof: `Session::DisabledValidators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 1000]`.
+	fn on_offence(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640 + c * (48 ±0)`
+		//  Estimated: `49487`
+		// Minimum execution time: 68_217_000 picoseconds.
+		Weight::from_parts(70_102_566, 49487)
+			// Standard Error: 1_377
+			.saturating_add(Weight::from_parts(50_120, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ValidatorStaking::Unbonding` (r:1 w:0)
+	/// Proof: `ValidatorStaking::Unbonding` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `ValidatorStaking::Candidates` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Candidates` (`max_values`: Some(1), `max_size`: Some(48002), added: 48497, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 999]`.
+	fn bond(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1017 + c * (48 ±0)`
+		//  Estimated: `49487`
+		// Minimum execution time: 46_512_000 picoseconds.
+		Weight::from_parts(48_204_113, 49487)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(61_322, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ValidatorStaking::Candidates` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Candidates` (`max_values`: Some(1), `max_size`: Some(48002), added: 48497, mode: `MaxEncodedLen`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ValidatorStaking::Unbonding` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Unbonding` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 1000]`.
+	fn unbond(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154 + c * (48 ±0)`
+		//  Estimated: `49487`
+		// Minimum execution time: 16_891_000 picoseconds.
+		Weight::from_parts(17_442_020, 49487)
+			// Standard Error: 871
+			.saturating_add(Weight::from_parts(48_914, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ValidatorStaking::Unbonding` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Unbonding` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429`
+		//  Estimated: `3676`
+		// Minimum execution time: 41_006_000 picoseconds.
+		Weight::from_parts(41_006_000, 3676)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ValidatorStaking::WhitelistedValidators` (r:1 w:1)
+	/// Proof: `ValidatorStaking::WhitelistedValidators` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 99]`.
+	fn add_whitelisted(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `549 + b * (36 ±0)`
+		//  Estimated: `4687 + b * (37 ±0)`
+		// Minimum execution time: 14_155_000 picoseconds.
+		Weight::from_parts(17_230_418, 4687)
+			// Standard Error: 1_498
+			.saturating_add(Weight::from_parts(77_012, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 37).saturating_mul(b.into()))
+	}
+	/// Storage: `ValidatorStaking::WhitelistedValidators` (r:1 w:1)
+	/// Proof: `ValidatorStaking::WhitelistedValidators` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 100]`.
+	fn remove_whitelisted(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `70 + b * (32 ±0)`
+		//  Estimated: `4687`
+		// Minimum execution time: 8_701_000 picoseconds.
+		Weight::from_parts(10_622_935, 4687)
+			// Standard Error: 1_003
+			.saturating_add(Weight::from_parts(52_877, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ValidatorStaking::WhitelistedValidators` (r:1 w:0)
+	/// Proof: `ValidatorStaking::WhitelistedValidators` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `ValidatorStaking::Candidates` (r:1 w:0)
+	/// Proof: `ValidatorStaking::Candidates` (`max_values`: Some(1), `max_size`: Some(48002), added: 48497, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockWeight` (r:1 w:1)
+	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `Session::NextKeys` (r:1000 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 1000]`.
+	fn new_session(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212 + c * (170 ±0)`
+		//  Estimated: `49487 + c * (2645 ±0)`
+		// Minimum execution time: 13_487_000 picoseconds.
+		Weight::from_parts(14_011_230, 49487)
+			// Standard Error: 6_027
+			.saturating_add(Weight::from_parts(3_611_402, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2645).saturating_mul(c.into()))
+	}
+	/// Storage: `ValidatorStaking::Candidates` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Candidates` (`max_values`: Some(1), `max_size`: Some(48002), added: 48497, mode: `MaxEncodedLen`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ValidatorStaking::Unbonding` (r:1 w:1)
+	/// Proof: `ValidatorStaking::Unbonding` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Session::Validators` (r:1 w:0)
+	/// Proof: `Session::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::DisabledValidators` (r:1 w:1)
+	/// Proof: `Session::DisabledValidators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 1000]`.
+	fn on_offence(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640 + c * (48 ±0)`
+		//  Estimated: `49487`
+		// Minimum execution time: 68_217_000 picoseconds.
+		Weight::from_parts(70_102_566, 49487)
+			// Standard Error: 1_377
+			.saturating_add(Weight::from_parts(50_120, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
@@ -18,7 +18,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-pub use pallet_xcm_core_buyer::BuyingError;
+pub use pallet_xcm_core_buyer::{
+    BuyCoreDryRunError, BuyingError, CoreBuyingSpending, SpendingLimits,
+};
 use {
     frame_support::sp_runtime,
     sp_consensus_slots::Slot,
@@ -28,10 +30,19 @@ use {
 };
 
 sp_api::decl_runtime_apis! {
-    pub trait XCMCoreBuyerApi<BlockNumber, ParaId, CollatorPublicKey> where ParaId: parity_scale_codec::Codec, BlockNumber: parity_scale_codec::Codec, BuyingError<BlockNumber>: parity_scale_codec::Codec, CollatorPublicKey: RuntimeAppPublic + Clone + core::fmt::Debug + parity_scale_codec::Codec,  {
+    #[api_version(2)]
+    pub trait XCMCoreBuyerApi<BlockNumber, ParaId, CollatorPublicKey> where ParaId: parity_scale_codec::Codec, BlockNumber: parity_scale_codec::Codec, BuyingError<BlockNumber>: parity_scale_codec::Codec, CoreBuyingSpending<BlockNumber>: parity_scale_codec::Codec, CollatorPublicKey: RuntimeAppPublic + Clone + core::fmt::Debug + parity_scale_codec::Codec,  {
         fn is_core_buying_allowed(para_id: ParaId, collator_public_key: CollatorPublicKey) -> Result<(), BuyingError<BlockNumber>>;
         fn create_buy_core_unsigned_extrinsic(para_id: ParaId, proof: BuyCoreCollatorProof<CollatorPublicKey>) -> Box<<Block as BlockT>::Extrinsic>;
+        /// Dispatch the `buy_core` call without persisting its effects, and check that it sends the
+        /// purchase XCM to the relay chain.
+        #[api_version(2)]
+        fn dry_run_buy_core(para_id: ParaId, proof: BuyCoreCollatorProof<CollatorPublicKey>) -> Result<(), BuyCoreDryRunError>;
         fn get_buy_core_signature_nonce(para_id: ParaId) -> u64;
         fn get_buy_core_slot_drift() -> Slot;
+        #[api_version(2)]
+        fn get_core_buying_spending_limits(para_id: ParaId) -> Option<SpendingLimits>;
+        #[api_version(2)]
+        fn get_core_buying_spending(para_id: ParaId) -> CoreBuyingSpending<BlockNumber>;
     }
 }
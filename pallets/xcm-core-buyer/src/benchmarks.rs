@@ -19,12 +19,12 @@
 //! Benchmarking
 use {
     crate::{
-        Call, Config, GetParathreadParams, InFlightOrders, Pallet, RelayXcmWeightConfig,
-        RelayXcmWeightConfigInner,
+        Call, Config, GetParathreadMaxCorePrice, GetParathreadParams, InFlightOrders, Pallet,
+        ParaSpendingLimits, RelayXcmWeightConfig, RelayXcmWeightConfigInner, SpendingLimits,
     },
     core::marker::PhantomData,
     frame_benchmarking::v2::*,
-    frame_support::{assert_ok, pallet_prelude::Weight, BoundedVec},
+    frame_support::{assert_ok, pallet_prelude::Weight, traits::EnsureOriginWithArg, BoundedVec},
     frame_system::RawOrigin,
     tp_traits::{ParaId, ParathreadParams, SlotFrequency},
 };
@@ -71,6 +71,9 @@ mod benchmarks {
                     query_id: QueryId::from(i),
                     ttl: <frame_system::Pallet<T>>::block_number()
                         + BlockNumberFor::<T>::from(100u32),
+                    core_price: BUY_EXECUTION_COST,
+                    session_index: 0,
+                    day_index: BlockNumberFor::<T>::from(0u32),
                 }),
             );
 
@@ -125,6 +128,9 @@ mod benchmarks {
                     query_id: QueryId::from(i),
                     ttl: <frame_system::Pallet<T>>::block_number()
                         + BlockNumberFor::<T>::from(100u32),
+                    core_price: BUY_EXECUTION_COST,
+                    session_index: 0,
+                    day_index: BlockNumberFor::<T>::from(0u32),
                 }),
             );
 
@@ -162,6 +168,9 @@ mod benchmarks {
                     query_id: QueryId::from(i),
                     ttl: <frame_system::Pallet<T>>::block_number()
                         + BlockNumberFor::<T>::from(100u32),
+                    core_price: BUY_EXECUTION_COST,
+                    session_index: 0,
+                    day_index: BlockNumberFor::<T>::from(0u32),
                 }),
             );
 
@@ -200,6 +209,9 @@ mod benchmarks {
                     para_id: ParaId::from(i),
                     query_id: QueryId::from(i),
                     ttl: BlockNumberFor::<T>::from(0u32),
+                    core_price: BUY_EXECUTION_COST,
+                    session_index: 0,
+                    day_index: BlockNumberFor::<T>::from(0u32),
                 }),
             );
 
@@ -260,5 +272,22 @@ mod benchmarks {
         Pallet::<T>::set_relay_chain(RawOrigin::Root, Some(T::RelayChain::default()));
     }
 
+    #[benchmark]
+    fn set_spending_limits() {
+        let para_id = ParaId::from(1000);
+        let origin = T::ManagerOrigin::try_successful_origin(&para_id)
+            .expect("failed to create ManagerOrigin");
+        let limits = SpendingLimits {
+            per_session: Some(BUY_EXECUTION_COST),
+            per_day: Some(BUY_EXECUTION_COST),
+        };
+        T::GetParathreadMaxCorePrice::set_max_core_price(para_id, BUY_EXECUTION_COST);
+
+        #[extrinsic_call]
+        Pallet::<T>::set_spending_limits(origin, para_id, limits.clone());
+
+        assert_eq!(ParaSpendingLimits::<T>::get(para_id), Some(limits));
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
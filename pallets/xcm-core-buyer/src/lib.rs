@@ -39,19 +39,23 @@ use {
     frame_support::{
         dispatch::GetDispatchInfo,
         pallet_prelude::*,
-        traits::fungible::{Balanced, Inspect},
+        traits::{
+            fungible::{Balanced, Inspect},
+            EnsureOriginWithArg,
+        },
     },
     frame_system::pallet_prelude::*,
     parity_scale_codec::EncodeLike,
     sp_consensus_slots::Slot,
-    sp_runtime::traits::{AccountIdConversion, Convert, Get},
+    sp_runtime::traits::{AccountIdConversion, Convert, Get, Zero},
     sp_std::{vec, vec::Vec},
     staging_xcm::{
         latest::{Asset, Assets, InteriorLocation, Response, Xcm},
         prelude::*,
     },
     tp_traits::{
-        AuthorNotingHook, BlockNumber, LatestAuthorInfoFetcher, ParathreadParams, SlotFrequency,
+        AuthorNotingHook, BlockNumber, GetSessionIndex, LatestAuthorInfoFetcher, ParathreadParams,
+        SlotFrequency,
     },
     tp_xcm_core_buyer::BuyCoreCollatorProof,
 };
@@ -79,9 +83,14 @@ impl<T: Config> XCMNotifier<T> for () {
 
 #[derive(RuntimeDebug, PartialEq, Eq, Encode, Decode, Clone, TypeInfo, Serialize, Deserialize)]
 pub struct InFlightCoreBuyingOrder<BN> {
-    para_id: ParaId,
-    query_id: QueryId,
-    ttl: BN,
+    pub para_id: ParaId,
+    pub query_id: QueryId,
+    pub ttl: BN,
+    /// Max core price of this order, accounted as spent until the order fails
+    pub core_price: u128,
+    /// Spending period in which this order was accounted
+    pub session_index: u32,
+    pub day_index: BN,
 }
 
 #[derive(
@@ -102,6 +111,55 @@ pub enum BuyingError<BlockNumber> {
         max_slot_earlier_core_buying_permitted: Slot,
         last_block_production_slot: Slot,
     },
+    SpendingLimitReached {
+        order_cost: u128,
+        spent: u128,
+        limit: u128,
+    },
+    MaxCorePriceNotSet,
+}
+
+/// Reasons why a `buy_core` dry run, performed by collators before submitting the extrinsic, failed.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo)]
+pub enum BuyCoreDryRunError {
+    /// The runtime was not able to dry run the call
+    DryRunUnavailable,
+    /// The `buy_core` call failed
+    CallFailed(DispatchError),
+    /// The `buy_core` call succeeded but no XCM message was sent to the relay chain
+    XcmNotSent,
+}
+
+/// Core buying budget of a parathread, in relay chain currency. `None` means no limit.
+/// Orders are accounted at the max core price of the parathread, so limits can only be set if
+/// that price is set.
+#[derive(
+    RuntimeDebug, Default, PartialEq, Eq, Encode, Decode, Clone, TypeInfo, Serialize, Deserialize,
+)]
+pub struct SpendingLimits {
+    pub per_session: Option<u128>,
+    pub per_day: Option<u128>,
+}
+
+/// Amounts spent by a parathread tank account on buying cores, in relay chain currency.
+/// The relay chain does not report the price actually paid for a core, so the core price of an
+/// order is accounted as the max core price of the parathread when the order was sent. These
+/// values are therefore an upper bound of what was actually spent in the relay chain. The core
+/// price of orders that fail in the relay chain is subtracted again.
+#[derive(
+    RuntimeDebug, Default, PartialEq, Eq, Encode, Decode, Clone, TypeInfo, Serialize, Deserialize,
+)]
+pub struct CoreBuyingSpending<BN> {
+    /// Session in which `session_spent` was accumulated
+    pub session_index: u32,
+    pub session_spent: u128,
+    /// Day in which `day_spent` was accumulated, computed as `block_number / BlocksPerDay`
+    pub day_index: BN,
+    pub day_spent: u128,
+    /// Total amount withdrawn to pay for XCM execution in the relay chain
+    pub total_xcm_fees: u128,
+    /// Total amount offered to pay for cores in the relay chain
+    pub total_core_price: u128,
 }
 
 impl<T: Config> AuthorNotingHook<T::AccountId> for Pallet<T> {
@@ -125,8 +183,12 @@ pub mod pallet {
         sp_runtime::{app_crypto::AppCrypto, RuntimeAppPublic},
     };
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::config]
@@ -202,6 +264,19 @@ pub mod pallet {
 
         type LatestAuthorInfoFetcher: LatestAuthorInfoFetcher<Self::AccountId>;
 
+        /// Owner of the container chain, can set the core buying spending limits
+        type ManagerOrigin: EnsureOriginWithArg<
+            <Self as frame_system::Config>::RuntimeOrigin,
+            ParaId,
+        >;
+
+        /// Current session index, used to reset the per-session spending counter
+        type CurrentSessionIndex: GetSessionIndex<u32>;
+
+        /// Length of a day in blocks, used to reset the per-day spending counter
+        #[pallet::constant]
+        type BlocksPerDay: Get<BlockNumberFor<Self>>;
+
         type SlotBeacon: SlotBeacon;
 
         /// A PublicKey can be converted into an `AccountId`. This is required in order to verify
@@ -232,6 +307,12 @@ pub mod pallet {
 
         /// We cleaned up expired in flight orders entries.
         CleanedUpExpiredInFlightOrderEntries { para_ids: Vec<ParaId> },
+
+        /// The para manager updated the core buying spending limits.
+        SpendingLimitsUpdated {
+            para_id: ParaId,
+            limits: SpendingLimits,
+        },
     }
 
     #[pallet::error]
@@ -269,6 +350,10 @@ pub mod pallet {
         IncorrectCollatorSignatureNonce,
         /// Collator signature is invalid
         InvalidCollatorSignature,
+        /// The core buying spending limit of this para id for the current period has been reached
+        SpendingLimitReached,
+        /// Spending limits require a max core price, otherwise the cost of an order is unbounded
+        MaxCorePriceNotSet,
     }
 
     impl<T: Config> From<BuyingError<BlockNumberFor<T>>> for Error<T> {
@@ -280,6 +365,8 @@ pub mod pallet {
                 BuyingError::NotAllowedToProduceBlockRightNow { .. } => {
                     Error::<T>::NotAllowedToProduceBlockRightNow
                 }
+                BuyingError::SpendingLimitReached { .. } => Error::<T>::SpendingLimitReached,
+                BuyingError::MaxCorePriceNotSet => Error::<T>::MaxCorePriceNotSet,
             }
         }
     }
@@ -311,6 +398,18 @@ pub mod pallet {
     #[pallet::storage]
     pub type CollatorSignatureNonce<T: Config> = StorageMap<_, Twox128, ParaId, u64, ValueQuery>;
 
+    /// Core buying spending limits, set by the para manager
+    #[pallet::storage]
+    pub type ParaSpendingLimits<T: Config> =
+        StorageMap<_, Twox128, ParaId, SpendingLimits, OptionQuery>;
+
+    /// Running totals of the amounts spent on buying cores. Core prices are accounted at the max
+    /// core price, not at the price actually paid. Use `Pallet::current_spending` to read the
+    /// per-period counters, as they are only reset when the next order is sent.
+    #[pallet::storage]
+    pub type ParaSpending<T: Config> =
+        StorageMap<_, Twox128, ParaId, CoreBuyingSpending<BlockNumberFor<T>>, ValueQuery>;
+
     #[derive(Encode, Decode, CloneNoBound, PartialEq, Eq, DebugNoBound, TypeInfo)]
     #[scale_info(skip_type_params(T))]
     pub struct RelayXcmWeightConfigInner<T> {
@@ -412,7 +511,7 @@ pub mod pallet {
             };
 
             QueryIdToParaId::<T>::remove(query_id);
-            InFlightOrders::<T>::remove(para_id);
+            let maybe_in_flight_order = InFlightOrders::<T>::take(para_id);
 
             match response {
                 Response::DispatchResult(MaybeErrorCode::Success) => {
@@ -422,7 +521,13 @@ pub mod pallet {
                     PendingBlocks::<T>::insert(para_id, now + ttl);
                 }
                 Response::DispatchResult(_) => {
-                    // We do not add paraid to pending block on failure
+                    // We do not add paraid to pending block on failure.
+                    // The core was not bought, so its price was never taken from the parathread
+                    // tank account in the relay chain, and the XCM appendix already deposited the
+                    // unused XCM fees back into it. Only the spending counters need to be adjusted.
+                    if let Some(in_flight_order) = maybe_in_flight_order {
+                        Self::unaccount_core_price(para_id, &in_flight_order);
+                    }
                 }
                 _ => {
                     // Unexpected.
@@ -493,6 +598,33 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Set the max amount that the parathread tank account can spend on buying cores, per
+        /// session and per day. Can only be called by the para manager, and only if the para id
+        /// has a max core price, because each order is accounted at that price.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_spending_limits())]
+        pub fn set_spending_limits(
+            origin: OriginFor<T>,
+            para_id: ParaId,
+            limits: SpendingLimits,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin, &para_id)?;
+
+            if limits == SpendingLimits::default() {
+                ParaSpendingLimits::<T>::remove(para_id);
+            } else {
+                ensure!(
+                    T::GetParathreadMaxCorePrice::get_max_core_price(para_id).is_some(),
+                    Error::<T>::MaxCorePriceNotSet
+                );
+                ParaSpendingLimits::<T>::insert(para_id, limits.clone());
+            }
+
+            Self::deposit_event(Event::SpendingLimitsUpdated { para_id, limits });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -577,9 +709,122 @@ pub mod pallet {
                 }
             }
 
+            // Check that the next order fits in the budget. If the weights config is not set
+            // the order will fail anyway, so there is nothing to check.
+            if let Some(xcm_weights) = RelayXcmWeightConfig::<T>::get() {
+                let order_cost = xcm_weights
+                    .buy_execution_cost
+                    .saturating_add(Self::max_core_price(para_id));
+                Self::check_spending_limits(para_id, order_cost)?;
+            }
+
+            Ok(())
+        }
+
+        /// Max price that this parathread is willing to pay for a core, in relay chain currency.
+        fn max_core_price(para_id: ParaId) -> u128 {
+            T::GetParathreadMaxCorePrice::get_max_core_price(para_id).unwrap_or(u128::MAX)
+        }
+
+        fn current_day_index() -> BlockNumberFor<T> {
+            let blocks_per_day = T::BlocksPerDay::get();
+            if blocks_per_day.is_zero() {
+                return Zero::zero();
+            }
+
+            <frame_system::Pallet<T>>::block_number() / blocks_per_day
+        }
+
+        /// Returns the core buying spending of this para id, with the per-session and per-day
+        /// counters reset if the stored ones belong to a past period.
+        pub fn current_spending(para_id: ParaId) -> CoreBuyingSpending<BlockNumberFor<T>> {
+            let mut spending = ParaSpending::<T>::get(para_id);
+
+            let session_index = T::CurrentSessionIndex::session_index();
+            if spending.session_index != session_index {
+                spending.session_index = session_index;
+                spending.session_spent = 0;
+            }
+
+            let day_index = Self::current_day_index();
+            if spending.day_index != day_index {
+                spending.day_index = day_index;
+                spending.day_spent = 0;
+            }
+
+            spending
+        }
+
+        fn check_spending_limits(
+            para_id: ParaId,
+            order_cost: u128,
+        ) -> Result<(), BuyingError<BlockNumberFor<T>>> {
+            let limits = match ParaSpendingLimits::<T>::get(para_id) {
+                Some(limits) => limits,
+                None => return Ok(()),
+            };
+            // The max core price may have been removed after the limits were set
+            if T::GetParathreadMaxCorePrice::get_max_core_price(para_id).is_none() {
+                return Err(BuyingError::MaxCorePriceNotSet);
+            }
+            let spending = Self::current_spending(para_id);
+
+            for (spent, maybe_limit) in [
+                (spending.session_spent, limits.per_session),
+                (spending.day_spent, limits.per_day),
+            ] {
+                if let Some(limit) = maybe_limit {
+                    if spent.saturating_add(order_cost) > limit {
+                        return Err(BuyingError::SpendingLimitReached {
+                            order_cost,
+                            spent,
+                            limit,
+                        });
+                    }
+                }
+            }
+
             Ok(())
         }
 
+        fn note_spending(
+            para_id: ParaId,
+            xcm_fee: u128,
+            core_price: u128,
+        ) -> CoreBuyingSpending<BlockNumberFor<T>> {
+            let mut spending = Self::current_spending(para_id);
+            let order_cost = xcm_fee.saturating_add(core_price);
+
+            spending.session_spent = spending.session_spent.saturating_add(order_cost);
+            spending.day_spent = spending.day_spent.saturating_add(order_cost);
+            spending.total_xcm_fees = spending.total_xcm_fees.saturating_add(xcm_fee);
+            spending.total_core_price = spending.total_core_price.saturating_add(core_price);
+
+            ParaSpending::<T>::insert(para_id, spending.clone());
+
+            spending
+        }
+
+        /// Remove the core price of a failed order from the spending counters. This does not move
+        /// any funds, the XCM fees used by the failed order are still accounted as spent.
+        fn unaccount_core_price(
+            para_id: ParaId,
+            in_flight_order: &InFlightCoreBuyingOrder<BlockNumberFor<T>>,
+        ) {
+            let core_price = in_flight_order.core_price;
+
+            ParaSpending::<T>::mutate(para_id, |spending| {
+                // Period counters are only adjusted if the order belongs to the stored period
+                if spending.session_index == in_flight_order.session_index {
+                    spending.session_spent = spending.session_spent.saturating_sub(core_price);
+                }
+                if spending.day_index == in_flight_order.day_index {
+                    spending.day_spent = spending.day_spent.saturating_sub(core_price);
+                }
+                spending.total_core_price = spending.total_core_price.saturating_sub(core_price);
+            });
+        }
+
         /// Send an XCM message to the relay chain to try to buy a core for this para_id.
         fn on_collator_instantaneous_core_requested(
             para_id: ParaId,
@@ -606,8 +851,7 @@ pub mod pallet {
             // TODO: max_amount is the max price of a core that this parathread is willing to pay
             // It should be defined in a storage item somewhere, controllable by the container chain
             // manager.
-            let max_amount =
-                T::GetParathreadMaxCorePrice::get_max_core_price(para_id).unwrap_or(u128::MAX);
+            let max_amount = Self::max_core_price(para_id);
             let call =
                 T::GetPurchaseCoreCall::get_encoded(RelayChain::<T>::get(), max_amount, para_id);
             let weight_at_most = xcm_weights_storage.weight_at_most;
@@ -678,6 +922,8 @@ pub mod pallet {
                 transaction_status_query_id: query_id,
             });
 
+            let spending = Self::note_spending(para_id, withdraw_amount, max_amount);
+
             let in_flight_order_ttl = notify_query_ttl + T::AdditionalTtlForInflightOrders::get();
             InFlightOrders::<T>::insert(
                 para_id,
@@ -685,6 +931,9 @@ pub mod pallet {
                     para_id,
                     query_id,
                     ttl: in_flight_order_ttl,
+                    core_price: max_amount,
+                    session_index: spending.session_index,
+                    day_index: spending.day_index,
                 },
             );
 
@@ -702,6 +951,9 @@ pub mod pallet {
 
             // We need to clean the pending block entry if any
             PendingBlocks::<T>::remove(para_id);
+
+            ParaSpendingLimits::<T>::remove(para_id);
+            ParaSpending::<T>::remove(para_id);
         }
     }
 
@@ -764,12 +1016,18 @@ pub trait CheckCollatorValidity<AccountId, PublicKey> {
 
 pub trait GetParathreadMaxCorePrice {
     fn get_max_core_price(para_id: ParaId) -> Option<u128>;
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_max_core_price(para_id: ParaId, max_core_price: u128);
 }
 
 impl GetParathreadMaxCorePrice for () {
     fn get_max_core_price(_para_id: ParaId) -> Option<u128> {
         None
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_max_core_price(_para_id: ParaId, _max_core_price: u128) {}
 }
 
 pub trait GetParathreadParams {
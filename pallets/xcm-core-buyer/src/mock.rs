@@ -16,8 +16,8 @@
 
 use {
     crate::{
-        self as pallet_xcm_core_buyer, CheckCollatorValidity, GetPurchaseCoreCall,
-        ParaIdIntoAccountTruncating, RelayXcmWeightConfigInner,
+        self as pallet_xcm_core_buyer, CheckCollatorValidity, GetParathreadMaxCorePrice,
+        GetPurchaseCoreCall, ParaIdIntoAccountTruncating, RelayXcmWeightConfigInner,
     },
     dp_core::ParaId,
     frame_support::{
@@ -26,6 +26,7 @@ use {
         parameter_types,
         traits::{ConstU64, Everything},
     },
+    frame_system::EnsureRoot,
     nimbus_primitives::NimbusId,
     pallet_xcm::Origin,
     serde::{Deserialize, Serialize},
@@ -43,7 +44,8 @@ use {
         prelude::{GlobalConsensus, InteriorLocation, Junctions::X2, NetworkId, Parachain},
     },
     tp_traits::{
-        ContainerChainBlockInfo, LatestAuthorInfoFetcher, ParathreadParams, SlotFrequency,
+        ContainerChainBlockInfo, GetSessionIndex, LatestAuthorInfoFetcher, ParathreadParams,
+        SlotFrequency,
     },
 };
 
@@ -175,6 +177,7 @@ pub struct Mocks {
     pub latest_author_info: BTreeMap<ParaId, ContainerChainBlockInfo<AccountId>>,
     pub container_chain_collators: BTreeMap<ParaId, Vec<NimbusId>>,
     pub parathread_params: BTreeMap<ParaId, ParathreadParams>,
+    pub max_core_price: BTreeMap<ParaId, u128>,
 }
 
 impl Default for Mocks {
@@ -198,6 +201,7 @@ impl Default for Mocks {
                     slot_frequency: SlotFrequency { min: 1, max: 1 },
                 },
             )]),
+            max_core_price: BTreeMap::new(),
         }
     }
 }
@@ -212,6 +216,7 @@ parameter_types! {
     pub const AdditionalTtlForInflightOrders: u32 = 5;
     pub UniversalLocation: InteriorLocation = X2([GlobalConsensus(NetworkId::Westend), Parachain(1000)].into());
     pub BuyCoreSlotDrift: Slot = Slot::from(2u64);
+    pub const BlocksPerDay: u64 = 50;
 }
 
 impl pallet_xcm_core_buyer::Config for Test {
@@ -220,7 +225,7 @@ impl pallet_xcm_core_buyer::Config for Test {
     type XcmSender = DevNull;
     type GetPurchaseCoreCall = EncodedCallToBuyCore;
     type GetParathreadAccountId = ParaIdIntoAccountTruncating;
-    type GetParathreadMaxCorePrice = ();
+    type GetParathreadMaxCorePrice = GetParathreadMaxCorePriceImpl;
     type SelfParaId = ParachainId;
     type RelayChain = ();
     type GetParathreadParams = GetParathreadParamsImpl;
@@ -237,6 +242,9 @@ impl pallet_xcm_core_buyer::Config for Test {
     type LatestAuthorInfoFetcher = LatestAuthorInfoFetcherImpl;
     type SlotBeacon = DummyBeacon;
     type CollatorPublicKey = NimbusId;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type CurrentSessionIndex = CurrentSessionIndexGetter;
+    type BlocksPerDay = BlocksPerDay;
 
     type WeightInfo = ();
 }
@@ -271,6 +279,31 @@ impl crate::GetParathreadParams for GetParathreadParamsImpl {
     }
 }
 
+pub struct GetParathreadMaxCorePriceImpl;
+
+impl GetParathreadMaxCorePrice for GetParathreadMaxCorePriceImpl {
+    fn get_max_core_price(para_id: ParaId) -> Option<u128> {
+        MockData::mock().max_core_price.get(&para_id).cloned()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_max_core_price(para_id: ParaId, max_core_price: u128) {
+        MockData::mutate(|m| {
+            m.max_core_price.insert(para_id, max_core_price);
+        });
+    }
+}
+
+pub const SESSION_LEN: u64 = 10;
+
+pub struct CurrentSessionIndexGetter;
+
+impl GetSessionIndex<u32> for CurrentSessionIndexGetter {
+    fn session_index() -> u32 {
+        (System::block_number() / SESSION_LEN) as u32
+    }
+}
+
 pub struct DummyBeacon {}
 impl nimbus_primitives::SlotBeacon for DummyBeacon {
     fn slot() -> u32 {
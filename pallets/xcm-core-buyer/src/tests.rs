@@ -547,6 +547,9 @@ fn force_buy_two_messages_fails_after_receiving_order_success_response() {
                 para_id,
                 query_id,
                 ttl: 2 + <Test as Config>::AdditionalTtlForInflightOrders::get() as u64 + <Test as Config>::CoreBuyingXCMQueryTtl::get() as u64,
+                core_price: u128::MAX,
+                session_index: 0,
+                day_index: 0,
             });
 
             // QueryId -> ParaId mapping should exists
@@ -618,6 +621,9 @@ fn clean_up_in_flight_orders_extrinsic_works() {
                 para_id,
                 query_id,
                 ttl: 2 + <Test as Config>::AdditionalTtlForInflightOrders::get() as u64 + <Test as Config>::CoreBuyingXCMQueryTtl::get() as u64,
+                core_price: u128::MAX,
+                session_index: 0,
+                day_index: 0,
             });
 
             // QueryId -> ParaId mapping should exists
@@ -689,6 +695,9 @@ fn clean_up_pending_block_entries_extrinsic_works() {
                 para_id,
                 query_id,
                 ttl: 2 + <Test as Config>::AdditionalTtlForInflightOrders::get() as u64 + <Test as Config>::CoreBuyingXCMQueryTtl::get() as u64,
+                core_price: u128::MAX,
+                session_index: 0,
+                day_index: 0,
             });
 
             assert_ok!(XcmCoreBuyer::query_response(RuntimeOrigin::root(), query_id, Response::DispatchResult(MaybeErrorCode::Success)));
@@ -883,3 +892,317 @@ fn xcm_locations() {
             assert_eq!(format!("{:?}", absolute_mloc), "Location { parents: 0, interior: X2([Parachain(1000), AccountId32 { network: None, id: [112, 97, 114, 97, 5, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0] }]) }");
         });
 }
+
+const MAX_CORE_PRICE: u128 = 10_000_000;
+const ORDER_COST: u128 = BUY_EXECUTION_COST + MAX_CORE_PRICE;
+
+fn force_buy_core_and_produce_block(para_id: ParaId) -> DispatchResult {
+    XcmCoreBuyer::force_buy_core(RuntimeOrigin::root(), para_id)?;
+
+    let query_id = InFlightOrders::<Test>::get(para_id)
+        .expect("order was just placed")
+        .query_id;
+    XcmCoreBuyer::query_response(
+        RuntimeOrigin::root(),
+        query_id,
+        Response::DispatchResult(MaybeErrorCode::Success),
+    )?;
+
+    // Clear the pending block so that the next order can be placed right away
+    Pallet::<Test>::on_container_author_noted(&BOB, 1, para_id);
+
+    Ok(())
+}
+
+#[test]
+fn only_manager_can_set_spending_limits() {
+    ExtBuilder::default()
+        .with_balances([(ALICE, 1_000)].into())
+        .build()
+        .execute_with(|| {
+            run_to_block(1);
+            let para_id = 3333.into();
+            let limits = SpendingLimits {
+                per_session: Some(ORDER_COST),
+                per_day: None,
+            };
+            MockData::mutate(|m| {
+                m.max_core_price.insert(para_id, MAX_CORE_PRICE);
+            });
+
+            assert_noop!(
+                XcmCoreBuyer::set_spending_limits(
+                    RuntimeOrigin::signed(ALICE),
+                    para_id,
+                    limits.clone()
+                ),
+                BadOrigin
+            );
+
+            assert_ok!(XcmCoreBuyer::set_spending_limits(
+                RuntimeOrigin::root(),
+                para_id,
+                limits.clone()
+            ));
+            assert_eq!(
+                ParaSpendingLimits::<Test>::get(para_id),
+                Some(limits.clone())
+            );
+            assert_eq!(
+                events(),
+                vec![Event::SpendingLimitsUpdated { para_id, limits }]
+            );
+
+            // Setting no limits removes the entry
+            assert_ok!(XcmCoreBuyer::set_spending_limits(
+                RuntimeOrigin::root(),
+                para_id,
+                SpendingLimits::default()
+            ));
+            assert_eq!(ParaSpendingLimits::<Test>::get(para_id), None);
+        });
+}
+
+#[test]
+fn spending_is_accounted_per_order() {
+    ExtBuilder::default()
+        .with_balances([(ALICE, 1_000)].into())
+        .build()
+        .execute_with(|| {
+            run_to_block(1);
+            let para_id = 3333.into();
+            MockData::mutate(|m| {
+                m.max_core_price.insert(para_id, MAX_CORE_PRICE);
+            });
+
+            assert_ok!(force_buy_core_and_produce_block(para_id));
+            assert_ok!(force_buy_core_and_produce_block(para_id));
+
+            assert_eq!(
+                XcmCoreBuyer::current_spending(para_id),
+                CoreBuyingSpending {
+                    session_index: 0,
+                    session_spent: 2 * ORDER_COST,
+                    day_index: 0,
+                    day_spent: 2 * ORDER_COST,
+                    total_xcm_fees: 2 * BUY_EXECUTION_COST,
+                    total_core_price: 2 * MAX_CORE_PRICE,
+                }
+            );
+
+            // Period counters are reset, totals are kept
+            run_to_block(SESSION_LEN);
+            let spending = XcmCoreBuyer::current_spending(para_id);
+            assert_eq!(spending.session_index, 1);
+            assert_eq!(spending.session_spent, 0);
+            assert_eq!(spending.day_spent, 2 * ORDER_COST);
+            assert_eq!(spending.total_xcm_fees, 2 * BUY_EXECUTION_COST);
+
+            XcmCoreBuyer::para_deregistered(para_id);
+            assert_eq!(
+                ParaSpending::<Test>::get(para_id),
+                CoreBuyingSpending::default()
+            );
+        });
+}
+
+#[test]
+fn cannot_buy_core_above_session_spending_limit() {
+    ExtBuilder::default()
+        .with_balances([(ALICE, 1_000)].into())
+        .build()
+        .execute_with(|| {
+            run_to_block(1);
+            let para_id = 3333.into();
+            MockData::mutate(|m| {
+                m.max_core_price.insert(para_id, MAX_CORE_PRICE);
+            });
+            assert_ok!(XcmCoreBuyer::set_spending_limits(
+                RuntimeOrigin::root(),
+                para_id,
+                SpendingLimits {
+                    per_session: Some(ORDER_COST + ORDER_COST / 2),
+                    per_day: None,
+                }
+            ));
+
+            assert_ok!(force_buy_core_and_produce_block(para_id));
+            assert_eq!(
+                XcmCoreBuyer::is_core_buying_allowed(para_id, None),
+                Err(BuyingError::SpendingLimitReached {
+                    order_cost: ORDER_COST,
+                    spent: ORDER_COST,
+                    limit: ORDER_COST + ORDER_COST / 2,
+                })
+            );
+            assert_noop!(
+                XcmCoreBuyer::force_buy_core(RuntimeOrigin::root(), para_id),
+                Error::<Test>::SpendingLimitReached
+            );
+
+            // The budget is available again in the next session
+            run_to_block(SESSION_LEN);
+            assert_ok!(force_buy_core_and_produce_block(para_id));
+        });
+}
+
+#[test]
+fn cannot_buy_core_above_daily_spending_limit() {
+    ExtBuilder::default()
+        .with_balances([(ALICE, 1_000)].into())
+        .build()
+        .execute_with(|| {
+            run_to_block(1);
+            let para_id = 3333.into();
+            MockData::mutate(|m| {
+                m.max_core_price.insert(para_id, MAX_CORE_PRICE);
+            });
+            assert_ok!(XcmCoreBuyer::set_spending_limits(
+                RuntimeOrigin::root(),
+                para_id,
+                SpendingLimits {
+                    per_session: None,
+                    per_day: Some(2 * ORDER_COST),
+                }
+            ));
+
+            assert_ok!(force_buy_core_and_produce_block(para_id));
+            run_to_block(SESSION_LEN + 1);
+            assert_ok!(force_buy_core_and_produce_block(para_id));
+            run_to_block(2 * SESSION_LEN + 1);
+            assert_noop!(
+                XcmCoreBuyer::force_buy_core(RuntimeOrigin::root(), para_id),
+                Error::<Test>::SpendingLimitReached
+            );
+
+            // The budget is available again the next day
+            run_to_block(<Test as Config>::BlocksPerDay::get());
+            assert_ok!(force_buy_core_and_produce_block(para_id));
+        });
+}
+
+#[test]
+fn spending_limit_requires_max_core_price() {
+    ExtBuilder::default()
+        .with_balances([(ALICE, 1_000)].into())
+        .build()
+        .execute_with(|| {
+            run_to_block(1);
+            let para_id = 3333.into();
+            let limits = SpendingLimits {
+                per_session: Some(u128::MAX / 2),
+                per_day: None,
+            };
+
+            // Without a max core price the order cost is unbounded
+            assert_noop!(
+                XcmCoreBuyer::set_spending_limits(RuntimeOrigin::root(), para_id, limits.clone()),
+                Error::<Test>::MaxCorePriceNotSet
+            );
+
+            MockData::mutate(|m| {
+                m.max_core_price.insert(para_id, MAX_CORE_PRICE);
+            });
+            assert_ok!(XcmCoreBuyer::set_spending_limits(
+                RuntimeOrigin::root(),
+                para_id,
+                limits
+            ));
+
+            // The max core price is removed after setting the limits
+            MockData::mutate(|m| {
+                m.max_core_price.remove(&para_id);
+            });
+            assert_eq!(
+                XcmCoreBuyer::is_core_buying_allowed(para_id, None),
+                Err(BuyingError::MaxCorePriceNotSet)
+            );
+            assert_noop!(
+                XcmCoreBuyer::force_buy_core(RuntimeOrigin::root(), para_id),
+                Error::<Test>::MaxCorePriceNotSet
+            );
+
+            // Removing the limits is always allowed
+            assert_ok!(XcmCoreBuyer::set_spending_limits(
+                RuntimeOrigin::root(),
+                para_id,
+                SpendingLimits::default()
+            ));
+        });
+}
+
+#[test]
+fn core_price_is_unaccounted_after_order_failure() {
+    ExtBuilder::default()
+        .with_balances([(ALICE, 1_000)].into())
+        .build()
+        .execute_with(|| {
+            run_to_block(1);
+            let para_id = 3333.into();
+            MockData::mutate(|m| {
+                m.max_core_price.insert(para_id, MAX_CORE_PRICE);
+            });
+            assert_ok!(XcmCoreBuyer::set_spending_limits(
+                RuntimeOrigin::root(),
+                para_id,
+                SpendingLimits {
+                    per_session: Some(ORDER_COST + BUY_EXECUTION_COST),
+                    per_day: None,
+                }
+            ));
+
+            assert_ok!(XcmCoreBuyer::force_buy_core(RuntimeOrigin::root(), para_id));
+            let in_flight_order = InFlightOrders::<Test>::get(para_id).unwrap();
+            assert_eq!(in_flight_order.core_price, MAX_CORE_PRICE);
+            assert_eq!(
+                XcmCoreBuyer::current_spending(para_id).session_spent,
+                ORDER_COST
+            );
+
+            assert_ok!(XcmCoreBuyer::query_response(
+                RuntimeOrigin::root(),
+                in_flight_order.query_id,
+                Response::DispatchResult(MaybeErrorCode::Error(BoundedVec::new()))
+            ));
+
+            // Only the xcm fees are accounted
+            let spending = XcmCoreBuyer::current_spending(para_id);
+            assert_eq!(spending.session_spent, BUY_EXECUTION_COST);
+            assert_eq!(spending.total_xcm_fees, BUY_EXECUTION_COST);
+            assert_eq!(spending.total_core_price, 0);
+
+            // So there is budget left for another order
+            assert_ok!(XcmCoreBuyer::force_buy_core(RuntimeOrigin::root(), para_id));
+        });
+}
+
+#[test]
+fn failed_order_from_past_session_does_not_affect_current_session() {
+    ExtBuilder::default()
+        .with_balances([(ALICE, 1_000)].into())
+        .build()
+        .execute_with(|| {
+            run_to_block(1);
+            let para_id = 3333.into();
+            MockData::mutate(|m| {
+                m.max_core_price.insert(para_id, MAX_CORE_PRICE);
+            });
+
+            assert_ok!(XcmCoreBuyer::force_buy_core(RuntimeOrigin::root(), para_id));
+            let query_id = InFlightOrders::<Test>::get(para_id).unwrap().query_id;
+
+            // The order fails in the next session, and a new order is placed
+            run_to_block(SESSION_LEN);
+            assert_ok!(XcmCoreBuyer::query_response(
+                RuntimeOrigin::root(),
+                query_id,
+                Response::DispatchResult(MaybeErrorCode::Error(BoundedVec::new()))
+            ));
+            assert_ok!(force_buy_core_and_produce_block(para_id));
+
+            let spending = XcmCoreBuyer::current_spending(para_id);
+            assert_eq!(spending.session_spent, ORDER_COST);
+            assert_eq!(spending.day_spent, ORDER_COST + BUY_EXECUTION_COST);
+            assert_eq!(spending.total_core_price, MAX_CORE_PRICE);
+        });
+}
@@ -60,6 +60,7 @@ pub trait WeightInfo {
 	fn clean_up_expired_pending_blocks(x: u32, ) -> Weight;
 	fn set_relay_xcm_weight_config() -> Weight;
 	fn set_relay_chain() -> Weight;
+	fn set_spending_limits() -> Weight;
 }
 
 /// Weights for pallet_xcm_core_buyer using the Substrate node and recommended hardware.
@@ -220,6 +221,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(2_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmCoreBuyer::ParaSpendingLimits` (r:0 w:1)
+	/// Proof: `XcmCoreBuyer::ParaSpendingLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_spending_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -379,4 +390,14 @@ impl WeightInfo for () {
 		Weight::from_parts(2_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmCoreBuyer::ParaSpendingLimits` (r:0 w:1)
+	/// Proof: `XcmCoreBuyer::ParaSpendingLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_spending_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,87 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {
+    crate::{orchestrator_account_key, orchestrator_tank_account, OrchestratorCreditsInherentData},
+    cumulus_primitives_core::{relay_chain::HeadData, ParaId},
+    cumulus_relay_chain_interface::{PHash, RelayChainInterface},
+    dc_orchestrator_chain_interface::OrchestratorChainInterface,
+    dp_core::well_known_keys::para_id_head,
+    parity_scale_codec::Decode,
+    sp_runtime::traits::Header,
+};
+
+impl OrchestratorCreditsInherentData {
+    /// Create the [`OrchestratorCreditsInherentData`] for `para_id` at the given `relay_parent`.
+    ///
+    /// Returns `None` if the creation failed.
+    pub async fn create_at(
+        relay_parent: PHash,
+        relay_chain_interface: &impl RelayChainInterface,
+        orchestrator_chain_interface: &impl OrchestratorChainInterface,
+        orchestrator_para_id: ParaId,
+        para_id: ParaId,
+    ) -> Option<OrchestratorCreditsInherentData> {
+        let orchestrator_head_key = para_id_head(orchestrator_para_id);
+
+        // The orchestrator proof must be rooted at the orchestrator head included in the relay
+        // chain, so we need to read that head first
+        let head_data = relay_chain_interface
+            .get_storage_by_key(relay_parent, &orchestrator_head_key)
+            .await
+            .ok()??;
+        let head_data = HeadData::decode(&mut head_data.as_slice()).ok()?;
+        let orchestrator_header = dp_core::Header::decode(&mut head_data.0.as_slice()).ok()?;
+
+        let relay_chain_state = relay_chain_interface
+            .prove_read(relay_parent, &vec![orchestrator_head_key])
+            .await
+            .ok()?;
+
+        let tank = orchestrator_tank_account(para_id);
+        let orchestrator_chain_state = orchestrator_chain_interface
+            .prove_read(
+                orchestrator_header.hash(),
+                &[orchestrator_account_key(&tank)],
+            )
+            .await
+            .ok()?;
+
+        Some(OrchestratorCreditsInherentData {
+            relay_chain_state,
+            orchestrator_chain_state,
+        })
+    }
+}
+
+// Implementation of InherentDataProvider
+#[async_trait::async_trait]
+impl sp_inherents::InherentDataProvider for OrchestratorCreditsInherentData {
+    async fn provide_inherent_data(
+        &self,
+        inherent_data: &mut sp_inherents::InherentData,
+    ) -> Result<(), sp_inherents::Error> {
+        inherent_data.put_data(crate::INHERENT_IDENTIFIER, &self)
+    }
+
+    async fn try_handle_error(
+        &self,
+        _: &sp_inherents::InherentIdentifier,
+        _: &[u8],
+    ) -> Option<Result<(), sp_inherents::Error>> {
+        None
+    }
+}
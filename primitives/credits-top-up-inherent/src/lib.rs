@@ -0,0 +1,69 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! # Credits Top Up Inherent Primitives
+//!
+//! This crate defines those primitives that should be taken into account when building
+//! the container chain credits top up pallet inherent
+//!
+//! In particular this crate contains:
+//! - The Inherent identifier
+//! - The storage keys needed to read the tank balance of a container chain in the orchestrator
+//! - The client side trait implementations to introduce the inherent
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+mod client_side;
+
+use {
+    dp_core::ParaId,
+    parity_scale_codec::{Decode, Encode},
+    scale_info::TypeInfo,
+    sp_core::hashing::{blake2_128, blake2_256, twox_128},
+    sp_inherents::InherentIdentifier,
+    sp_std::vec::Vec,
+};
+
+/// Storage proofs needed to read the tank balance of this container chain in the orchestrator.
+#[derive(Encode, Decode, sp_core::RuntimeDebug, Clone, PartialEq, TypeInfo)]
+pub struct OrchestratorCreditsInherentData {
+    /// Relay chain proof of the orchestrator head
+    pub relay_chain_state: sp_trie::StorageProof,
+    /// Orchestrator chain proof of the tank account, rooted at the orchestrator head
+    pub orchestrator_chain_state: sp_trie::StorageProof,
+}
+
+// Identifier of the credits top up inherent
+pub const INHERENT_IDENTIFIER: InherentIdentifier = *b"cctopup0";
+
+/// Account that holds the credits of `para_id` in the orchestrator.
+///
+/// Same derivation as `pallet_services_payment::Pallet::parachain_tank`.
+pub fn orchestrator_tank_account(para_id: ParaId) -> [u8; 32] {
+    (b"modlpy/serpayment", para_id).using_encoded(blake2_256)
+}
+
+/// Storage key of `System::Account` for `account` in the orchestrator.
+pub fn orchestrator_account_key(account: &[u8; 32]) -> Vec<u8> {
+    [
+        twox_128(b"System").as_slice(),
+        twox_128(b"Account").as_slice(),
+        blake2_128(account).as_slice(),
+        account.as_slice(),
+    ]
+    .concat()
+}
@@ -50,6 +50,10 @@ use {
 // of the macro.
 use sp_std::vec;
 
+/// Max encoded size of the genesis data of a container chain accepted by the orchestrator
+/// registrar. Shared with the node so that genesis data can be checked before registering it.
+pub const MAX_ENCODED_GENESIS_DATA_SIZE: u32 = 5_000_000; // 5MB
+
 /// The collator-assignment hook to react to collators being assigned to container chains.
 pub trait CollatorAssignmentHook<Balance> {
     /// This hook is called when collators are assigned to a container
@@ -201,6 +205,12 @@ pub trait GetContainerChainAuthor<AccountId> {
     fn set_authors_for_para_id(para_id: ParaId, authors: Vec<AccountId>);
 }
 
+/// Returns the number of collators currently assigned to each chain.
+pub trait GetCollatorsPerChain {
+    fn orchestrator_collators() -> u32;
+    fn container_chain_collators(para_id: ParaId) -> u32;
+}
+
 /// Returns the host configuration composed of the amount of collators assigned
 /// to the orchestrator chain, and how many collators are assigned per container chain.
 pub trait GetHostConfiguration<SessionIndex> {
@@ -216,6 +226,10 @@ pub trait GetHostConfiguration<SessionIndex> {
 /// Returns current session index.
 pub trait GetSessionIndex<SessionIndex> {
     fn session_index() -> SessionIndex;
+
+    /// Rotate sessions until the provided session index is reached.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn skip_to_session(_session_index: SessionIndex) {}
 }
 
 /// Should pallet_collator_assignment trigger a full rotation on this session?
@@ -309,6 +323,47 @@ pub struct ContainerChainBlockInfo<AccountId> {
     pub latest_slot_number: Slot,
 }
 
+/// Runtime code hash and head of a container chain, as read from the relay chain state
+#[derive(
+    Default,
+    Clone,
+    Encode,
+    Decode,
+    PartialEq,
+    Eq,
+    sp_core::RuntimeDebug,
+    scale_info::TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub struct ContainerChainCodeInfo {
+    /// Hash of the current validation code, which is the container chain runtime wasm
+    pub code_hash: H256,
+    /// Encoded head data, the latest container chain header included in the relay chain
+    pub head: Vec<u8>,
+    /// Relay chain block number of the storage proof used to read this info
+    pub relay_block_number: BlockNumber,
+}
+
+/// Sessions in which a container chain is paused and unpaused, scheduled in advance by its manager
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    PartialEq,
+    Eq,
+    sp_core::RuntimeDebug,
+    scale_info::TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub struct PauseWindow<SessionIndex> {
+    /// First session in which the container chain is paused
+    pub start: SessionIndex,
+    /// Session in which the container chain is unpaused
+    pub end: SessionIndex,
+}
+
 pub trait LatestAuthorInfoFetcher<AccountId> {
     fn get_latest_author_info(para_id: ParaId) -> Option<ContainerChainBlockInfo<AccountId>>;
 }
@@ -49,11 +49,13 @@ use {
         weights::Weight,
         Blake2_128Concat, BoundedVec, StoragePrefixedMap,
     },
+    frame_system::pallet_prelude::BlockNumberFor,
     pallet_configuration::{weights::WeightInfo as _, HostConfiguration},
     pallet_foreign_asset_creator::{AssetId, AssetIdToForeignAsset, ForeignAssetToAssetId},
     pallet_migrations::{GetMigrations, Migration},
     pallet_registrar::HoldReason,
     sp_core::Get,
+    sp_runtime::traits::{Saturating, Zero},
     sp_std::{collections::btree_set::BTreeSet, marker::PhantomData, prelude::*},
 };
 
@@ -775,11 +777,386 @@ where
     }
 }
 
+pub struct MigrateValidatorManagerToValidatorStaking<T>(pub PhantomData<T>);
+impl<T> Migration for MigrateValidatorManagerToValidatorStaking<T>
+where
+    T: pallet_validator_staking::Config,
+{
+    fn friendly_name(&self) -> &str {
+        "TM_MigrateValidatorManagerToValidatorStaking"
+    }
+
+    fn migrate(&self, _available_weight: Weight) -> Weight {
+        // Pending changes of the removed validator manager are applied before the migration, so
+        // that the whitelist matches the validator set the chain would have had otherwise.
+        let to_retire: Vec<T::AccountId> = frame_support::storage::unhashed::take(
+            &frame_support::storage::storage_prefix(b"ValidatorManager", b"ValidatorsToRetire"),
+        )
+        .unwrap_or_default();
+        let to_add: Vec<T::AccountId> = frame_support::storage::unhashed::take(
+            &frame_support::storage::storage_prefix(b"ValidatorManager", b"ValidatorsToAdd"),
+        )
+        .unwrap_or_default();
+
+        let mut validators = pallet_session::Pallet::<T>::validators();
+        validators.retain(|v| !to_retire.contains(v));
+        for v in to_add {
+            if !validators.contains(&v) {
+                validators.push(v);
+            }
+        }
+
+        let max_whitelisted =
+            <T as pallet_validator_staking::Config>::MaxWhitelistedValidators::get();
+        if validators.len() > max_whitelisted as usize {
+            log::warn!(
+                "Too many validators to whitelist, keeping the first {} out of {}",
+                max_whitelisted,
+                validators.len()
+            );
+        }
+        pallet_validator_staking::WhitelistedValidators::<T>::put(BoundedVec::truncate_from(
+            validators,
+        ));
+
+        T::DbWeight::get().reads_writes(3, 3)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+        Ok(vec![])
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(&self, _state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+        ensure!(
+            !pallet_validator_staking::WhitelistedValidators::<T>::get().is_empty(),
+            "Whitelisted validators should not be empty after migration"
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(
+    Clone,
+    parity_scale_codec::Encode,
+    parity_scale_codec::Decode,
+    PartialEq,
+    sp_core::RuntimeDebug,
+    scale_info::TypeInfo,
+)]
+struct InFlightCoreBuyingOrderV0<BlockNumber> {
+    pub para_id: ParaId,
+    pub query_id: u64,
+    pub ttl: BlockNumber,
+}
+
+pub struct XcmCoreBuyerInFlightOrdersMigration<T>(pub PhantomData<T>);
+impl<T> Migration for XcmCoreBuyerInFlightOrdersMigration<T>
+where
+    T: pallet_xcm_core_buyer::Config,
+{
+    fn friendly_name(&self) -> &str {
+        "TM_XcmCoreBuyerInFlightOrdersMigration"
+    }
+
+    fn migrate(&self, _available_weight: Weight) -> Weight {
+        if <pallet_xcm_core_buyer::Pallet<T> as GetStorageVersion>::on_chain_storage_version() != 0
+        {
+            return Weight::default();
+        }
+
+        let mut migrated_count = 0u64;
+        // Orders sent before this migration were not accounted in the spending counters, so they
+        // are migrated with a core price of 0 to leave the counters untouched if they fail.
+        pallet_xcm_core_buyer::InFlightOrders::<T>::translate(
+            |_para_id, old: InFlightCoreBuyingOrderV0<BlockNumberFor<T>>| {
+                migrated_count += 1;
+                let spending = pallet_xcm_core_buyer::Pallet::<T>::current_spending(old.para_id);
+
+                Some(pallet_xcm_core_buyer::InFlightCoreBuyingOrder {
+                    para_id: old.para_id,
+                    query_id: old.query_id,
+                    ttl: old.ttl,
+                    core_price: 0,
+                    session_index: spending.session_index,
+                    day_index: spending.day_index,
+                })
+            },
+        );
+        StorageVersion::new(1).put::<pallet_xcm_core_buyer::Pallet<T>>();
+
+        T::DbWeight::get().reads_writes(2 * migrated_count + 1, migrated_count + 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+        use parity_scale_codec::Encode;
+
+        let count = pallet_xcm_core_buyer::InFlightOrders::<T>::iter_keys().count() as u32;
+
+        Ok(count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(&self, state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+        use parity_scale_codec::Decode;
+
+        let count: u32 = Decode::decode(&mut &state[..]).expect("state to be decoded properly");
+
+        ensure!(
+            <pallet_xcm_core_buyer::Pallet<T> as GetStorageVersion>::on_chain_storage_version()
+                == 1,
+            "XcmCoreBuyer storage version should be 1"
+        );
+        ensure!(
+            pallet_xcm_core_buyer::InFlightOrders::<T>::iter_values().count() as u32 == count,
+            "All InFlightOrders should be decodable after migration"
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(
+    Clone,
+    parity_scale_codec::Encode,
+    parity_scale_codec::Decode,
+    PartialEq,
+    sp_core::RuntimeDebug,
+    scale_info::TypeInfo,
+)]
+struct ChainsToRewardValueV0<Balance> {
+    pub para_ids: Vec<ParaId>,
+    pub rewards_per_chain: Balance,
+}
+
+pub struct InflationRewardsChainsToRewardMigration<T>(pub PhantomData<T>);
+impl<T> Migration for InflationRewardsChainsToRewardMigration<T>
+where
+    T: pallet_inflation_rewards::Config,
+{
+    fn friendly_name(&self) -> &str {
+        "TM_InflationRewardsChainsToRewardMigration"
+    }
+
+    fn migrate(&self, _available_weight: Weight) -> Weight {
+        // Every chain left to reward keeps the same rewards, now stored per chain.
+        let result = pallet_inflation_rewards::ChainsToReward::<T>::translate(
+            |old: Option<ChainsToRewardValueV0<pallet_inflation_rewards::BalanceOf<T>>>| {
+                old.map(|old| pallet_inflation_rewards::ChainsToRewardValue {
+                    rewards: BoundedVec::truncate_from(
+                        old.para_ids
+                            .into_iter()
+                            .map(|para_id| (para_id, old.rewards_per_chain))
+                            .collect(),
+                    ),
+                })
+            },
+        );
+
+        if result.is_err() {
+            log::warn!("Failed to decode ChainsToReward, it will be overwritten on next block");
+        }
+
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+        Ok(vec![])
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(&self, _state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+        ensure!(
+            pallet_inflation_rewards::ChainsToReward::<T>::get().is_some(),
+            "ChainsToReward should be decodable after migration"
+        );
+
+        Ok(())
+    }
+}
+
+pub struct PooledStakingTotalStakeMigration<T>(pub PhantomData<T>);
+impl<T> Migration for PooledStakingTotalStakeMigration<T>
+where
+    T: pallet_pooled_staking::Config,
+{
+    fn friendly_name(&self) -> &str {
+        "TM_PooledStakingTotalStakeMigration"
+    }
+
+    fn migrate(&self, _available_weight: Weight) -> Weight {
+        use {
+            frame_support::{storage::KeyPrefixIterator, StorageHasher},
+            pallet_pooled_staking::{Pools, PoolsKey},
+            parity_scale_codec::{Decode, Encode},
+        };
+
+        let mut reads = 0u64;
+        let mut total_stake = T::Balance::zero();
+
+        // `Pools` keys are `prefix ++ blake2_128(candidate) ++ candidate ++ ...`, with one
+        // entry per delegator and pool. Only the first key of each candidate is read, then
+        // all its other keys are skipped, so this is bounded by the number of candidates.
+        let prefix = Pools::<T>::final_prefix().to_vec();
+        let mut previous_key = prefix.clone();
+        loop {
+            let next_candidate = KeyPrefixIterator::new(prefix.clone(), previous_key, |raw_key| {
+                let mut candidate = raw_key.get(16..).ok_or("Pools key too short")?;
+                T::AccountId::decode(&mut candidate)
+            })
+            .next();
+            let Some(candidate) = next_candidate else {
+                break;
+            };
+
+            reads += 2;
+            total_stake = total_stake
+                .saturating_add(Pools::<T>::get(&candidate, &PoolsKey::CandidateTotalStake));
+
+            // Smallest key greater than all the keys of this candidate
+            previous_key = [
+                prefix.as_slice(),
+                Blake2_128Concat::hash(&candidate.encode()).as_slice(),
+            ]
+            .concat();
+            while previous_key.last() == Some(&u8::MAX) {
+                previous_key.pop();
+            }
+            match previous_key.last_mut() {
+                Some(last) => *last += 1,
+                None => break,
+            }
+        }
+
+        pallet_pooled_staking::TotalStake::<T>::put(total_stake);
+
+        T::DbWeight::get().reads_writes(reads, 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+        Ok(vec![])
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(&self, _state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+        let total_stake = pallet_pooled_staking::Pools::<T>::iter()
+            .filter(|(_, key, _)| key == &pallet_pooled_staking::PoolsKey::CandidateTotalStake)
+            .fold(T::Balance::zero(), |total, (_, _, stake)| {
+                total.saturating_add(stake)
+            });
+
+        ensure!(
+            pallet_pooled_staking::TotalStake::<T>::get() == total_stake,
+            "TotalStake should be the sum of the total stake of all candidates"
+        );
+
+        Ok(())
+    }
+}
+
+/// Add the pending operations created before the operations queues existed to the
+/// queues, so they are executed automatically too.
+pub struct PooledStakingOperationsQueueMigration<T>(pub PhantomData<T>);
+impl<T> Migration for PooledStakingOperationsQueueMigration<T>
+where
+    T: pallet_pooled_staking::Config,
+{
+    fn friendly_name(&self) -> &str {
+        "TM_PooledStakingOperationsQueueMigration"
+    }
+
+    fn migrate(&self, _available_weight: Weight) -> Weight {
+        use pallet_pooled_staking::PendingOperationKey;
+
+        let mut reads = 0u64;
+        let mut joining = vec![];
+        let mut leaving = vec![];
+
+        for (delegator, operation, _value) in pallet_pooled_staking::PendingOperations::<T>::iter()
+        {
+            reads += 1;
+            match &operation {
+                PendingOperationKey::JoiningAutoCompounding { at, .. }
+                | PendingOperationKey::JoiningManualRewards { at, .. } => {
+                    joining.push((at.clone(), delegator, operation))
+                }
+                PendingOperationKey::Leaving { at, .. }
+                | PendingOperationKey::Redelegating { at, .. } => {
+                    leaving.push((at.clone(), delegator, operation))
+                }
+            }
+        }
+
+        // Queues must be ordered by maturity.
+        joining.sort_by(|a, b| a.0.cmp(&b.0));
+        leaving.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let queued = (joining.len() + leaving.len()) as u64;
+        for (_at, delegator, operation) in joining {
+            pallet_pooled_staking::Pallet::<T>::queue_pending_operation(delegator, operation);
+        }
+        for (_at, delegator, operation) in leaving {
+            pallet_pooled_staking::Pallet::<T>::queue_pending_operation(delegator, operation);
+        }
+
+        // Each push reads and writes the queue cursor and writes the queued operation.
+        T::DbWeight::get().reads_writes(reads.saturating_add(queued), queued.saturating_mul(2))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+        use {
+            pallet_pooled_staking::{OperationsQueue, QueueCursors},
+            parity_scale_codec::Encode,
+        };
+
+        for queue in [OperationsQueue::Joining, OperationsQueue::Leaving] {
+            let cursor = QueueCursors::<T>::get(queue);
+            ensure!(
+                cursor.head == cursor.tail,
+                "Operations queues should be empty before the migration"
+            );
+        }
+
+        let count = pallet_pooled_staking::PendingOperations::<T>::iter().count() as u64;
+
+        Ok(count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(&self, state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+        use {
+            pallet_pooled_staking::{OperationsQueue, QueueCursors},
+            parity_scale_codec::Decode,
+        };
+
+        let count = u64::decode(&mut state.as_slice()).map_err(|_| "Invalid pre upgrade state")?;
+
+        let queued = [OperationsQueue::Joining, OperationsQueue::Leaving]
+            .into_iter()
+            .map(|queue| {
+                let cursor = QueueCursors::<T>::get(queue);
+                cursor.tail.saturating_sub(cursor.head)
+            })
+            .sum::<u64>();
+
+        ensure!(queued == count, "All pending operations should be queued");
+
+        Ok(())
+    }
+}
+
 pub struct FlashboxMigrations<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> GetMigrations for FlashboxMigrations<Runtime>
 where
     Runtime: pallet_balances::Config,
+    Runtime: pallet_inflation_rewards::Config,
     Runtime: pallet_configuration::Config,
     Runtime: pallet_registrar::Config,
     Runtime: pallet_data_preservers::Config,
@@ -806,6 +1183,8 @@ where
         let migrate_data_preservers_assignments =
             DataPreserversAssignmentsMigration::<Runtime>(Default::default());
         let migrate_registrar_reserves = RegistrarReserveToHoldMigration::<Runtime>(Default::default());
+        let migrate_inflation_rewards_chains_to_reward =
+            InflationRewardsChainsToRewardMigration::<Runtime>(Default::default());
 
         vec![
             // Applied in runtime 400
@@ -819,6 +1198,7 @@ where
             Box::new(migrate_registrar_manager),
             Box::new(migrate_data_preservers_assignments),
             Box::new(migrate_registrar_reserves),
+            Box::new(migrate_inflation_rewards_chains_to_reward),
         ]
     }
 }
@@ -828,6 +1208,8 @@ pub struct DanceboxMigrations<Runtime>(PhantomData<Runtime>);
 impl<Runtime> GetMigrations for DanceboxMigrations<Runtime>
 where
     Runtime: pallet_pooled_staking::Config,
+    Runtime: pallet_inflation_rewards::Config,
+    Runtime: pallet_xcm_core_buyer::Config,
     Runtime: pallet_registrar::Config,
     Runtime: pallet_balances::Config,
     Runtime: pallet_configuration::Config,
@@ -874,6 +1256,14 @@ where
         let foreign_asset_creator_migration =
             ForeignAssetCreatorMigration::<Runtime>(Default::default());
         let migrate_registrar_reserves = RegistrarReserveToHoldMigration::<Runtime>(Default::default());
+        let migrate_inflation_rewards_chains_to_reward =
+            InflationRewardsChainsToRewardMigration::<Runtime>(Default::default());
+        let migrate_pooled_staking_total_stake =
+            PooledStakingTotalStakeMigration::<Runtime>(Default::default());
+        let migrate_xcm_core_buyer_in_flight_orders =
+            XcmCoreBuyerInFlightOrdersMigration::<Runtime>(Default::default());
+        let migrate_pooled_staking_operations_queue =
+            PooledStakingOperationsQueueMigration::<Runtime>(Default::default());
 
         vec![
             // Applied in runtime 200
@@ -900,15 +1290,38 @@ where
             Box::new(migrate_pallet_xcm_v4),
             Box::new(foreign_asset_creator_migration),
             Box::new(migrate_data_preservers_assignments),
-            Box::new(migrate_registrar_reserves)
+            Box::new(migrate_registrar_reserves),
+            Box::new(migrate_inflation_rewards_chains_to_reward),
+            Box::new(migrate_pooled_staking_total_stake),
+            Box::new(migrate_xcm_core_buyer_in_flight_orders),
+            Box::new(migrate_pooled_staking_operations_queue),
         ]
     }
 }
 
 pub struct StarlightMigrations<Runtime>(PhantomData<Runtime>);
 
-impl<Runtime> GetMigrations for StarlightMigrations<Runtime> {
+impl<Runtime> GetMigrations for StarlightMigrations<Runtime>
+where
+    Runtime: pallet_validator_staking::Config,
+    Runtime: pallet_inflation_rewards::Config,
+    Runtime: pallet_pooled_staking::Config,
+{
     fn get_migrations() -> Vec<Box<dyn Migration>> {
-        vec![]
+        let migrate_validator_manager =
+            MigrateValidatorManagerToValidatorStaking::<Runtime>(Default::default());
+        let migrate_inflation_rewards_chains_to_reward =
+            InflationRewardsChainsToRewardMigration::<Runtime>(Default::default());
+        let migrate_pooled_staking_total_stake =
+            PooledStakingTotalStakeMigration::<Runtime>(Default::default());
+        let migrate_pooled_staking_operations_queue =
+            PooledStakingOperationsQueueMigration::<Runtime>(Default::default());
+
+        vec![
+            Box::new(migrate_validator_manager),
+            Box::new(migrate_inflation_rewards_chains_to_reward),
+            Box::new(migrate_pooled_staking_total_stake),
+            Box::new(migrate_pooled_staking_operations_queue),
+        ]
     }
 }
@@ -75,14 +75,14 @@ use {
     pallet_balances::NegativeImbalance,
     pallet_collator_assignment::{GetRandomnessForNextBlock, RotateCollatorsEveryNSessions},
     pallet_invulnerables::InvulnerableRewardDistribution,
-    pallet_pooled_staking::traits::{IsCandidateEligible, Timer},
+    pallet_pooled_staking::traits::{IsCandidateEligible, SessionTimer},
     pallet_registrar::RegistrarHooks,
     pallet_registrar_runtime_api::ContainerChainGenesisData,
     pallet_services_payment::{ProvideBlockProductionCost, ProvideCollatorAssignmentCost},
     pallet_session::{SessionManager, ShouldEndSession},
     pallet_stream_payment_runtime_api::{StreamPaymentApiError, StreamPaymentApiStatus},
     pallet_transaction_payment::FungibleAdapter,
-    pallet_xcm_core_buyer::BuyingError,
+    pallet_xcm_core_buyer::{BuyCoreDryRunError, BuyingError, CoreBuyingSpending, SpendingLimits},
     polkadot_runtime_common::BlockHashCount,
     scale_info::{prelude::format, TypeInfo},
     serde::{Deserialize, Serialize},
@@ -100,12 +100,13 @@ use {
             IdentityLookup, Verify,
         },
         transaction_validity::{TransactionSource, TransactionValidity},
-        AccountId32, ApplyExtrinsicResult,
+        AccountId32, ApplyExtrinsicResult, Perquintill,
     },
     sp_std::{collections::btree_set::BTreeSet, marker::PhantomData, prelude::*},
     sp_version::RuntimeVersion,
     staging_xcm::{
-        IntoVersion, VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm,
+        latest::Location, IntoVersion, VersionedAssetId, VersionedAssets, VersionedLocation,
+        VersionedXcm,
     },
     tp_traits::{
         apply, derive_storage_traits, GetContainerChainAuthor, GetHostConfiguration,
@@ -1106,7 +1107,7 @@ impl pallet_invulnerables::Config for Runtime {
 parameter_types! {
     #[derive(Clone)]
     pub const MaxLengthParaIds: u32 = 100u32;
-    pub const MaxEncodedGenesisDataSize: u32 = 5_000_000u32; // 5MB
+    pub const MaxEncodedGenesisDataSize: u32 = tp_traits::MAX_ENCODED_GENESIS_DATA_SIZE;
 }
 
 pub struct CurrentSessionIndexGetter;
@@ -1116,6 +1117,13 @@ impl tp_traits::GetSessionIndex<u32> for CurrentSessionIndexGetter {
     fn session_index() -> u32 {
         Session::current_index()
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn skip_to_session(session_index: u32) {
+        while Session::current_index() < session_index {
+            Session::rotate_session();
+        }
+    }
 }
 
 impl pallet_configuration::Config for Runtime {
@@ -1250,6 +1258,7 @@ impl pallet_registrar::Config for Runtime {
     type MaxGenesisDataSize = MaxEncodedGenesisDataSize;
     type RegisterWithRelayProofOrigin = EnsureSigned<AccountId>;
     type RelayStorageRootProvider = PalletRelayStorageRootProvider;
+    type RegisterWithXcmOrigin = xcm_config::EnsureSiblingParachain;
     type SessionDelay = ConstU32<2>;
     type SessionIndex = u32;
     type CurrentSessionIndex = CurrentSessionIndexGetter;
@@ -1333,7 +1342,12 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             // We don't have governance yet
             ProxyType::Governance => false,
             ProxyType::Staking => {
-                matches!(c, RuntimeCall::Session(..) | RuntimeCall::PooledStaking(..))
+                matches!(
+                    c,
+                    RuntimeCall::Session(..)
+                        | RuntimeCall::PooledStaking(..)
+                        | RuntimeCall::LiquidStaking(..)
+                )
             }
             ProxyType::CancelProxy => matches!(
                 c,
@@ -1421,6 +1435,7 @@ impl Contains<RuntimeCall> for MaintenanceFilter {
                 | RuntimeCall::Session(..)
                 | RuntimeCall::System(..)
                 | RuntimeCall::PooledStaking(..)
+                | RuntimeCall::LiquidStaking(..)
                 | RuntimeCall::Utility(..)
                 | RuntimeCall::PolkadotXcm(..)
         )
@@ -1465,43 +1480,13 @@ parameter_types! {
     pub const RewardsCollatorCommission: Perbill = Perbill::from_percent(20);
     // Need to wait 2 sessions before being able to join or leave staking pools
     pub const StakingSessionDelay: u32 = 2;
-}
-
-pub struct SessionTimer<G>(PhantomData<G>);
-
-impl<G> Timer for SessionTimer<G>
-where
-    G: Get<u32>,
-{
-    type Instant = u32;
-
-    fn now() -> Self::Instant {
-        Session::current_index()
-    }
-
-    fn is_elapsed(instant: &Self::Instant) -> bool {
-        let delay = G::get();
-        let Some(end) = instant.checked_add(delay) else {
-            return false;
-        };
-        end <= Self::now()
-    }
-
-    #[cfg(feature = "runtime-benchmarks")]
-    fn elapsed_instant() -> Self::Instant {
-        let delay = G::get();
-        Self::now()
-            .checked_add(delay)
-            .expect("overflow when computing valid elapsed instant")
-    }
-
-    #[cfg(feature = "runtime-benchmarks")]
-    fn skip_to_elapsed() {
-        let session_to_reach = Self::elapsed_instant();
-        while Self::now() < session_to_reach {
-            Session::rotate_session();
-        }
-    }
+    // Delegators have 4 sessions to leave a candidate before its new commission applies
+    pub const CommissionChangeSessionDelay: u32 = 4;
+    // Commission changes can be executed during 4 sessions once the delay is elapsed
+    pub const CommissionChangeExpirySessionDelay: u32 = 8;
+    pub const MaxCandidateMetadataLength: u32 = 128;
+    pub const CandidateInfoDepositBaseFee: Balance = currency::STORAGE_ITEM_FEE;
+    pub const CandidateInfoDepositByteFee: Balance = currency::STORAGE_BYTE_FEE;
 }
 
 pub struct CandidateHasRegisteredKeys;
@@ -1537,13 +1522,59 @@ impl pallet_pooled_staking::Config for Runtime {
     type MinimumSelfDelegation = MinimumSelfDelegation;
     type RuntimeHoldReason = RuntimeHoldReason;
     type RewardsCollatorCommission = RewardsCollatorCommission;
-    type JoiningRequestTimer = SessionTimer<StakingSessionDelay>;
-    type LeavingRequestTimer = SessionTimer<StakingSessionDelay>;
+    type CommissionChangeTimer =
+        SessionTimer<CurrentSessionIndexGetter, CommissionChangeSessionDelay>;
+    type CommissionChangeExpiryTimer =
+        SessionTimer<CurrentSessionIndexGetter, CommissionChangeExpirySessionDelay>;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type JoiningRequestTimer = SessionTimer<CurrentSessionIndexGetter, StakingSessionDelay>;
+    type LeavingRequestTimer = SessionTimer<CurrentSessionIndexGetter, StakingSessionDelay>;
     type EligibleCandidatesBufferSize = ConstU32<100>;
     type EligibleCandidatesFilter = CandidateHasRegisteredKeys;
+    type SaturatedRewardsHandler = OnUnbalancedInflation;
+    type MaxCandidateMetadataLength = MaxCandidateMetadataLength;
+    type CandidateInfoDeposit =
+        tp_traits::BytesDeposit<CandidateInfoDepositBaseFee, CandidateInfoDepositByteFee>;
     type WeightInfo = weights::pallet_pooled_staking::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub LiquidStakingAccount: AccountId32 = PalletId(*b"LIQSTAKE").into_account_truncating();
+}
+
+pub type LiquidStakingAssetsInstance = pallet_assets::Instance2;
+impl pallet_assets::Config<LiquidStakingAssetsInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    // Assets are only created by pallet_liquid_staking
+    type CreateOrigin = frame_support::traits::NeverEnsureOrigin<AccountId>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = weights::pallet_assets::SubstrateWeight<Runtime>;
+    type CallbackHandle = ();
+    type AssetAccountDeposit = ConstU128<0>;
+    type RemoveItemsLimit = ConstU32<1000>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+impl pallet_liquid_staking::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = LiquidStakingAssets;
+    type AssetId = u32;
+    type LiquidStakingAccount = LiquidStakingAccount;
+    type WeightInfo = weights::pallet_liquid_staking::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub ParachainBondAccount: AccountId32 = PalletId(*b"ParaBond").into_account_truncating();
     pub PendingRewardsAccount: AccountId32 = PalletId(*b"PENDREWD").into_account_truncating();
@@ -1553,22 +1584,41 @@ parameter_types! {
     // 1% in the case of dev mode
     // TODO: check if we can put the prod inflation for tests too
     // TODO: better calculus for going from annual to block inflation (if it can be done)
-    pub const InflationRate: Perbill = prod_or_fast!(Perbill::from_parts(9), Perbill::from_percent(1));
+    pub const InflationRate: Perquintill =
+        prod_or_fast!(Perquintill::from_parts(9_000_000_000), Perquintill::from_percent(1));
 
     // 30% for parachain bond, so 70% for staking
     pub const RewardsPortion: Perbill = Perbill::from_percent(70);
+
+    // Inflation does not depend on the staking rate until governance sets a schedule
+    pub DefaultInflationSchedule: pallet_inflation_rewards::InflationSchedule =
+        pallet_inflation_rewards::InflationSchedule {
+            max_inflation: InflationRate::get(),
+            min_inflation: InflationRate::get(),
+            target_staking_rate: Perbill::from_percent(50),
+            decay_per_year: Perbill::zero(),
+            rewards_portion: RewardsPortion::get(),
+        };
+    pub const BlocksPerYear: u32 = 365 * DAYS;
+    pub const MaxPooledRewardsPayouts: u32 = 100;
 }
 
 pub struct GetSelfChainBlockAuthor;
-impl Get<AccountId32> for GetSelfChainBlockAuthor {
-    fn get() -> AccountId32 {
+impl Get<Option<AccountId32>> for GetSelfChainBlockAuthor {
+    fn get() -> Option<AccountId32> {
         // TODO: we should do a refactor here, and use either authority-mapping or collator-assignemnt
         // we should also make sure we actually account for the weight of these
         // although most of these should be cached as they are read every block
         let slot = u64::from(<Runtime as pallet_author_inherent::Config>::SlotBeacon::slot());
         let self_para_id = ParachainInfo::get();
-        let author = CollatorAssignment::author_for_slot(slot.into(), self_para_id);
-        author.expect("author should be set")
+        CollatorAssignment::author_for_slot(slot.into(), self_para_id)
+    }
+}
+
+pub struct PooledStakingTotalStake;
+impl Get<Balance> for PooledStakingTotalStake {
+    fn get() -> Balance {
+        PooledStaking::total_stake()
     }
 }
 
@@ -1584,11 +1634,17 @@ impl pallet_inflation_rewards::Config for Runtime {
     type Currency = Balances;
     type ContainerChains = Registrar;
     type GetSelfChainBlockAuthor = GetSelfChainBlockAuthor;
-    type InflationRate = InflationRate;
+    type DefaultInflationSchedule = DefaultInflationSchedule;
+    type InflationScheduleOrigin = EnsureRoot<AccountId>;
+    type BlocksPerYear = BlocksPerYear;
+    type StakedBalance = PooledStakingTotalStake;
     type OnUnbalanced = OnUnbalancedInflation;
     type PendingRewardsAccount = PendingRewardsAccount;
     type StakingRewardsDistributor = InvulnerableRewardDistribution<Self, Balances, PooledStaking>;
-    type RewardsPortion = RewardsPortion;
+    type RewardsPolicy = pallet_inflation_rewards::EqualSplit;
+    type CurrentSessionIndex = CurrentSessionIndexGetter;
+    type MaxPooledRewardsPayouts = MaxPooledRewardsPayouts;
+    type WeightInfo = weights::pallet_inflation_rewards::SubstrateWeight<Runtime>;
 }
 
 impl pallet_tx_pause::Config for Runtime {
@@ -1878,6 +1934,8 @@ construct_runtime!(
         PooledStaking: pallet_pooled_staking = 34,
         // InflationRewards must be after Session and AuthorInherent
         InflationRewards: pallet_inflation_rewards = 35,
+        LiquidStaking: pallet_liquid_staking = 36,
+        LiquidStakingAssets: pallet_assets::<Instance2>::{Pallet, Call, Storage, Event<T>} = 37,
 
         // Treasury stuff.
         Treasury: pallet_treasury::{Pallet, Storage, Config<T>, Event<T>, Call} = 40,
@@ -1924,7 +1982,9 @@ mod benches {
         [pallet_invulnerables, Invulnerables]
         [pallet_session, SessionBench::<Runtime>]
         [pallet_author_inherent, AuthorInherent]
+        [pallet_inflation_rewards, InflationRewards]
         [pallet_pooled_staking, PooledStaking]
+        [pallet_liquid_staking, LiquidStaking]
         [pallet_treasury, Treasury]
         [cumulus_pallet_xcmp_queue, XcmpQueue]
         [cumulus_pallet_dmp_queue, DmpQueue]
@@ -2456,6 +2516,16 @@ impl_runtime_apis! {
                 .map(|profile| profile.url.into())
                 .collect()
         }
+
+        /// Fetch the latest known runtime code hash and head for this para id
+        fn code_info(para_id: ParaId) -> Option<tp_traits::ContainerChainCodeInfo> {
+            Registrar::para_code_info(para_id)
+        }
+
+        /// Fetch the scheduled pause window of this para id, in session indexes
+        fn pause_window(para_id: ParaId) -> Option<tp_traits::PauseWindow<u32>> {
+            Registrar::pause_window(para_id)
+        }
     }
 
     impl pallet_registrar_runtime_api::OnDemandBlockProductionApi<Block, ParaId, Slot> for Runtime {
@@ -2588,6 +2658,40 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_pooled_staking_runtime_api::PooledStakingApi<Block, AccountId, Balance> for Runtime {
+        fn candidate_stake_cap() -> Option<Balance> {
+            PooledStaking::candidate_stake_cap()
+        }
+
+        fn block_reward_accruals() -> Result<
+            (
+                SessionIndex,
+                Vec<pallet_pooled_staking_runtime_api::RewardAccrual<AccountId, Balance>>,
+            ),
+            sp_runtime::DispatchError,
+        > {
+            let events =
+                System::read_events_no_consensus().filter_map(|record| match record.event {
+                    RuntimeEvent::PooledStaking(event) => Some(event),
+                    _ => None,
+                });
+
+            let accruals = PooledStaking::reward_accruals(events)?
+                .into_iter()
+                .map(|accrual| pallet_pooled_staking_runtime_api::RewardAccrual {
+                    delegator: accrual.delegator,
+                    candidate: accrual.candidate,
+                    auto_compounding_rewards: accrual.auto_compounding_rewards,
+                    manual_claim_rewards: accrual.manual_claim_rewards,
+                    claimed_manual_rewards: accrual.claimed_manual_rewards,
+                })
+                .collect();
+
+            Ok((Session::current_index(), accruals))
+        }
+    }
+
+    #[api_version(2)]
     impl pallet_xcm_core_buyer_runtime_api::XCMCoreBuyerApi<Block, BlockNumber, ParaId, NimbusId> for Runtime {
         fn is_core_buying_allowed(para_id: ParaId, collator_public_key: NimbusId) -> Result<(), BuyingError<BlockNumber>> {
             XcmCoreBuyer::is_core_buying_allowed(para_id, Some(collator_public_key))
@@ -2604,6 +2708,27 @@ impl_runtime_apis! {
             Box::new(unsigned_extrinsic)
         }
 
+        fn dry_run_buy_core(para_id: ParaId, proof: BuyCoreCollatorProof<NimbusId>) -> Result<(), BuyCoreDryRunError> {
+            let call = RuntimeCall::XcmCoreBuyer(pallet_xcm_core_buyer::Call::buy_core {
+                para_id,
+                proof
+            });
+
+            let effects = PolkadotXcm::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(
+                frame_system::RawOrigin::None.into(),
+                call,
+            ).map_err(|_| BuyCoreDryRunError::DryRunUnavailable)?;
+
+            effects.execution_result.map_err(|e| BuyCoreDryRunError::CallFailed(e.error))?;
+
+            let relay_chain = VersionedLocation::from(Location::parent());
+            if !effects.forwarded_xcms.iter().any(|(destination, messages)| *destination == relay_chain && !messages.is_empty()) {
+                return Err(BuyCoreDryRunError::XcmNotSent);
+            }
+
+            Ok(())
+        }
+
         fn get_buy_core_signature_nonce(para_id: ParaId) -> u64 {
             pallet_xcm_core_buyer::CollatorSignatureNonce::<Runtime>::get(para_id)
         }
@@ -2611,6 +2736,14 @@ impl_runtime_apis! {
         fn get_buy_core_slot_drift() -> Slot {
             <Runtime as pallet_xcm_core_buyer::Config>::BuyCoreSlotDrift::get()
         }
+
+        fn get_core_buying_spending_limits(para_id: ParaId) -> Option<SpendingLimits> {
+            pallet_xcm_core_buyer::ParaSpendingLimits::<Runtime>::get(para_id)
+        }
+
+        fn get_core_buying_spending(para_id: ParaId) -> CoreBuyingSpending<BlockNumber> {
+            XcmCoreBuyer::current_spending(para_id)
+        }
     }
 
     impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
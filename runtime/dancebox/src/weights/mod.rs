@@ -30,7 +30,9 @@ pub mod pallet_configuration;
 pub mod pallet_data_preservers;
 pub mod pallet_foreign_asset_creator;
 pub mod pallet_identity;
+pub mod pallet_inflation_rewards;
 pub mod pallet_invulnerables;
+pub mod pallet_liquid_staking;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_pooled_staking;
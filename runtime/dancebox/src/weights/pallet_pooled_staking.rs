@@ -74,8 +74,8 @@ impl<T: frame_system::Config> pallet_pooled_staking::WeightInfo for SubstrateWei
 		//  Estimated: `32155`
 		// Minimum execution time: 194_244_000 picoseconds.
 		Weight::from_parts(196_320_000, 32155)
-			.saturating_add(T::DbWeight::get().reads(18_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: `PooledStaking::PendingOperations` (r:100 w:100)
 	/// Proof: `PooledStaking::PendingOperations` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -116,8 +116,8 @@ impl<T: frame_system::Config> pallet_pooled_staking::WeightInfo for SubstrateWei
 		//  Estimated: `33890`
 		// Minimum execution time: 145_912_000 picoseconds.
 		Weight::from_parts(147_881_000, 33890)
-			.saturating_add(T::DbWeight::get().reads(16_u64))
-			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
 	}
 	/// Storage: `PooledStaking::Pools` (r:300 w:100)
 	/// Proof: `PooledStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -182,8 +182,8 @@ impl<T: frame_system::Config> pallet_pooled_staking::WeightInfo for SubstrateWei
 		//  Estimated: `31168`
 		// Minimum execution time: 107_025_000 picoseconds.
 		Weight::from_parts(109_000_000, 31168)
-			.saturating_add(T::DbWeight::get().reads(12_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `PooledStaking::Pools` (r:9 w:5)
 	/// Proof: `PooledStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -193,13 +193,214 @@ impl<T: frame_system::Config> pallet_pooled_staking::WeightInfo for SubstrateWei
 	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PooledStaking::CandidateCommission` (r:1 w:0)
+	/// Proof: `PooledStaking::CandidateCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::CommissionBounds` (r:1 w:0)
+	/// Proof: `PooledStaking::CommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn distribute_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1341`
 		//  Estimated: `24606`
 		// Minimum execution time: 182_187_000 picoseconds.
 		Weight::from_parts(186_128_000, 24606)
-			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	/// Storage: `PooledStaking::CommissionBounds` (r:1 w:0)
+	/// Proof: `PooledStaking::CommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::Pools` (r:1 w:0)
+	/// Proof: `PooledStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::PendingCommissionChanges` (r:0 w:1)
+	/// Proof: `PooledStaking::PendingCommissionChanges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1527`
+		// Minimum execution time: 14_210_000 picoseconds.
+		Weight::from_parts(14_782_000, 1527)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PooledStaking::PendingCommissionChanges` (r:1 w:1)
+	/// Proof: `PooledStaking::PendingCommissionChanges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::CommissionBounds` (r:1 w:0)
+	/// Proof: `PooledStaking::CommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::CandidateCommission` (r:1 w:1)
+	/// Proof: `PooledStaking::CandidateCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn execute_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `3680`
+		// Minimum execution time: 21_473_000 picoseconds.
+		Weight::from_parts(22_106_000, 3680)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `PooledStaking::CommissionBounds` (r:0 w:1)
+	/// Proof: `PooledStaking::CommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_commission_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_912_000 picoseconds.
+		Weight::from_parts(7_245_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PooledStaking::Pools` (r:13 w:9)
+	/// Proof: `PooledStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::SortedEligibleCandidates` (r:1 w:1)
+	/// Proof: `PooledStaking::SortedEligibleCandidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::PendingOperations` (r:1 w:1)
+	/// Proof: `PooledStaking::PendingOperations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn redelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `726`
+		//  Estimated: `33891`
+		// Minimum execution time: 148_203_000 picoseconds.
+		Weight::from_parts(151_460_000, 33891)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: `PooledStaking::QueueCursors` (r:2 w:2)
+	/// Proof: `PooledStaking::QueueCursors` (`max_values`: Some(2), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::QueuedOperations` (r:100 w:100)
+	/// Proof: `PooledStaking::QueuedOperations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::PendingOperations` (r:100 w:100)
+	/// Proof: `PooledStaking::PendingOperations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::AutoExecutionOptOut` (r:100 w:0)
+	/// Proof: `PooledStaking::AutoExecutionOptOut` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::Pools` (r:600 w:400)
+	/// Proof: `PooledStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::SortedEligibleCandidates` (r:1 w:1)
+	/// Proof: `PooledStaking::SortedEligibleCandidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 100]`.
+	fn execute_queued_operations(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `520 + b * (423 ±0)`
+		//  Estimated: `3919 + b * (15302 ±0)`
+		// Minimum execution time: 71_512_000 picoseconds.
+		Weight::from_parts(12_184_302, 3919)
+			// Standard Error: 21_407
+			.saturating_add(Weight::from_parts(62_905_118, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 15302).saturating_mul(b.into()))
+	}
+	/// Storage: `PooledStaking::AutoExecutionOptOut` (r:1 w:1)
+	/// Proof: `PooledStaking::AutoExecutionOptOut` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::PendingOperations` (r:101 w:0)
+	/// Proof: `PooledStaking::PendingOperations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::QueueCursors` (r:100 w:100)
+	/// Proof: `PooledStaking::QueueCursors` (`max_values`: Some(2), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::QueuedOperations` (r:0 w:100)
+	/// Proof: `PooledStaking::QueuedOperations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[0, 100]`.
+	fn set_auto_execution(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_016_000 picoseconds.
+		Weight::from_parts(7_389_000, 0)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(4_215_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2580).saturating_mul(b.into()))
+	}
+	/// Storage: `PooledStaking::CandidateStakeCap` (r:0 w:1)
+	/// Proof: `PooledStaking::CandidateStakeCap` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::SortedEligibleCandidates` (r:1 w:1)
+	/// Proof: `PooledStaking::SortedEligibleCandidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::Pools` (r:100 w:0)
+	/// Proof: `PooledStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 100]`.
+	fn set_candidate_stake_cap(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `165 + b * (105 ±0)`
+		//  Estimated: `1650 + b * (2580 ±0)`
+		// Minimum execution time: 14_305_000 picoseconds.
+		Weight::from_parts(13_087_452, 1650)
+			// Standard Error: 2_918
+			.saturating_add(Weight::from_parts(4_361_207, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2580).saturating_mul(b.into()))
+	}
+	/// Storage: `PooledStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `PooledStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_candidate_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `186`
+		//  Estimated: `3676`
+		// Minimum execution time: 47_862_000 picoseconds.
+		Weight::from_parts(49_115_000, 3676)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PooledStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `PooledStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn clear_candidate_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `449`
+		//  Estimated: `3676`
+		// Minimum execution time: 45_097_000 picoseconds.
+		Weight::from_parts(46_321_000, 3676)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PooledStaking::OfflineCandidates` (r:1 w:1)
+	/// Proof: `PooledStaking::OfflineCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::Pools` (r:7 w:1)
+	/// Proof: `PooledStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::SortedEligibleCandidates` (r:1 w:1)
+	/// Proof: `PooledStaking::SortedEligibleCandidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn go_offline() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1322`
+		//  Estimated: `22187`
+		// Minimum execution time: 43_116_000 picoseconds.
+		Weight::from_parts(44_530_000, 22187)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PooledStaking::OfflineCandidates` (r:1 w:1)
+	/// Proof: `PooledStaking::OfflineCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::Pools` (r:7 w:1)
+	/// Proof: `PooledStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PooledStaking::SortedEligibleCandidates` (r:1 w:1)
+	/// Proof: `PooledStaking::SortedEligibleCandidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn go_online() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1322`
+		//  Estimated: `22187`
+		// Minimum execution time: 43_904_000 picoseconds.
+		Weight::from_parts(45_262_000, 22187)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
\ No newline at end of file
@@ -209,4 +209,14 @@ impl<T: frame_system::Config> pallet_xcm_core_buyer::WeightInfo for SubstrateWei
 		Weight::from_parts(3_285_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmCoreBuyer::ParaSpendingLimits` (r:0 w:1)
+	/// Proof: `XcmCoreBuyer::ParaSpendingLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_spending_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -30,11 +30,13 @@ use {
         PolkadotXcm, Registrar, Runtime, RuntimeBlockWeights, RuntimeCall, RuntimeEvent,
         RuntimeOrigin, TransactionByteFee, WeightToFee, XcmpQueue,
     },
-    crate::{get_para_id_authorities, weights, AuthorNoting},
+    crate::{get_para_id_authorities, weights, AuthorNoting, CurrentSessionIndexGetter, DAYS},
     cumulus_primitives_core::{AggregateMessageOrigin, ParaId},
     frame_support::{
         parameter_types,
-        traits::{Everything, Nothing, PalletInfoAccess, TransformOrigin},
+        traits::{
+            EitherOfDiverse, EnsureOrigin, Everything, Nothing, PalletInfoAccess, TransformOrigin,
+        },
         weights::Weight,
     },
     frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot},
@@ -61,7 +63,11 @@ use {
         SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId,
         UsingComponents, WeightInfoBounds, WithComputedOrigin,
     },
-    staging_xcm_executor::{traits::JustTry, XcmExecutor},
+    staging_xcm_executor::{
+        traits::{ConvertLocation, JustTry},
+        XcmExecutor,
+    },
+    tanssi_relay_encoder::custom::RelayCallIndices,
     tp_traits::ParathreadParams,
 };
 
@@ -176,6 +182,33 @@ pub type XcmOriginToTransactDispatchOrigin = (
     XcmPassthrough<RuntimeOrigin>,
 );
 
+/// Ensures the origin is a sibling parachain, sent through XCM with `OriginKind::Native`. Returns
+/// its para id and its sovereign account in this chain. Used by container chains to register
+/// themselves.
+pub struct EnsureSiblingParachain;
+
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingParachain {
+    type Success = (ParaId, AccountId);
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        let origin: Result<cumulus_pallet_xcm::Origin, RuntimeOrigin> = o.clone().into();
+        let para_id = match origin {
+            Ok(cumulus_pallet_xcm::Origin::SiblingParachain(para_id)) => para_id,
+            _ => return Err(o),
+        };
+        let sovereign_account =
+            LocationToAccountId::convert_location(&Location::new(1, [Parachain(para_id.into())]))
+                .ok_or(o)?;
+
+        Ok((para_id, sovereign_account))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(cumulus_pallet_xcm::Origin::SiblingParachain(1001.into()).into())
+    }
+}
+
 /// Means for transacting assets on this chain.
 pub type AssetTransactors = (CurrencyTransactor, ForeignFungiblesTransactor);
 pub type XcmWeigher =
@@ -510,6 +543,7 @@ parameter_types! {
     pub const AdditionalTtlForInflightOrders: BlockNumber = 5;
     pub const PendingBlockTtl: BlockNumber = 10;
     pub BuyCoreSlotDrift: Slot = Slot::from(5u64);
+    pub const CoreBuyingBlocksPerDay: BlockNumber = DAYS;
 }
 
 impl pallet_xcm_core_buyer::Config for Runtime {
@@ -536,6 +570,10 @@ impl pallet_xcm_core_buyer::Config for Runtime {
     type LatestAuthorInfoFetcher = AuthorNoting;
     type SlotBeacon = dp_consensus::AuraDigestSlotBeacon<Runtime>;
     type CollatorPublicKey = NimbusId;
+    type ManagerOrigin =
+        EitherOfDiverse<pallet_registrar::EnsureSignedByManager<Runtime>, EnsureRoot<AccountId>>;
+    type CurrentSessionIndex = CurrentSessionIndexGetter;
+    type BlocksPerDay = CoreBuyingBlocksPerDay;
     type WeightInfo = weights::pallet_xcm_core_buyer::SubstrateWeight<Runtime>;
 }
 
@@ -594,6 +632,11 @@ pub enum RelayChain {
     #[default]
     Westend,
     Rococo,
+    Starlight,
+    Polkadot,
+    Kusama,
+    /// Any other relay chain, using the pallet and call indices read from its metadata
+    Custom(RelayCallIndices),
 }
 
 pub struct EncodedCallToBuyCore;
@@ -621,6 +664,39 @@ impl GetPurchaseCoreCall<RelayChain> for EncodedCallToBuyCore {
 
                 call.encode()
             }
+            RelayChain::Starlight => {
+                let call = tanssi_relay_encoder::starlight::RelayCall::OnDemandAssignmentProvider(
+                    tanssi_relay_encoder::starlight::OnDemandAssignmentProviderCall::PlaceOrderAllowDeath {
+                        max_amount,
+                        para_id,
+                    },
+                );
+
+                call.encode()
+            }
+            RelayChain::Polkadot => {
+                let call = tanssi_relay_encoder::polkadot::RelayCall::OnDemandAssignmentProvider(
+                    tanssi_relay_encoder::polkadot::OnDemandAssignmentProviderCall::PlaceOrderAllowDeath {
+                        max_amount,
+                        para_id,
+                    },
+                );
+
+                call.encode()
+            }
+            RelayChain::Kusama => {
+                let call = tanssi_relay_encoder::kusama::RelayCall::OnDemandAssignmentProvider(
+                    tanssi_relay_encoder::kusama::OnDemandAssignmentProviderCall::PlaceOrderAllowDeath {
+                        max_amount,
+                        para_id,
+                    },
+                );
+
+                call.encode()
+            }
+            RelayChain::Custom(indices) => {
+                indices.encode_place_order_allow_death(max_amount, para_id)
+            }
         }
     }
 }
@@ -631,4 +707,9 @@ impl GetParathreadMaxCorePrice for GetMaxCorePriceFromServicesPayment {
     fn get_max_core_price(para_id: ParaId) -> Option<u128> {
         pallet_services_payment::MaxCorePrice::<Runtime>::get(para_id)
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_max_core_price(para_id: ParaId, max_core_price: u128) {
+        pallet_services_payment::MaxCorePrice::<Runtime>::insert(para_id, max_core_price);
+    }
 }
@@ -446,3 +446,49 @@ fn xcm_core_buyer_set_max_core_price() {
         assert_query_response_failure(ParaId::from(PARATHREAD_ID), query_id);
     });
 }
+
+#[test]
+fn xcm_core_buyer_failed_order_keeps_core_price_in_tank() {
+    let parathread_tank_in_relay = get_parathread_tank_relay_address();
+    let spot_price = get_on_demand_base_fee();
+    let balance_before = ROCOCO_ED + BUY_EXECUTION_COST + spot_price + 1;
+    // Max core price lower than spot_price makes the order fail in the relay chain
+    let max_core_price = spot_price / 2;
+
+    let query_id = do_test(balance_before, Some(max_core_price), false);
+
+    // The core price of the order is accounted as spent until the relay chain answers
+    Dancebox::execute_with(|| {
+        let spending = pallet_xcm_core_buyer::ParaSpending::<<Dancebox as Chain>::Runtime>::get(
+            ParaId::from(PARATHREAD_ID),
+        );
+        assert_eq!(spending.total_xcm_fees, BUY_EXECUTION_COST);
+        assert_eq!(spending.total_core_price, max_core_price);
+    });
+
+    // Only the XCM fees are taken from the tank, the core price stays in it
+    Rococo::execute_with(|| {
+        let balance_after =
+            <Rococo as RococoRelayPallet>::System::account(parathread_tank_in_relay.clone())
+                .data
+                .free;
+        assert_relay_order_event_not_emitted();
+        assert_eq!(
+            balance_after,
+            balance_before - BUY_EXECUTION_COST + BUY_EXECUTION_REFUND_ON_FAILURE
+        );
+    });
+
+    // The failure notification removes the core price from the spending counters
+    Dancebox::execute_with(|| {
+        assert_query_response_failure(ParaId::from(PARATHREAD_ID), query_id);
+
+        let spending = pallet_xcm_core_buyer::ParaSpending::<<Dancebox as Chain>::Runtime>::get(
+            ParaId::from(PARATHREAD_ID),
+        );
+        assert_eq!(spending.total_xcm_fees, BUY_EXECUTION_COST);
+        assert_eq!(spending.total_core_price, 0);
+        assert_eq!(spending.session_spent, BUY_EXECUTION_COST);
+        assert_eq!(spending.day_spent, BUY_EXECUTION_COST);
+    });
+}
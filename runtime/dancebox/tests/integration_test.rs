@@ -6221,3 +6221,284 @@ fn test_migration_registrar_reserves_to_hold() {
             );
         })
 }
+
+#[test]
+fn test_migration_xcm_core_buyer_in_flight_orders() {
+    ExtBuilder::default().build().execute_with(|| {
+        use {
+            dancebox_runtime::XcmCoreBuyer,
+            frame_support::traits::{GetStorageVersion, StorageVersion},
+            pallet_xcm_core_buyer::{InFlightCoreBuyingOrder, InFlightOrders},
+            tanssi_runtime_common::migrations::XcmCoreBuyerInFlightOrdersMigration,
+        };
+
+        let para_id = ParaId::from(3001);
+        StorageVersion::new(0).put::<XcmCoreBuyer>();
+        // Order as encoded before `core_price`, `session_index` and `day_index` were added
+        frame_support::storage::unhashed::put(
+            &InFlightOrders::<Runtime>::hashed_key_for(para_id),
+            &(para_id, 5u64, 20u32),
+        );
+
+        // Apply migration
+        let migration = XcmCoreBuyerInFlightOrdersMigration::<Runtime>(Default::default());
+        migration.migrate(Default::default());
+
+        assert_eq!(XcmCoreBuyer::on_chain_storage_version(), 1);
+        assert_eq!(
+            InFlightOrders::<Runtime>::get(para_id),
+            Some(InFlightCoreBuyingOrder {
+                para_id,
+                query_id: 5,
+                ttl: 20,
+                core_price: 0,
+                session_index: 0,
+                day_index: 0,
+            })
+        );
+    })
+}
+
+#[test]
+fn test_migration_pooled_staking_total_stake() {
+    ExtBuilder::default().build().execute_with(|| {
+        use {
+            pallet_pooled_staking::{Pools, PoolsKey, TotalStake},
+            tanssi_runtime_common::migrations::PooledStakingTotalStakeMigration,
+        };
+
+        let _ = Pools::<Runtime>::clear(u32::MAX, None);
+        for (candidate, stake) in [
+            (ALICE, 100 * UNIT),
+            (BOB, 200 * UNIT),
+            (CHARLIE, 300 * UNIT),
+        ] {
+            let candidate = AccountId::from(candidate);
+            Pools::<Runtime>::insert(&candidate, PoolsKey::CandidateTotalStake, stake);
+            // Entries of delegators are not part of the total stake
+            for delegator in [ALICE, BOB, CHARLIE, DAVE] {
+                Pools::<Runtime>::insert(
+                    &candidate,
+                    PoolsKey::AutoCompoundingShares {
+                        delegator: delegator.into(),
+                    },
+                    stake,
+                );
+            }
+        }
+        TotalStake::<Runtime>::kill();
+
+        // Apply migration
+        let migration = PooledStakingTotalStakeMigration::<Runtime>(Default::default());
+        migration.migrate(Default::default());
+
+        assert_eq!(TotalStake::<Runtime>::get(), 600 * UNIT);
+    })
+}
+
+#[test]
+fn test_migration_pooled_staking_operations_queue() {
+    ExtBuilder::default().build().execute_with(|| {
+        use {
+            pallet_pooled_staking::{
+                OperationsQueue, PendingOperationKey, PendingOperationQuery, PendingOperations,
+                QueueCursors, QueuedOperations,
+            },
+            tanssi_runtime_common::migrations::PooledStakingOperationsQueueMigration,
+        };
+
+        let candidate: AccountId = ALICE.into();
+        let late_joining = PendingOperationKey::JoiningAutoCompounding {
+            candidate: candidate.clone(),
+            at: 5,
+        };
+        let early_joining = PendingOperationKey::JoiningManualRewards {
+            candidate: candidate.clone(),
+            at: 2,
+        };
+        let leaving = PendingOperationKey::Leaving {
+            candidate: candidate.clone(),
+            at: 3,
+        };
+        PendingOperations::<Runtime>::insert(AccountId::from(BOB), &late_joining, 100);
+        PendingOperations::<Runtime>::insert(AccountId::from(CHARLIE), &early_joining, 100);
+        PendingOperations::<Runtime>::insert(AccountId::from(DAVE), &leaving, 100);
+
+        // Apply migration
+        let migration = PooledStakingOperationsQueueMigration::<Runtime>(Default::default());
+        migration.migrate(Default::default());
+
+        // Operations are queued in the order they mature
+        assert_eq!(
+            QueueCursors::<Runtime>::get(OperationsQueue::Joining).tail,
+            2
+        );
+        assert_eq!(
+            QueuedOperations::<Runtime>::get(OperationsQueue::Joining, 0),
+            Some(PendingOperationQuery {
+                delegator: CHARLIE.into(),
+                operation: early_joining,
+            })
+        );
+        assert_eq!(
+            QueuedOperations::<Runtime>::get(OperationsQueue::Joining, 1),
+            Some(PendingOperationQuery {
+                delegator: BOB.into(),
+                operation: late_joining,
+            })
+        );
+        assert_eq!(
+            QueueCursors::<Runtime>::get(OperationsQueue::Leaving).tail,
+            1
+        );
+        assert_eq!(
+            QueuedOperations::<Runtime>::get(OperationsQueue::Leaving, 0),
+            Some(PendingOperationQuery {
+                delegator: DAVE.into(),
+                operation: leaving,
+            })
+        );
+    })
+}
+
+#[test]
+fn test_register_with_xcm_from_sibling_parachain() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .build()
+        .execute_with(|| {
+            use {
+                frame_support::traits::fungible::InspectHold,
+                staging_xcm_executor::traits::ConvertLocation,
+            };
+
+            run_to_block(2);
+            let para_id = ParaId::from(3001);
+            let sovereign_account =
+                dancebox_runtime::xcm_config::LocationToAccountId::convert_location(
+                    &Location::new(1, [Parachain(3001)]),
+                )
+                .unwrap();
+            assert_ok!(Balances::force_set_balance(
+                root_origin(),
+                sovereign_account.clone().into(),
+                1_000 * UNIT
+            ));
+
+            // A signed origin is not enough, even if it is the sovereign account
+            assert_noop!(
+                Registrar::register_with_xcm(
+                    origin_of(sovereign_account.clone()),
+                    None,
+                    empty_genesis_data()
+                ),
+                BadOrigin
+            );
+
+            // The origin of a `Transact` with `OriginKind::Native` sent by the sibling
+            let sibling_origin: <Runtime as frame_system::Config>::RuntimeOrigin =
+                cumulus_pallet_xcm::Origin::SiblingParachain(para_id).into();
+            assert_ok!(Registrar::register_with_xcm(
+                sibling_origin,
+                None,
+                empty_genesis_data()
+            ));
+
+            assert_eq!(
+                Registrar::registrar_deposit(para_id).map(|x| x.creator),
+                Some(sovereign_account.clone())
+            );
+            assert_eq!(
+                Balances::balance_on_hold(
+                    &pallet_registrar::HoldReason::RegistrarDeposit.into(),
+                    &sovereign_account
+                ),
+                dancebox_runtime::DepositAmount::get()
+            );
+            assert_eq!(
+                pallet_registrar::ParaManager::<Runtime>::get(para_id),
+                Some(sovereign_account)
+            );
+        });
+}
+
+#[test]
+fn test_pause_window_stops_collator_assignment_and_credits() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+            (AccountId::from(CHARLIE), 100 * UNIT),
+            (AccountId::from(DAVE), 100 * UNIT),
+        ])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            assert_ok!(Registrar::register(
+                origin_of(ALICE.into()),
+                1001.into(),
+                empty_genesis_data()
+            ));
+            set_dummy_boot_node(origin_of(ALICE.into()), 1001.into());
+            assert_ok!(Registrar::mark_valid_for_collating(
+                root_origin(),
+                1001.into()
+            ));
+
+            run_to_session(2u32);
+            let assignment = CollatorAssignment::collator_container_chain();
+            assert_eq!(
+                assignment.container_chains[&1001u32.into()],
+                vec![CHARLIE.into(), DAVE.into()]
+            );
+
+            // The para manager schedules a maintenance window
+            assert_ok!(Registrar::schedule_pause_window(
+                origin_of(ALICE.into()),
+                1001.into(),
+                5,
+                7
+            ));
+            assert_eq!(
+                Runtime::pause_window(1001.into()),
+                Some(tp_traits::PauseWindow { start: 5, end: 7 })
+            );
+
+            run_to_session(5u32);
+            let assignment = CollatorAssignment::collator_container_chain();
+            assert_eq!(assignment.container_chains.get(&1001u32.into()), None);
+            let credits = ServicesPayment::free_collator_assignment_credits(ParaId::from(1001));
+
+            // No credits are charged while the container chain is paused
+            run_to_session(6u32);
+            let assignment = CollatorAssignment::collator_container_chain();
+            assert_eq!(assignment.container_chains.get(&1001u32.into()), None);
+            assert_eq!(
+                ServicesPayment::free_collator_assignment_credits(ParaId::from(1001)),
+                credits
+            );
+
+            run_to_session(7u32);
+            let assignment = CollatorAssignment::collator_container_chain();
+            assert_eq!(
+                assignment.container_chains[&1001u32.into()],
+                vec![CHARLIE.into(), DAVE.into()]
+            );
+        });
+}
@@ -89,7 +89,7 @@ use {
             Verify,
         },
         transaction_validity::{TransactionSource, TransactionValidity},
-        AccountId32, ApplyExtrinsicResult, RuntimeDebug,
+        AccountId32, ApplyExtrinsicResult, Perquintill, RuntimeDebug,
     },
     sp_std::{collections::btree_set::BTreeSet, marker::PhantomData, prelude::*},
     sp_version::RuntimeVersion,
@@ -952,7 +952,7 @@ impl pallet_invulnerables::Config for Runtime {
 parameter_types! {
     #[derive(Clone)]
     pub const MaxLengthParaIds: u32 = 200u32;
-    pub const MaxEncodedGenesisDataSize: u32 = 5_000_000u32; // 5MB
+    pub const MaxEncodedGenesisDataSize: u32 = tp_traits::MAX_ENCODED_GENESIS_DATA_SIZE;
 }
 
 pub struct CurrentSessionIndexGetter;
@@ -1093,6 +1093,7 @@ impl pallet_registrar::Config for Runtime {
     type MaxGenesisDataSize = MaxEncodedGenesisDataSize;
     type RegisterWithRelayProofOrigin = EnsureNever<AccountId>;
     type RelayStorageRootProvider = PalletRelayStorageRootProvider;
+    type RegisterWithXcmOrigin = EnsureNever<(ParaId, AccountId)>;
     type SessionDelay = ConstU32<2>;
     type SessionIndex = u32;
     type CurrentSessionIndex = CurrentSessionIndexGetter;
@@ -1300,22 +1301,34 @@ parameter_types! {
     // 1% in the case of dev mode
     // TODO: check if we can put the prod inflation for tests too
     // TODO: better calculus for going from annual to block inflation (if it can be done)
-    pub const InflationRate: Perbill = prod_or_fast!(Perbill::from_parts(9), Perbill::from_percent(1));
+    pub const InflationRate: Perquintill =
+        prod_or_fast!(Perquintill::from_parts(9_000_000_000), Perquintill::from_percent(1));
 
     // 30% for parachain bond, so 70% for staking
     pub const RewardsPortion: Perbill = Perbill::from_percent(70);
+
+    // Inflation does not depend on the staking rate until governance sets a schedule
+    pub DefaultInflationSchedule: pallet_inflation_rewards::InflationSchedule =
+        pallet_inflation_rewards::InflationSchedule {
+            max_inflation: InflationRate::get(),
+            min_inflation: InflationRate::get(),
+            target_staking_rate: Perbill::from_percent(50),
+            decay_per_year: Perbill::zero(),
+            rewards_portion: RewardsPortion::get(),
+        };
+    pub const BlocksPerYear: u32 = 365 * DAYS;
+    pub const MaxPooledRewardsPayouts: u32 = 100;
 }
 
 pub struct GetSelfChainBlockAuthor;
-impl Get<AccountId32> for GetSelfChainBlockAuthor {
-    fn get() -> AccountId32 {
+impl Get<Option<AccountId32>> for GetSelfChainBlockAuthor {
+    fn get() -> Option<AccountId32> {
         // TODO: we should do a refactor here, and use either authority-mapping or collator-assignemnt
         // we should also make sure we actually account for the weight of these
         // although most of these should be cached as they are read every block
         let slot = u64::from(<Runtime as pallet_author_inherent::Config>::SlotBeacon::slot());
         let self_para_id = ParachainInfo::get();
-        let author = CollatorAssignment::author_for_slot(slot.into(), self_para_id);
-        author.expect("author should be set")
+        CollatorAssignment::author_for_slot(slot.into(), self_para_id)
     }
 }
 
@@ -1331,11 +1344,17 @@ impl pallet_inflation_rewards::Config for Runtime {
     type Currency = Balances;
     type ContainerChains = Registrar;
     type GetSelfChainBlockAuthor = GetSelfChainBlockAuthor;
-    type InflationRate = InflationRate;
+    type DefaultInflationSchedule = DefaultInflationSchedule;
+    type InflationScheduleOrigin = EnsureRoot<AccountId>;
+    type BlocksPerYear = BlocksPerYear;
+    type StakedBalance = ConstU128<0>;
     type OnUnbalanced = OnUnbalancedInflation;
     type PendingRewardsAccount = PendingRewardsAccount;
     type StakingRewardsDistributor = InvulnerableRewardDistribution<Self, Balances, ()>;
-    type RewardsPortion = RewardsPortion;
+    type RewardsPolicy = pallet_inflation_rewards::EqualSplit;
+    type CurrentSessionIndex = CurrentSessionIndexGetter;
+    type MaxPooledRewardsPayouts = MaxPooledRewardsPayouts;
+    type WeightInfo = weights::pallet_inflation_rewards::SubstrateWeight<Runtime>;
 }
 
 impl pallet_tx_pause::Config for Runtime {
@@ -1658,6 +1677,7 @@ mod benches {
         [pallet_invulnerables, Invulnerables]
         [pallet_session, SessionBench::<Runtime>]
         [pallet_author_inherent, AuthorInherent]
+        [pallet_inflation_rewards, InflationRewards]
         [pallet_treasury, Treasury]
         [pallet_relay_storage_roots, RelayStorageRoots]
     );
@@ -1966,6 +1986,16 @@ impl_runtime_apis! {
                 .map(|profile| profile.url.into())
                 .collect()
         }
+
+        /// Fetch the latest known runtime code hash and head for this para id
+        fn code_info(para_id: ParaId) -> Option<tp_traits::ContainerChainCodeInfo> {
+            Registrar::para_code_info(para_id)
+        }
+
+        /// Fetch the scheduled pause window of this para id, in session indexes
+        fn pause_window(para_id: ParaId) -> Option<tp_traits::PauseWindow<u32>> {
+            Registrar::pause_window(para_id)
+        }
     }
 
     impl pallet_author_noting_runtime_api::AuthorNotingApi<Block, AccountId, BlockNumber, ParaId> for Runtime
@@ -25,6 +25,7 @@ pub mod pallet_collator_assignment;
 pub mod pallet_configuration;
 pub mod pallet_data_preservers;
 pub mod pallet_identity;
+pub mod pallet_inflation_rewards;
 pub mod pallet_invulnerables;
 pub mod pallet_multisig;
 pub mod pallet_proxy;
@@ -268,4 +268,67 @@ impl<T: frame_system::Config> pallet_registrar::WeightInfo for SubstrateWeight<T
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Registrar::RegistrarDeposit` (r:1 w:0)
+	/// Proof: `Registrar::RegistrarDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::PendingParaOwner` (r:0 w:1)
+	/// Proof: `Registrar::PendingParaOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_para_ownership_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `237`
+		//  Estimated: `3702`
+		// Minimum execution time: 15_109_000 picoseconds.
+		Weight::from_parts(15_477_000, 3702)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Registrar::PendingParaOwner` (r:1 w:1)
+	/// Proof: `Registrar::PendingParaOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::RegistrarDeposit` (r:1 w:1)
+	/// Proof: `Registrar::RegistrarDeposit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Registrar::ParaManager` (r:0 w:1)
+	/// Proof: `Registrar::ParaManager` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_para_ownership_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 65_870_000 picoseconds.
+		Weight::from_parts(67_023_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn update_para_code_with_relay_proof() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 0_000 picoseconds.
+		Weight::from_parts(0, 0)
+	}
+	/// The range of component `x` is `[100, 3000000]`.
+	/// The range of component `z` is `[1, 10]`.
+	fn register_with_xcm(_x: u32, _z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 0_000 picoseconds.
+		Weight::from_parts(0, 0)
+	}
+	/// Storage: `Registrar::ParaManager` (r:1 w:0)
+	/// Proof: `Registrar::ParaManager` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::ParaGenesisData` (r:1 w:0)
+	/// Proof: `Registrar::ParaGenesisData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::PauseWindows` (r:1 w:1)
+	/// Proof: `Registrar::PauseWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_pause_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247`
+		//  Estimated: `3712`
+		// Minimum execution time: 16_104_000 picoseconds.
+		Weight::from_parts(16_104_000, 3712)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,159 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Metadata-driven encoding of relay chain calls.
+//!
+//! The other modules of this crate hardcode the pallet and call indices of each supported relay
+//! chain. Here the indices are plain data, so they can be read from the metadata of any relay
+//! chain and stored on chain by governance, allowing to target a new relay chain without a
+//! runtime upgrade.
+
+use {
+    cumulus_primitives_core::ParaId,
+    parity_scale_codec::{Decode, Encode},
+    scale_info::TypeInfo,
+    sp_std::vec::Vec,
+};
+
+pub type Balance = u128;
+
+/// Indices of the `OnDemandAssignmentProvider` pallet and its calls in a relay chain runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct RelayCallIndices {
+    /// Index of the `parachains_assigner_on_demand` pallet in `construct_runtime!`
+    pub on_demand_assignment_provider: u8,
+    /// Call index of `place_order_allow_death`
+    pub place_order_allow_death: u8,
+    /// Call index of `place_order_keep_alive`
+    pub place_order_keep_alive: u8,
+}
+
+impl RelayCallIndices {
+    pub const ROCOCO: Self = Self::on_demand_pallet_at(66);
+    pub const WESTEND: Self = Self::on_demand_pallet_at(56);
+    pub const STARLIGHT: Self = Self::on_demand_pallet_at(66);
+    // Not checked in tests, see the `polkadot` and `kusama` modules.
+    pub const POLKADOT: Self = Self::on_demand_pallet_at(64);
+    pub const KUSAMA: Self = Self::on_demand_pallet_at(64);
+
+    /// Indices for a relay chain that uses the default call indices of
+    /// `parachains_assigner_on_demand`.
+    pub const fn on_demand_pallet_at(pallet_index: u8) -> Self {
+        Self {
+            on_demand_assignment_provider: pallet_index,
+            place_order_allow_death: 0,
+            place_order_keep_alive: 1,
+        }
+    }
+
+    pub fn encode_place_order_allow_death(&self, max_amount: Balance, para_id: ParaId) -> Vec<u8> {
+        (
+            self.on_demand_assignment_provider,
+            self.place_order_allow_death,
+            max_amount,
+            para_id,
+        )
+            .encode()
+    }
+
+    pub fn encode_place_order_keep_alive(&self, max_amount: Balance, para_id: ParaId) -> Vec<u8> {
+        (
+            self.on_demand_assignment_provider,
+            self.place_order_keep_alive,
+            max_amount,
+            para_id,
+        )
+            .encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, polkadot_runtime_parachains::assigner_on_demand as parachains_assigner_on_demand,
+    };
+
+    #[test]
+    fn encode_place_order_allow_death_rococo() {
+        let max_amount = u128::MAX;
+        let para_id = u32::MAX.into();
+        let call = rococo_runtime::RuntimeCall::OnDemandAssignmentProvider(
+            parachains_assigner_on_demand::Call::place_order_allow_death {
+                max_amount,
+                para_id,
+            },
+        );
+
+        // If this fails check most probably indices changed
+        assert_eq!(
+            call.encode(),
+            RelayCallIndices::ROCOCO.encode_place_order_allow_death(max_amount, para_id)
+        );
+    }
+
+    #[test]
+    fn encode_place_order_keep_alive_rococo() {
+        let max_amount = u128::MAX;
+        let para_id = u32::MAX.into();
+        let call = rococo_runtime::RuntimeCall::OnDemandAssignmentProvider(
+            parachains_assigner_on_demand::Call::place_order_keep_alive {
+                max_amount,
+                para_id,
+            },
+        );
+
+        // If this fails check most probably indices changed
+        assert_eq!(
+            call.encode(),
+            RelayCallIndices::ROCOCO.encode_place_order_keep_alive(max_amount, para_id)
+        );
+    }
+
+    #[test]
+    fn encode_place_order_allow_death_starlight() {
+        let max_amount = u128::MAX;
+        let para_id = u32::MAX.into();
+        let call = starlight_runtime::RuntimeCall::OnDemandAssignmentProvider(
+            parachains_assigner_on_demand::Call::place_order_allow_death {
+                max_amount,
+                para_id,
+            },
+        );
+
+        // If this fails check most probably indices changed
+        assert_eq!(
+            call.encode(),
+            RelayCallIndices::STARLIGHT.encode_place_order_allow_death(max_amount, para_id)
+        );
+    }
+
+    #[test]
+    fn encode_matches_hardcoded_westend_call() {
+        let max_amount = u128::MAX;
+        let para_id = u32::MAX.into();
+        let call = crate::westend::RelayCall::OnDemandAssignmentProvider(
+            crate::westend::OnDemandAssignmentProviderCall::PlaceOrderAllowDeath {
+                max_amount,
+                para_id,
+            },
+        );
+
+        assert_eq!(
+            call.encode(),
+            RelayCallIndices::WESTEND.encode_place_order_allow_death(max_amount, para_id)
+        );
+    }
+}
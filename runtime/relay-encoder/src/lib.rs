@@ -16,5 +16,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod custom;
+pub mod kusama;
+pub mod polkadot;
 pub mod rococo;
+pub mod starlight;
 pub mod westend;
@@ -0,0 +1,42 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+use {cumulus_primitives_core::ParaId, parity_scale_codec::Encode};
+
+pub type Balance = u128;
+
+// The Polkadot runtime lives in the fellowship runtimes repo and depends on a different
+// polkadot-sdk than this workspace, so these indices can't be checked against it in tests. They
+// must be checked against the Polkadot metadata before being used.
+#[derive(Encode)]
+pub enum RelayCall {
+    #[codec(index = 64u8)]
+    OnDemandAssignmentProvider(OnDemandAssignmentProviderCall),
+}
+
+#[derive(Encode)]
+pub enum OnDemandAssignmentProviderCall {
+    #[codec(index = 0u8)]
+    PlaceOrderAllowDeath {
+        max_amount: Balance,
+        para_id: ParaId,
+    },
+    #[codec(index = 1u8)]
+    PlaceOrderKeepAlive {
+        max_amount: Balance,
+        para_id: ParaId,
+    },
+}
@@ -31,6 +31,11 @@ pub enum OnDemandAssignmentProviderCall {
         max_amount: Balance,
         para_id: ParaId,
     },
+    #[codec(index = 1u8)]
+    PlaceOrderKeepAlive {
+        max_amount: Balance,
+        para_id: ParaId,
+    },
 }
 
 // TODO: uncomment tests after polkadot 1.8.0 upgrade
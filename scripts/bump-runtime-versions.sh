@@ -66,6 +66,7 @@ update_cargo_toml "node/Cargo.toml" "$CLIENT_VERSION"
 
 # Update lib.rs files
 update_lib_rs "container-chains/runtime-templates/frontier/src/lib.rs" "$RUNTIME_VERSION"
+update_lib_rs "container-chains/runtime-templates/governance/src/lib.rs" "$RUNTIME_VERSION"
 update_lib_rs "container-chains/runtime-templates/simple/src/lib.rs" "$RUNTIME_VERSION"
 update_lib_rs "runtime/dancebox/src/lib.rs" "$RUNTIME_VERSION"
 update_lib_rs "runtime/flashbox/src/lib.rs" "$RUNTIME_VERSION"
@@ -212,6 +212,9 @@ fn starlight_testnet_genesis(
         "balances": {
             "balances": endowed_accounts.iter().map(|k| (k.clone(), ENDOWMENT)).collect::<Vec<_>>(),
         },
+        "validatorStaking": {
+            "whitelistedValidators": initial_authorities.iter().map(|x| x.stash.clone()).collect::<Vec<_>>(),
+        },
         "session": {
             "keys": initial_authorities
                 .iter()
@@ -465,6 +468,12 @@ fn starlight_staging_testnet_config_genesis() -> serde_json::Value {
                 .chain(initial_authorities.iter().map(|x| (x.stash.clone(), STASH)))
                 .collect::<Vec<_>>(),
         },
+        "validatorStaking": {
+            "whitelistedValidators": initial_authorities
+                .iter()
+                .map(|x| x.stash.clone())
+                .collect::<Vec<_>>(),
+        },
         "session": {
             "keys": initial_authorities
                 .into_iter()
@@ -29,12 +29,19 @@ use {
     frame_support::{
         dispatch::DispatchResult,
         dynamic_params::{dynamic_pallet_params, dynamic_params},
-        traits::{ConstBool, FromContains},
+        traits::{
+            fungible::{Balanced, Credit, Inspect, InspectHold, Mutate, MutateHold},
+            tokens::{Precision, Preservation},
+            ConstBool, FromContains, Get, ValidatorRegistration,
+        },
     },
     frame_system::EnsureNever,
     nimbus_primitives::NimbusId,
     pallet_initializer as tanssi_initializer,
+    pallet_invulnerables::InvulnerableRewardDistribution,
+    pallet_pooled_staking::traits::{IsCandidateEligible, SessionTimer},
     pallet_registrar_runtime_api::ContainerChainGenesisData,
+    pallet_services_payment::{ProvideBlockProductionCost, ProvideCollatorAssignmentCost},
     pallet_session::ShouldEndSession,
     parity_scale_codec::{Decode, Encode, MaxEncodedLen},
     primitives::{
@@ -51,7 +58,8 @@ use {
             ContainsParts, LocatableAssetConverter, ToAuthor, VersionedLocatableAsset,
             VersionedLocationConverter,
         },
-        paras_registrar, paras_sudo_wrapper, BlockHashCount, BlockLength, SlowAdjustingFeeUpdate,
+        paras_registrar, paras_sudo_wrapper, prod_or_fast, BlockHashCount, BlockLength,
+        SlowAdjustingFeeUpdate,
     },
     runtime_parachains::{
         assigner_coretime as parachains_assigner_coretime,
@@ -70,14 +78,18 @@ use {
     },
     scale_info::TypeInfo,
     sp_genesis_builder::PresetId,
-    sp_runtime::traits::BlockNumberProvider,
+    sp_runtime::traits::{AccountIdConversion, BlockNumberProvider},
     sp_std::{
         cmp::Ordering,
-        collections::{btree_map::BTreeMap, vec_deque::VecDeque},
+        collections::{btree_map::BTreeMap, btree_set::BTreeSet, vec_deque::VecDeque},
+        marker::PhantomData,
         prelude::*,
     },
     starlight_runtime_constants::system_parachain::BROKER_ID,
-    tp_traits::{GetSessionContainerChains, Slot, SlotFrequency},
+    tp_traits::{
+        GetHostConfiguration, GetSessionContainerChains, RemoveParaIdsWithNoCredits, Slot,
+        SlotFrequency,
+    },
 };
 
 #[cfg(any(feature = "std", test))]
@@ -109,7 +121,8 @@ use {
             Keccak256, OpaqueKeys, SaturatedConversion, Verify, Zero,
         },
         transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
-        ApplyExtrinsicResult, FixedU128, KeyTypeId, Perbill, Percent, Permill, RuntimeDebug,
+        ApplyExtrinsicResult, FixedU128, KeyTypeId, Perbill, Percent, Permill, Perquintill,
+        RuntimeDebug,
     },
     sp_staking::SessionIndex,
     sp_version::RuntimeVersion,
@@ -145,7 +158,6 @@ use {
 mod tests;
 
 pub mod genesis_config_presets;
-mod validator_manager;
 
 impl_runtime_weights!(starlight_runtime_constants);
 
@@ -448,7 +460,7 @@ impl pallet_session::Config for Runtime {
     type ValidatorIdOf = ValidatorIdOf;
     type ShouldEndSession = Babe;
     type NextSessionRotation = Babe;
-    type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, ValidatorManager>;
+    type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, ValidatorStaking>;
     type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
     type Keys = SessionKeys;
     type WeightInfo = ();
@@ -541,7 +553,7 @@ impl pallet_treasury::Config for Runtime {
 impl pallet_offences::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
-    type OnOffenceHandler = ();
+    type OnOffenceHandler = ValidatorStaking;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -1101,9 +1113,26 @@ parameter_types! {
     pub const MaxTemporarySlotPerLeasePeriod: u32 = 5;
 }
 
-impl validator_manager::Config for Runtime {
+parameter_types! {
+    pub const MaxWhitelistedValidators: u32 = 100;
+    pub const MaxValidatorCandidates: u32 = 1000;
+    pub const MaxValidators: u32 = 300;
+    pub const MinimumValidatorBond: Balance = 10_000 * UNITS;
+    // Unbonding funds must stay slashable while offences of the validator can still be reported
+    pub const ValidatorUnbondingDelay: SessionIndex = BondingDuration::get() * SessionsPerEra::get();
+}
+
+impl pallet_validator_staking::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type PrivilegedOrigin = EnsureRoot<AccountId>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type MaxWhitelistedValidators = MaxWhitelistedValidators;
+    type MaxCandidates = MaxValidatorCandidates;
+    type MaxValidators = MaxValidators;
+    type MinimumBond = MinimumValidatorBond;
+    type UnbondingDelay = ValidatorUnbondingDelay;
+    type WeightInfo = pallet_validator_staking::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -1151,6 +1180,13 @@ impl tp_traits::GetSessionIndex<SessionIndex> for CurrentSessionIndexGetter {
     fn session_index() -> SessionIndex {
         Session::current_index()
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn skip_to_session(session_index: SessionIndex) {
+        while Session::current_index() < session_index {
+            Session::rotate_session();
+        }
+    }
 }
 
 impl pallet_configuration::Config for Runtime {
@@ -1280,9 +1316,6 @@ construct_runtime! {
 
         ParasSudoWrapper: paras_sudo_wrapper = 250,
 
-        // Validator Manager pallet.
-        ValidatorManager: validator_manager = 252,
-
         // Root testing pallet.
         RootTesting: pallet_root_testing = 249,
 
@@ -1300,6 +1333,11 @@ construct_runtime! {
         Migrations: pallet_migrations = 107,
         MultiBlockMigrations: pallet_multiblock_migrations = 108,
         AuthorNoting: pallet_author_noting = 109,
+        ServicesPayment: pallet_services_payment = 110,
+        PooledStaking: pallet_pooled_staking = 111,
+        InflationRewards: pallet_inflation_rewards = 112,
+        StreamPayment: pallet_stream_payment = 113,
+        ValidatorStaking: pallet_validator_staking = 114,
     }
 }
 
@@ -1352,7 +1390,7 @@ parameter_types! {
     pub const DepositAmount: Balance = 100 * UNITS;
     #[derive(Clone)]
     pub const MaxLengthParaIds: u32 = 100u32;
-    pub const MaxEncodedGenesisDataSize: u32 = 5_000_000u32; // 5MB
+    pub const MaxEncodedGenesisDataSize: u32 = tp_traits::MAX_ENCODED_GENESIS_DATA_SIZE;
 }
 impl pallet_registrar::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
@@ -1362,6 +1400,7 @@ impl pallet_registrar::Config for Runtime {
     type MaxGenesisDataSize = MaxEncodedGenesisDataSize;
     type RegisterWithRelayProofOrigin = EnsureNever<AccountId>;
     type RelayStorageRootProvider = ();
+    type RegisterWithXcmOrigin = EnsureNever<(ParaId, AccountId)>;
     type SessionDelay = ConstU32<2>;
     type SessionIndex = u32;
     type CurrentSessionIndex = CurrentSessionIndexGetter;
@@ -1375,17 +1414,13 @@ impl pallet_registrar::Config for Runtime {
 pub struct StarlightRegistrarHooks;
 
 impl pallet_registrar::RegistrarHooks for StarlightRegistrarHooks {
-    fn para_marked_valid_for_collating(_para_id: ParaId) -> Weight {
+    fn para_marked_valid_for_collating(para_id: ParaId) -> Weight {
         // Give free credits but only once per para id
-        // TODO: uncomment when ServicesPayment pallet exists
-        //ServicesPayment::give_free_credits(&para_id)
-        Weight::default()
+        ServicesPayment::give_free_credits(&para_id)
     }
 
-    fn para_deregistered(_para_id: ParaId) -> Weight {
+    fn para_deregistered(para_id: ParaId) -> Weight {
         // Clear pallet_author_noting storage
-        // TODO: uncomment when pallets exists
-        /*
         if let Err(e) = AuthorNoting::kill_author_data(RuntimeOrigin::root(), para_id) {
             log::warn!(
                 "Failed to kill_author_data after para id {} deregistered: {:?}",
@@ -1393,11 +1428,14 @@ impl pallet_registrar::RegistrarHooks for StarlightRegistrarHooks {
                 e,
             );
         }
-        // Remove bootnodes from pallet_data_preservers
-        DataPreservers::para_deregistered(para_id);
 
         ServicesPayment::para_deregistered(para_id);
 
+        // TODO: uncomment when pallets exist
+        /*
+        // Remove bootnodes from pallet_data_preservers
+        DataPreservers::para_deregistered(para_id);
+
         XcmCoreBuyer::para_deregistered(para_id);
          */
 
@@ -1480,13 +1518,428 @@ impl pallet_author_noting::Config for Runtime {
     #[cfg(feature = "runtime-benchmarks")]
     type AuthorNotingHook = ();
     #[cfg(not(feature = "runtime-benchmarks"))]
-    type AuthorNotingHook = ();
-    // TODO: uncomment when pallets exist
-    //type AuthorNotingHook = (InflationRewards, ServicesPayment);
+    type AuthorNotingHook = (InflationRewards, ServicesPayment);
     type RelayOrPara = pallet_author_noting::RelayMode;
     type WeightInfo = pallet_author_noting::weights::SubstrateWeight<Runtime>;
 }
 
+pub struct RemoveParaIdsWithNoCreditsImpl;
+
+impl RemoveParaIdsWithNoCredits for RemoveParaIdsWithNoCreditsImpl {
+    fn remove_para_ids_with_no_credits(
+        para_ids: &mut Vec<ParaId>,
+        currently_assigned: &BTreeSet<ParaId>,
+    ) {
+        let blocks_per_session = EpochDurationInBlocks::get();
+
+        para_ids.retain(|para_id| {
+            // If the para has been assigned collators for this session it must have enough block credits
+            // for the current and the next session.
+            let block_credits_needed = if currently_assigned.contains(para_id) {
+                blocks_per_session * 2
+            } else {
+                blocks_per_session
+            };
+
+            // Check if the container chain has enough credits for producing blocks
+            let free_block_credits =
+                pallet_services_payment::BlockProductionCredits::<Runtime>::get(para_id)
+                    .unwrap_or_default();
+
+            // Check if the container chain has enough credits for a session assignments
+            let free_session_credits =
+                pallet_services_payment::CollatorAssignmentCredits::<Runtime>::get(para_id)
+                    .unwrap_or_default();
+
+            // If para's max tip is set it should have enough to pay for one assignment with tip
+            let max_tip =
+                pallet_services_payment::MaxTip::<Runtime>::get(para_id).unwrap_or_default();
+
+            // Return if we can survive with free credits
+            if free_block_credits >= block_credits_needed && free_session_credits >= 1 {
+                // Max tip should always be checked, as it can be withdrawn even if free credits were used
+                return Balances::can_withdraw(
+                    &pallet_services_payment::Pallet::<Runtime>::parachain_tank(*para_id),
+                    max_tip,
+                )
+                .into_result(true)
+                .is_ok();
+            }
+
+            let remaining_block_credits = block_credits_needed.saturating_sub(free_block_credits);
+            let remaining_session_credits = 1u32.saturating_sub(free_session_credits);
+
+            let (block_production_costs, _) =
+                <Runtime as pallet_services_payment::Config>::ProvideBlockProductionCost::block_cost(
+                    para_id,
+                );
+            let (collator_assignment_costs, _) =
+                <Runtime as pallet_services_payment::Config>::ProvideCollatorAssignmentCost::collator_assignment_cost(
+                    para_id,
+                );
+            // let's check if we can withdraw
+            let remaining_block_credits_to_pay =
+                u128::from(remaining_block_credits).saturating_mul(block_production_costs);
+            let remaining_session_credits_to_pay =
+                u128::from(remaining_session_credits).saturating_mul(collator_assignment_costs);
+
+            let remaining_to_pay = remaining_block_credits_to_pay
+                .saturating_add(remaining_session_credits_to_pay)
+                .saturating_add(max_tip);
+
+            // This should take into account whether we tank goes below ED
+            // The true refers to keepAlive
+            Balances::can_withdraw(
+                &pallet_services_payment::Pallet::<Runtime>::parachain_tank(*para_id),
+                remaining_to_pay,
+            )
+            .into_result(true)
+            .is_ok()
+        });
+    }
+
+    /// Make those para ids valid by giving them enough credits, for benchmarking.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn make_valid_para_ids(para_ids: &[ParaId]) {
+        use frame_support::assert_ok;
+
+        let blocks_per_session = EpochDurationInBlocks::get();
+        // Enough credits to run any benchmark
+        let block_credits = 20 * blocks_per_session;
+        let session_credits = 20;
+
+        for para_id in para_ids {
+            assert_ok!(ServicesPayment::set_block_production_credits(
+                RuntimeOrigin::root(),
+                *para_id,
+                block_credits,
+            ));
+            assert_ok!(ServicesPayment::set_collator_assignment_credits(
+                RuntimeOrigin::root(),
+                *para_id,
+                session_credits,
+            ));
+        }
+    }
+}
+
+pub const FIXED_BLOCK_PRODUCTION_COST: u128 = 1 * MILLICENTS;
+pub const FIXED_COLLATOR_ASSIGNMENT_COST: u128 = 100 * MILLICENTS;
+
+pub struct BlockProductionCost<Runtime>(PhantomData<Runtime>);
+impl ProvideBlockProductionCost<Runtime> for BlockProductionCost<Runtime> {
+    fn block_cost(_para_id: &ParaId) -> (u128, Weight) {
+        (FIXED_BLOCK_PRODUCTION_COST, Weight::zero())
+    }
+}
+
+pub struct CollatorAssignmentCost<Runtime>(PhantomData<Runtime>);
+impl ProvideCollatorAssignmentCost<Runtime> for CollatorAssignmentCost<Runtime> {
+    fn collator_assignment_cost(_para_id: &ParaId) -> (u128, Weight) {
+        (FIXED_COLLATOR_ASSIGNMENT_COST, Weight::zero())
+    }
+}
+
+parameter_types! {
+    // 60 days worth of blocks
+    pub const FreeBlockProductionCredits: BlockNumber = 60 * DAYS;
+    // 60 days worth of blocks
+    pub FreeCollatorAssignmentCredits: u32 =
+        FreeBlockProductionCredits::get() / EpochDurationInBlocks::get();
+}
+
+impl pallet_services_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// Handler for fees
+    type OnChargeForBlock = ();
+    type OnChargeForCollatorAssignment = ();
+    type OnChargeForCollatorAssignmentTip = ();
+    /// Currency type for fee payment
+    type Currency = Balances;
+    /// Provider of a block cost which can adjust from block to block
+    type ProvideBlockProductionCost = BlockProductionCost<Runtime>;
+    /// Provider of a block cost which can adjust from block to block
+    type ProvideCollatorAssignmentCost = CollatorAssignmentCost<Runtime>;
+    /// The maximum number of block credits that can be accumulated
+    type FreeBlockProductionCredits = FreeBlockProductionCredits;
+    /// The maximum number of session credits that can be accumulated
+    type FreeCollatorAssignmentCredits = FreeCollatorAssignmentCredits;
+    type ManagerOrigin =
+        EitherOfDiverse<pallet_registrar::EnsureSignedByManager<Runtime>, EnsureRoot<AccountId>>;
+    type WeightInfo = pallet_services_payment::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub StakingAccount: AccountId = PalletId(*b"POOLSTAK").into_account_truncating();
+    pub const InitialManualClaimShareValue: u128 = UNITS / 1_000;
+    pub const InitialAutoCompoundingShareValue: u128 = UNITS / 1_000;
+    pub const MinimumSelfDelegation: u128 = 10_000 * UNITS;
+    pub const RewardsCollatorCommission: Perbill = Perbill::from_percent(20);
+    // Need to wait 2 sessions before being able to join or leave staking pools
+    pub const StakingSessionDelay: u32 = 2;
+    // Delegators have 4 sessions to leave a candidate before its new commission applies
+    pub const CommissionChangeSessionDelay: u32 = 4;
+    // Commission changes can be executed during 4 sessions once the delay is elapsed
+    pub const CommissionChangeExpirySessionDelay: u32 = 8;
+    pub const MaxCandidateMetadataLength: u32 = 128;
+    pub const CandidateInfoDepositBaseFee: Balance = deposit(1, 0);
+    pub const CandidateInfoDepositByteFee: Balance = deposit(0, 1);
+}
+
+pub struct CandidateHasRegisteredKeys;
+impl IsCandidateEligible<AccountId> for CandidateHasRegisteredKeys {
+    fn is_candidate_eligible(a: &AccountId) -> bool {
+        <Session as ValidatorRegistration<AccountId>>::is_registered(a)
+    }
+    #[cfg(feature = "runtime-benchmarks")]
+    fn make_candidate_eligible(a: &AccountId, eligible: bool) {
+        use sp_runtime::traits::TrailingZeroInput;
+        if eligible {
+            // Session keys of collators include all the validator keys, derive a unique set of
+            // keys from the account id so that they don't collide with other candidates.
+            let account_slice: &[u8; 32] = a.as_ref();
+            let keys = SessionKeys::decode(&mut TrailingZeroInput::new(&account_slice.repeat(8)))
+                .expect("decoding from trailing zero input never fails");
+            let _ = Session::set_keys(RuntimeOrigin::signed(a.clone()), keys, vec![]);
+        } else {
+            let _ = Session::purge_keys(RuntimeOrigin::signed(a.clone()));
+        }
+    }
+}
+
+impl pallet_pooled_staking::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Balance = Balance;
+    type StakingAccount = StakingAccount;
+    type InitialManualClaimShareValue = InitialManualClaimShareValue;
+    type InitialAutoCompoundingShareValue = InitialAutoCompoundingShareValue;
+    type MinimumSelfDelegation = MinimumSelfDelegation;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RewardsCollatorCommission = RewardsCollatorCommission;
+    type CommissionChangeTimer =
+        SessionTimer<CurrentSessionIndexGetter, CommissionChangeSessionDelay>;
+    type CommissionChangeExpiryTimer =
+        SessionTimer<CurrentSessionIndexGetter, CommissionChangeExpirySessionDelay>;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type JoiningRequestTimer = SessionTimer<CurrentSessionIndexGetter, StakingSessionDelay>;
+    type LeavingRequestTimer = SessionTimer<CurrentSessionIndexGetter, StakingSessionDelay>;
+    type EligibleCandidatesBufferSize = ConstU32<100>;
+    type EligibleCandidatesFilter = CandidateHasRegisteredKeys;
+    type SaturatedRewardsHandler = OnUnbalancedInflation;
+    type MaxCandidateMetadataLength = MaxCandidateMetadataLength;
+    type CandidateInfoDeposit =
+        tp_traits::BytesDeposit<CandidateInfoDepositBaseFee, CandidateInfoDepositByteFee>;
+    type WeightInfo = pallet_pooled_staking::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub ParachainBondAccount: AccountId = PalletId(*b"ParaBond").into_account_truncating();
+    pub PendingRewardsAccount: AccountId = PalletId(*b"PENDREWD").into_account_truncating();
+    // The equation to solve is:
+    // initial_supply * (1.05) = initial_supply * (1+x)^5_259_600
+    // we should solve for x = (1.05)^(1/5_259_600) -1 -> 0.000000009 per block or 9/1_000_000_000
+    // 1% in the case of dev mode
+    pub const InflationRate: Perquintill =
+        prod_or_fast!(Perquintill::from_parts(9_000_000_000), Perquintill::from_percent(1));
+
+    // 30% for parachain bond, so 70% for staking
+    pub const RewardsPortion: Perbill = Perbill::from_percent(70);
+
+    // Inflation does not depend on the staking rate until governance sets a schedule
+    pub DefaultInflationSchedule: pallet_inflation_rewards::InflationSchedule =
+        pallet_inflation_rewards::InflationSchedule {
+            max_inflation: InflationRate::get(),
+            min_inflation: InflationRate::get(),
+            target_staking_rate: Perbill::from_percent(50),
+            decay_per_year: Perbill::zero(),
+            rewards_portion: RewardsPortion::get(),
+        };
+    pub const BlocksPerYear: u32 = 365 * DAYS;
+    pub const MaxPooledRewardsPayouts: u32 = 100;
+}
+
+/// The author of the current Starlight block, which is a validator and not a collator.
+pub struct GetSelfChainBlockAuthor;
+impl Get<Option<AccountId>> for GetSelfChainBlockAuthor {
+    fn get() -> Option<AccountId> {
+        Authorship::author()
+    }
+}
+
+pub struct PooledStakingTotalStake;
+impl Get<Balance> for PooledStakingTotalStake {
+    fn get() -> Balance {
+        PooledStaking::total_stake()
+    }
+}
+
+pub struct OnUnbalancedInflation;
+impl frame_support::traits::OnUnbalanced<Credit<AccountId, Balances>> for OnUnbalancedInflation {
+    fn on_nonzero_unbalanced(credit: Credit<AccountId, Balances>) {
+        let _ = <Balances as Balanced<_>>::resolve(&ParachainBondAccount::get(), credit);
+    }
+}
+
+impl pallet_inflation_rewards::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type ContainerChains = ContainerRegistrar;
+    type GetSelfChainBlockAuthor = GetSelfChainBlockAuthor;
+    type DefaultInflationSchedule = DefaultInflationSchedule;
+    type InflationScheduleOrigin = EnsureRoot<AccountId>;
+    type BlocksPerYear = BlocksPerYear;
+    type StakedBalance = PooledStakingTotalStake;
+    type OnUnbalanced = OnUnbalancedInflation;
+    type PendingRewardsAccount = PendingRewardsAccount;
+    type StakingRewardsDistributor = InvulnerableRewardDistribution<Self, Balances, PooledStaking>;
+    type RewardsPolicy = pallet_inflation_rewards::EqualSplit;
+    type CurrentSessionIndex = CurrentSessionIndexGetter;
+    type MaxPooledRewardsPayouts = MaxPooledRewardsPayouts;
+    type WeightInfo = pallet_inflation_rewards::weights::SubstrateWeight<Runtime>;
+}
+
+#[derive(RuntimeDebug, PartialEq, Eq, Encode, Decode, Copy, Clone, TypeInfo, MaxEncodedLen)]
+pub enum StreamPaymentAssetId {
+    Native,
+}
+
+pub struct StreamPaymentAssets;
+impl pallet_stream_payment::Assets<AccountId, StreamPaymentAssetId, Balance>
+    for StreamPaymentAssets
+{
+    fn transfer_deposit(
+        asset_id: &StreamPaymentAssetId,
+        from: &AccountId,
+        to: &AccountId,
+        amount: Balance,
+    ) -> frame_support::pallet_prelude::DispatchResult {
+        match asset_id {
+            StreamPaymentAssetId::Native => {
+                // We remove the hold before transfering.
+                Self::decrease_deposit(asset_id, from, amount)?;
+                Balances::transfer(from, to, amount, Preservation::Preserve).map(|_| ())
+            }
+        }
+    }
+
+    fn increase_deposit(
+        asset_id: &StreamPaymentAssetId,
+        account: &AccountId,
+        amount: Balance,
+    ) -> frame_support::pallet_prelude::DispatchResult {
+        match asset_id {
+            StreamPaymentAssetId::Native => Balances::hold(
+                &pallet_stream_payment::HoldReason::StreamPayment.into(),
+                account,
+                amount,
+            ),
+        }
+    }
+
+    fn decrease_deposit(
+        asset_id: &StreamPaymentAssetId,
+        account: &AccountId,
+        amount: Balance,
+    ) -> frame_support::pallet_prelude::DispatchResult {
+        match asset_id {
+            StreamPaymentAssetId::Native => Balances::release(
+                &pallet_stream_payment::HoldReason::StreamPayment.into(),
+                account,
+                amount,
+                Precision::Exact,
+            )
+            .map(|_| ()),
+        }
+    }
+
+    fn get_deposit(asset_id: &StreamPaymentAssetId, account: &AccountId) -> Balance {
+        match asset_id {
+            StreamPaymentAssetId::Native => Balances::balance_on_hold(
+                &pallet_stream_payment::HoldReason::StreamPayment.into(),
+                account,
+            ),
+        }
+    }
+
+    /// Benchmarks: should return the asset id which has the worst performance when interacting
+    /// with it.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn bench_worst_case_asset_id() -> StreamPaymentAssetId {
+        StreamPaymentAssetId::Native
+    }
+
+    /// Benchmarks: should return the another asset id which has the worst performance when interacting
+    /// with it afther `bench_worst_case_asset_id`. This is to benchmark the worst case when changing config
+    /// from one asset to another.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn bench_worst_case_asset_id2() -> StreamPaymentAssetId {
+        StreamPaymentAssetId::Native
+    }
+
+    /// Benchmarks: should set the balance for the asset id returned by `bench_worst_case_asset_id`.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn bench_set_balance(asset_id: &StreamPaymentAssetId, account: &AccountId, amount: Balance) {
+        // only one asset id
+        let StreamPaymentAssetId::Native = asset_id;
+
+        Balances::set_balance(account, amount);
+    }
+}
+
+#[derive(RuntimeDebug, PartialEq, Eq, Encode, Decode, Copy, Clone, TypeInfo, MaxEncodedLen)]
+pub enum TimeUnit {
+    BlockNumber,
+    Timestamp,
+    // TODO: Container chains/relay block number.
+}
+
+pub struct TimeProvider;
+impl pallet_stream_payment::TimeProvider<TimeUnit, Balance> for TimeProvider {
+    fn now(unit: &TimeUnit) -> Option<Balance> {
+        match *unit {
+            TimeUnit::BlockNumber => Some(System::block_number().into()),
+            TimeUnit::Timestamp => Some(Timestamp::now().into()),
+        }
+    }
+
+    /// Benchmarks: should return the time unit which has the worst performance calling
+    /// `TimeProvider::now(unit)` with.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn bench_worst_case_time_unit() -> TimeUnit {
+        // Both BlockNumber and Timestamp cost the same (1 db read), but overriding timestamp
+        // doesn't work well in benches, while block number works fine.
+        TimeUnit::BlockNumber
+    }
+
+    /// Benchmarks: sets the "now" time for time unit returned by `worst_case_time_unit`.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn bench_set_now(instant: Balance) {
+        System::set_block_number(instant as u32)
+    }
+}
+
+type StreamId = u64;
+
+parameter_types! {
+    // 1 entry, storing 173 bytes on-chain
+    pub const OpenStreamHoldAmount: Balance = deposit(1, 173);
+}
+
+impl pallet_stream_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type StreamId = StreamId;
+    type TimeUnit = TimeUnit;
+    type Balance = Balance;
+    type AssetId = StreamPaymentAssetId;
+    type Assets = StreamPaymentAssets;
+    type Currency = Balances;
+    type OpenStreamHoldAmount = OpenStreamHoldAmount;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type TimeProvider = TimeProvider;
+    type WeightInfo = pallet_stream_payment::weights::SubstrateWeight<Runtime>;
+}
+
 frame_support::ord_parameter_types! {
     pub const MigController: AccountId = AccountId::from(hex_literal::hex!("52bc71c1eca5353749542dfdf0af97bf764f9c2f44e860cd485f1cd86400f649"));
 }
@@ -2078,6 +2531,16 @@ sp_api::impl_runtime_apis! {
                 .collect()*/
             vec![]
         }
+
+        /// Fetch the latest known runtime code hash and head for this para id
+        fn code_info(para_id: ParaId) -> Option<tp_traits::ContainerChainCodeInfo> {
+            ContainerRegistrar::para_code_info(para_id)
+        }
+
+        /// Fetch the scheduled pause window of this para id, in session indexes
+        fn pause_window(para_id: ParaId) -> Option<tp_traits::PauseWindow<u32>> {
+            ContainerRegistrar::pause_window(para_id)
+        }
     }
 
     impl pallet_registrar_runtime_api::OnDemandBlockProductionApi<Block, ParaId, Slot> for Runtime {
@@ -2114,6 +2577,39 @@ sp_api::impl_runtime_apis! {
         }
     }
 
+    impl pallet_pooled_staking_runtime_api::PooledStakingApi<Block, AccountId, Balance> for Runtime {
+        fn candidate_stake_cap() -> Option<Balance> {
+            PooledStaking::candidate_stake_cap()
+        }
+
+        fn block_reward_accruals() -> Result<
+            (
+                SessionIndex,
+                Vec<pallet_pooled_staking_runtime_api::RewardAccrual<AccountId, Balance>>,
+            ),
+            sp_runtime::DispatchError,
+        > {
+            let events =
+                System::read_events_no_consensus().filter_map(|record| match record.event {
+                    RuntimeEvent::PooledStaking(event) => Some(event),
+                    _ => None,
+                });
+
+            let accruals = PooledStaking::reward_accruals(events)?
+                .into_iter()
+                .map(|accrual| pallet_pooled_staking_runtime_api::RewardAccrual {
+                    delegator: accrual.delegator,
+                    candidate: accrual.candidate,
+                    auto_compounding_rewards: accrual.auto_compounding_rewards,
+                    manual_claim_rewards: accrual.manual_claim_rewards,
+                    claimed_manual_rewards: accrual.claimed_manual_rewards,
+                })
+                .collect();
+
+            Ok((Session::current_index(), accruals))
+        }
+    }
+
     impl dp_consensus::TanssiAuthorityAssignmentApi<Block, NimbusId> for Runtime {
         /// Return the current authorities assigned to a given paraId
         fn para_id_authorities(para_id: ParaId) -> Option<Vec<NimbusId>> {
@@ -2415,6 +2911,38 @@ sp_api::impl_runtime_apis! {
     }
 }
 
+/// Returns a list of collators by combining pallet_invulnerables and pallet_pooled_staking.
+///
+/// Unlike dancebox/flashbox, the collators are not the session validators here, so this is called
+/// from `OwnApplySession` instead of being used as the `SessionManager`.
+fn collators_from_invulnerables_and_then_from_staking(
+    session_index: SessionIndex,
+) -> Vec<AccountId> {
+    let invulnerables = TanssiInvulnerables::invulnerables().to_vec();
+    let candidates_staking =
+        pallet_pooled_staking::SortedEligibleCandidates::<Runtime>::get().to_vec();
+    // Max number of collators is set in pallet_configuration
+    // The collators returned here will be assigned in the next session
+    let target_session_index = session_index.saturating_add(1);
+    let max_collators =
+        <CollatorConfiguration as GetHostConfiguration<u32>>::max_collators(target_session_index);
+
+    invulnerables
+        .iter()
+        .cloned()
+        .chain(candidates_staking.into_iter().filter_map(|elig| {
+            let cand = elig.candidate;
+            if invulnerables.contains(&cand) {
+                // If a candidate is both in pallet_invulnerables and pallet_staking, do not count it twice
+                None
+            } else {
+                Some(cand)
+            }
+        }))
+        .take(max_collators as usize)
+        .collect()
+}
+
 pub struct OwnApplySession;
 impl tanssi_initializer::ApplyNewSession<Runtime> for OwnApplySession {
     fn apply_new_session(
@@ -2430,9 +2958,7 @@ impl tanssi_initializer::ApplyNewSession<Runtime> for OwnApplySession {
         // 2. Second, registrar
         ContainerRegistrar::initializer_on_new_session(&session_index);
 
-        let invulnerables = TanssiInvulnerables::invulnerables().to_vec();
-
-        let next_collators = invulnerables;
+        let next_collators = collators_from_invulnerables_and_then_from_staking(session_index);
 
         // Queue next session keys.
         let queued_amalgamated = next_collators
@@ -2505,9 +3031,9 @@ impl pallet_collator_assignment::Config for Runtime {
     type ShouldRotateAllCollators = ();
     type GetRandomnessForNextBlock = ();
     type RemoveInvulnerables = ();
-    type RemoveParaIdsWithNoCredits = ();
-    type CollatorAssignmentHook = ();
-    type CollatorAssignmentTip = ();
+    type RemoveParaIdsWithNoCredits = RemoveParaIdsWithNoCreditsImpl;
+    type CollatorAssignmentHook = ServicesPayment;
+    type CollatorAssignmentTip = ServicesPayment;
     type Currency = Balances;
     type ForceEmptyOrchestrator = ConstBool<true>;
     type WeightInfo = ();
@@ -597,12 +597,11 @@ fn test_authors_paras_inserted_a_posteriori() {
                 1001.into()
             ));
 
-            // TODO: uncomment when we add ServicesPayment
-            /*  assert_ok!(ServicesPayment::purchase_credits(
+            assert_ok!(ServicesPayment::purchase_credits(
                 origin_of(ALICE.into()),
                 1001.into(),
                 block_credits_to_required_balance(1000, 1001.into())
-            )); */
+            ));
 
             assert_ok!(ContainerRegistrar::register(
                 origin_of(ALICE.into()),
@@ -617,12 +616,11 @@ fn test_authors_paras_inserted_a_posteriori() {
                 1002.into()
             ));
 
-            // TODO: uncomment when we add ServicesPayment
-            /*  assert_ok!(ServicesPayment::purchase_credits(
+            assert_ok!(ServicesPayment::purchase_credits(
                 origin_of(ALICE.into()),
                 1002.into(),
                 block_credits_to_required_balance(1000, 1002.into())
-            )); */
+            ));
 
             // Assignment should happen after 2 sessions
             run_to_session(1u32);
@@ -27,10 +27,11 @@ use {
     frame_support::traits::{OnFinalize, OnInitialize},
     nimbus_primitives::NimbusId,
     pallet_registrar_runtime_api::ContainerChainGenesisData,
+    pallet_services_payment::{ProvideBlockProductionCost, ProvideCollatorAssignmentCost},
     parity_scale_codec::{Decode, Encode, MaxEncodedLen},
     sp_runtime::traits::Dispatchable,
     sp_runtime::{traits::SaturatedConversion, BuildStorage, Digest, DigestItem},
-    starlight_runtime::RuntimeCall,
+    starlight_runtime::{BlockProductionCost, CollatorAssignmentCost, RuntimeCall},
     test_relay_sproof_builder::ParaHeaderSproofBuilder,
 };
 
@@ -39,8 +40,8 @@ use {
 #[allow(unused_imports)]
 pub use starlight_runtime::{
     genesis_config_presets::get_authority_keys_from_seed, AccountId, AuthorNoting, Babe, Balance,
-    Grandpa, Initializer, Runtime, Session, System, TanssiAuthorityAssignment,
-    TanssiCollatorAssignment, TransactionPayment,
+    Balances, Grandpa, InflationRewards, Initializer, PooledStaking, Runtime, ServicesPayment,
+    Session, System, TanssiAuthorityAssignment, TanssiCollatorAssignment, TransactionPayment,
 };
 
 pub fn session_to_block(n: u32) -> u32 {
@@ -185,6 +186,7 @@ pub fn start_block() {
     Babe::on_initialize(System::block_number());
     Session::on_initialize(System::block_number());
     Initializer::on_initialize(System::block_number());
+    InflationRewards::on_initialize(System::block_number());
 }
 
 pub fn end_block() {
@@ -352,7 +354,23 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
-        // TODO: add here pallet_services_payment::GenesisConfig
+        pallet_services_payment::GenesisConfig::<Runtime> {
+            para_id_credits: self
+                .para_ids
+                .clone()
+                .into_iter()
+                .map(|registered_para| {
+                    (
+                        registered_para.para_id.into(),
+                        registered_para.block_production_credits,
+                        registered_para.collator_assignment_credits,
+                    )
+                        .into()
+                })
+                .collect(),
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
 
         pallet_configuration::GenesisConfig::<Runtime> {
             config: self.config,
@@ -501,6 +519,19 @@ pub fn current_slot() -> u64 {
     Babe::current_slot().into()
 }
 
+pub fn block_credits_to_required_balance(number_of_blocks: u32, para_id: ParaId) -> Balance {
+    let block_cost = BlockProductionCost::block_cost(&para_id).0;
+    u128::from(number_of_blocks).saturating_mul(block_cost)
+}
+
+pub fn collator_assignment_credits_to_required_balance(
+    number_of_sessions: u32,
+    para_id: ParaId,
+) -> Balance {
+    let collator_assignment_cost = CollatorAssignmentCost::collator_assignment_cost(&para_id).0;
+    u128::from(number_of_sessions).saturating_mul(collator_assignment_cost)
+}
+
 pub const ALICE: [u8; 32] = [4u8; 32];
 pub const BOB: [u8; 32] = [5u8; 32];
 pub const CHARLIE: [u8; 32] = [6u8; 32];
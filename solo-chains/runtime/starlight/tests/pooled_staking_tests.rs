@@ -0,0 +1,477 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+#![cfg(test)]
+
+use {
+    crate::common::*,
+    frame_support::assert_ok,
+    pallet_pooled_staking::{
+        traits::IsCandidateEligible, EligibleCandidate, PendingOperationKey, PendingOperationQuery,
+        TargetPool,
+    },
+    parity_scale_codec::Encode,
+    sp_consensus_aura::AURA_ENGINE_ID,
+    sp_runtime::{generic::DigestItem, traits::BlakeTwo256},
+    sp_std::vec,
+    starlight_runtime::{MinimumSelfDelegation, RewardsCollatorCommission, TanssiInvulnerables},
+    test_relay_sproof_builder::{HeaderAs, ParaHeaderSproofBuilder, ParaHeaderSproofBuilderItem},
+    tp_traits::ParaId,
+};
+
+mod common;
+
+const UNIT: Balance = 1_000_000_000_000_000_000;
+
+fn set_session_keys(account: [u8; 32]) {
+    let keys = get_authority_keys_from_seed(&AccountId::from(account).to_string());
+    assert_ok!(Session::set_keys(
+        origin_of(account.into()),
+        starlight_runtime::SessionKeys {
+            babe: keys.babe.clone(),
+            grandpa: keys.grandpa.clone(),
+            para_validator: keys.para_validator.clone(),
+            para_assignment: keys.para_assignment.clone(),
+            authority_discovery: keys.authority_discovery.clone(),
+            beefy: keys.beefy.clone(),
+            nimbus: keys.nimbus.clone(),
+        },
+        vec![]
+    ));
+}
+
+/// Simulate the inclusion of a container chain block authored at `slot`.
+fn note_container_block(para_id: ParaId, block_number: u32, slot: u64) {
+    let mut sproof = ParaHeaderSproofBuilder::default();
+    let s = ParaHeaderSproofBuilderItem {
+        para_id,
+        author_id: HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+            parent_hash: Default::default(),
+            number: block_number,
+            state_root: Default::default(),
+            extrinsics_root: Default::default(),
+            digest: sp_runtime::generic::Digest {
+                logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())],
+            },
+        }),
+    };
+    sproof.items.push(s);
+
+    set_author_noting_inherent_data(sproof);
+}
+
+#[test]
+fn test_staking_no_candidates_in_genesis() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![(1001, empty_genesis_data(), u32::MAX, u32::MAX).into()])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            let initial_candidates =
+                pallet_pooled_staking::SortedEligibleCandidates::<Runtime>::get().to_vec();
+
+            assert_eq!(initial_candidates, vec![]);
+        });
+}
+
+#[test]
+fn test_staking_join() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![(1001, empty_genesis_data(), u32::MAX, u32::MAX).into()])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            let balance_before = System::account(AccountId::from(ALICE)).data.free;
+            assert_eq!(System::account(AccountId::from(ALICE)).data.reserved, 0);
+            let stake = MinimumSelfDelegation::get() * 10;
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(ALICE.into()),
+                ALICE.into(),
+                TargetPool::AutoCompounding,
+                stake
+            ));
+
+            // Immediately after joining, Alice is the top candidate
+            let eligible_candidates =
+                pallet_pooled_staking::SortedEligibleCandidates::<Runtime>::get().to_vec();
+            assert_eq!(
+                eligible_candidates,
+                vec![EligibleCandidate {
+                    candidate: ALICE.into(),
+                    stake
+                }]
+            );
+
+            // And staked amount is immediately marked as "reserved"
+            let balance_after = System::account(AccountId::from(ALICE)).data.free;
+            assert_eq!(balance_before - balance_after, stake);
+            assert_eq!(System::account(AccountId::from(ALICE)).data.reserved, stake);
+        });
+}
+
+#[test]
+fn test_staking_join_no_keys_registered() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![(1001, empty_genesis_data(), u32::MAX, u32::MAX).into()])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            let stake = MinimumSelfDelegation::get() * 10;
+            let new_account = AccountId::from([42u8; 32]);
+            assert_ok!(Balances::transfer_allow_death(
+                origin_of(ALICE.into()),
+                new_account.clone().into(),
+                stake * 2
+            ));
+            let balance_before = System::account(new_account.clone()).data.free;
+            assert_eq!(System::account(new_account.clone()).data.reserved, 0);
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(new_account.clone()),
+                new_account.clone(),
+                TargetPool::AutoCompounding,
+                stake
+            ));
+
+            // The new account should be the top candidate but it has no keys registered in
+            // pallet_session, so it is not eligible
+            assert!(!<Runtime as pallet_pooled_staking::Config>::EligibleCandidatesFilter::is_candidate_eligible(&new_account));
+            let eligible_candidates =
+                pallet_pooled_staking::SortedEligibleCandidates::<Runtime>::get().to_vec();
+
+            assert_eq!(eligible_candidates, vec![]);
+
+            // And staked amount is immediately marked as "reserved"
+            let balance_after = System::account(new_account.clone()).data.free;
+            assert_eq!(balance_before - balance_after, stake);
+            assert_eq!(System::account(new_account.clone()).data.reserved, stake);
+        });
+}
+
+#[test]
+fn test_staking_candidates_are_assigned_after_invulnerables() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![
+            (1000, empty_genesis_data(), u32::MAX, u32::MAX).into(),
+            (1001, empty_genesis_data(), u32::MAX, u32::MAX).into(),
+        ])
+        .with_config(pallet_configuration::HostConfiguration {
+            max_collators: 100,
+            min_orchestrator_collators: 0,
+            max_orchestrator_collators: 0,
+            collators_per_container: 2,
+            ..Default::default()
+        })
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            // Invulnerables fill the first container chain
+            let assignment = TanssiCollatorAssignment::collator_container_chain();
+            assert_eq!(
+                assignment.container_chains[&1000u32.into()],
+                vec![ALICE.into(), BOB.into()]
+            );
+            assert_eq!(assignment.container_chains.get(&1001u32.into()), None);
+
+            set_session_keys(CHARLIE);
+            set_session_keys(DAVE);
+
+            let stake = MinimumSelfDelegation::get() * 10;
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(CHARLIE.into()),
+                CHARLIE.into(),
+                TargetPool::AutoCompounding,
+                stake
+            ));
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(DAVE.into()),
+                DAVE.into(),
+                TargetPool::AutoCompounding,
+                stake
+            ));
+
+            // Staking candidates are selected after invulnerables, after 2 sessions
+            run_to_session(2u32);
+            let assignment = TanssiCollatorAssignment::collator_container_chain();
+            assert_eq!(
+                assignment.container_chains[&1000u32.into()],
+                vec![ALICE.into(), BOB.into()]
+            );
+            let mut collators_1001 = assignment.container_chains[&1001u32.into()].clone();
+            collators_1001.sort();
+            assert_eq!(collators_1001, vec![CHARLIE.into(), DAVE.into()]);
+        });
+}
+
+#[test]
+fn test_staking_candidates_not_assigned_above_max_collators() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![
+            (1000, empty_genesis_data(), u32::MAX, u32::MAX).into(),
+            (1001, empty_genesis_data(), u32::MAX, u32::MAX).into(),
+        ])
+        .with_config(pallet_configuration::HostConfiguration {
+            max_collators: 2,
+            min_orchestrator_collators: 0,
+            max_orchestrator_collators: 0,
+            collators_per_container: 2,
+            ..Default::default()
+        })
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            set_session_keys(CHARLIE);
+            set_session_keys(DAVE);
+
+            let stake = MinimumSelfDelegation::get() * 10;
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(CHARLIE.into()),
+                CHARLIE.into(),
+                TargetPool::AutoCompounding,
+                stake
+            ));
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(DAVE.into()),
+                DAVE.into(),
+                TargetPool::AutoCompounding,
+                stake
+            ));
+
+            // Invulnerables take all the available collator slots
+            run_to_session(2u32);
+            let assignment = TanssiCollatorAssignment::collator_container_chain();
+            assert_eq!(
+                assignment.container_chains[&1000u32.into()],
+                vec![ALICE.into(), BOB.into()]
+            );
+            assert_eq!(assignment.container_chains.get(&1001u32.into()), None);
+        });
+}
+
+#[test]
+fn test_reward_to_staking_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![(1001, empty_genesis_data(), u32::MAX, u32::MAX).into()])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            // Bob stops being an invulnerable, Dave takes his place as a staking candidate
+            assert_ok!(TanssiInvulnerables::remove_invulnerable(
+                root_origin(),
+                BOB.into()
+            ));
+            set_session_keys(DAVE);
+
+            let stake = 10 * MinimumSelfDelegation::get();
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(DAVE.into()),
+                DAVE.into(),
+                TargetPool::ManualRewards,
+                stake,
+            ));
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(BOB.into()),
+                DAVE.into(),
+                TargetPool::AutoCompounding,
+                stake,
+            ));
+
+            // Wait for the requests to be executable
+            run_to_session(2u32);
+            assert_ok!(PooledStaking::execute_pending_operations(
+                origin_of(ALICE.into()),
+                vec![
+                    PendingOperationQuery {
+                        delegator: DAVE.into(),
+                        operation: PendingOperationKey::JoiningManualRewards {
+                            candidate: DAVE.into(),
+                            at: 0
+                        }
+                    },
+                    PendingOperationQuery {
+                        delegator: BOB.into(),
+                        operation: PendingOperationKey::JoiningAutoCompounding {
+                            candidate: DAVE.into(),
+                            at: 0
+                        }
+                    }
+                ]
+            ));
+
+            let assignment = TanssiCollatorAssignment::collator_container_chain();
+            assert_eq!(
+                assignment.container_chains[&1001u32.into()],
+                vec![ALICE.into(), DAVE.into()]
+            );
+
+            let (_, rewards_per_chain) = InflationRewards::container_chains_to_reward()
+                .expect("container chains should be rewarded")
+                .rewards
+                .into_iter()
+                .find(|(para_id, _)| *para_id == ParaId::from(1001))
+                .expect("container chain 1001 should be rewarded");
+            let balance_before = System::account(AccountId::from(DAVE)).data.free;
+
+            // Slot 5 is assigned to Dave
+            note_container_block(1001.into(), 1, 5);
+            assert_eq!(
+                AuthorNoting::latest_author(ParaId::from(1001))
+                    .expect("author should be noted")
+                    .author,
+                AccountId::from(DAVE)
+            );
+
+            let balance_after = System::account(AccountId::from(DAVE)).data.free;
+            assert_eq!(
+                balance_after - balance_before,
+                RewardsCollatorCommission::get() * rewards_per_chain,
+                "dave should get the collator commission"
+            );
+        });
+}
+
+#[test]
+fn test_reward_to_invulnerable() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![(1001, empty_genesis_data(), u32::MAX, u32::MAX).into()])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            // We make delegations to ALICE so that she is an elligible candidate.
+            // However since she is an invulnerable she should get all the
+            // rewards.
+            let stake = 10 * MinimumSelfDelegation::get();
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(ALICE.into()),
+                ALICE.into(),
+                TargetPool::ManualRewards,
+                stake,
+            ));
+            assert_ok!(PooledStaking::request_delegate(
+                origin_of(BOB.into()),
+                ALICE.into(),
+                TargetPool::AutoCompounding,
+                stake,
+            ));
+
+            let (_, rewards_per_chain) = InflationRewards::container_chains_to_reward()
+                .expect("container chains should be rewarded")
+                .rewards
+                .into_iter()
+                .find(|(para_id, _)| *para_id == ParaId::from(1001))
+                .expect("container chain 1001 should be rewarded");
+            let balance_before = System::account(AccountId::from(ALICE)).data.free;
+
+            // Slot 4 is assigned to Alice
+            note_container_block(1001.into(), 1, 4);
+            assert_eq!(
+                AuthorNoting::latest_author(ParaId::from(1001))
+                    .expect("author should be noted")
+                    .author,
+                AccountId::from(ALICE)
+            );
+
+            let balance_after = System::account(AccountId::from(ALICE)).data.free;
+            assert_eq!(
+                balance_after - balance_before,
+                rewards_per_chain,
+                "alice should get all the rewards"
+            );
+        });
+}
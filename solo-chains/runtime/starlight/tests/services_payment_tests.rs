@@ -0,0 +1,325 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+#![cfg(test)]
+
+use {
+    crate::common::*,
+    frame_support::assert_ok,
+    parity_scale_codec::Encode,
+    sp_consensus_aura::AURA_ENGINE_ID,
+    sp_runtime::{generic::DigestItem, traits::BlakeTwo256},
+    sp_std::vec,
+    starlight_runtime::{ContainerRegistrar, EpochDurationInBlocks},
+    starlight_runtime_constants::currency::EXISTENTIAL_DEPOSIT,
+    test_relay_sproof_builder::{HeaderAs, ParaHeaderSproofBuilder, ParaHeaderSproofBuilderItem},
+    tp_traits::ParaId,
+};
+
+mod common;
+
+const UNIT: Balance = 1_000_000_000_000_000_000;
+
+/// Simulate the inclusion of a container chain block authored at `slot`.
+fn note_container_block(para_id: ParaId, block_number: u32, slot: u64) {
+    let mut sproof = ParaHeaderSproofBuilder::default();
+    let s = ParaHeaderSproofBuilderItem {
+        para_id,
+        author_id: HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+            parent_hash: Default::default(),
+            number: block_number,
+            state_root: Default::default(),
+            extrinsics_root: Default::default(),
+            digest: sp_runtime::generic::Digest {
+                logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())],
+            },
+        }),
+    };
+    sproof.items.push(s);
+
+    set_author_noting_inherent_data(sproof);
+}
+
+#[test]
+fn test_paras_registered_but_zero_credits() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            assert_ok!(ContainerRegistrar::register(
+                origin_of(ALICE.into()),
+                1001.into(),
+                empty_genesis_data()
+            ));
+            assert_ok!(ContainerRegistrar::mark_valid_for_collating(
+                root_origin(),
+                1001.into()
+            ));
+            // Need to reset credits to 0 because now parachains are given free credits on register
+            assert_ok!(ServicesPayment::set_block_production_credits(
+                root_origin(),
+                1001.into(),
+                0
+            ));
+
+            // Assignment should happen after 2 sessions
+            run_to_session(1u32);
+            let assignment = TanssiCollatorAssignment::collator_container_chain();
+            assert!(assignment.container_chains.is_empty());
+            run_to_session(2u32);
+
+            // Nobody should be assigned to para 1001
+            let assignment = TanssiCollatorAssignment::collator_container_chain();
+            assert_eq!(assignment.container_chains.get(&1001u32.into()), None);
+        });
+}
+
+#[test]
+fn test_paras_registered_but_not_enough_credits() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            assert_ok!(ContainerRegistrar::register(
+                origin_of(ALICE.into()),
+                1001.into(),
+                empty_genesis_data()
+            ));
+            assert_ok!(ContainerRegistrar::mark_valid_for_collating(
+                root_origin(),
+                1001.into()
+            ));
+            // Purchase 1 credit less that what is needed
+            let credits_1001 = EpochDurationInBlocks::get() - 1;
+            assert_ok!(ServicesPayment::set_block_production_credits(
+                root_origin(),
+                1001.into(),
+                credits_1001
+            ));
+
+            // Assignment should happen after 2 sessions
+            run_to_session(1u32);
+            let assignment = TanssiCollatorAssignment::collator_container_chain();
+            assert!(assignment.container_chains.is_empty());
+            run_to_session(2u32);
+            // Nobody should be assigned to para 1001
+            let assignment = TanssiCollatorAssignment::collator_container_chain();
+            assert_eq!(assignment.container_chains.get(&1001u32.into()), None);
+
+            // Now purchase the missing block credit
+            assert_ok!(ServicesPayment::set_block_production_credits(
+                root_origin(),
+                1001.into(),
+                credits_1001 + 1
+            ));
+
+            run_to_session(4u32);
+            // Alice and Bob should be assigned to para 1001
+            let assignment = TanssiCollatorAssignment::collator_container_chain();
+            assert_eq!(
+                assignment.container_chains[&1001u32.into()],
+                vec![ALICE.into(), BOB.into()]
+            );
+        });
+}
+
+#[test]
+fn test_can_buy_credits_before_registering_para() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_validators(vec![
+            (AccountId::from(CHARLIE), 100 * UNIT),
+            (AccountId::from(DAVE), 100 * UNIT),
+        ])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            // Try to buy the maximum amount of credits
+            let balance_before = System::account(AccountId::from(ALICE)).data.free;
+
+            assert_ok!(ServicesPayment::purchase_credits(
+                origin_of(ALICE.into()),
+                1001.into(),
+                block_credits_to_required_balance(u32::MAX, 1001.into())
+            ));
+            let balance_after = System::account(AccountId::from(ALICE)).data.free;
+
+            // Now parachain tank should have this amount
+            let balance_tank = System::account(ServicesPayment::parachain_tank(1001.into()))
+                .data
+                .free;
+
+            assert_eq!(
+                balance_tank,
+                block_credits_to_required_balance(u32::MAX, 1001.into())
+            );
+
+            let expected_cost = block_credits_to_required_balance(u32::MAX, 1001.into());
+            assert_eq!(balance_before - balance_after, expected_cost);
+        });
+}
+
+#[test]
+fn test_block_production_credits_are_burned_on_author_noting() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![(1001, empty_genesis_data(), u32::MAX, u32::MAX).into()])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            note_container_block(1001.into(), 1, 5);
+
+            assert_eq!(
+                pallet_services_payment::BlockProductionCredits::<Runtime>::get(ParaId::from(1001)),
+                Some(u32::MAX - 1)
+            );
+        });
+}
+
+#[test]
+fn test_block_production_paid_from_tank_without_credits() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![(1001, empty_genesis_data(), u32::MAX, u32::MAX).into()])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            // Remove free credits, the block must be paid from the tank
+            assert_ok!(ServicesPayment::set_block_production_credits(
+                root_origin(),
+                1001.into(),
+                0
+            ));
+            let tank_funds =
+                block_credits_to_required_balance(10, 1001.into()) + EXISTENTIAL_DEPOSIT;
+            assert_ok!(ServicesPayment::purchase_credits(
+                origin_of(ALICE.into()),
+                1001.into(),
+                tank_funds
+            ));
+
+            note_container_block(1001.into(), 1, 5);
+
+            let balance_tank = System::account(ServicesPayment::parachain_tank(1001.into()))
+                .data
+                .free;
+            assert_eq!(
+                balance_tank,
+                tank_funds - block_credits_to_required_balance(1, 1001.into())
+            );
+        });
+}
+
+#[test]
+fn test_deregister_para_drains_tank_and_removes_credits() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+            (AccountId::from(DAVE), 100_000 * UNIT),
+        ])
+        .with_collators(vec![
+            (AccountId::from(ALICE), 210 * UNIT),
+            (AccountId::from(BOB), 100 * UNIT),
+        ])
+        .with_para_ids(vec![(1001, empty_genesis_data(), u32::MAX, u32::MAX).into()])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+
+            assert_ok!(ServicesPayment::purchase_credits(
+                origin_of(ALICE.into()),
+                1001.into(),
+                block_credits_to_required_balance(1000, 1001.into())
+            ));
+            assert_ok!(ContainerRegistrar::deregister(root_origin(), 1001.into()));
+
+            // Para id is cleaned up after 2 sessions
+            run_to_session(2u32);
+
+            assert_eq!(
+                pallet_services_payment::BlockProductionCredits::<Runtime>::get(ParaId::from(1001)),
+                None
+            );
+            assert_eq!(
+                pallet_services_payment::CollatorAssignmentCredits::<Runtime>::get(ParaId::from(
+                    1001
+                )),
+                None
+            );
+            assert_eq!(
+                System::account(ServicesPayment::parachain_tank(1001.into()))
+                    .data
+                    .free,
+                0
+            );
+        });
+}
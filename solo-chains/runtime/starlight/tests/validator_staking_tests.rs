@@ -0,0 +1,148 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+#![cfg(test)]
+
+use {
+    crate::common::*,
+    frame_support::{assert_noop, assert_ok},
+    sp_std::vec,
+    starlight_runtime::{MinimumValidatorBond, ValidatorStaking},
+};
+
+mod common;
+
+const UNIT: Balance = 1_000_000_000_000_000_000;
+
+fn set_session_keys(account: [u8; 32]) {
+    let keys = get_authority_keys_from_seed(&AccountId::from(account).to_string());
+    assert_ok!(Session::set_keys(
+        origin_of(account.into()),
+        starlight_runtime::SessionKeys {
+            babe: keys.babe.clone(),
+            grandpa: keys.grandpa.clone(),
+            para_validator: keys.para_validator.clone(),
+            para_assignment: keys.para_assignment.clone(),
+            authority_discovery: keys.authority_discovery.clone(),
+            beefy: keys.beefy.clone(),
+            nimbus: keys.nimbus.clone(),
+        },
+        vec![]
+    ));
+}
+
+fn whitelist_genesis_validators() {
+    assert_ok!(ValidatorStaking::add_whitelisted(
+        root_origin(),
+        AccountId::from(ALICE)
+    ));
+    assert_ok!(ValidatorStaking::add_whitelisted(
+        root_origin(),
+        AccountId::from(BOB)
+    ));
+}
+
+#[test]
+fn empty_whitelist_keeps_genesis_validators() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(2);
+        assert_eq!(
+            Session::validators(),
+            vec![AccountId::from(ALICE), AccountId::from(BOB)]
+        );
+
+        run_to_session(2u32);
+        assert_eq!(
+            Session::validators(),
+            vec![AccountId::from(ALICE), AccountId::from(BOB)]
+        );
+    });
+}
+
+#[test]
+fn bonded_candidate_becomes_validator_after_two_sessions() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+        ])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+            whitelist_genesis_validators();
+
+            set_session_keys(CHARLIE);
+            assert_ok!(ValidatorStaking::bond(
+                origin_of(CHARLIE.into()),
+                MinimumValidatorBond::get()
+            ));
+
+            run_to_session(1u32);
+            assert_eq!(
+                Session::validators(),
+                vec![AccountId::from(ALICE), AccountId::from(BOB)]
+            );
+
+            run_to_session(2u32);
+            assert_eq!(
+                Session::validators(),
+                vec![
+                    AccountId::from(ALICE),
+                    AccountId::from(BOB),
+                    AccountId::from(CHARLIE)
+                ]
+            );
+        });
+}
+
+#[test]
+fn unbonded_candidate_stops_validating() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            // Alice gets 10k extra tokens for her mapping deposit
+            (AccountId::from(ALICE), 210_000 * UNIT),
+            (AccountId::from(BOB), 100_000 * UNIT),
+            (AccountId::from(CHARLIE), 100_000 * UNIT),
+        ])
+        .build()
+        .execute_with(|| {
+            run_to_block(2);
+            whitelist_genesis_validators();
+
+            set_session_keys(CHARLIE);
+            assert_ok!(ValidatorStaking::bond(
+                origin_of(CHARLIE.into()),
+                MinimumValidatorBond::get()
+            ));
+            run_to_session(2u32);
+            assert!(Session::validators().contains(&AccountId::from(CHARLIE)));
+
+            assert_ok!(ValidatorStaking::unbond(origin_of(CHARLIE.into())));
+            // Funds stay bonded until the unbonding delay has passed
+            assert_noop!(
+                ValidatorStaking::withdraw_unbonded(origin_of(CHARLIE.into())),
+                pallet_validator_staking::Error::<Runtime>::UnbondingDelayNotPassed
+            );
+
+            run_to_session(4u32);
+            assert_eq!(
+                Session::validators(),
+                vec![AccountId::from(ALICE), AccountId::from(BOB)]
+            );
+        });
+}
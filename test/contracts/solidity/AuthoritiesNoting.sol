@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The AuthoritiesNoting contract's address.
+address constant AUTHORITIES_NOTING_ADDRESS = 0x0000000000000000000000000000000000000806;
+
+/// @dev The AuthoritiesNoting contract's instance.
+AuthoritiesNoting constant AUTHORITIES_NOTING_CONTRACT = AuthoritiesNoting(
+    AUTHORITIES_NOTING_ADDRESS
+);
+
+/// @author The Moondance Labs Team
+/// @title Authorities Noting Interface
+/// The interface through which solidity contracts will read the collators assigned to this
+/// container chain by the orchestrator
+/// @custom:address 0x0000000000000000000000000000000000000806
+interface AuthoritiesNoting {
+    /// Get the para id of the orchestrator chain
+    /// @custom:selector 6fb7cb1c
+    /// @return paraId The para id of the orchestrator chain
+    function orchestratorParaId() external view returns (uint32 paraId);
+
+    /// Get the nimbus ids of the collators currently assigned to this chain
+    /// @custom:selector 2b0d1816
+    /// @return authorities The sr25519 public keys of the collators
+    function authorities() external view returns (bytes32[] memory authorities);
+
+    /// Check if a nimbus id belongs to one of the collators currently assigned to this chain
+    /// @custom:selector 8748a0c6
+    /// @param nimbusId The sr25519 public key of the collator
+    /// @return isAuthority True if the collator is assigned to this chain
+    function isAuthority(bytes32 nimbusId) external view returns (bool isAuthority);
+}
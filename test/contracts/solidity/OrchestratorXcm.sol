@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The OrchestratorXcm contract's address.
+address constant ORCHESTRATOR_XCM_ADDRESS = 0x0000000000000000000000000000000000000807;
+
+/// @dev The OrchestratorXcm contract's instance.
+OrchestratorXcm constant ORCHESTRATOR_XCM_CONTRACT = OrchestratorXcm(
+    ORCHESTRATOR_XCM_ADDRESS
+);
+
+/// @author The Moondance Labs Team
+/// @title Orchestrator Xcm Interface
+/// The interface through which solidity contracts will send XCM messages that interact with the
+/// orchestrator chain. Messages are sent on behalf of the caller, so the fees and the call are
+/// paid from the account derived from the caller in the orchestrator. Unused fees are refunded to
+/// that account.
+/// @custom:address 0x0000000000000000000000000000000000000807
+interface OrchestratorXcm {
+    /// Buy block production credits for this chain in the orchestrator
+    /// @custom:selector 68f0ceb8
+    /// @param credit The amount of credits to buy
+    /// @param maxFee The amount of orchestrator currency withdrawn to pay for XCM execution
+    /// @param transactRefTime The max ref time of the call in the orchestrator
+    /// @param transactProofSize The max proof size of the call in the orchestrator
+    function purchaseCredits(
+        uint128 credit,
+        uint128 maxFee,
+        uint64 transactRefTime,
+        uint64 transactProofSize
+    ) external;
+
+    /// Register a data preserver profile in the orchestrator. The profile only accepts free
+    /// assignments.
+    /// @custom:selector 23f8b7f8
+    /// @param url The url of the node
+    /// @param paraIdsFilter 0 for any para id, 1 for a whitelist and 2 for a blacklist of `paraIds`
+    /// @param paraIds The para ids of the whitelist or blacklist
+    /// @param mode 0 for a bootnode, 1 for an RPC node and 2 for an RPC node with Ethereum RPCs
+    /// @param maxFee The amount of orchestrator currency withdrawn to pay for XCM execution
+    /// @param transactRefTime The max ref time of the call in the orchestrator
+    /// @param transactProofSize The max proof size of the call in the orchestrator
+    function createProfile(
+        string memory url,
+        uint8 paraIdsFilter,
+        uint32[] memory paraIds,
+        uint8 mode,
+        uint128 maxFee,
+        uint64 transactRefTime,
+        uint64 transactProofSize
+    ) external;
+}
@@ -2,7 +2,12 @@ import "@tanssi/api-augment";
 import { describeSuite, expect, beforeAll } from "@moonwall/cli";
 import { ApiPromise } from "@polkadot/api";
 import { KeyringPair } from "@moonwall/util";
-import { fetchIssuance, filterRewardFromOrchestrator, filterRewardFromContainer } from "util/block";
+import {
+    fetchIssuance,
+    fetchRewardAuthorOrchestrator,
+    filterRewardFromOrchestrator,
+    filterRewardFromContainer,
+} from "util/block";
 import { getAuthorFromDigest } from "util/author";
 import { PARACHAIN_BOND } from "util/constants";
 
@@ -48,9 +53,9 @@ describeSuite({
 
                 const pendingChainRewards = await polkadotJs.query.inflationRewards.chainsToReward();
                 if (pendingChainRewards.isSome) {
-                    const rewardPerChain = pendingChainRewards.unwrap().rewardsPerChain.toBigInt();
-                    const pendingChainsToReward = BigInt(pendingChainRewards.unwrap().paraIds.length);
-                    expectedAmountParachainBond += pendingChainsToReward * rewardPerChain;
+                    for (const [, rewards] of pendingChainRewards.unwrap().rewards) {
+                        expectedAmountParachainBond += rewards.toBigInt();
+                    }
                 }
 
                 const parachainBondBalanceBefore = (
@@ -58,13 +63,13 @@ describeSuite({
                 ).data.free.toBigInt();
                 await context.createBlock();
 
-                const currentChainRewards = await polkadotJs.query.inflationRewards.chainsToReward();
                 const events = await polkadotJs.query.system.events();
                 const issuance = await fetchIssuance(events).amount.toBigInt();
+                // Rewards are split equally, so every chain gets the orchestrator reward
                 let dust = 0n;
-                if (currentChainRewards.isSome) {
-                    const currentRewardPerChain = currentChainRewards.unwrap().rewardsPerChain.toBigInt();
-                    dust = (issuance * 7n) / 10n - 3n * currentRewardPerChain;
+                const orchestratorReward = fetchRewardAuthorOrchestrator(events);
+                if (orchestratorReward !== undefined) {
+                    dust = (issuance * 7n) / 10n - 3n * orchestratorReward.balance.toBigInt();
                 }
                 const parachainBondBalanceAfter = (
                     await polkadotJs.query.system.account(PARACHAIN_BOND)
@@ -89,8 +94,9 @@ describeSuite({
 
                 await context.createBlock();
 
-                const currentChainRewards = (await polkadotJs.query.inflationRewards.chainsToReward()).unwrap();
                 const events = await polkadotJs.query.system.events();
+                // Rewards are split equally, so the container author gets the orchestrator reward
+                const rewardPerChain = fetchRewardAuthorOrchestrator(events).balance.toBigInt();
                 const receivedRewardCharlie = filterRewardFromContainer(events, charlie.address, 2000);
 
                 const balacharlieBalanceBeforenceAfter = (
@@ -98,9 +104,7 @@ describeSuite({
                 ).data.free.toBigInt();
 
                 // Not sure where this one comes from, looks like a rounding thing
-                expect(balacharlieBalanceBeforenceAfter - balacharlieBalanceBeforenceBefore).to.equal(
-                    currentChainRewards.rewardsPerChain.toBigInt()
-                );
+                expect(balacharlieBalanceBeforenceAfter - balacharlieBalanceBeforenceBefore).to.equal(rewardPerChain);
 
                 // Not sure where this one comes from, looks like a rounding thing
                 expect(balacharlieBalanceBeforenceAfter - balacharlieBalanceBeforenceBefore).to.equal(
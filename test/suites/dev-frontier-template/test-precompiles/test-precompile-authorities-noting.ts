@@ -0,0 +1,75 @@
+import "@tanssi/api-augment";
+import { describeSuite, expect } from "@moonwall/cli";
+import { u8aToHex } from "@polkadot/util";
+
+const AUTHORITIES_NOTING_ADDRESS = "0x0000000000000000000000000000000000000806";
+
+describeSuite({
+    id: "DF1116",
+    title: "Precompiles - authorities noting",
+    foundationMethods: "dev",
+    testCases: ({ context, it }) => {
+        it({
+            id: "T01",
+            title: "allows to read the orchestrator para id",
+            test: async function () {
+                const orchestratorParaId = (
+                    await context.polkadotJs().query.authoritiesNoting.orchestratorParaId()
+                ).toNumber();
+
+                expect(
+                    await context.readContract!({
+                        contractAddress: AUTHORITIES_NOTING_ADDRESS,
+                        contractName: "AuthoritiesNoting",
+                        functionName: "orchestratorParaId",
+                        args: [],
+                    })
+                ).to.equal(orchestratorParaId);
+            },
+        });
+
+        it({
+            id: "T02",
+            title: "allows to read the authorities",
+            test: async function () {
+                const authorities = (await context.polkadotJs().query.authoritiesNoting.authorities()).map(
+                    (authority) => u8aToHex(authority.toU8a())
+                );
+
+                const result = (await context.readContract!({
+                    contractAddress: AUTHORITIES_NOTING_ADDRESS,
+                    contractName: "AuthoritiesNoting",
+                    functionName: "authorities",
+                    args: [],
+                })) as string[];
+                expect(result).to.deep.equal(authorities);
+
+                for (const authority of authorities) {
+                    expect(
+                        await context.readContract!({
+                            contractAddress: AUTHORITIES_NOTING_ADDRESS,
+                            contractName: "AuthoritiesNoting",
+                            functionName: "isAuthority",
+                            args: [authority],
+                        })
+                    ).to.be.true;
+                }
+            },
+        });
+
+        it({
+            id: "T03",
+            title: "returns false for unknown authorities",
+            test: async function () {
+                expect(
+                    await context.readContract!({
+                        contractAddress: AUTHORITIES_NOTING_ADDRESS,
+                        contractName: "AuthoritiesNoting",
+                        functionName: "isAuthority",
+                        args: ["0x" + "ff".repeat(32)],
+                    })
+                ).to.be.false;
+            },
+        });
+    },
+});
@@ -3,7 +3,12 @@ import { ApiPromise } from "@polkadot/api";
 
 import { ApiDecoration } from "@polkadot/api/types";
 import { getAuthorFromDigest } from "util/author";
-import { fetchIssuance, filterRewardFromOrchestratorWithFailure, fetchRewardAuthorContainers } from "util/block";
+import {
+    fetchIssuance,
+    filterRewardFromOrchestratorWithFailure,
+    fetchRewardAuthorContainers,
+    fetchRewardAuthorOrchestrator,
+} from "util/block";
 import { PARACHAIN_BOND } from "util/constants";
 
 describeSuite({
@@ -137,24 +142,24 @@ describeSuite({
                 );
 
                 if (pendingChainRewards.isSome) {
-                    const rewardPerChain = pendingChainRewards.unwrap().rewardsPerChain.toBigInt();
-                    const pendingChainsToReward = BigInt(pendingChainRewards.unwrap().paraIds.length);
-                    expectedAmountParachainBond += pendingChainsToReward * rewardPerChain;
+                    for (const [, rewards] of pendingChainRewards.unwrap().rewards) {
+                        expectedAmountParachainBond += rewards.toBigInt();
+                    }
                 }
 
                 const parachainBondBalanceBefore = (
                     await apiAtIssuanceBefore.query.system.account(PARACHAIN_BOND)
                 ).data.free.toBigInt();
 
-                const currentChainRewards = await apiAtIssuanceAfter.query.inflationRewards.chainsToReward();
                 const events = await apiAtIssuanceAfter.query.system.events();
                 const issuance = await fetchIssuance(events).amount.toBigInt();
 
                 // Dust from computations also goes to parachainBond
+                // Rewards are split equally, so every chain gets the orchestrator reward
                 let dust = 0n;
-                if (currentChainRewards.isSome) {
-                    const currentRewardPerChain = currentChainRewards.unwrap().rewardsPerChain.toBigInt();
-                    dust = (issuance * 7n) / 10n - numberOfChains * currentRewardPerChain;
+                const orchestratorReward = fetchRewardAuthorOrchestrator(events);
+                if (orchestratorReward !== undefined) {
+                    dust = (issuance * 7n) / 10n - numberOfChains * orchestratorReward.balance.toBigInt();
                 }
                 const parachainBondBalanceAfter = (
                     await apiAtIssuanceAfter.query.system.account(PARACHAIN_BOND)
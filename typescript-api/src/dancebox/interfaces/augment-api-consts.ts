@@ -121,12 +121,10 @@ declare module "@polkadot/api-base/types/consts" {
             [key: string]: Codec;
         };
         inflationRewards: {
-            /** Inflation rate per orchestrator block (proportion of the total issuance) */
-            inflationRate: Perbill & AugmentedConst<ApiType>;
+            /** Number of orchestrator blocks in a year, used to decay the inflation schedule */
+            blocksPerYear: u32 & AugmentedConst<ApiType>;
             /** The account that will store rewards waiting to be paid out */
             pendingRewardsAccount: AccountId32 & AugmentedConst<ApiType>;
-            /** Proportion of the new supply dedicated to staking */
-            rewardsPortion: Perbill & AugmentedConst<ApiType>;
             /** Generic const */
             [key: string]: Codec;
         };
@@ -4299,8 +4299,7 @@ export default {
     },
     /** Lookup501: pallet_inflation_rewards::pallet::ChainsToRewardValue<T> */
     PalletInflationRewardsChainsToRewardValue: {
-        paraIds: "Vec<u32>",
-        rewardsPerChain: "u128",
+        rewards: "Vec<(u32,u128)>",
     },
     /** Lookup502: pallet_treasury::Proposal<sp_core::crypto::AccountId32, Balance> */
     PalletTreasuryProposal: {
@@ -5876,8 +5876,7 @@ declare module "@polkadot/types/lookup" {
 
     /** @name PalletInflationRewardsChainsToRewardValue (501) */
     interface PalletInflationRewardsChainsToRewardValue extends Struct {
-        readonly paraIds: Vec<u32>;
-        readonly rewardsPerChain: u128;
+        readonly rewards: Vec<ITuple<[u32, u128]>>;
     }
 
     /** @name PalletTreasuryProposal (502) */
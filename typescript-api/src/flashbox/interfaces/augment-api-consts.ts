@@ -8,7 +8,7 @@ import "@polkadot/api-base/types/consts";
 import type { ApiTypes, AugmentedConst } from "@polkadot/api-base/types";
 import type { Option, u128, u16, u32, u64, u8 } from "@polkadot/types-codec";
 import type { Codec } from "@polkadot/types-codec/types";
-import type { AccountId32, Perbill, Permill } from "@polkadot/types/interfaces/runtime";
+import type { AccountId32, Permill } from "@polkadot/types/interfaces/runtime";
 import type {
     FrameSupportPalletId,
     FrameSystemLimitsBlockLength,
@@ -97,12 +97,10 @@ declare module "@polkadot/api-base/types/consts" {
             [key: string]: Codec;
         };
         inflationRewards: {
-            /** Inflation rate per orchestrator block (proportion of the total issuance) */
-            inflationRate: Perbill & AugmentedConst<ApiType>;
+            /** Number of orchestrator blocks in a year, used to decay the inflation schedule */
+            blocksPerYear: u32 & AugmentedConst<ApiType>;
             /** The account that will store rewards waiting to be paid out */
             pendingRewardsAccount: AccountId32 & AugmentedConst<ApiType>;
-            /** Proportion of the new supply dedicated to staking */
-            rewardsPortion: Perbill & AugmentedConst<ApiType>;
             /** Generic const */
             [key: string]: Codec;
         };
@@ -2906,8 +2906,7 @@ declare module "@polkadot/types/lookup" {
 
     /** @name PalletInflationRewardsChainsToRewardValue (349) */
     interface PalletInflationRewardsChainsToRewardValue extends Struct {
-        readonly paraIds: Vec<u32>;
-        readonly rewardsPerChain: u128;
+        readonly rewards: Vec<ITuple<[u32, u128]>>;
     }
 
     /** @name PalletTreasuryProposal (350) */